@@ -0,0 +1,143 @@
+//! Compares deep and copy-on-write copies of insertion context built for bundled problems: the copy
+//! is either left intact or one of its routes is modified as operators usually do. Amount of heap
+//! allocations per copy is reported before timing measurements.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use vrp_pragmatic::core::construction::heuristics::InsertionContext;
+use vrp_pragmatic::core::prelude::Problem;
+use vrp_pragmatic::core::rosomaxa::evolution::TelemetryMode;
+use vrp_pragmatic::core::rosomaxa::prelude::HeuristicSolution;
+use vrp_pragmatic::core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_pragmatic::core::solver::{create_elitism_population, RefinementContext};
+use vrp_pragmatic::core::utils::Environment;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::FormatError;
+
+const PROBLEMS: &[(&str, &str)] = &[
+    ("100 trivial deliveries", "../data/pragmatic/benches/simple.deliveries.100.json"),
+    ("50 multi jobs", "../data/pragmatic/benches/multi-job.100.json"),
+];
+
+/// An allocator which counts amount of allocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Clone, Copy)]
+enum CopyType {
+    Deep,
+    CopyOnWrite,
+}
+
+impl CopyType {
+    fn name(&self) -> &str {
+        match self {
+            Self::Deep => "deep",
+            Self::CopyOnWrite => "cow",
+        }
+    }
+
+    fn copy(&self, insertion_ctx: &InsertionContext, is_modified: bool) -> InsertionContext {
+        let mut insertion_ctx = match self {
+            Self::Deep => insertion_ctx.deep_copy(),
+            Self::CopyOnWrite => insertion_ctx.cow_copy(),
+        };
+
+        if is_modified {
+            if let Some(route_ctx) = insertion_ctx.solution.routes.first_mut() {
+                let job = route_ctx.route.tour.jobs().next();
+                if let Some(job) = job {
+                    route_ctx.route_mut().tour.remove(&job);
+                }
+            }
+        }
+
+        insertion_ctx
+    }
+}
+
+fn get_problem(problem_path: &str) -> Arc<Problem> {
+    let file = File::open(problem_path).unwrap_or_else(|err| panic!("cannot open {} file: '{}'", problem_path, err));
+    Arc::new(BufReader::new(file).read_pragmatic().unwrap_or_else(|errs| {
+        panic!("cannot create pragmatic problem: {}", FormatError::format_many(errs.as_slice(), ","))
+    }))
+}
+
+/// Creates a solution using cheapest insertion heuristic.
+fn create_solution(problem_path: &str) -> InsertionContext {
+    let problem = get_problem(problem_path);
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        create_elitism_population(problem.objective.clone(), environment.clone()),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+
+    RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment))
+}
+
+fn count_allocations(insertion_ctx: &InsertionContext, copy_type: CopyType, is_modified: bool) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let copy = copy_type.copy(insertion_ctx, is_modified);
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(copy);
+
+    after - before
+}
+
+fn copy_benchmark(c: &mut Criterion) {
+    let solutions = PROBLEMS.iter().map(|(name, path)| (*name, create_solution(path))).collect::<Vec<_>>();
+    let cases = [CopyType::Deep, CopyType::CopyOnWrite]
+        .into_iter()
+        .flat_map(|copy_type| [(copy_type, false), (copy_type, true)])
+        .collect::<Vec<_>>();
+
+    solutions.iter().for_each(|(name, insertion_ctx)| {
+        println!("{} ({} routes), allocations per copy:", name, insertion_ctx.solution.routes.len());
+        cases.iter().for_each(|&(copy_type, is_modified)| {
+            println!(
+                "  {:<4} {:<10} {}",
+                copy_type.name(),
+                if is_modified { "modified" } else { "intact" },
+                count_allocations(insertion_ctx, copy_type, is_modified)
+            );
+        });
+    });
+
+    solutions.iter().for_each(|(name, insertion_ctx)| {
+        let mut group = c.benchmark_group(format!("copy of solution for {}", name));
+        cases.iter().for_each(|&(copy_type, is_modified)| {
+            let id = format!("{}/{}", copy_type.name(), if is_modified { "modified" } else { "intact" });
+            group.bench_function(id, |b| b.iter(|| black_box(copy_type.copy(insertion_ctx, black_box(is_modified)))));
+        });
+        group.finish();
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(50);
+    targets = copy_benchmark
+}
+criterion_main!(benches);
@@ -6,6 +6,8 @@ use vrp_pragmatic::core::construction::heuristics::InsertionContext;
 use vrp_pragmatic::core::models::Solution;
 use vrp_pragmatic::core::prelude::{create_default_config_builder, Problem, Solver};
 use vrp_pragmatic::core::rosomaxa::evolution::TelemetryMode;
+use vrp_pragmatic::core::rosomaxa::population::{HeuristicPopulation, Rosomaxa, RosomaxaConfig};
+use vrp_pragmatic::core::rosomaxa::prelude::{HeuristicSolution, HeuristicStatistics};
 use vrp_pragmatic::core::solver::search::{Recreate, RecreateWithCheapest};
 use vrp_pragmatic::core::solver::{create_elitism_population, RefinementContext};
 use vrp_pragmatic::core::utils::Environment;
@@ -36,6 +38,21 @@ fn solve_problem_with_max_generations(problem_path: &str, generations: usize) ->
     solution
 }
 
+/// Runs solver till the first generation, so most of the time is spent on building initial population.
+fn solve_problem_till_first_generation(problem: Arc<Problem>, is_parallel: bool) -> Solution {
+    let (solution, _, _) =
+        create_default_config_builder(problem.clone(), Arc::new(Environment::default()), TelemetryMode::None)
+            .with_max_generations(Some(1))
+            .with_initial_parallelism(is_parallel)
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+            .solve()
+            .unwrap_or_else(|err| panic!("cannot solver problem: {}", err));
+
+    solution
+}
+
 /// Solve problem using cheapest insertion heuristic and returns one solution.
 fn solve_problem_with_init(problem_path: &str) {
     let problem = get_problem(problem_path);
@@ -52,6 +69,48 @@ fn solve_problem_with_init(problem_path: &str) {
         .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment));
 }
 
+/// Creates multiple initial solutions using cheapest insertion heuristic.
+fn create_init_solutions(problem_path: &str, amount: usize) -> (Arc<Problem>, Vec<InsertionContext>) {
+    let problem = get_problem(problem_path);
+
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        create_elitism_population(problem.objective.clone(), environment.clone()),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+
+    let solutions = (0..amount)
+        .map(|_| {
+            RecreateWithCheapest::new(environment.random.clone())
+                .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment.clone()))
+        })
+        .collect();
+
+    (problem, solutions)
+}
+
+/// Feeds rosomaxa population with given solutions for specific amount of generations. As network
+/// is frequently optimized, fitness of stored individuals is requested many times.
+fn run_rosomaxa_population(problem: Arc<Problem>, solutions: &[InsertionContext], generations: usize) -> usize {
+    let environment = Arc::new(Environment::default());
+    let config = RosomaxaConfig { rebalance_memory: 5, ..RosomaxaConfig::new_with_defaults(4) };
+    let mut population = Rosomaxa::new(problem.objective.clone(), environment, config)
+        .unwrap_or_else(|err| panic!("cannot create population: {}", err));
+
+    (0..generations).for_each(|generation| {
+        population.add_all(solutions.iter().map(|solution| solution.deep_copy()).collect());
+        population.on_generation(&HeuristicStatistics {
+            generation,
+            termination_estimate: generation as f64 / generations as f64,
+            ..HeuristicStatistics::default()
+        });
+    });
+
+    population.size()
+}
+
 fn simple_deliveries_100_benchmark(c: &mut Criterion) {
     c.bench_function("a problem with 100 trivial deliveries", |b| {
         b.iter(|| {
@@ -86,6 +145,26 @@ fn init_multi_job_100_benchmark(c: &mut Criterion) {
     });
 }
 
+fn initial_population_multi_job_100_benchmark(c: &mut Criterion) {
+    let problem = get_problem("../data/pragmatic/benches/multi-job.100.json");
+
+    let mut group = c.benchmark_group("time to first generation for a problem with 50 multi jobs");
+    [("sequential", false), ("parallel", true)].into_iter().for_each(|(name, is_parallel)| {
+        group.bench_function(name, |b| {
+            b.iter(|| black_box(solve_problem_till_first_generation(problem.clone(), black_box(is_parallel))))
+        });
+    });
+    group.finish();
+}
+
+fn rosomaxa_population_deliveries_100_benchmark(c: &mut Criterion) {
+    let (problem, solutions) = create_init_solutions("../data/pragmatic/benches/simple.deliveries.100.json", 8);
+
+    c.bench_function("rosomaxa population with network optimization for 100 trivial deliveries", |b| {
+        b.iter(|| black_box(run_rosomaxa_population(problem.clone(), solutions.as_slice(), black_box(50))))
+    });
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(15);
@@ -93,6 +172,8 @@ criterion_group! {
               simple_reload_100_benchmark,
               simple_multi_job_100_benchmark,
               init_deliveries_100_benchmark,
-              init_multi_job_100_benchmark
+              init_multi_job_100_benchmark,
+              initial_population_multi_job_100_benchmark,
+              rosomaxa_population_deliveries_100_benchmark
 }
 criterion_main!(benches);
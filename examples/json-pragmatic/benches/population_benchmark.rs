@@ -0,0 +1,128 @@
+//! Compares rosomaxa population against plain elitism: both populations are driven by the same
+//! default heuristic on the same bundled problems with the same random seeds and fixed generation
+//! budget. Best costs and their gap are reported before timing measurements.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_pragmatic::core::models::common::Cost;
+use vrp_pragmatic::core::prelude::{create_default_config_builder, Problem, Solver};
+use vrp_pragmatic::core::rosomaxa::evolution::TelemetryMode;
+use vrp_pragmatic::core::rosomaxa::population::{Elitism, RosomaxaConfig};
+use vrp_pragmatic::core::solver::{RefinementContext, RosomaxaPopulation, TargetPopulation};
+use vrp_pragmatic::core::utils::{DefaultRandom, Environment};
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::FormatError;
+
+const GENERATIONS: usize = 100;
+const SELECTION_SIZE: usize = 4;
+const SEEDS: &[u64] = &[7, 42, 1024];
+const PROBLEMS: &[(&str, &str)] = &[
+    ("100 trivial deliveries", "../data/pragmatic/benches/simple.deliveries.100.json"),
+    ("100 trivial deliveries and one reload", "../data/pragmatic/benches/simple.reload.100.json"),
+    ("50 multi jobs", "../data/pragmatic/benches/multi-job.100.json"),
+];
+
+#[derive(Clone, Copy)]
+enum PopulationType {
+    Rosomaxa,
+    Elitism,
+}
+
+impl PopulationType {
+    fn name(&self) -> &str {
+        match self {
+            Self::Rosomaxa => "rosomaxa",
+            Self::Elitism => "elitism",
+        }
+    }
+}
+
+fn get_problem(problem_path: &str) -> Arc<Problem> {
+    let file = File::open(problem_path).unwrap_or_else(|err| panic!("cannot open {} file: '{}'", problem_path, err));
+    Arc::new(BufReader::new(file).read_pragmatic().unwrap_or_else(|errs| {
+        panic!("cannot create pragmatic problem: {}", FormatError::format_many(errs.as_slice(), ","))
+    }))
+}
+
+/// Runs default heuristic with given population type and returns the best known cost.
+fn solve_with_population(
+    problem: Arc<Problem>,
+    population_type: PopulationType,
+    seed: u64,
+    generations: usize,
+) -> Cost {
+    let environment = Arc::new(Environment {
+        random: Arc::new(DefaultRandom::with_seed(seed)),
+        logger: Arc::new(|_| {}),
+        ..Environment::default()
+    });
+    let population: TargetPopulation = match population_type {
+        PopulationType::Rosomaxa => Box::new(
+            RosomaxaPopulation::new(
+                problem.objective.clone(),
+                environment.clone(),
+                RosomaxaConfig::new_with_defaults(SELECTION_SIZE),
+            )
+            .unwrap_or_else(|err| panic!("cannot create population: {}", err)),
+        ),
+        PopulationType::Elitism => {
+            Box::new(Elitism::new(problem.objective.clone(), environment.random.clone(), 4, SELECTION_SIZE))
+        }
+    };
+
+    let (_, cost, _) = create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+        .with_context(RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment))
+        .with_max_generations(Some(generations))
+        .build()
+        .map(|config| Solver::new(problem, config))
+        .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+        .solve()
+        .unwrap_or_else(|err| panic!("cannot solve problem: {}", err));
+
+    cost
+}
+
+/// Reports mean best costs of both populations and relative gap of rosomaxa against elitism.
+/// Positive gap means that rosomaxa finds cheaper solutions.
+fn report_gap(name: &str, problem: Arc<Problem>) {
+    let get_mean_cost = |population_type: PopulationType| {
+        SEEDS
+            .iter()
+            .map(|&seed| solve_with_population(problem.clone(), population_type, seed, GENERATIONS))
+            .sum::<Cost>()
+            / SEEDS.len() as f64
+    };
+
+    let rosomaxa = get_mean_cost(PopulationType::Rosomaxa);
+    let elitism = get_mean_cost(PopulationType::Elitism);
+    let gap = if elitism > 0. { (elitism - rosomaxa) / elitism * 100. } else { 0. };
+
+    println!(
+        "{} at {} generations: rosomaxa best cost {:.3}, elitism best cost {:.3}, gap {:.2}%",
+        name, GENERATIONS, rosomaxa, elitism, gap
+    );
+}
+
+fn rosomaxa_vs_elitism_benchmark(c: &mut Criterion) {
+    PROBLEMS.iter().for_each(|(name, path)| {
+        let problem = get_problem(path);
+        report_gap(name, problem.clone());
+
+        let mut group = c.benchmark_group(format!("rosomaxa vs elitism for a problem with {}", name));
+        [PopulationType::Rosomaxa, PopulationType::Elitism].into_iter().for_each(|population_type| {
+            group.bench_function(population_type.name(), |b| {
+                b.iter(|| solve_with_population(problem.clone(), population_type, SEEDS[0], black_box(GENERATIONS)))
+            });
+        });
+        group.finish();
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = rosomaxa_vs_elitism_benchmark
+}
+criterion_main!(benches);
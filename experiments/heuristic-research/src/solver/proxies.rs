@@ -142,6 +142,14 @@ where
         }))
     }
 
+    fn select_n<'a>(&'a self, n: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        Box::new(self.inner.select_n(n).map(|individual| {
+            self.acquire().on_select.entry(self.generation).or_insert_with(Vec::new).push(individual.into());
+
+            individual
+        }))
+    }
+
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a> {
         self.inner.ranked()
     }
@@ -150,6 +158,14 @@ where
         self.inner.all()
     }
 
+    fn sample_diverse<'a>(&'a self, k: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        self.inner.sample_diverse(k)
+    }
+
+    fn revalidate(&mut self, revalidate_fn: &dyn Fn(&mut Self::Individual)) {
+        self.inner.revalidate(revalidate_fn)
+    }
+
     fn size(&self) -> usize {
         self.inner.size()
     }
@@ -157,6 +173,10 @@ where
     fn selection_phase(&self) -> SelectionPhase {
         self.inner.selection_phase()
     }
+
+    fn settings(&self) -> Option<PopulationSettings> {
+        self.inner.settings()
+    }
 }
 
 impl<P, O, S> Display for ProxyPopulation<P, O, S>
@@ -4,10 +4,13 @@ mod network_test;
 
 use super::*;
 use crate::utils::{compare_floats, parallel_into_collect, Noise, Random};
-use hashbrown::HashMap;
 use rand::prelude::SliceRandom;
+use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Write};
 use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 
 /// A customized Growing Self Organizing Map designed to store and retrieve trained input.
@@ -25,9 +28,15 @@ where
     distribution_factor: f64,
     learning_rate: f64,
     time: usize,
+    last_growth: Option<usize>,
+    is_growth_frozen: bool,
     rebalance_memory: usize,
+    clamp_inputs: bool,
+    neighbourhood: NeighbourhoodFunction,
     min_max_weights: MinMaxWeights,
-    nodes: HashMap<Coordinate, NodeLink<I, S>>,
+    /// Nodes ordered by their coordinate.
+    nodes: BTreeMap<Coordinate, NodeLink<I, S>>,
+    random: Arc<dyn Random + Send + Sync>,
     storage_factory: F,
 }
 
@@ -43,33 +52,161 @@ pub struct NetworkConfig {
     pub rebalance_memory: usize,
     /// If set to true, initial nodes have error set to the value equal to growing threshold.
     pub has_initial_error: bool,
+    /// If set to true, input weights are clamped to the range of node weights observed so far.
+    pub clamp_inputs: bool,
+    /// A neighbourhood function used to adjust weights of best matching unit's neighbours.
+    pub neighbourhood: NeighbourhoodFunction,
+}
+
+/// Specifies how strongly neighbours of the best matching unit adapt toward an input.
+/// Distance between nodes is measured as manhattan distance on the grid.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeighbourhoodFunction {
+    /// Learning rate of a neighbour is divided by its distance to the best matching unit.
+    #[default]
+    InverseDistance,
+    /// Learning rate of a neighbour decays as gaussian function of its distance with given sigma.
+    Gaussian {
+        /// A width of gaussian kernel, must be positive.
+        sigma: f64,
+    },
+    /// All neighbours within given radius adapt with the same learning rate.
+    Bubble {
+        /// A neighbourhood radius, must be positive.
+        radius: usize,
+    },
+}
+
+impl NeighbourhoodFunction {
+    /// Checks whether function parameters are valid.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::InverseDistance => true,
+            Self::Gaussian { sigma } => sigma.is_finite() && *sigma > 0.,
+            Self::Bubble { radius } => *radius > 0,
+        }
+    }
+
+    /// Returns neighbourhood radius. Default radius is used when function has no own radius.
+    pub fn radius(&self, default: usize) -> usize {
+        match self {
+            Self::Bubble { radius } => *radius,
+            _ => default,
+        }
+    }
+
+    /// Returns learning rate factor for a neighbour at given distance.
+    pub fn factor(&self, distance: usize) -> f64 {
+        match self {
+            Self::InverseDistance => 1. / distance as f64,
+            Self::Gaussian { sigma } => (-((distance * distance) as f64) / (2. * sigma * sigma)).exp(),
+            Self::Bubble { .. } => 1.,
+        }
+    }
+}
+
+/// Keeps learned topology of the network: node coordinates and their weights, but not stored data.
+/// Can be used to warm-start a network for a structurally similar problem.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LearnedNetwork {
+    /// Nodes as coordinate and weights pairs sorted by coordinate.
+    pub nodes: Vec<(Coordinate, Vec<f64>)>,
+}
+
+/// Keeps network topology together with node weights, hit counters and growth parameters, but not
+/// stored data. Can be used to checkpoint the network and resume learning later.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkSnapshot {
+    /// Data dimension.
+    pub dimension: usize,
+    /// Growth threshold.
+    pub growing_threshold: f64,
+    /// The factor of distribution.
+    pub distribution_factor: f64,
+    /// Current learning rate.
+    pub learning_rate: f64,
+    /// Current time.
+    pub time: usize,
+    /// Time of the last growth.
+    pub last_growth: Option<usize>,
+    /// Whether network growth is frozen.
+    pub is_growth_frozen: bool,
+    /// A rebalance memory.
+    pub rebalance_memory: usize,
+    /// Whether input weights are clamped.
+    pub clamp_inputs: bool,
+    /// A neighbourhood function.
+    pub neighbourhood: NeighbourhoodFunction,
+    /// Min and max weights observed so far.
+    pub min_max_weights: (Vec<f64>, Vec<f64>),
+    /// Nodes sorted by coordinate.
+    pub nodes: Vec<NodeSnapshot>,
+}
+
+/// Keeps node state without stored data.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeSnapshot {
+    /// A coordinate in network.
+    pub coordinate: Coordinate,
+    /// A weight vector.
+    pub weights: Vec<f64>,
+    /// An error of the node.
+    pub error: f64,
+    /// Amount of times node is selected as BU.
+    pub total_hits: usize,
+    /// Last hits.
+    pub last_hits: Vec<usize>,
+}
+
+#[cfg(feature = "checkpoint")]
+impl NetworkSnapshot {
+    /// Serializes snapshot into a byte buffer.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(self).map_err(|err| format!("cannot serialize network snapshot: {}", err))
+    }
+
+    /// Deserializes snapshot from a byte buffer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(bytes).map_err(|err| format!("cannot deserialize network snapshot: {}", err))
+    }
 }
 
 /// Specifies min max weights type.
 type MinMaxWeights = (Vec<f64>, Vec<f64>);
 
+/// Specifies input training data: best matching unit, its error, input weights and input itself.
+type TrainingData<I, S> = (NodeLink<I, S>, f64, Vec<f64>, I);
+
 impl<I, S, F> Network<I, S, F>
 where
     I: Input,
     S: Storage<Item = I>,
     F: StorageFactory<I, S>,
 {
-    /// Creates a new instance of `Network`.
+    /// Creates a new instance of `Network`. Initial nodes are created from given roots and placed
+    /// row by row in a snake order on a grid which is as close to a square as possible. Roots are
+    /// expected to have finite weights of the same dimension.
     pub fn new(
-        roots: [I; 4],
+        roots: Vec<I>,
         config: NetworkConfig,
         random: Arc<dyn Random + Send + Sync>,
         storage_factory: F,
     ) -> Self {
+        assert!(!roots.is_empty());
         let dimension = roots[0].weights().len();
 
         assert!(roots.iter().all(|r| r.weights().len() == dimension));
+        assert!(roots.iter().all(|r| r.weights().iter().all(|value| value.is_finite())));
         assert!(config.distribution_factor > 0. && config.distribution_factor < 1.);
         assert!(config.spread_factor > 0. && config.spread_factor < 1.);
+        assert!(config.neighbourhood.is_valid());
 
         let growing_threshold = -1. * dimension as f64 * config.spread_factor.log2();
         let initial_error = if config.has_initial_error { growing_threshold } else { 0. };
-        let noise = Noise::new(1., (0.75, 1.25), random);
+        let noise = Noise::new(1., (0.75, 1.25), random.clone());
 
         let (nodes, min_max_weights) =
             Self::create_initial_nodes(roots, initial_error, config.rebalance_memory, &noise, &storage_factory);
@@ -80,11 +217,220 @@ where
             distribution_factor: config.distribution_factor,
             learning_rate: config.learning_rate,
             time: 0,
+            last_growth: None,
+            is_growth_frozen: false,
             rebalance_memory: config.rebalance_memory,
+            clamp_inputs: config.clamp_inputs,
+            neighbourhood: config.neighbourhood,
             min_max_weights,
             nodes,
+            random,
+            storage_factory,
+        }
+    }
+
+    /// Creates a new instance of `Network` from previously learned network. Nodes have no data.
+    pub fn from_learned(
+        learned: &LearnedNetwork,
+        config: NetworkConfig,
+        random: Arc<dyn Random + Send + Sync>,
+        storage_factory: F,
+    ) -> Result<Self, String> {
+        let dimension = learned.nodes.first().map(|(_, weights)| weights.len()).ok_or("learned network is empty")?;
+
+        if dimension == 0 || learned.nodes.iter().any(|(_, weights)| weights.len() != dimension) {
+            return Err("learned network has inconsistent weights dimension".to_string());
+        }
+
+        let is_in_range = |value: f64| value > 0. && value < 1.;
+        if !is_in_range(config.distribution_factor)
+            || !is_in_range(config.spread_factor)
+            || !config.neighbourhood.is_valid()
+        {
+            return Err("invalid network configuration".to_string());
+        }
+
+        let growing_threshold = -(dimension as f64) * config.spread_factor.log2();
+        let initial_error = if config.has_initial_error { growing_threshold } else { 0. };
+
+        let mut network = Self {
+            dimension,
+            growing_threshold,
+            distribution_factor: config.distribution_factor,
+            learning_rate: config.learning_rate,
+            time: 0,
+            last_growth: None,
+            is_growth_frozen: false,
+            rebalance_memory: config.rebalance_memory,
+            clamp_inputs: config.clamp_inputs,
+            neighbourhood: config.neighbourhood,
+            min_max_weights: (vec![f64::MAX; dimension], vec![f64::MIN; dimension]),
+            nodes: Default::default(),
+            random,
             storage_factory,
+        };
+
+        learned.nodes.iter().for_each(|(coordinate, weights)| {
+            update_min_max(&mut network.min_max_weights, weights.as_slice());
+            let node = network.create_node(*coordinate, weights.as_slice(), initial_error);
+            network.nodes.insert(*coordinate, Arc::new(RwLock::new(node)));
+        });
+
+        Ok(network)
+    }
+
+    /// Exports learned topology and node weights of the network.
+    pub fn export_learned(&self) -> LearnedNetwork {
+        let mut nodes = self
+            .nodes
+            .iter()
+            .map(|(coordinate, node)| (*coordinate, node.read().unwrap().weights.clone()))
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|(Coordinate(x, y), _)| (*x, *y));
+
+        LearnedNetwork { nodes }
+    }
+
+    /// Saves topology of the network: node coordinates, weights, hit counters and growth parameters.
+    /// Stored data is not saved.
+    pub fn save_topology(&self) -> NetworkSnapshot {
+        let nodes = self
+            .iter()
+            .map(|(coordinate, node)| {
+                let node = node.read().unwrap();
+                NodeSnapshot {
+                    coordinate: *coordinate,
+                    weights: node.weights.clone(),
+                    error: node.error,
+                    total_hits: node.total_hits,
+                    last_hits: node.last_hits.iter().cloned().collect(),
+                }
+            })
+            .collect();
+
+        NetworkSnapshot {
+            dimension: self.dimension,
+            growing_threshold: self.growing_threshold,
+            distribution_factor: self.distribution_factor,
+            learning_rate: self.learning_rate,
+            time: self.time,
+            last_growth: self.last_growth,
+            is_growth_frozen: self.is_growth_frozen,
+            rebalance_memory: self.rebalance_memory,
+            clamp_inputs: self.clamp_inputs,
+            neighbourhood: self.neighbourhood.clone(),
+            min_max_weights: self.min_max_weights.clone(),
+            nodes,
+        }
+    }
+
+    /// Restores a network from previously saved topology. Nodes get empty storages created by
+    /// given storage factory.
+    pub fn restore_topology(
+        snapshot: NetworkSnapshot,
+        random: Arc<dyn Random + Send + Sync>,
+        storage_factory: F,
+    ) -> Result<Self, String> {
+        let dimension = snapshot.dimension;
+
+        if snapshot.nodes.is_empty() {
+            return Err("network snapshot is empty".to_string());
+        }
+
+        let has_invalid_dimension = |weights: &[f64]| weights.len() != dimension;
+        if dimension == 0
+            || snapshot.nodes.iter().any(|node| has_invalid_dimension(node.weights.as_slice()))
+            || has_invalid_dimension(snapshot.min_max_weights.0.as_slice())
+            || has_invalid_dimension(snapshot.min_max_weights.1.as_slice())
+        {
+            return Err("network snapshot has inconsistent weights dimension".to_string());
+        }
+
+        if !snapshot.neighbourhood.is_valid() {
+            return Err("network snapshot has invalid neighbourhood".to_string());
         }
+
+        let nodes = snapshot
+            .nodes
+            .into_iter()
+            .map(|node_snapshot| {
+                let mut node = Node::new(
+                    node_snapshot.coordinate,
+                    node_snapshot.weights.as_slice(),
+                    node_snapshot.error,
+                    snapshot.rebalance_memory,
+                    storage_factory.eval(),
+                );
+                node.total_hits = node_snapshot.total_hits;
+                node.last_hits = node_snapshot.last_hits.into_iter().collect();
+
+                (node_snapshot.coordinate, Arc::new(RwLock::new(node)))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(Self {
+            dimension,
+            growing_threshold: snapshot.growing_threshold,
+            distribution_factor: snapshot.distribution_factor,
+            learning_rate: snapshot.learning_rate,
+            time: snapshot.time,
+            last_growth: snapshot.last_growth,
+            is_growth_frozen: snapshot.is_growth_frozen,
+            rebalance_memory: snapshot.rebalance_memory,
+            clamp_inputs: snapshot.clamp_inputs,
+            neighbourhood: snapshot.neighbourhood,
+            min_max_weights: snapshot.min_max_weights,
+            nodes,
+            random,
+            storage_factory,
+        })
+    }
+
+    /// Exports the network in GraphViz DOT format as undirected graph. Each node is labeled with
+    /// its coordinate, total hits, stored population size and mean weights of stored inputs (node
+    /// weights are used when storage is empty). Edges connect direct topological neighbours.
+    pub fn to_dot(&self) -> String {
+        let format_weights =
+            |weights: &[f64]| weights.iter().map(|w| format!("{:.3}", w)).collect::<Vec<_>>().join(",");
+
+        let mut dot = String::from("graph network {\n");
+
+        self.iter().for_each(|(Coordinate(x, y), node)| {
+            let node = node.read().unwrap();
+            let size = node.storage.size();
+            let weights = if size > 0 {
+                node.storage.iter().fold(vec![0.; self.dimension], |mut acc, input| {
+                    acc.iter_mut().zip(input.weights().iter()).for_each(|(sum, w)| *sum += w / size as f64);
+                    acc
+                })
+            } else {
+                node.weights.clone()
+            };
+
+            writeln!(
+                &mut dot,
+                "  \"{},{}\" [label=\"({},{})\\nhits: {}\\nsize: {}\\nweights: [{}]\"];",
+                x,
+                y,
+                x,
+                y,
+                node.total_hits,
+                size,
+                format_weights(weights.as_slice()),
+            )
+            .unwrap();
+        });
+
+        self.get_coordinates().for_each(|Coordinate(x, y)| {
+            [Coordinate(x + 1, y), Coordinate(x, y + 1)]
+                .iter()
+                .filter(|neighbour| self.nodes.contains_key(*neighbour))
+                .for_each(|Coordinate(nx, ny)| writeln!(&mut dot, "  \"{},{}\" -- \"{},{}\";", x, y, nx, ny).unwrap());
+        });
+
+        dot.push_str("}\n");
+
+        dot
     }
 
     /// Sets a new learning rate.
@@ -92,45 +438,94 @@ where
         self.learning_rate = learning_rate;
     }
 
-    /// Stores input into the network.
-    pub fn store(&mut self, input: I, time: usize) {
-        debug_assert!(input.weights().len() == self.dimension);
+    /// Freezes or unfreezes network growth. When frozen, no new nodes are created, so inputs only
+    /// update existing nodes.
+    pub fn set_growth_frozen(&mut self, is_frozen: bool) {
+        self.is_growth_frozen = is_frozen;
+    }
+
+    /// Returns true if network growth is frozen.
+    pub fn is_growth_frozen(&self) -> bool {
+        self.is_growth_frozen
+    }
+
+    /// Stores input into the network. Returns error if input is rejected due to invalid weights.
+    pub fn store(&mut self, input: I, time: usize) -> Result<(), String> {
         self.time = time;
+        self.last_growth.get_or_insert(time);
         self.train(input, true)
     }
 
-    /// Stores multiple inputs into the network.
-    pub fn store_batch<T: Send + Sync>(&mut self, item_data: Vec<T>, time: usize, map_func: fn(T) -> I) {
+    /// Stores multiple inputs into the network. Inputs with invalid weights are rejected and
+    /// reported as error, the rest is stored.
+    pub fn store_batch<T: Send + Sync>(
+        &mut self,
+        item_data: Vec<T>,
+        time: usize,
+        map_func: fn(T) -> I,
+    ) -> Result<(), String> {
         self.time = time;
+        self.last_growth.get_or_insert(time);
         let nodes_data = parallel_into_collect(item_data, |item| {
             let input = map_func(item);
-            let bmu = self.find_bmu(&input);
-            let error = bmu.read().unwrap().distance(input.weights());
-            (bmu, error, input)
+            let weights = self.get_input_weights(&input)?.into_owned();
+            let bmu = self.find_bmu(weights.as_slice());
+            let error = bmu.read().unwrap().distance(weights.as_slice());
+            Ok((bmu, error, weights, input))
         });
-        self.train_batch(nodes_data, true);
+
+        let (nodes_data, errors): (Vec<_>, Vec<_>) = nodes_data.into_iter().partition(|result| result.is_ok());
+        self.train_batch(nodes_data.into_iter().filter_map(Result::ok).collect(), true);
+
+        match errors.into_iter().filter_map(Result::err).next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
     }
 
     /// Performs smoothing phase.
     pub fn smooth(&mut self, rebalance_count: usize) {
         (0..rebalance_count).for_each(|_| {
-            let mut data = self
-                .nodes
-                .iter_mut()
-                .flat_map(|(_, node)| node.write().unwrap().storage.drain(0..))
-                .collect::<Vec<_>>();
-            data.shuffle(&mut rand::thread_rng());
+            // NOTE drain nodes in coordinate order, so shuffle result depends only on the random
+            let mut data =
+                self.iter().flat_map(|(_, node)| node.write().unwrap().storage.drain(0..)).collect::<Vec<_>>();
+            data.shuffle(&mut self.random.get_rng());
 
             let nodes_data = parallel_into_collect(data, |input| {
-                let bmu = self.find_bmu(&input);
-                let error = bmu.read().unwrap().distance(input.weights());
-                (bmu, error, input)
+                let weights = self.clamp_weights(input.weights()).into_owned();
+                let bmu = self.find_bmu(weights.as_slice());
+                let error = bmu.read().unwrap().distance(weights.as_slice());
+                (bmu, error, weights, input)
             });
 
             self.train_batch(nodes_data, false);
         });
     }
 
+    /// Moves weights of each node with data to the mean weights of its data, e.g. after the data was
+    /// changed in place. Nodes without data or with non-finite data weights are left untouched.
+    pub fn recenter(&mut self) {
+        self.nodes.values().for_each(|node| {
+            let mut node = node.write().unwrap();
+            let (count, sum) = node.storage.iter().fold((0, vec![0.; self.dimension]), |(count, mut sum), input| {
+                sum.iter_mut().zip(input.weights().iter()).for_each(|(acc, value)| *acc += value);
+                (count + 1, sum)
+            });
+
+            if count > 0 && sum.iter().all(|value| value.is_finite()) {
+                node.weights = sum.into_iter().map(|value| value / count as f64).collect();
+            }
+        });
+
+        self.min_max_weights = self.nodes.values().fold(
+            (vec![f64::MAX; self.dimension], vec![f64::MIN; self.dimension]),
+            |mut min_max_weights, node| {
+                update_min_max(&mut min_max_weights, node.read().unwrap().weights.as_slice());
+                min_max_weights
+            },
+        );
+    }
+
     /// Compacts network.
     pub fn compact(&mut self, node_filter: &(dyn Fn(&NodeLink<I, S>, f64) -> bool)) {
         let original = self.nodes.len();
@@ -156,22 +551,77 @@ where
         });
     }
 
+    /// Removes nodes which were not hit for more than `max_age` time units. Individuals stored in
+    /// removed nodes are recycled to their best matching units among remaining nodes. As topology is
+    /// defined by node coordinates, neighbourhood edges of removed nodes are dropped implicitly.
+    /// Nodes which were never hit are left for compaction. Network is never pruned below `min_size` nodes.
+    pub fn prune_stale(&mut self, current_time: usize, max_age: usize, min_size: usize) {
+        let mut stale = self
+            .iter()
+            .filter_map(|(coordinate, node)| {
+                let last_hit = node.read().unwrap().last_hits.front().cloned()?;
+                let age = current_time.saturating_sub(last_hit);
+
+                if age > max_age {
+                    Some((*coordinate, age))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE: remove the oldest nodes first and prevent network to be less than min size
+        stale.sort_by(|(_, a), (_, b)| b.cmp(a));
+        stale.truncate(self.nodes.len().saturating_sub(min_size));
+
+        let data = stale
+            .iter()
+            .filter_map(|(coordinate, _)| self.nodes.remove(coordinate))
+            .flat_map(|node| node.write().unwrap().storage.drain(0..))
+            .collect::<Vec<_>>();
+
+        let nodes_data = data
+            .into_iter()
+            .map(|input| {
+                let weights = self.clamp_weights(input.weights()).into_owned();
+                let bmu = self.find_bmu(weights.as_slice());
+                let error = bmu.read().unwrap().distance(weights.as_slice());
+                (bmu, error, weights, input)
+            })
+            .collect::<Vec<_>>();
+
+        self.train_batch(nodes_data, false);
+    }
+
     /// Finds node by its coordinate.
     pub fn find(&self, coordinate: &Coordinate) -> Option<&NodeLink<I, S>> {
         self.nodes.get(coordinate)
     }
 
-    /// Returns node coordinates in arbitrary order.
+    /// Returns up to `k` nodes closest to the given weights together with their distances, sorted by
+    /// storage distance ascending. Ties are resolved by node coordinate.
+    pub fn nearest(&self, weights: &[f64], k: usize) -> Vec<(NodeLink<I, S>, f64)> {
+        let mut nodes =
+            self.get_nodes().map(|node| (node.clone(), node.read().unwrap().distance(weights))).collect::<Vec<_>>();
+
+        nodes.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+        nodes.truncate(k);
+
+        nodes
+    }
+
+    /// Returns node coordinates ordered by coordinate.
     pub fn get_coordinates(&'_ self) -> impl Iterator<Item = Coordinate> + '_ {
-        self.nodes.keys().cloned()
+        self.iter().map(|(coordinate, _)| *coordinate)
     }
 
-    /// Return nodes in arbitrary order.
+    /// Return nodes ordered by their coordinate.
     pub fn get_nodes<'a>(&'a self) -> impl Iterator<Item = &NodeLink<I, S>> + 'a {
-        self.nodes.values()
+        self.iter().map(|(_, node)| node)
     }
 
-    /// Iterates over coordinates and their nodes.
+    /// Iterates over coordinates and their nodes ordered by coordinate. The order is deterministic
+    /// which keeps results reproducible with the same random seed.
     pub fn iter(&self) -> impl Iterator<Item = (&Coordinate, &NodeLink<I, S>)> {
         self.nodes.iter()
     }
@@ -186,6 +636,12 @@ where
         self.time
     }
 
+    /// Returns amount of generations passed since the network has grown new nodes last time.
+    /// Time of the first stored input is used when the network has not grown yet.
+    pub fn get_generations_since_growth(&self) -> usize {
+        self.time.saturating_sub(self.last_growth.unwrap_or(self.time))
+    }
+
     /// Calculates mean squared error of the whole network.
     pub fn mse(&self) -> f64 {
         let n = if self.nodes.is_empty() { 1 } else { self.nodes.len() } as f64;
@@ -194,37 +650,74 @@ where
     }
 
     /// Trains network on an input.
-    fn train(&mut self, input: I, is_new_input: bool) {
-        debug_assert!(input.weights().len() == self.dimension);
+    fn train(&mut self, input: I, is_new_input: bool) -> Result<(), String> {
+        let weights = self.get_input_weights(&input)?.into_owned();
 
-        let bmu = self.find_bmu(&input);
-        let error = bmu.read().unwrap().distance(input.weights());
+        let bmu = self.find_bmu(weights.as_slice());
+        let error = bmu.read().unwrap().distance(weights.as_slice());
 
-        self.update(&bmu, &input, error, is_new_input);
+        self.update(&bmu, weights.as_slice(), error, is_new_input);
 
         bmu.write().unwrap().storage.add(input);
+
+        Ok(())
     }
 
     /// Trains network on inputs.
-    fn train_batch(&mut self, nodes_data: Vec<(NodeLink<I, S>, f64, I)>, is_new_input: bool) {
-        nodes_data.into_iter().for_each(|(bmu, error, input)| {
-            self.update(&bmu, &input, error, is_new_input);
+    fn train_batch(&mut self, nodes_data: Vec<TrainingData<I, S>>, is_new_input: bool) {
+        nodes_data.into_iter().for_each(|(bmu, error, weights, input)| {
+            self.update(&bmu, weights.as_slice(), error, is_new_input);
             bmu.write().unwrap().storage.add(input);
         });
     }
 
-    /// Finds the best matching unit within the map for the given input.
-    fn find_bmu(&self, input: &I) -> NodeLink<I, S> {
-        self.nodes
-            .iter()
-            .map(|(_, node)| (node.clone(), node.read().unwrap().distance(input.weights())))
-            .min_by(|(_, x), (_, y)| x.partial_cmp(y).unwrap_or(Ordering::Less))
-            .map(|(node, _)| node)
+    /// Validates input weights: they should have expected dimension and finite values.
+    /// Returns weights clamped if such option is enabled.
+    fn get_input_weights<'a>(&self, input: &'a I) -> Result<Cow<'a, [f64]>, String> {
+        let weights = input.weights();
+
+        if weights.len() != self.dimension {
+            return Err(format!("input is rejected: expected dimension {}, got {}", self.dimension, weights.len()));
+        }
+
+        if weights.iter().any(|value| !value.is_finite()) {
+            return Err(format!("input is rejected: weights have non-finite values: {:?}", weights));
+        }
+
+        Ok(self.clamp_weights(weights))
+    }
+
+    /// Clamps weights to the range of node weights if such option is enabled.
+    fn clamp_weights<'a>(&self, weights: &'a [f64]) -> Cow<'a, [f64]> {
+        if self.clamp_inputs {
+            let (min, max) = &self.min_max_weights;
+            Cow::Owned(
+                weights
+                    .iter()
+                    .zip(min.iter().zip(max.iter()))
+                    .map(|(value, (min, max))| if min <= max { value.clamp(*min, *max) } else { *value })
+                    .collect(),
+            )
+        } else {
+            Cow::Borrowed(weights)
+        }
+    }
+
+    /// Finds the best matching unit within the map for the given input weights.
+    /// Ties are resolved in favor of the node with the lowest coordinate.
+    fn find_bmu(&self, weights: &[f64]) -> NodeLink<I, S> {
+        self.iter()
+            .map(|(_, node)| (node, node.read().unwrap().distance(weights)))
+            .fold(None, |best: Option<(&NodeLink<I, S>, f64)>, (node, distance)| match best {
+                Some((_, best_distance)) if compare_floats(distance, best_distance) != Ordering::Less => best,
+                _ => Some((node, distance)),
+            })
+            .map(|(node, _)| node.clone())
             .expect("no nodes")
     }
 
     /// Updates network according to the error.
-    fn update(&mut self, node: &NodeLink<I, S>, input: &I, error: f64, is_new_input: bool) {
+    fn update(&mut self, node: &NodeLink<I, S>, weights: &[f64], error: f64, is_new_input: bool) {
         let radius = if is_new_input { 2 } else { 1 };
 
         let (exceeds_ae, can_grow) = {
@@ -238,20 +731,21 @@ where
 
             (
                 matches!(compare_floats(node.error, self.growing_threshold), Ordering::Equal | Ordering::Greater),
-                node.is_boundary(self) && is_new_input,
+                node.is_boundary(self) && is_new_input && !self.is_growth_frozen,
             )
         };
 
         match (exceeds_ae, can_grow) {
             (true, false) => self.distribute_error(node, radius),
             (true, true) => {
-                self.grow_nodes(node).into_iter().for_each(|(coordinate, weights)| {
-                    self.insert(coordinate, weights.as_slice());
+                self.last_growth = Some(self.time);
+                self.grow_nodes(node).into_iter().for_each(|(coordinate, node_weights)| {
+                    self.insert(coordinate, node_weights.as_slice());
                     let new_node = self.nodes.get(&coordinate).unwrap();
-                    self.adjust_weights(new_node, input.weights(), radius, is_new_input);
+                    self.adjust_weights(new_node, weights, radius, is_new_input);
                 });
             }
-            _ => self.adjust_weights(node, input.weights(), radius, is_new_input),
+            _ => self.adjust_weights(node, weights, radius, is_new_input),
         }
     }
 
@@ -348,11 +842,13 @@ where
         let learning_rate = if is_new_input { learning_rate } else { 0.25 * learning_rate };
 
         node.adjust(weights, learning_rate);
-        node.neighbours(self, radius).filter_map(|(n, offset)| n.map(|n| (n, offset))).for_each(|(n, offset)| {
-            let distance = offset.0.abs() + offset.1.abs();
-            let learning_rate = learning_rate / distance as f64;
-            n.write().unwrap().adjust(weights, learning_rate);
-        });
+        node.neighbours(self, self.neighbourhood.radius(radius))
+            .filter_map(|(n, offset)| n.map(|n| (n, offset)))
+            .for_each(|(n, offset)| {
+                let distance = (offset.0.abs() + offset.1.abs()) as usize;
+                let learning_rate = learning_rate * self.neighbourhood.factor(distance);
+                n.write().unwrap().adjust(weights, learning_rate);
+            });
     }
 
     /// Inserts new neighbors if necessary.
@@ -368,12 +864,12 @@ where
 
     /// Creates nodes for initial topology.
     fn create_initial_nodes(
-        roots: [I; 4],
+        roots: Vec<I>,
         initial_error: f64,
         rebalance_memory: usize,
         noise: &Noise,
         storage_factory: &F,
-    ) -> (HashMap<Coordinate, NodeLink<I, S>>, MinMaxWeights) {
+    ) -> (BTreeMap<Coordinate, NodeLink<I, S>>, MinMaxWeights) {
         let create_node_link = |coordinate: Coordinate, input: I| {
             let weights = input.weights().iter().map(|&value| noise.generate(value)).collect::<Vec<_>>();
             let mut node = Node::<I, S>::new(
@@ -388,18 +884,19 @@ where
         };
 
         let dimension = roots[0].weights().len();
-        let [n00, n01, n11, n10] = roots;
+        let columns = (roots.len() as f64).sqrt().ceil() as usize;
 
-        let n00 = create_node_link(Coordinate(0, 0), n00);
-        let n01 = create_node_link(Coordinate(0, 1), n01);
-        let n11 = create_node_link(Coordinate(1, 1), n11);
-        let n10 = create_node_link(Coordinate(1, 0), n10);
+        let nodes = roots
+            .into_iter()
+            .enumerate()
+            .map(|(idx, input)| {
+                let (row, column) = (idx / columns, idx % columns);
+                let column = if row % 2 == 0 { column } else { columns - 1 - column };
+                let coordinate = Coordinate(row as i32, column as i32);
 
-        let nodes =
-            [(Coordinate(0, 0), n00), (Coordinate(0, 1), n01), (Coordinate(1, 1), n11), (Coordinate(1, 0), n10)]
-                .iter()
-                .cloned()
-                .collect::<HashMap<_, _>>();
+                (coordinate, create_node_link(coordinate, input))
+            })
+            .collect::<BTreeMap<_, _>>();
 
         let min_max_weights = nodes.iter().fold(
             (vec![f64::MAX; dimension], vec![f64::MIN; dimension]),
@@ -419,3 +916,57 @@ fn update_min_max(min_max_weights: &mut (Vec<f64>, Vec<f64>), weights: &[f64]) {
     min_max_weights.0.iter_mut().zip(weights.iter()).for_each(|(curr, v)| *curr = curr.min(*v));
     min_max_weights.1.iter_mut().zip(weights.iter()).for_each(|(curr, v)| *curr = curr.max(*v));
 }
+
+impl Display for LearnedNetwork {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // NOTE use default float formatting as it guarantees lossless round trip
+        let nodes = self.nodes.iter().fold(String::new(), |mut res, (Coordinate(x, y), weights)| {
+            let weights = weights.iter().map(|w| w.to_string()).collect::<Vec<_>>().join(",");
+            let separator = if res.is_empty() { "" } else { "," };
+            write!(&mut res, "{}({},{},[{}])", separator, x, y, weights).unwrap();
+
+            res
+        });
+
+        write!(f, "[{}]", nodes)
+    }
+}
+
+impl FromStr for LearnedNetwork {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let nodes = value
+            .trim()
+            .strip_prefix('[')
+            .and_then(|value| value.strip_suffix(']'))
+            .ok_or_else(|| "learned network should be enclosed in square brackets".to_string())?;
+
+        let nodes = nodes
+            .split_terminator("),")
+            .map(|node| {
+                let node = node.trim().trim_start_matches('(').trim_end_matches(')');
+                let (coordinate, weights) =
+                    node.split_once(",[").ok_or_else(|| format!("cannot parse learned node: '{}'", node))?;
+
+                let coordinate = coordinate
+                    .split(',')
+                    .map(|value| value.trim().parse::<i32>().map_err(|err| err.to_string()))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let weights = weights
+                    .trim_end_matches(']')
+                    .split(',')
+                    .map(|value| value.trim().parse::<f64>().map_err(|err| err.to_string()))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match coordinate.as_slice() {
+                    &[x, y] => Ok((Coordinate(x, y), weights)),
+                    _ => Err(format!("cannot parse learned node coordinate: '{}'", node)),
+                }
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self { nodes })
+    }
+}
@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/math/distance_test.rs"]
+mod distance_test;
+
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
 
@@ -17,3 +21,117 @@ where
         })
         .sqrt()
 }
+
+/// Calculates euclidean distance between two vectors: D = sqrt(sum((x - y)^2)).
+pub fn euclidean_distance<A, B>(a: A, b: B) -> f64
+where
+    A: Iterator<Item = f64>,
+    B: Iterator<Item = f64>,
+{
+    a.zip(b).fold(0_f64, |acc, (a, b)| acc + (a - b) * (a - b)).sqrt()
+}
+
+/// Calculates weighted euclidean distance between two vectors: D = sqrt(sum(w * (x - y)^2)).
+/// Dimensions without weight do not contribute to the distance.
+pub fn weighted_euclidean_distance<A, B>(a: A, b: B, weights: &[f64]) -> f64
+where
+    A: Iterator<Item = f64>,
+    B: Iterator<Item = f64>,
+{
+    a.zip(b).zip(weights.iter()).fold(0_f64, |acc, ((a, b), weight)| acc + weight * (a - b) * (a - b)).sqrt()
+}
+
+/// Calculates cosine distance between two vectors: D = 1 - (x * y) / (|x| * |y|).
+/// Distance is zero when both vectors are zero and one when only one of them is zero.
+pub fn cosine_distance<A, B>(a: A, b: B) -> f64
+where
+    A: Iterator<Item = f64>,
+    B: Iterator<Item = f64>,
+{
+    let (dot, norm_a, norm_b) = a
+        .zip(b)
+        .fold((0_f64, 0_f64, 0_f64), |(dot, norm_a, norm_b), (a, b)| (dot + a * b, norm_a + a * a, norm_b + b * b));
+
+    match (compare_floats(norm_a, 0.), compare_floats(norm_b, 0.)) {
+        (Ordering::Equal, Ordering::Equal) => 0.,
+        (Ordering::Equal, _) | (_, Ordering::Equal) => 1.,
+        _ => 1. - dot / (norm_a.sqrt() * norm_b.sqrt()),
+    }
+}
+
+/// Specifies a metric used to measure distance between two vectors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DistanceMetric {
+    /// A relative distance, see `relative_distance`.
+    #[default]
+    Relative,
+    /// An euclidean distance, see `euclidean_distance`.
+    Euclidean,
+    /// A cosine distance, see `cosine_distance`.
+    Cosine,
+    /// A weighted euclidean distance with weight per dimension, see `weighted_euclidean_distance`.
+    WeightedEuclidean(Vec<f64>),
+}
+
+impl DistanceMetric {
+    /// Checks whether metric parameters are valid.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::WeightedEuclidean(weights) => weights.iter().all(|weight| weight.is_finite() && *weight >= 0.),
+            _ => true,
+        }
+    }
+
+    /// Calculates distance between two vectors using the metric.
+    pub fn distance<A, B>(&self, a: A, b: B) -> f64
+    where
+        A: Iterator<Item = f64>,
+        B: Iterator<Item = f64>,
+    {
+        match self {
+            Self::Relative => relative_distance(a, b),
+            Self::Euclidean => euclidean_distance(a, b),
+            Self::Cosine => cosine_distance(a, b),
+            Self::WeightedEuclidean(weights) => weighted_euclidean_distance(a, b, weights.as_slice()),
+        }
+    }
+}
+
+/// Calculates euclidean distance between two vectors where difference in each dimension is
+/// normalized by its observed value range: D = sqrt(sum(((x - y) / (max - min))^2)).
+/// This way, each dimension contributes comparably to the distance regardless of its scale.
+/// Dimensions without range or with zero range do not contribute to the distance.
+pub fn normalized_distance<A, B>(a: A, b: B, ranges: &[(f64, f64)]) -> f64
+where
+    A: Iterator<Item = f64>,
+    B: Iterator<Item = f64>,
+{
+    a.zip(b)
+        .zip(ranges.iter())
+        .fold(0_f64, |acc, ((a, b), (min, max))| {
+            let range = max - min;
+            let change = if compare_floats(range, 0.) == Ordering::Greater { (a - b).abs() / range } else { 0. };
+
+            acc + change * change
+        })
+        .sqrt()
+}
+
+/// Returns observed value ranges as _(min, max)_ for each dimension of given vectors.
+pub fn get_value_ranges<I, V>(values: I) -> Vec<(f64, f64)>
+where
+    I: Iterator<Item = V>,
+    V: Iterator<Item = f64>,
+{
+    values.fold(Vec::default(), |mut ranges, vector| {
+        vector.enumerate().for_each(|(idx, value)| match ranges.get_mut(idx) {
+            Some((min, max)) => {
+                *min = value.min(*min);
+                *max = value.max(*max);
+            }
+            None => ranges.push((value, value)),
+        });
+
+        ranges
+    })
+}
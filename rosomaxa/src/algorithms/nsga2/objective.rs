@@ -1,4 +1,10 @@
-use crate::utils::compare_floats;
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/nsga2/objective_test.rs"]
+mod objective_test;
+
+use crate::population::Shuffled;
+use crate::utils::{compare_floats, Random};
+use crate::HeuristicObjective;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
@@ -72,3 +78,63 @@ pub fn dominance_order<T>(a: &T, b: &T, objectives: &[Arc<dyn Objective<Solution
         Ordering::Equal
     }
 }
+
+/// A type alias for a component of lexicographic objective.
+pub type ObjectiveComponent<S> = Arc<dyn Objective<Solution = S> + Send + Sync>;
+
+/// A multi objective which compares solutions strictly lexicographically: the next component is
+/// considered only when all previous components are equal. Components are never weighted, shuffled
+/// or reordered, so a worse value of a secondary component cannot compensate a better primary one.
+pub struct LexicographicObjective<S> {
+    objectives: Vec<ObjectiveComponent<S>>,
+}
+
+impl<S> LexicographicObjective<S> {
+    /// Creates a new instance of `LexicographicObjective` from components in their priority order.
+    pub fn new(objectives: Vec<ObjectiveComponent<S>>) -> Self {
+        assert!(!objectives.is_empty());
+
+        Self { objectives }
+    }
+}
+
+impl<S> Objective for LexicographicObjective<S> {
+    type Solution = S;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        self.objectives
+            .iter()
+            .map(|objective| objective.total_order(a, b))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        // NOTE use distance of the first component which distinguishes solutions
+        self.objectives
+            .iter()
+            .find(|objective| objective.total_order(a, b) != Ordering::Equal)
+            .map_or(0., |objective| objective.distance(a, b))
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objectives.first().expect("no objectives").fitness(solution)
+    }
+}
+
+impl<S> MultiObjective for LexicographicObjective<S> {
+    fn objectives<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a (dyn Objective<Solution = Self::Solution> + Send + Sync)> + 'a> {
+        Box::new(self.objectives.iter().map(|objective| objective.as_ref()))
+    }
+}
+
+impl<S> HeuristicObjective for LexicographicObjective<S> {}
+
+impl<S> Shuffled for LexicographicObjective<S> {
+    /// Returns a copy of the objective: the order of components is fixed.
+    fn get_shuffled(&self, _: &(dyn Random + Send + Sync)) -> Self {
+        Self { objectives: self.objectives.clone() }
+    }
+}
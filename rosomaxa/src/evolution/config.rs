@@ -1,6 +1,7 @@
 use crate::evolution::*;
 use crate::hyper::*;
 use crate::termination::*;
+use crate::utils::Random;
 use std::hash::Hash;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -42,6 +43,17 @@ pub trait InitialOperator {
 
     /// Creates an initial solution from scratch.
     fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution;
+
+    /// Creates an initial solution from scratch using given random generator instead of the one
+    /// from the context environment. It is used to keep parallel construction reproducible.
+    /// Falls back to `create` by default.
+    fn create_with_random(
+        &self,
+        heuristic_ctx: &Self::Context,
+        _random: Arc<dyn Random + Send + Sync>,
+    ) -> Self::Solution {
+        self.create(heuristic_ctx)
+    }
 }
 
 /// A collection of initial operators.
@@ -63,6 +75,8 @@ where
     pub quota: f64,
     /// Initial individuals in population.
     pub individuals: Vec<S>,
+    /// Specifies whether initial solutions can be built in parallel.
+    pub is_parallel: bool,
 }
 
 /// Specifies pre/post processing logic which is run before and after the solver.
@@ -124,7 +138,13 @@ where
             search_operators: None,
             diversify_operators: None,
             objective: None,
-            initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
+            initial: InitialConfig {
+                operators: vec![],
+                max_size: 4,
+                quota: 0.05,
+                individuals: vec![],
+                is_parallel: true,
+            },
             processing: ProcessingConfig { context: vec![], solution: vec![] },
         }
     }
@@ -170,6 +190,13 @@ where
         self
     }
 
+    /// Sets whether initial solutions can be built in parallel, in batches limited by amount of
+    /// available CPUs. Default is true.
+    pub fn with_initial_parallelism(mut self, is_parallel: bool) -> Self {
+        self.initial.is_parallel = is_parallel;
+        self
+    }
+
     /// Specifies processing configuration.
     pub fn with_processing(mut self, processing: ProcessingConfig<C, O, S>) -> Self {
         self.processing = processing;
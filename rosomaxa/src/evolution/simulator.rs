@@ -1,6 +1,6 @@
 use crate::evolution::{EvolutionResult, EvolutionStrategy};
 use crate::prelude::*;
-use crate::utils::Timer;
+use crate::utils::{parallel_collect, Timer};
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -51,10 +51,18 @@ where
 
         let weights = config.initial.operators.iter().map(|(_, weight)| *weight).collect::<Vec<_>>();
 
+        // NOTE replay requires strict order of random decisions, so parallel construction is not used
+        let batch_size = if config.initial.is_parallel && heuristic_ctx.environment().tracer.is_none() {
+            heuristic_ctx.environment().parallelism.available_cpus().max(1)
+        } else {
+            1
+        };
+
         let init_size = heuristic_ctx.population().size();
         let init_time = Timer::start();
-        let _ = (init_size..config.initial.max_size).try_for_each(|idx| {
-            let item_time = Timer::start();
+        let mut next_idx = init_size;
+        while next_idx < config.initial.max_size {
+            let batch_time = Timer::start();
 
             let is_overall_termination = config.termination.is_termination(&mut heuristic_ctx);
             let is_initial_quota_reached = config.termination.estimate(&heuristic_ctx) > config.initial.quota;
@@ -65,23 +73,34 @@ where
                         "stop building initial solutions due to initial quota reached ({}) or overall termination ({}).",
                         is_initial_quota_reached, is_overall_termination
                     )
-                        .as_str(),
+                    .as_str(),
                 );
-                return Err(());
+                break;
             }
 
-            let operator_idx = if idx < config.initial.operators.len() {
-                idx
-            } else {
-                random.weighted(weights.as_slice())
-            };
+            // NOTE operators are selected sequentially, so their choice does not depend on parallel execution,
+            // each solution gets its own random stream derived from the seed index, when it is supported
+            let operators = (next_idx..(next_idx + batch_size).min(config.initial.max_size))
+                .map(|idx| {
+                    let operator_idx =
+                        if idx < config.initial.operators.len() { idx } else { random.weighted(weights.as_slice()) };
+                    (operator_idx, random.fork(idx as u64))
+                })
+                .collect::<Vec<_>>();
+            next_idx += operators.len();
 
             // TODO consider initial quota limit
-            let solution = config.initial.operators[operator_idx].0.create(&heuristic_ctx);
-            heuristic_ctx.on_initial(solution, item_time);
-
-            Ok(())
-        });
+            let solutions = parallel_collect(operators.as_slice(), |(operator_idx, stream_random)| {
+                let operator = &config.initial.operators[*operator_idx].0;
+                match stream_random {
+                    Some(stream_random) => operator.create_with_random(&heuristic_ctx, stream_random.clone()),
+                    None => operator.create(&heuristic_ctx),
+                }
+            });
+
+            // NOTE solutions are added in the order of their operators to keep population state reproducible
+            solutions.into_iter().for_each(|solution| heuristic_ctx.on_initial(solution, batch_time.clone()));
+        }
 
         if heuristic_ctx.population().size() > 0 {
             logger.deref()(&format!("created initial population in {}ms", init_time.elapsed_millis()));
@@ -22,6 +22,21 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// Usage statistics of search operators, if they are tracked.
+    pub operators: Vec<TelemetryOperator>,
+}
+
+/// Keeps usage statistics of a single search operator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TelemetryOperator {
+    /// A name of the operator.
+    pub name: String,
+    /// How many times operator was called.
+    pub calls: usize,
+    /// How many times operator returned a new solution.
+    pub explored: usize,
+    /// How many times operator returned a solution better than original one.
+    pub improved: usize,
 }
 
 /// Represents information about generation.
@@ -117,7 +132,7 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![], operators: vec![] },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
@@ -168,6 +183,7 @@ where
             improvement_all_ratio: self.improvement_tracker.i_all_ratio,
             improvement_1000_ratio: self.improvement_tracker.i_1000_ratio,
             termination_estimate,
+            stagnation: self.improvement_tracker.stagnation,
         };
 
         let (log_best, log_population, track_population, should_dump_population) = match &self.mode {
@@ -350,6 +366,7 @@ struct ImprovementTracker {
     pub i_all_ratio: f64,
     pub i_1000_ratio: f64,
     pub is_last_improved: bool,
+    pub stagnation: usize,
 }
 
 impl ImprovementTracker {
@@ -360,6 +377,7 @@ impl ImprovementTracker {
             i_all_ratio: 0.,
             i_1000_ratio: 0.,
             is_last_improved: false,
+            stagnation: 0,
         }
     }
 
@@ -371,6 +389,7 @@ impl ImprovementTracker {
         }
 
         self.is_last_improved = is_improved;
+        self.stagnation = if is_improved { 0 } else { self.stagnation + 1 };
         self.buffer[generation % length] = is_improved;
 
         let improvements = (0..generation + 1).zip(self.buffer.iter()).filter(|(_, is_improved)| **is_improved).count();
@@ -306,6 +306,7 @@ pub struct Solver {
     search_operators: Vec<(TargetSearchOperator, String, f64)>,
     diversify_operators: Vec<TargetDiversifyOperator>,
     context_factory: Option<ContextFactory>,
+    seed: Option<u64>,
 }
 
 impl Default for Solver {
@@ -324,6 +325,7 @@ impl Default for Solver {
             search_operators: vec![],
             diversify_operators: vec![],
             context_factory: None,
+            seed: None,
         }
     }
 }
@@ -401,10 +403,21 @@ impl Solver {
         self
     }
 
+    /// Sets random seed which makes solver's random stream reproducible.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// Runs the solver using configuration provided through fluent interface methods.
     pub fn solve(self) -> Result<(SolverSolutions, Option<TelemetryMetrics>), String> {
         // create an environment based on max_time and logger parameters supplied
         let environment = Environment::new_with_time_quota(self.max_time);
+        let environment = if let Some(seed) = self.seed {
+            Environment { random: Arc::new(DefaultRandom::with_seed(seed)), ..environment }
+        } else {
+            environment
+        };
         let environment = Arc::new(if let Some(logger) = self.logger.clone() {
             Environment { logger, ..environment }
         } else {
@@ -5,7 +5,7 @@ mod dynamic_selective_test;
 use super::*;
 use crate::algorithms::math::{relative_distance, Remedian};
 use crate::algorithms::mdp::*;
-use crate::utils::compare_floats;
+use crate::utils::{compare_floats, emit_diagnostic, DiagnosticEvent, LogLevel};
 use crate::Timer;
 use hashbrown::HashMap;
 use std::cmp::Ordering;
@@ -110,6 +110,12 @@ where
     fn diversify(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
         diversify_solutions(heuristic_ctx, solutions, self.diversify_operators.as_slice())
     }
+
+    fn settings(&self) -> Option<HeuristicSettings> {
+        Some(HeuristicSettings::Dynamic {
+            search_operators: self.action_registry.heuristics.iter().map(|(_, name)| name.clone()).collect(),
+        })
+    }
 }
 
 impl<C, O, S> DynamicSelective<C, O, S>
@@ -315,6 +321,18 @@ where
             (_, _) => SearchState::Stagnated(ratio),
         };
 
+        if let Some(tracer) = self.heuristic_ctx.environment().tracer.as_ref() {
+            tracer.on_operator(name, !matches!(self.state, SearchState::Stagnated(_)));
+        }
+
+        emit_diagnostic(self.heuristic_ctx.environment().solver_logger.as_ref(), LogLevel::Trace, || {
+            DiagnosticEvent::OperatorApplied {
+                name: name.to_string(),
+                duration: duration.as_millis(),
+                is_improved: compare_to_old == Ordering::Less,
+            }
+        });
+
         self.solution = Some(new_solution);
         self.runtime.push((name.to_string(), duration, self.state.clone()))
     }
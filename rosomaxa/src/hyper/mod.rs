@@ -53,6 +53,27 @@ pub trait HyperHeuristic: Display {
     /// Performs a diversification of selected solutions in order to increase exploration
     /// of the solution space.
     fn diversify(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution>;
+
+    /// Returns effective settings of the heuristic. By default, returns nothing, so custom
+    /// heuristic types are not required to report them.
+    fn settings(&self) -> Option<HeuristicSettings> {
+        None
+    }
+}
+
+/// Specifies effective settings of built-in hyper heuristics.
+#[derive(Clone, Debug)]
+pub enum HeuristicSettings {
+    /// Settings of static selective heuristic.
+    Static {
+        /// Names and weights of search operators. Empty if they are not specified.
+        search_weights: Vec<(String, f64)>,
+    },
+    /// Settings of dynamic selective heuristic. Operator weights are learned during the search.
+    Dynamic {
+        /// Names of search operators.
+        search_operators: Vec<String>,
+    },
 }
 
 /// For each solution, picks an operator with equal probability and runs diversify once.
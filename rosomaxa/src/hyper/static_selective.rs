@@ -26,6 +26,7 @@ where
 {
     search_group: HeuristicSearchGroup<C, O, S>,
     diversify_group: HeuristicDiversifyGroup<C, O, S>,
+    search_weights: Vec<(String, f64)>,
 }
 
 impl<C, O, S> HyperHeuristic for StaticSelective<C, O, S>
@@ -66,6 +67,10 @@ where
     fn diversify(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
         diversify_solutions(heuristic_ctx, solutions, self.diversify_group.as_slice())
     }
+
+    fn settings(&self) -> Option<HeuristicSettings> {
+        Some(HeuristicSettings::Static { search_weights: self.search_weights.clone() })
+    }
 }
 
 impl<C, O, S> StaticSelective<C, O, S>
@@ -79,7 +84,16 @@ where
         assert!(!search_group.is_empty());
         assert!(!diversify_group.is_empty());
 
-        Self { search_group, diversify_group }
+        Self { search_group, diversify_group, search_weights: vec![] }
+    }
+
+    /// Sets names and weights of search operators in the same order as in search group. They are
+    /// reported by heuristic settings only and don't affect operator selection.
+    pub fn with_search_weights(mut self, search_weights: Vec<(String, f64)>) -> Self {
+        assert_eq!(search_weights.len(), self.search_group.len());
+        self.search_weights = search_weights;
+
+        self
     }
 }
 
@@ -128,6 +128,9 @@ pub struct HeuristicStatistics {
 
     /// A progress till algorithm's termination.
     pub termination_estimate: f64,
+
+    /// An amount of consecutive generations without improvement.
+    pub stagnation: usize,
 }
 
 impl Default for HeuristicStatistics {
@@ -139,6 +142,7 @@ impl Default for HeuristicStatistics {
             improvement_all_ratio: 0.,
             improvement_1000_ratio: 0.,
             termination_estimate: 0.,
+            stagnation: 0,
         }
     }
 }
@@ -153,6 +157,55 @@ where
     population: Box<DynHeuristicPopulation<O, S>>,
     telemetry: Telemetry<O, S>,
     environment: Arc<Environment>,
+    new_best_notifier: Option<NewBestNotifier<S>>,
+    checkpoints: Option<(Vec<usize>, CheckpointCallback<S>)>,
+}
+
+/// A callback which receives a copy of a new best known solution.
+pub type NewBestCallback<S> = Arc<dyn Fn(S) + Send + Sync>;
+
+/// A callback which receives amount of completed generations and a copy of the best known solution
+/// at that moment.
+pub type CheckpointCallback<S> = Arc<dyn Fn(usize, S) + Send + Sync>;
+
+/// Notifies about new best known solutions, but not more often than specified interval allows:
+/// an improvement found within the interval is reported once it is elapsed or at the end of search.
+struct NewBestNotifier<S: HeuristicSolution> {
+    callback: NewBestCallback<S>,
+    min_interval_ms: u128,
+    last_notified: Option<Timer>,
+    is_pending: bool,
+}
+
+impl<S: HeuristicSolution> NewBestNotifier<S> {
+    fn on_population<O: HeuristicObjective<Solution = S>>(
+        &mut self,
+        population: &DynHeuristicPopulation<O, S>,
+        is_improved: bool,
+    ) {
+        self.is_pending |= is_improved;
+
+        let is_interval_elapsed = match &self.last_notified {
+            Some(timer) => timer.elapsed_millis() >= self.min_interval_ms,
+            None => true,
+        };
+
+        if is_interval_elapsed {
+            self.flush(population);
+        }
+    }
+
+    fn flush<O: HeuristicObjective<Solution = S>>(&mut self, population: &DynHeuristicPopulation<O, S>) {
+        if !self.is_pending {
+            return;
+        }
+
+        if let Some((best, _)) = population.ranked().next() {
+            (self.callback)(best.deep_copy());
+            self.last_notified = Some(Timer::start());
+            self.is_pending = false;
+        }
+    }
 }
 
 impl<O, S> TelemetryHeuristicContext<O, S>
@@ -168,7 +221,27 @@ where
         environment: Arc<Environment>,
     ) -> Self {
         let telemetry = Telemetry::new(telemetry_mode);
-        Self { objective, population, telemetry, environment }
+        Self { objective, population, telemetry, environment, new_best_notifier: None, checkpoints: None }
+    }
+
+    /// Sets a callback which is called with a copy of the best known solution when it is improved.
+    /// Calls are throttled: they are made not more often than once per given interval in milliseconds.
+    pub fn with_new_best_callback(mut self, callback: NewBestCallback<S>, min_interval_ms: usize) -> Self {
+        self.new_best_notifier = Some(NewBestNotifier {
+            callback,
+            min_interval_ms: min_interval_ms as u128,
+            last_notified: None,
+            is_pending: false,
+        });
+
+        self
+    }
+
+    /// Sets a callback which is called with a copy of the best known solution once amount of completed
+    /// generations reaches one of the given checkpoints.
+    pub fn with_checkpoints(mut self, generations: Vec<usize>, callback: CheckpointCallback<S>) -> Self {
+        self.checkpoints = Some((generations, callback));
+        self
     }
 
     /// Adds solution to population.
@@ -203,11 +276,20 @@ where
 
     fn on_initial(&mut self, solution: Self::Solution, item_time: Timer) {
         self.telemetry.on_initial(&solution, item_time);
-        self.population.add(solution);
+        let is_improved = self.population.add(solution);
+
+        if let Some(notifier) = self.new_best_notifier.as_mut() {
+            notifier.on_population(self.population.as_ref(), is_improved);
+        }
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: f64, generation_time: Timer) {
         let is_improved = self.population.add_all(offspring);
+
+        if let Some(notifier) = self.new_best_notifier.as_mut() {
+            notifier.on_population(self.population.as_ref(), is_improved);
+        }
+
         self.telemetry.on_generation(
             self.objective.as_ref(),
             self.population.as_ref(),
@@ -215,12 +297,26 @@ where
             generation_time,
             is_improved,
         );
+
+        if let Some((generations, callback)) = self.checkpoints.as_ref() {
+            let completed = self.telemetry.get_statistics().generation + 1;
+            if generations.contains(&completed) {
+                if let Some((best, _)) = self.population.ranked().next() {
+                    callback(completed, best.deep_copy());
+                }
+            }
+        }
+
         self.population.on_generation(self.telemetry.get_statistics());
     }
 
     fn on_result(self) -> Result<(Box<DynHeuristicPopulation<O, S>>, Option<TelemetryMetrics>), String> {
         let mut telemetry = self.telemetry;
 
+        if let Some(mut notifier) = self.new_best_notifier {
+            notifier.flush(self.population.as_ref());
+        }
+
         telemetry.on_result(self.objective.as_ref(), self.population.as_ref());
 
         Ok((self.population, telemetry.take_metrics()))
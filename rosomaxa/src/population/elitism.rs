@@ -50,6 +50,15 @@ pub trait DominanceOrdered {
 pub trait Shuffled {
     /// Returns a new objective.
     fn get_shuffled(&self, random: &(dyn Random + Send + Sync)) -> Self;
+
+    /// Returns a new objective which emphasizes an objective with given index (modulo total amount),
+    /// e.g. by giving it the highest priority. Returns `None` when there is nothing to emphasize.
+    fn get_emphasized(&self, _index: usize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 /// Contains ordering information about individual in population.
@@ -108,15 +117,17 @@ where
             _ => self.selection_size,
         };
 
+        self.select_n(selection_size)
+    }
+
+    fn select_n<'a>(&'a self, n: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
         if self.individuals.is_empty() {
             Box::new(empty())
         } else {
             Box::new(
                 once(0_usize)
-                    .chain(
-                        (1..selection_size).map(move |_| self.random.uniform_int(0, self.size() as i32 - 1) as usize),
-                    )
-                    .take(selection_size)
+                    .chain((1..n).map(move |_| self.random.uniform_int(0, self.size() as i32 - 1) as usize))
+                    .take(n)
                     .filter_map(move |idx| self.individuals.get(idx)),
             )
         }
@@ -130,6 +141,14 @@ where
         Box::new(self.individuals.iter())
     }
 
+    fn revalidate(&mut self, revalidate_fn: &dyn Fn(&mut Self::Individual)) {
+        self.individuals.iter_mut().for_each(revalidate_fn);
+
+        if !self.individuals.is_empty() {
+            self.sort();
+        }
+    }
+
     fn size(&self) -> usize {
         self.individuals.len()
     }
@@ -137,6 +156,13 @@ where
     fn selection_phase(&self) -> SelectionPhase {
         SelectionPhase::Exploitation
     }
+
+    fn settings(&self) -> Option<PopulationSettings> {
+        Some(PopulationSettings::Elitism {
+            max_population_size: self.max_population_size,
+            selection_size: self.selection_size,
+        })
+    }
 }
 
 impl<O, S> Elitism<O, S>
@@ -52,8 +52,12 @@ where
     }
 
     fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        self.select_n(self.selection_size)
+    }
+
+    fn select_n<'a>(&'a self, n: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
         if let Some(best_known) = self.best_known.as_ref() {
-            Box::new(repeat(best_known).take(self.selection_size))
+            Box::new(repeat(best_known).take(n))
         } else {
             Box::new(empty())
         }
@@ -67,6 +71,10 @@ where
         Box::new(self.best_known.iter())
     }
 
+    fn revalidate(&mut self, revalidate_fn: &dyn Fn(&mut Self::Individual)) {
+        self.best_known.iter_mut().for_each(revalidate_fn);
+    }
+
     fn size(&self) -> usize {
         if self.best_known.is_some() {
             1
@@ -78,6 +86,10 @@ where
     fn selection_phase(&self) -> SelectionPhase {
         SelectionPhase::Exploitation
     }
+
+    fn settings(&self) -> Option<PopulationSettings> {
+        Some(PopulationSettings::Greedy { selection_size: self.selection_size })
+    }
 }
 
 impl<O, S> Display for Greedy<O, S>
@@ -10,6 +10,7 @@ mod greedy;
 pub use self::greedy::Greedy;
 
 mod rosomaxa;
+pub use self::rosomaxa::NetworkStats;
 pub use self::rosomaxa::Rosomaxa;
 pub use self::rosomaxa::RosomaxaConfig;
 pub use self::rosomaxa::RosomaxaWeighted;
@@ -19,7 +20,7 @@ use std::cmp::Ordering;
 use std::fmt::Display;
 
 /// Specifies a selection phase.
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SelectionPhase {
     /// A phase of building an initial solution(-s).
     Initial,
@@ -29,6 +30,25 @@ pub enum SelectionPhase {
     Exploitation,
 }
 
+/// Specifies effective settings of built-in population types.
+#[derive(Clone, Debug)]
+pub enum PopulationSettings {
+    /// Settings of greedy population.
+    Greedy {
+        /// Selection size.
+        selection_size: usize,
+    },
+    /// Settings of elitism population.
+    Elitism {
+        /// Max population size.
+        max_population_size: usize,
+        /// Selection size.
+        selection_size: usize,
+    },
+    /// Settings of rosomaxa population.
+    Rosomaxa(Box<RosomaxaConfig>),
+}
+
 /// A trait which models a population with individuals.
 pub trait HeuristicPopulation: Display + Send + Sync {
     /// A heuristic objective type.
@@ -54,15 +74,39 @@ pub trait HeuristicPopulation: Display + Send + Sync {
     /// Selects parents from the population based on current selection phase.
     fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a>;
 
+    /// Selects up to `n` parents from the population based on current selection phase.
+    /// By default, takes first `n` individuals returned by `select`, so, whether the best known
+    /// individual is selected first depends on population settings.
+    fn select_n<'a>(&'a self, n: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        Box::new(self.select().take(n))
+    }
+
     /// Returns subset of individuals within their rank sorted according their quality.
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a>;
 
     /// Returns all individuals in arbitrary order.
     fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a>;
 
+    /// Returns up to `k` near optimal individuals which are diverse between each other.
+    /// By default, returns the best ranked individuals.
+    fn sample_diverse<'a>(&'a self, k: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        Box::new(self.ranked().map(|(individual, _)| individual).take(k))
+    }
+
+    /// Applies given function to each individual, e.g. to re-evaluate it after a problem change,
+    /// and re-orders the population accordingly. By default, does nothing, so custom population
+    /// types have to override it to support problem changes.
+    fn revalidate(&mut self, _revalidate_fn: &dyn Fn(&mut Self::Individual)) {}
+
     /// Returns population size.
     fn size(&self) -> usize;
 
     /// Returns a current selection phase.
     fn selection_phase(&self) -> SelectionPhase;
+
+    /// Returns effective settings of the population. By default, returns nothing, so custom
+    /// population types are not required to report them.
+    fn settings(&self) -> Option<PopulationSettings> {
+        None
+    }
 }
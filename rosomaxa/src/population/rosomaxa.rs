@@ -4,16 +4,19 @@ mod rosomaxa_test;
 
 use super::*;
 use crate::algorithms::gsom::*;
-use crate::algorithms::math::relative_distance;
+use crate::algorithms::math::{get_value_ranges, normalized_distance, relative_distance, DistanceMetric};
 use crate::population::elitism::{DedupFn, DominanceOrdered, Shuffled};
-use crate::utils::{Environment, Random};
+use crate::utils::{compare_floats_rounded, SolverLogger, FLOAT_EPSILON};
+use crate::utils::{compare_floats_with_epsilon, emit_diagnostic, DiagnosticEvent, Environment, LogLevel, Random};
+use hashbrown::HashSet;
 use rand::prelude::SliceRandom;
-use std::convert::TryInto;
 use std::fmt::Formatter;
 use std::ops::RangeBounds;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, RwLock};
 
 /// Specifies rosomaxa configuration settings.
+#[derive(Clone, Debug)]
 pub struct RosomaxaConfig {
     /// Selection size.
     pub selection_size: usize,
@@ -21,18 +24,76 @@ pub struct RosomaxaConfig {
     pub elite_size: usize,
     /// Node population size.
     pub node_size: usize,
+    /// Node selection size, so a node can keep more individuals than it selects.
+    /// `node_size` is used if not set.
+    pub node_selection_size: Option<usize>,
+    /// Max amount of elite individuals promoted to selection in exploration phase when selection
+    /// size is large enough. Actual amount is sampled from `[1, elite_selection_size]` range.
+    pub elite_selection_size: usize,
+    /// Max amount of individuals promoted to selection from each network node in exploration phase.
+    pub node_promotion_size: usize,
     /// Spread factor of GSOM.
     pub spread_factor: f64,
     /// Distribution factor of GSOM.
     pub distribution_factor: f64,
     /// Objective reshuffling probability.
     pub objective_reshuffling: f64,
+    /// If set to true, network nodes alternate emphasis between objectives, so the network
+    /// covers different regions of their trade-off.
+    pub objective_emphasis: bool,
     /// Learning rate of GSOM.
     pub learning_rate: f64,
     /// A node rebalance memory of GSOM.
     pub rebalance_memory: usize,
+    /// A neighbourhood function of GSOM.
+    pub neighbourhood: NeighbourhoodFunction,
     /// A ratio of exploration phase.
     pub exploration_ratio: f64,
+    /// A max amount of generations without network growth after which exploration phase
+    /// is considered as converged and ends earlier. No limit if not set.
+    pub max_generations_without_growth: Option<usize>,
+    /// A max amount of generations without improvement of the best known individual after which
+    /// exploration phase ends earlier. No limit if not set.
+    pub stagnation_limit: Option<usize>,
+    /// If set to true, selection in exploration phase does not promote elite individuals
+    /// and draws them from network nodes only, so the best known individual is not guaranteed
+    /// to be selected first. Elite is still tracked.
+    pub disable_elite_selection: bool,
+    /// A soft cap on amount of network nodes. When exceeded, nodes the most distant from the best
+    /// known individuals are pruned. Memory usage is not estimated: as each node keeps up to
+    /// `node_size` individuals and elite is capped by its own size, the cap bounds total amount of
+    /// stored individuals only. No limit if not set.
+    pub max_node_count: Option<usize>,
+    /// A max amount of generations since the last hit after which network node is considered
+    /// as stale and removed, its individuals are moved to the closest remaining nodes. Network is not pruned
+    /// below `initial_size` nodes. Nodes are not removed by age if not set.
+    pub max_node_age: Option<usize>,
+    /// A share of selection size drawn from network nodes in exploration phase, the rest is drawn
+    /// from elite. Should be in `[0, 1]` range, e.g. lower value makes search more greedy. If not set,
+    /// selection is apportioned using built-in rules.
+    pub explore_exploit_ratio: Option<f64>,
+    /// A termination estimate after which the network stops growing and only updates existing nodes,
+    /// so late exploration refines the map instead of expanding it before it is abandoned. Should be
+    /// in `[0, 1]` range and below exploration ratio to have an effect. Growth is never frozen if not set.
+    pub growth_freeze_threshold: Option<f64>,
+    /// If set to true, distances between network weights and between fitness values used to optimize
+    /// network are normalized by observed value range per dimension, so objectives of very different
+    /// scale contribute comparably. Otherwise, relative distance is used.
+    pub fitness_normalization: bool,
+    /// Amount of individuals used to seed initial nodes of the network. Initial phase ends once
+    /// that many individuals are collected. Should be at least 2.
+    pub initial_size: usize,
+    /// A metric used to measure distance between network weights and between node fitness when
+    /// network is compacted or pruned. Except for pruning, it is replaced by normalized distance
+    /// when fitness normalization is enabled.
+    pub distance_metric: DistanceMetric,
+    /// An epsilon used to compare distances when network is optimized, so floating point noise
+    /// doesn't affect which nodes are removed. Should be non-negative.
+    pub float_epsilon: f64,
+    /// If set to true, weights of individuals stored in the network are clamped to the range of node
+    /// weights observed so far, so a single outlier cannot stretch the map. Individuals with non-finite
+    /// weights are rejected regardless of this option.
+    pub clamp_inputs: bool,
 }
 
 impl RosomaxaConfig {
@@ -43,16 +104,46 @@ impl RosomaxaConfig {
             selection_size,
             elite_size: 2,
             node_size: 2,
+            node_selection_size: None,
+            elite_selection_size: 2,
+            node_promotion_size: 2,
             spread_factor: 0.75,
             distribution_factor: 0.75,
             objective_reshuffling: 0.01,
+            objective_emphasis: false,
             learning_rate: 0.1,
             rebalance_memory: 100,
+            neighbourhood: NeighbourhoodFunction::default(),
             exploration_ratio: 0.9,
+            max_generations_without_growth: None,
+            stagnation_limit: None,
+            disable_elite_selection: false,
+            max_node_count: None,
+            max_node_age: None,
+            explore_exploit_ratio: None,
+            growth_freeze_threshold: None,
+            fitness_normalization: false,
+            initial_size: 4,
+            distance_metric: DistanceMetric::default(),
+            float_epsilon: FLOAT_EPSILON,
+            clamp_inputs: false,
         }
     }
 }
 
+/// Provides statistics of GSOM network used in exploration phase.
+#[derive(Clone, Debug)]
+pub struct NetworkStats {
+    /// Amount of network nodes.
+    pub node_count: usize,
+    /// Mean of total hits per node.
+    pub mean_hits: f64,
+    /// Max of total hits per node.
+    pub max_hits: usize,
+    /// Current selection phase.
+    pub phase: SelectionPhase,
+}
+
 /// Specifies behavior which keeps track of weights used to distinguish different solutions.
 pub trait RosomaxaWeighted: Input {
     /// Initializes weights.
@@ -71,6 +162,10 @@ where
     config: RosomaxaConfig,
     elite: Elitism<O, S>,
     phase: RosomaxaPhases<O, S>,
+    best_node_trajectory: Vec<(usize, Coordinate)>,
+    generation: usize,
+    last_improvement: usize,
+    weight_ranges: Option<ValueRanges>,
 }
 
 impl<O, S> HeuristicPopulation for Rosomaxa<O, S>
@@ -96,11 +191,16 @@ where
                 known_individuals.extend(individuals.into_iter())
             }
             RosomaxaPhases::Exploration { network, statistics, .. } => {
-                network.store_batch(individuals, statistics.generation, init_individual);
+                let nodes_before = network.size();
+                let result = network.store_batch(individuals, statistics.generation, init_individual);
+                log_rejected(&self.environment, result);
+                log_growth(self.environment.solver_logger.as_ref(), self.generation, nodes_before, network.size());
             }
             RosomaxaPhases::Exploitation { .. } => {}
         }
 
+        self.track_improvement(is_improved);
+
         is_improved
     }
 
@@ -115,10 +215,16 @@ where
 
         match &mut self.phase {
             RosomaxaPhases::Initial { solutions: individuals } => individuals.push(individual),
-            RosomaxaPhases::Exploration { network, statistics, .. } => network.store(individual, statistics.generation),
+            RosomaxaPhases::Exploration { network, statistics, .. } => {
+                let nodes_before = network.size();
+                log_rejected(&self.environment, network.store(individual, statistics.generation));
+                log_growth(self.environment.solver_logger.as_ref(), self.generation, nodes_before, network.size());
+            }
             RosomaxaPhases::Exploitation { .. } => {}
         }
 
+        self.track_improvement(is_improved);
+
         is_improved
     }
 
@@ -132,42 +238,66 @@ where
 
     fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
         match &self.phase {
-            RosomaxaPhases::Exploration { network, coordinates, selection_size, .. } => {
-                let (elite_explore_size, node_explore_size) = match *selection_size {
+            RosomaxaPhases::Exploration { selection_size, .. } => self.select_n(*selection_size),
+            RosomaxaPhases::Exploitation { selection_size } => Box::new(self.elite.select().take(*selection_size)),
+            _ => Box::new(self.elite.select()),
+        }
+    }
+
+    fn select_n<'a>(&'a self, n: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        match &self.phase {
+            RosomaxaPhases::Exploration { network, coordinates, .. } => {
+                let (elite_explore_size, node_explore_size) = match n {
                     value if value > 6 => {
-                        let elite_size = self.environment.random.uniform_int(1, 2) as usize;
-                        (elite_size, 2)
+                        let elite_size =
+                            self.environment.random.uniform_int(1, self.config.elite_selection_size as i32) as usize;
+                        (elite_size, self.config.node_promotion_size)
                     }
-                    value if value > 4 => (1, 2),
+                    value if value > 4 => (1, self.config.node_promotion_size),
                     _ => (1, 1),
                 };
+                let (elite_explore_size, network_explore_size) = match self.config.explore_exploit_ratio {
+                    Some(ratio) => {
+                        let elite_size = (n as f64 * (1. - ratio)).round() as usize;
+                        (elite_size, n - elite_size)
+                    }
+                    None => (elite_explore_size, n),
+                };
+                let elite_explore_size = if self.config.disable_elite_selection { 0 } else { elite_explore_size };
 
                 Box::new(
                     self.elite
                         .select()
                         .take(elite_explore_size)
-                        .chain(coordinates.iter().flat_map(move |coordinate| {
-                            let explore_size = self.environment.random.uniform_int(1, node_explore_size) as usize;
-
-                            network
-                                .find(coordinate)
-                                .map(|node| {
-                                    let node = node.read().unwrap();
-                                    // NOTE this is black magic to trick borrow checker, it should be safe to do
-                                    // TODO is there better way to achieve similar result?
-                                    unsafe { &*(&node.storage.population as *const Elitism<O, S>) as &Elitism<O, S> }
-                                        .select()
-                                        .take(explore_size)
-                                        .collect::<Vec<_>>()
+                        .chain(
+                            coordinates
+                                .iter()
+                                .flat_map(move |coordinate| {
+                                    let explore_size =
+                                        self.environment.random.uniform_int(1, node_explore_size as i32) as usize;
+
+                                    network
+                                        .find(coordinate)
+                                        .map(|node| {
+                                            let node = node.read().unwrap();
+                                            // NOTE this is black magic to trick borrow checker, it should be safe to do
+                                            // TODO is there better way to achieve similar result?
+                                            unsafe {
+                                                &*(&node.storage.population as *const Elitism<O, S>) as &Elitism<O, S>
+                                            }
+                                            .select()
+                                            .take(explore_size)
+                                            .collect::<Vec<_>>()
+                                        })
+                                        .unwrap_or_else(Vec::new)
+                                        .into_iter()
                                 })
-                                .unwrap_or_else(Vec::new)
-                                .into_iter()
-                        }))
-                        .take(*selection_size),
+                                .take(network_explore_size),
+                        )
+                        .take(n),
                 )
             }
-            RosomaxaPhases::Exploitation { selection_size } => Box::new(self.elite.select().take(*selection_size)),
-            _ => Box::new(self.elite.select()),
+            _ => self.elite.select_n(n),
         }
     }
 
@@ -188,6 +318,63 @@ where
         }
     }
 
+    fn sample_diverse<'a>(&'a self, k: usize) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        // NOTE consider only the better half of all individuals, then greedily pick the one which
+        // is the most distant in weight space from already selected ones, starting from the best
+        let mut candidates = self.all().collect::<Vec<_>>();
+        candidates.sort_by(|a, b| self.elite.cmp(a, b));
+        candidates.truncate(k.max((candidates.len() as f64 / 2.).ceil() as usize));
+
+        let mut selected: Vec<&S> = Vec::with_capacity(k);
+        while selected.len() < k && !candidates.is_empty() {
+            let idx = candidates
+                .iter()
+                .enumerate()
+                .map(|(idx, candidate)| {
+                    let min_distance = selected
+                        .iter()
+                        .map(|individual| {
+                            let metric = &self.config.distance_metric;
+                            metric.distance(candidate.weights().iter().cloned(), individual.weights().iter().cloned())
+                        })
+                        .min_by(|a, b| compare_floats(*a, *b))
+                        .unwrap_or(0.);
+
+                    (idx, min_distance)
+                })
+                .max_by(|(idx_a, a), (idx_b, b)| compare_floats(*a, *b).then_with(|| idx_b.cmp(idx_a)))
+                .map(|(idx, _)| idx)
+                .expect("no candidates");
+
+            selected.push(candidates.remove(idx));
+        }
+
+        Box::new(selected.into_iter())
+    }
+
+    fn revalidate(&mut self, revalidate_fn: &dyn Fn(&mut Self::Individual)) {
+        // NOTE weights are derived from individual, so they have to be recalculated
+        let revalidate_fn = |individual: &mut S| {
+            revalidate_fn(individual);
+            individual.init_weights();
+        };
+
+        self.elite.revalidate(&revalidate_fn);
+
+        match &mut self.phase {
+            RosomaxaPhases::Initial { solutions } => solutions.iter_mut().for_each(revalidate_fn),
+            RosomaxaPhases::Exploration { network, .. } => {
+                network.get_nodes().for_each(|node| {
+                    node.write().unwrap().storage.population.revalidate(&revalidate_fn);
+                });
+
+                // NOTE node weights are learned from individuals, so they have to follow changes
+                network.recenter();
+            }
+            RosomaxaPhases::Exploitation { .. } => {}
+        }
+    }
+
     fn size(&self) -> usize {
         self.elite.size()
     }
@@ -199,9 +386,16 @@ where
             RosomaxaPhases::Exploitation { .. } => SelectionPhase::Exploitation,
         }
     }
+
+    fn settings(&self) -> Option<PopulationSettings> {
+        Some(PopulationSettings::Rosomaxa(Box::new(self.config.clone())))
+    }
 }
 
+/// Observed value ranges of network weights shared between node storages.
+type ValueRanges = Arc<RwLock<Vec<(f64, f64)>>>;
 type IndividualNetwork<O, S> = Network<S, IndividualStorage<O, S>, IndividualStorageFactory<O, S>>;
+type IndividualNode<O, S> = NodeLink<S, IndividualStorage<O, S>>;
 
 impl<O, S> Rosomaxa<O, S>
 where
@@ -210,8 +404,57 @@ where
 {
     /// Creates a new instance of `Rosomaxa`.
     pub fn new(objective: Arc<O>, environment: Arc<Environment>, config: RosomaxaConfig) -> Result<Self, String> {
-        if config.elite_size < 1 || config.node_size < 1 || config.selection_size < 2 {
-            return Err("Rosomaxa algorithm requires some parameters to be above thresholds".to_string());
+        if config.elite_size < 1 {
+            return Err(format!("Rosomaxa algorithm requires elite size to be at least 1, got {}", config.elite_size));
+        }
+
+        if config.node_size < 1 {
+            return Err(format!("Rosomaxa algorithm requires node size to be at least 1, got {}", config.node_size));
+        }
+
+        if config.selection_size < 2 {
+            return Err(format!(
+                "Rosomaxa algorithm requires selection size to be at least 2, got {}",
+                config.selection_size
+            ));
+        }
+
+        if config.initial_size < 2 {
+            return Err("Rosomaxa algorithm requires initial size to be at least 2".to_string());
+        }
+
+        if !config.neighbourhood.is_valid() {
+            return Err("Rosomaxa algorithm requires neighbourhood function parameters to be positive".to_string());
+        }
+
+        if !config.distance_metric.is_valid() {
+            return Err("Rosomaxa algorithm requires distance metric weights to be non-negative".to_string());
+        }
+
+        if config.elite_selection_size < 1 || config.node_promotion_size < 1 {
+            return Err(
+                "Rosomaxa algorithm requires elite selection and node promotion sizes to be at least 1".to_string()
+            );
+        }
+
+        if matches!(config.node_selection_size, Some(size) if size < 1 || size > config.node_size) {
+            return Err("Rosomaxa algorithm requires node selection size to be in range [1, node size]".to_string());
+        }
+
+        if matches!(config.explore_exploit_ratio, Some(ratio) if !(0. ..=1.).contains(&ratio)) {
+            return Err("Rosomaxa algorithm requires explore exploit ratio to be in range [0, 1]".to_string());
+        }
+
+        if matches!(config.stagnation_limit, Some(limit) if limit < 1) {
+            return Err("Rosomaxa algorithm requires stagnation limit to be at least 1".to_string());
+        }
+
+        if !(0. ..f64::INFINITY).contains(&config.float_epsilon) {
+            return Err("Rosomaxa algorithm requires float epsilon to be non-negative and finite".to_string());
+        }
+
+        if matches!(config.growth_freeze_threshold, Some(threshold) if !(0. ..=1.).contains(&threshold)) {
+            return Err("Rosomaxa algorithm requires growth freeze threshold to be in range [0, 1]".to_string());
         }
 
         Ok(Self {
@@ -225,11 +468,73 @@ where
                 create_dedup_fn(0.02),
             ),
             phase: RosomaxaPhases::Initial { solutions: vec![] },
+            best_node_trajectory: vec![],
+            generation: 0,
+            last_improvement: 0,
+            weight_ranges: if config.fitness_normalization { Some(ValueRanges::default()) } else { None },
             config,
         })
     }
 
+    /// Returns a trajectory of the best network node as a sequence of _(generation, node coordinate)_
+    /// pairs: the node which holds the best individual is recorded at each optimization cycle of
+    /// exploration phase.
+    pub fn get_best_node_trajectory(&self) -> &[(usize, Coordinate)] {
+        self.best_node_trajectory.as_slice()
+    }
+
+    /// Returns statistics of the network, e.g. to observe how it grows over generations.
+    /// Returns `None` outside of exploration phase as there is no network.
+    pub fn network_stats(&self) -> Option<NetworkStats> {
+        match &self.phase {
+            RosomaxaPhases::Exploration { network, .. } => {
+                let hits = network.get_nodes().map(|node| node.read().unwrap().total_hits).collect::<Vec<_>>();
+                let node_count = hits.len();
+                let mean_hits = if node_count > 0 { hits.iter().sum::<usize>() as f64 / node_count as f64 } else { 0. };
+                let max_hits = hits.into_iter().max().unwrap_or(0);
+
+                Some(NetworkStats { node_count, mean_hits, max_hits, phase: self.selection_phase() })
+            }
+            _ => None,
+        }
+    }
+
+    /// Forces transition to exploitation phase, e.g. when search schedule is controlled externally.
+    /// Elite is seeded with individuals from network populations before the network is dropped,
+    /// so only the best of them are kept. Does nothing if exploitation phase is already active.
+    pub fn enter_exploitation(&mut self) {
+        let (individuals, selection_size) = match &mut self.phase {
+            RosomaxaPhases::Initial { solutions } => {
+                (solutions.drain(0..).map(init_individual).collect::<Vec<_>>(), self.config.selection_size)
+            }
+            RosomaxaPhases::Exploration { network, selection_size, .. } => (
+                network
+                    .get_nodes()
+                    .flat_map(|node| {
+                        node.read()
+                            .unwrap()
+                            .storage
+                            .population
+                            .all()
+                            .map(|individual| init_individual(individual.deep_copy()))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect(),
+                *selection_size,
+            ),
+            RosomaxaPhases::Exploitation { .. } => return,
+        };
+
+        let from = self.selection_phase();
+        self.elite.add_all(individuals);
+        self.phase = RosomaxaPhases::Exploitation { selection_size };
+        self.log_phase_transition(from);
+    }
+
     fn update_phase(&mut self, statistics: &HeuristicStatistics) {
+        self.generation = statistics.generation;
+        let from = self.selection_phase();
+
         let selection_size = match statistics.speed {
             HeuristicSpeed::Unknown | HeuristicSpeed::Moderate { .. } => self.config.selection_size,
             HeuristicSpeed::Slow { ratio, .. } => (self.config.selection_size as f64 * ratio).max(1.).round() as usize,
@@ -237,14 +542,30 @@ where
 
         match &mut self.phase {
             RosomaxaPhases::Initial { solutions: individuals, .. } => {
-                if individuals.len() >= 4 {
+                // NOTE individuals with non-finite weights cannot be used as network roots
+                let (valid, invalid): (Vec<_>, Vec<_>) = individuals
+                    .drain(0..)
+                    .map(init_individual)
+                    .partition(|individual| individual.weights().iter().all(|value| value.is_finite()));
+                invalid.iter().for_each(|individual| {
+                    log_rejected(
+                        &self.environment,
+                        Err(format!("input is rejected: weights have non-finite values: {:?}", individual.weights())),
+                    )
+                });
+                *individuals = valid;
+
+                if individuals.len() >= self.config.initial_size {
                     let mut network = Self::create_network(
                         self.objective.clone(),
                         self.environment.clone(),
                         &self.config,
-                        individuals.drain(0..4).collect(),
+                        self.weight_ranges.clone(),
+                        individuals.drain(0..self.config.initial_size).collect(),
                     );
-                    individuals.drain(0..).for_each(|individual| network.store(init_individual(individual), 0));
+                    individuals
+                        .drain(0..)
+                        .for_each(|individual| log_rejected(&self.environment, network.store(individual, 0)));
 
                     self.phase = RosomaxaPhases::Exploration {
                         network,
@@ -265,22 +586,49 @@ where
                     HeuristicSpeed::Slow { ratio, .. } => self.config.exploration_ratio * ratio,
                 };
 
-                if statistics.termination_estimate < exploration_ratio {
+                let is_converged = matches!(self.config.max_generations_without_growth,
+                    Some(max_generations) if network.get_generations_since_growth() >= max_generations);
+
+                let is_stagnated = matches!(self.config.stagnation_limit,
+                    Some(limit) if statistics.generation.saturating_sub(self.last_improvement) >= limit);
+
+                if statistics.termination_estimate < exploration_ratio && !is_converged && !is_stagnated {
                     *old_statistics = statistics.clone();
                     *old_selection_size = selection_size;
 
                     let best_individual = self.elite.select().next().expect("expected individuals in elite");
                     let best_fitness = best_individual.get_fitness().collect::<Vec<_>>();
+                    let nodes_before = network.size();
 
-                    Self::optimize_network(
-                        network,
-                        statistics,
-                        best_fitness.as_slice(),
-                        self.config.rebalance_memory,
-                        self.config.learning_rate,
-                    );
+                    network.set_growth_frozen(matches!(self.config.growth_freeze_threshold,
+                        Some(threshold) if statistics.termination_estimate >= threshold));
+
+                    if let Some(weight_ranges) = self.weight_ranges.as_ref() {
+                        let weights =
+                            network.get_nodes().map(|node| node.read().unwrap().weights.clone()).collect::<Vec<_>>();
+                        *weight_ranges.write().unwrap() = get_value_ranges(weights.iter().map(|w| w.iter().cloned()));
+                    }
+
+                    Self::optimize_network(network, statistics, best_fitness.as_slice(), &self.config);
+
+                    if let Some(max_node_count) = self.config.max_node_count {
+                        let metric = &self.config.distance_metric;
+                        Self::prune_network(network, best_fitness.as_slice(), max_node_count, metric);
+                    }
+
+                    emit_diagnostic(self.environment.solver_logger.as_ref(), LogLevel::Debug, || {
+                        DiagnosticEvent::RebalanceCycle {
+                            generation: statistics.generation,
+                            nodes_before,
+                            nodes_after: network.size(),
+                        }
+                    });
 
                     Self::fill_populations(network, coordinates, self.environment.random.as_ref());
+
+                    if let Some(coordinate) = Self::find_best_node(network, self.objective.as_ref()) {
+                        self.best_node_trajectory.push((statistics.generation, coordinate));
+                    }
                 } else {
                     self.phase = RosomaxaPhases::Exploitation { selection_size }
                 }
@@ -289,6 +637,33 @@ where
                 *old_selection_size = selection_size;
             }
         }
+
+        self.log_phase_transition(from);
+    }
+
+    fn log_phase_transition(&self, from: SelectionPhase) {
+        let to = self.selection_phase();
+        if from != to {
+            emit_diagnostic(self.environment.solver_logger.as_ref(), LogLevel::Info, || {
+                DiagnosticEvent::PhaseTransition { generation: self.generation, from, to }
+            });
+        }
+    }
+
+    fn track_improvement(&mut self, is_improved: bool) {
+        if is_improved {
+            self.last_improvement = self.generation;
+        }
+
+        self.log_new_best(is_improved);
+    }
+
+    fn log_new_best(&self, is_improved: bool) {
+        if is_improved {
+            emit_diagnostic(self.environment.solver_logger.as_ref(), LogLevel::Info, || DiagnosticEvent::NewBest {
+                fitness: self.elite.ranked().next().map(|(best, _)| best.get_fitness().collect()).unwrap_or_default(),
+            });
+        }
     }
 
     fn is_comparable_with_best_known(&self, individual: &S, best_known: Option<&S>) -> bool {
@@ -310,16 +685,46 @@ where
             }
         }));
 
+        // NOTE nodes have no selection weight, so shuffle breaks ties uniformly at random instead of relying
+        // on network iteration order. Generator comes from environment, so it is deterministic when seeded.
         coordinates.shuffle(&mut random.get_rng());
     }
 
+    fn find_best_node(network: &IndividualNetwork<O, S>, objective: &O) -> Option<Coordinate> {
+        // NOTE compare node leaders under read locks to avoid copying individuals
+        network
+            .iter()
+            .filter(|(_, node)| node.read().unwrap().storage.population.size() > 0)
+            .fold(None, |best: Option<(&Coordinate, &IndividualNode<O, S>)>, (coordinate, node)| match best {
+                Some((best_coordinate, best_node)) => {
+                    let (a, b) = (node.read().unwrap(), best_node.read().unwrap());
+                    let a = a.storage.population.ranked().next().map(|(individual, _)| individual);
+                    let b = b.storage.population.ranked().next().map(|(individual, _)| individual);
+
+                    let order = a.zip(b).map_or(Ordering::Equal, |(a, b)| objective.total_order(a, b));
+                    let order = order.then_with(|| coordinate.cmp(best_coordinate));
+
+                    if order == Ordering::Less {
+                        Some((coordinate, node))
+                    } else {
+                        best
+                    }
+                }
+                None => Some((coordinate, node)),
+            })
+            .map(|(coordinate, _)| *coordinate)
+    }
+
     fn optimize_network(
         network: &mut IndividualNetwork<O, S>,
         statistics: &HeuristicStatistics,
         best_fitness: &[f64],
-        rebalance_memory: usize,
-        init_learning_rate: f64,
+        config: &RosomaxaConfig,
     ) {
+        let (rebalance_memory, init_learning_rate) = (config.rebalance_memory, config.learning_rate);
+        let (fitness_normalization, max_node_age, epsilon) =
+            (config.fitness_normalization, config.max_node_age, config.float_epsilon);
+
         // https://www.wolframalpha.com/input?i=plot+2+*+%281+-+1%2F%281%2Be%5E%28-10+*%28x+-+0.5%29%29%29%29%2C+x%3D0+to+1
         let x = match statistics.improvement_1000_ratio {
             v if v < 0.25 => v,
@@ -334,6 +739,10 @@ where
             network.smooth(1);
         }
 
+        if let Some(max_node_age) = max_node_age {
+            network.prune_stale(statistics.generation, max_node_age, config.initial_size);
+        }
+
         if network.size() <= keep_size {
             return;
         }
@@ -348,16 +757,36 @@ where
             .max_by(|a, b| compare_floats(*a, *b))
             .unwrap_or(0.);
 
-        let get_distance = |node: &NodeLink<S, IndividualStorage<O, S>>| {
+        let get_best_fitness = |node: &NodeLink<S, IndividualStorage<O, S>>| {
             let node = node.read().unwrap();
-            let individual = node.storage.population.ranked().next();
+            let fitness =
+                node.storage.population.ranked().next().map(|(individual, _)| individual.get_fitness().collect());
 
-            individual.map(|(individual, _)| relative_distance(best_fitness.iter().cloned(), individual.get_fitness()))
+            fitness
+        };
+
+        // NOTE fitness ranges are observed over the best individuals of all nodes
+        let fitness_ranges = if fitness_normalization {
+            let mut fitness = network.get_nodes().filter_map(get_best_fitness).collect::<Vec<Vec<_>>>();
+            fitness.push(best_fitness.to_vec());
+
+            Some(get_value_ranges(fitness.iter().map(|fitness| fitness.iter().cloned())))
+        } else {
+            None
+        };
+
+        let get_distance = |node: &NodeLink<S, IndividualStorage<O, S>>| {
+            get_best_fitness(node).map(|fitness| match fitness_ranges.as_ref() {
+                Some(ranges) => {
+                    normalized_distance(best_fitness.iter().cloned(), fitness.into_iter(), ranges.as_slice())
+                }
+                None => config.distance_metric.distance(best_fitness.iter().cloned(), fitness.into_iter()),
+            })
         };
 
         // determine percentile value
         let mut distances = network.get_nodes().filter_map(get_distance).collect::<Vec<_>>();
-        distances.sort_by(|a, b| compare_floats(*b, *a));
+        distances.sort_by(|a, b| compare_floats_rounded(*b, *a, epsilon));
 
         const PERCENTILE_THRESHOLD: f64 = 0.1;
         let percentile_idx = (distances.len() as f64 * PERCENTILE_THRESHOLD) as usize;
@@ -368,42 +797,84 @@ where
                 // unified distance filter improves diversity property
                 // distance filter improves exploitation characteristic by removing old (or empty) nodes
 
-                let is_far_enough = compare_floats(unified_distance, max_unified_distance * 0.1) != Ordering::Less;
-                is_far_enough && get_distance(node).map_or(false, |distance| distance < distance_threshold)
+                let is_far_enough = compare_floats_with_epsilon(unified_distance, max_unified_distance * 0.1, epsilon)
+                    != Ordering::Less;
+                is_far_enough
+                    && get_distance(node).map_or(false, |distance| {
+                        compare_floats_with_epsilon(distance, distance_threshold, epsilon) == Ordering::Less
+                    })
             });
             network.smooth(1);
         }
     }
 
+    fn prune_network(
+        network: &mut IndividualNetwork<O, S>,
+        best_fitness: &[f64],
+        max_node_count: usize,
+        distance_metric: &DistanceMetric,
+    ) {
+        if network.size() <= max_node_count {
+            return;
+        }
+
+        // NOTE empty nodes are pruned first, then the ones with the most distant individuals
+        let mut distances = network
+            .iter()
+            .map(|(coordinate, node)| {
+                let node = node.read().unwrap();
+                let distance = node
+                    .storage
+                    .population
+                    .ranked()
+                    .next()
+                    .map(|(individual, _)| {
+                        distance_metric.distance(best_fitness.iter().cloned(), individual.get_fitness())
+                    })
+                    .unwrap_or(f64::MAX);
+
+                (*coordinate, distance)
+            })
+            .collect::<Vec<_>>();
+        distances.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+        let keep = distances.into_iter().take(max_node_count).map(|(coordinate, _)| coordinate).collect::<HashSet<_>>();
+
+        network.compact(&|node, _| keep.contains(&node.read().unwrap().coordinate));
+        network.smooth(1);
+    }
+
     fn create_network(
         objective: Arc<O>,
         environment: Arc<Environment>,
         config: &RosomaxaConfig,
-        individuals: Vec<S>,
+        weight_ranges: Option<ValueRanges>,
+        inputs: Vec<S>,
     ) -> IndividualNetwork<O, S> {
-        let inputs_vec = individuals.into_iter().map(init_individual).collect::<Vec<_>>();
-
-        let inputs_slice = inputs_vec.into_boxed_slice();
-        let inputs_array: Box<[S; 4]> = match inputs_slice.try_into() {
-            Ok(ba) => ba,
-            Err(o) => panic!("expected individuals of length {} but it was {}", 4, o.len()),
-        };
+        assert_eq!(inputs.len(), config.initial_size);
 
         let storage_factory = IndividualStorageFactory {
             node_size: config.node_size,
+            node_selection_size: config.node_selection_size.unwrap_or(config.node_size),
             reshuffling_probability: config.objective_reshuffling,
+            has_emphasis: config.objective_emphasis,
+            node_counter: AtomicUsize::new(0),
             random: environment.random.clone(),
             objective,
+            weight_ranges,
+            distance_metric: config.distance_metric.clone(),
         };
 
         Network::new(
-            *inputs_array,
+            inputs,
             NetworkConfig {
                 spread_factor: config.spread_factor,
                 distribution_factor: config.distribution_factor,
                 learning_rate: config.learning_rate,
                 rebalance_memory: config.rebalance_memory,
                 has_initial_error: true,
+                clamp_inputs: config.clamp_inputs,
+                neighbourhood: config.neighbourhood.clone(),
             },
             environment.random.clone(),
             storage_factory,
@@ -472,15 +943,41 @@ where
     individual
 }
 
+/// Logs an input rejected by the network, e.g. individual with non-finite weights.
+fn log_rejected(environment: &Environment, result: Result<(), String>) {
+    if let Err(err) = result {
+        (environment.logger)(err.as_str());
+        emit_diagnostic(environment.solver_logger.as_ref(), LogLevel::Warn, || DiagnosticEvent::InputRejected {
+            reason: err,
+        });
+    }
+}
+
+/// Logs growth of the network, if any.
+fn log_growth(logger: &dyn SolverLogger, generation: usize, nodes_before: usize, nodes_after: usize) {
+    if nodes_after > nodes_before {
+        emit_diagnostic(logger, LogLevel::Debug, || DiagnosticEvent::NetworkGrowth {
+            generation,
+            nodes_before,
+            nodes_after,
+        });
+    }
+}
+
 struct IndividualStorageFactory<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
     S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
 {
     node_size: usize,
+    node_selection_size: usize,
     reshuffling_probability: f64,
+    has_emphasis: bool,
+    node_counter: AtomicUsize,
     random: Arc<dyn Random + Send + Sync>,
     objective: Arc<O>,
+    weight_ranges: Option<ValueRanges>,
+    distance_metric: DistanceMetric,
 }
 
 impl<O, S> StorageFactory<S, IndividualStorage<O, S>> for IndividualStorageFactory<O, S>
@@ -489,17 +986,28 @@ where
     S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
 {
     fn eval(&self) -> IndividualStorage<O, S> {
+        let objective = if self.has_emphasis {
+            let index = self.node_counter.fetch_add(1, AtomicOrdering::Relaxed);
+            self.objective.get_emphasized(index).map(Arc::new)
+        } else {
+            None
+        };
+
         let mut elitism = Elitism::new_with_dedup(
-            self.objective.clone(),
+            objective.unwrap_or_else(|| self.objective.clone()),
             self.random.clone(),
             self.node_size,
-            self.node_size,
+            self.node_selection_size,
             create_dedup_fn(0.1),
         );
         if self.random.is_hit(self.reshuffling_probability) {
             elitism.shuffle_objective();
         }
-        IndividualStorage { population: elitism }
+        IndividualStorage {
+            population: elitism,
+            weight_ranges: self.weight_ranges.clone(),
+            distance_metric: self.distance_metric.clone(),
+        }
     }
 }
 
@@ -509,6 +1017,8 @@ where
     S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
 {
     population: Elitism<O, S>,
+    weight_ranges: Option<ValueRanges>,
+    distance_metric: DistanceMetric,
 }
 
 impl<O, S> Storage for IndividualStorage<O, S>
@@ -534,7 +1044,12 @@ where
     }
 
     fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
-        relative_distance(a.iter().cloned(), b.iter().cloned())
+        match self.weight_ranges.as_ref().map(|ranges| ranges.read().unwrap()) {
+            Some(ranges) if !ranges.is_empty() => {
+                normalized_distance(a.iter().cloned(), b.iter().cloned(), ranges.as_slice())
+            }
+            _ => self.distance_metric.distance(a.iter().cloned(), b.iter().cloned()),
+        }
     }
 
     fn size(&self) -> usize {
@@ -16,13 +16,15 @@ pub use crate::evolution::InitialOperators;
 pub use crate::evolution::TelemetryMode;
 
 pub use crate::population::HeuristicPopulation;
+pub use crate::population::PopulationSettings;
 pub use crate::population::RosomaxaConfig;
 pub use crate::population::SelectionPhase;
 
 pub use crate::hyper::HeuristicSearchOperator;
+pub use crate::hyper::HeuristicSettings;
 pub use crate::hyper::HyperHeuristic;
 
-pub use crate::termination::Termination;
+pub use crate::termination::{Termination, TerminationSettings};
 
 pub use crate::algorithms::nsga2::MultiObjective;
 pub use crate::algorithms::nsga2::Objective;
@@ -34,4 +36,5 @@ pub use crate::utils::Environment;
 pub use crate::utils::InfoLogger;
 pub use crate::utils::Noise;
 pub use crate::utils::Quota;
+pub use crate::utils::{DiagnosticEvent, LogLevel, NoopSolverLogger, SolverLogger};
 pub use crate::utils::{Random, RandomGen};
@@ -44,4 +44,8 @@ where
     fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
         (heuristic_ctx.statistics().generation as f64 / self.limit as f64).min(1.)
     }
+
+    fn settings(&self) -> Vec<TerminationSettings> {
+        vec![TerminationSettings::MaxGeneration { limit: self.limit }]
+    }
 }
@@ -46,4 +46,8 @@ where
     fn estimate(&self, _: &Self::Context) -> f64 {
         (self.start.elapsed_secs_as_f64() / self.limit_in_secs).min(1.)
     }
+
+    fn settings(&self) -> Vec<TerminationSettings> {
+        vec![TerminationSettings::MaxTime { limit_in_secs: self.limit_in_secs }]
+    }
 }
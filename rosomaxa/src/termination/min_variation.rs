@@ -169,4 +169,13 @@ where
     fn estimate(&self, _: &Self::Context) -> f64 {
         0.
     }
+
+    fn settings(&self) -> Vec<TerminationSettings> {
+        let (sample, period) = match &self.interval_type {
+            IntervalType::Sample(sample) => (Some(*sample), None),
+            IntervalType::Period(period) => (None, Some((*period / 1000) as usize)),
+        };
+
+        vec![TerminationSettings::MinVariation { sample, period, threshold: self.threshold, is_global: self.is_global }]
+    }
 }
@@ -16,6 +16,45 @@ pub trait Termination {
 
     /// Returns a relative estimation till termination. Value is in the `[0, 1]` range.
     fn estimate(&self, heuristic_ctx: &Self::Context) -> f64;
+
+    /// Returns effective settings of the termination criteria. By default, returns nothing, so custom
+    /// termination types are not required to report them.
+    fn settings(&self) -> Vec<TerminationSettings> {
+        vec![]
+    }
+}
+
+/// Specifies effective settings of built-in termination criteria.
+#[derive(Clone, Debug)]
+pub enum TerminationSettings {
+    /// Settings of max generation termination.
+    MaxGeneration {
+        /// Max amount of generations.
+        limit: usize,
+    },
+    /// Settings of max time termination.
+    MaxTime {
+        /// Max time in seconds.
+        limit_in_secs: f64,
+    },
+    /// Settings of min variation termination.
+    MinVariation {
+        /// A sample size, if sample interval type is used.
+        sample: Option<usize>,
+        /// A period in seconds, if period interval type is used.
+        period: Option<usize>,
+        /// A variation coefficient threshold.
+        threshold: f64,
+        /// True if termination is checked in all selection phases, not only in exploitation.
+        is_global: bool,
+    },
+    /// Settings of target proximity termination.
+    TargetProximity {
+        /// A target fitness.
+        target_fitness: Vec<f64>,
+        /// A distance threshold.
+        distance_threshold: f64,
+    },
 }
 
 mod min_variation;
@@ -68,4 +107,8 @@ where
     fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
         self.terminations.iter().map(|t| t.estimate(heuristic_ctx)).max_by(|a, b| compare_floats(*a, *b)).unwrap_or(0.)
     }
+
+    fn settings(&self) -> Vec<TerminationSettings> {
+        self.terminations.iter().flat_map(|t| t.settings()).collect()
+    }
 }
@@ -54,4 +54,11 @@ where
     fn estimate(&self, _: &Self::Context) -> f64 {
         0.
     }
+
+    fn settings(&self) -> Vec<TerminationSettings> {
+        vec![TerminationSettings::TargetProximity {
+            target_fitness: self.target_fitness.clone(),
+            distance_threshold: self.distance_threshold,
+        }]
+    }
 }
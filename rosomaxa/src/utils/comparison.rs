@@ -1,6 +1,15 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/comparison_test.rs"]
+mod comparison_test;
+
 use std::cmp::{Ordering, PartialOrd};
 
-/// Compares floats.
+/// Default epsilon used to compare floats which are expected to be affected by floating point noise.
+/// Algorithms which rely on it usually allow to override it, e.g. see `RosomaxaConfig::float_epsilon`.
+pub const FLOAT_EPSILON: f64 = 1E-9;
+
+/// Compares floats exactly. NaN is considered equal to another NaN and greater than any other value,
+/// so it is placed at the end when sorting in ascending order.
 pub fn compare_floats(a: f64, b: f64) -> Ordering {
     match (a, b) {
         (x, y) if x.is_nan() && y.is_nan() => Ordering::Equal,
@@ -10,6 +19,35 @@ pub fn compare_floats(a: f64, b: f64) -> Ordering {
     }
 }
 
+/// Compares floats treating them as equal when their difference is within given epsilon relative
+/// to their magnitude (or absolute for magnitudes less than one). NaN is ordered as in `compare_floats`.
+/// Please note, such comparison is not transitive, so prefer `compare_floats` for sorting when
+/// exact total order is required.
+pub fn compare_floats_with_epsilon(a: f64, b: f64, epsilon: f64) -> Ordering {
+    if !a.is_finite() || !b.is_finite() || a == b {
+        return compare_floats(a, b);
+    }
+
+    let scale = a.abs().max(b.abs()).max(1.);
+
+    if (a - b).abs() <= epsilon * scale {
+        Ordering::Equal
+    } else {
+        compare_floats(a, b)
+    }
+}
+
+/// Compares floats rounded to the multiples of given epsilon, so values which differ only by floating
+/// point noise are most likely equal. Unlike `compare_floats_with_epsilon`, it has total order, so it
+/// can be used for sorting. NaN is ordered as in `compare_floats`, zero epsilon means exact comparison.
+pub fn compare_floats_rounded(a: f64, b: f64, epsilon: f64) -> Ordering {
+    if epsilon > 0. {
+        compare_floats((a / epsilon).round(), (b / epsilon).round())
+    } else {
+        compare_floats(a, b)
+    }
+}
+
 /// Unwraps result type.
 pub fn unwrap_from_result<T>(result: Result<T, T>) -> T {
     match result {
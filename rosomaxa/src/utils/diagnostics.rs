@@ -0,0 +1,97 @@
+use crate::population::SelectionPhase;
+
+/// Specifies severity level of a diagnostic event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Detailed information useful mostly for tracing algorithm behavior.
+    Trace,
+    /// Information useful for debugging.
+    Debug,
+    /// High level information about search progress.
+    Info,
+    /// Something unexpected, but search can continue.
+    Warn,
+}
+
+/// Specifies a structured diagnostic event emitted by the solver.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiagnosticEvent {
+    /// Population has changed its selection phase.
+    PhaseTransition {
+        /// A generation when transition happened.
+        generation: usize,
+        /// A previous phase.
+        from: SelectionPhase,
+        /// A new phase.
+        to: SelectionPhase,
+    },
+    /// A network of population has grown new nodes.
+    NetworkGrowth {
+        /// A generation when growth happened.
+        generation: usize,
+        /// Amount of nodes before growth.
+        nodes_before: usize,
+        /// Amount of nodes after growth.
+        nodes_after: usize,
+    },
+    /// A network of population has been rebalanced, some of its nodes might be removed.
+    RebalanceCycle {
+        /// A generation when rebalance happened.
+        generation: usize,
+        /// Amount of nodes before rebalance.
+        nodes_before: usize,
+        /// Amount of nodes after rebalance.
+        nodes_after: usize,
+    },
+    /// A new best known solution is found.
+    NewBest {
+        /// A fitness of the new best known solution.
+        fitness: Vec<f64>,
+    },
+    /// A search operator has been applied to a solution.
+    OperatorApplied {
+        /// A name of the operator.
+        name: String,
+        /// Duration of operator application in milliseconds.
+        duration: u128,
+        /// True if operator has produced a solution better than the original one.
+        is_improved: bool,
+    },
+    /// An input has been rejected by population, e.g. individual with non-finite weights.
+    InputRejected {
+        /// A rejection reason.
+        reason: String,
+    },
+}
+
+/// A logger which receives structured diagnostic events from the solver.
+pub trait SolverLogger: Send + Sync {
+    /// Returns true if events of given level should be emitted. It can be used to avoid the cost
+    /// of constructing events which are discarded anyway.
+    fn is_enabled(&self, level: LogLevel) -> bool;
+
+    /// Receives a diagnostic event with its severity level.
+    fn log(&self, level: LogLevel, event: DiagnosticEvent);
+}
+
+/// A solver logger which discards all events.
+#[derive(Default)]
+pub struct NoopSolverLogger;
+
+impl SolverLogger for NoopSolverLogger {
+    fn is_enabled(&self, _: LogLevel) -> bool {
+        false
+    }
+
+    fn log(&self, _: LogLevel, _: DiagnosticEvent) {}
+}
+
+/// Emits an event created lazily by given function, if its level is enabled by the logger.
+pub fn emit_diagnostic<F>(logger: &dyn SolverLogger, level: LogLevel, event_fn: F)
+where
+    F: FnOnce() -> DiagnosticEvent,
+{
+    if logger.is_enabled(level) {
+        logger.log(level, event_fn());
+    }
+}
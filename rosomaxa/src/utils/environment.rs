@@ -1,6 +1,6 @@
 //! Contains environment specific logic.
 
-use crate::utils::{DefaultRandom, Random, ThreadPool, Timer};
+use crate::utils::{DecisionTracer, DefaultRandom, NoopSolverLogger, Random, SolverLogger, ThreadPool, Timer};
 use std::sync::Arc;
 
 /// A logger type which is called with various information.
@@ -30,6 +30,12 @@ pub struct Environment {
 
     /// A boolean flag which signalizes that experimental behavior is allowed.
     pub is_experimental: bool,
+
+    /// A tracer of search decisions used to record and replay them.
+    pub tracer: Option<Arc<DecisionTracer>>,
+
+    /// A logger of structured diagnostic events, discards them by default.
+    pub solver_logger: Arc<dyn SolverLogger>,
 }
 
 impl Environment {
@@ -49,7 +55,21 @@ impl Environment {
         logger: InfoLogger,
         is_experimental: bool,
     ) -> Self {
-        Self { random, quota, parallelism, logger, is_experimental }
+        Self {
+            random,
+            quota,
+            parallelism,
+            logger,
+            is_experimental,
+            tracer: None,
+            solver_logger: Arc::new(NoopSolverLogger),
+        }
+    }
+
+    /// Creates an instance of `Environment` which uses given decision tracer as random generator
+    /// and as tracer of search decisions.
+    pub fn new_with_tracer(tracer: Arc<DecisionTracer>) -> Self {
+        Self { random: tracer.clone(), tracer: Some(tracer), ..Self::default() }
     }
 }
 
@@ -106,6 +126,11 @@ impl Parallelism {
         Self { available_cpus: get_cpus(), thread_pools: Some(Arc::new(thread_pools)) }
     }
 
+    /// Creates an instance of `Parallelism` which assumes given amount of available CPUs.
+    pub fn new_with_cpus(available_cpus: usize) -> Self {
+        Self { available_cpus, thread_pools: None }
+    }
+
     /// Amount of total available CPUs.
     pub fn available_cpus(&self) -> usize {
         self.available_cpus
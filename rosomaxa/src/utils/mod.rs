@@ -1,17 +1,21 @@
 //! This module contains helper functionality.
 
 pub use self::comparison::*;
+pub use self::diagnostics::*;
 pub use self::environment::*;
 pub use self::iterators::*;
 pub use self::noise::*;
 pub use self::parallel::*;
 pub use self::random::*;
+pub use self::replay::*;
 pub use self::timing::*;
 
 mod comparison;
+mod diagnostics;
 mod environment;
 mod iterators;
 mod noise;
 mod parallel;
 mod random;
+mod replay;
 mod timing;
@@ -6,6 +6,7 @@ use rand::prelude::*;
 use rand::Error;
 use std::cell::UnsafeCell;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -26,13 +27,55 @@ pub trait Random {
     /// and selects the smallest sampled value.
     fn weighted(&self, weights: &[usize]) -> usize;
 
+    /// Returns an index sampled with probability proportional to its weight. Weights are expected
+    /// to be non-negative, the last index is returned in case of rounding slack. Panics on empty input.
+    fn weighted_index(&self, weights: &[f64]) -> usize {
+        assert!(!weights.is_empty());
+
+        let total = weights.iter().sum::<f64>();
+        let threshold = self.uniform_real(0., total);
+
+        weights
+            .iter()
+            .scan(0., |acc, weight| {
+                *acc += weight;
+                Some(*acc)
+            })
+            .position(|cumulative| cumulative > threshold)
+            .unwrap_or(weights.len() - 1)
+    }
+
+    /// Returns an independent random generator for given stream index if it can be derived
+    /// deterministically, e.g. from a seed. Such generators can be used by parallel work without
+    /// making results dependent on thread scheduling. Returns `None` by default.
+    fn fork(&self, _stream: u64) -> Option<Arc<dyn Random + Send + Sync>> {
+        None
+    }
+
+    /// Returns a seed used to create the generator, if any. Returns `None` by default.
+    fn seed(&self) -> Option<u64> {
+        None
+    }
+
     /// Returns RNG.
     fn get_rng(&self) -> RandomGen;
 }
 
-/// A default random implementation.
+/// A default random implementation. By default, it uses thread local generator seeded from
+/// entropy. When created with a seed, it produces the same stream of values for the same seed
+/// and sequence of calls, so a solve is reproducible if it runs on a single thread. Parallel work
+/// can use independent streams derived from the seed, see `Random::fork`.
 #[derive(Default)]
-pub struct DefaultRandom {}
+pub struct DefaultRandom {
+    seeded: Option<(u64, Mutex<SmallRng>)>,
+}
+
+impl DefaultRandom {
+    /// Creates a new instance of `DefaultRandom` which uses a generator seeded with given value.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seeded: Some((seed, Mutex::new(SmallRng::seed_from_u64(seed)))) }
+    }
+}
 
 impl Random for DefaultRandom {
     fn uniform_int(&self, min: i32, max: i32) -> i32 {
@@ -71,8 +114,26 @@ impl Random for DefaultRandom {
             .1
     }
 
+    fn fork(&self, stream: u64) -> Option<Arc<dyn Random + Send + Sync>> {
+        // NOTE stream seed depends only on the master seed and stream index, not on the generator state
+        self.seeded.as_ref().map(|(seed, _)| {
+            let seed = seed ^ stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            Arc::new(DefaultRandom::with_seed(seed)) as Arc<dyn Random + Send + Sync>
+        })
+    }
+
+    fn seed(&self) -> Option<u64> {
+        self.seeded.as_ref().map(|(seed, _)| *seed)
+    }
+
     fn get_rng(&self) -> RandomGen {
-        let rng = DEFAULT_RNG.with(|t| t.clone());
+        let rng = match self.seeded.as_ref() {
+            // NOTE a new generator is derived from the seeded one, so returned stream depends only
+            // on the seed and the order of calls
+            Some((_, seeded)) => Rc::new(UnsafeCell::new(SmallRng::seed_from_u64(seeded.lock().unwrap().next_u64()))),
+            None => DEFAULT_RNG.with(|t| t.clone()),
+        };
+
         RandomGen { rng }
     }
 }
@@ -0,0 +1,207 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/replay_test.rs"]
+mod replay_test;
+
+use crate::utils::{Random, RandomGen};
+use rand::prelude::*;
+use std::cell::UnsafeCell;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// Specifies a single decision made during the search.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TraceEvent {
+    /// An integral random value.
+    Int(i32),
+    /// A real random value.
+    Real(f64),
+    /// A result of coin flip or probability test.
+    Bool(bool),
+    /// An index selected using weights.
+    Index(usize),
+    /// A seed of random generator.
+    Seed(u64),
+    /// A search operator applied to the solution with a flag whether its result was accepted as improvement.
+    Operator {
+        /// An operator name.
+        name: String,
+        /// True if the operator's result was accepted.
+        is_accepted: bool,
+    },
+}
+
+/// A sequence of decisions recorded during the search. It can be saved in text form, one event per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecisionTrace {
+    /// Recorded events in order of their occurrence.
+    pub events: Vec<TraceEvent>,
+}
+
+impl Display for DecisionTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.events.iter().try_for_each(|event| match event {
+            TraceEvent::Int(value) => writeln!(f, "int {}", value),
+            TraceEvent::Real(value) => writeln!(f, "real {}", value),
+            TraceEvent::Bool(value) => writeln!(f, "bool {}", value),
+            TraceEvent::Index(value) => writeln!(f, "index {}", value),
+            TraceEvent::Seed(value) => writeln!(f, "seed {}", value),
+            TraceEvent::Operator { name, is_accepted } => writeln!(f, "operator {} {}", is_accepted, name),
+        })
+    }
+}
+
+impl FromStr for DecisionTrace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse<T: FromStr>(value: &str, line: usize) -> Result<T, String> {
+            value.parse::<T>().map_err(|_| format!("cannot parse value '{}' at line {}", value, line))
+        }
+
+        let events = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                let line_number = idx + 1;
+                let (kind, value) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+
+                match kind {
+                    "int" => Ok(TraceEvent::Int(parse(value, line_number)?)),
+                    "real" => Ok(TraceEvent::Real(parse(value, line_number)?)),
+                    "bool" => Ok(TraceEvent::Bool(parse(value, line_number)?)),
+                    "index" => Ok(TraceEvent::Index(parse(value, line_number)?)),
+                    "seed" => Ok(TraceEvent::Seed(parse(value, line_number)?)),
+                    "operator" => {
+                        let (is_accepted, name) = value.split_once(' ').unwrap_or((value, ""));
+                        Ok(TraceEvent::Operator {
+                            name: name.to_string(),
+                            is_accepted: parse(is_accepted, line_number)?,
+                        })
+                    }
+                    _ => Err(format!("unknown event kind '{}' at line {}", kind, line_number)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { events })
+    }
+}
+
+/// Records decisions made during the search or replays previously recorded ones. It is used as
+/// a random generator of the environment, so all random draws are captured. Search operator
+/// decisions are captured by hyper-heuristic through `on_operator` method.
+///
+/// NOTE replay is exact only when the search is deterministic apart of random draws: e.g. it is
+/// run in single thread and its termination does not depend on time. When replayed run diverges
+/// from the recorded one, the tracer panics with the position of the first mismatched event.
+pub struct DecisionTracer {
+    inner: Option<Arc<dyn Random + Send + Sync>>,
+    state: Mutex<(DecisionTrace, usize)>,
+}
+
+impl DecisionTracer {
+    /// Creates a new instance of `DecisionTracer` which records decisions using given random generator.
+    pub fn new_recording(inner: Arc<dyn Random + Send + Sync>) -> Self {
+        Self { inner: Some(inner), state: Mutex::new((DecisionTrace::default(), 0)) }
+    }
+
+    /// Creates a new instance of `DecisionTracer` which replays decisions from given trace.
+    pub fn new_replaying(trace: DecisionTrace) -> Self {
+        Self { inner: None, state: Mutex::new((trace, 0)) }
+    }
+
+    /// Returns a copy of the recorded trace.
+    pub fn get_trace(&self) -> DecisionTrace {
+        self.state.lock().unwrap().0.clone()
+    }
+
+    /// Returns true if all events of the trace are replayed. Recording tracer is always completed.
+    pub fn is_completed(&self) -> bool {
+        let (trace, position) = &*self.state.lock().unwrap();
+        self.inner.is_some() || *position == trace.events.len()
+    }
+
+    /// Records applied search operator or checks that it is the same as recorded one.
+    pub fn on_operator(&self, name: &str, is_accepted: bool) {
+        let expected = TraceEvent::Operator { name: name.to_string(), is_accepted };
+        self.next(|| expected.clone(), |event| *event == expected);
+    }
+
+    /// Records a new event or returns the next one from the trace checking that it is expected.
+    fn next(&self, record: impl FnOnce() -> TraceEvent, is_expected: impl FnOnce(&TraceEvent) -> bool) -> TraceEvent {
+        let (trace, position) = &mut *self.state.lock().unwrap();
+
+        let event = if self.inner.is_some() {
+            trace.events.push(record());
+            trace.events.last()
+        } else {
+            trace.events.get(*position)
+        }
+        .cloned()
+        .unwrap_or_else(|| panic!("cannot replay decision trace: no more events at position {}", *position));
+
+        if !is_expected(&event) {
+            panic!("cannot replay decision trace: unexpected event {:?} at position {}", event, *position);
+        }
+        *position += 1;
+
+        event
+    }
+
+    fn inner(&self) -> &(dyn Random + Send + Sync) {
+        self.inner.as_ref().expect("no random generator in replay mode").as_ref()
+    }
+}
+
+impl Random for DecisionTracer {
+    fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        match self.next(|| TraceEvent::Int(self.inner().uniform_int(min, max)), |e| matches!(e, TraceEvent::Int(_))) {
+            TraceEvent::Int(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn uniform_real(&self, min: f64, max: f64) -> f64 {
+        match self.next(|| TraceEvent::Real(self.inner().uniform_real(min, max)), |e| matches!(e, TraceEvent::Real(_)))
+        {
+            TraceEvent::Real(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_head_not_tails(&self) -> bool {
+        match self.next(|| TraceEvent::Bool(self.inner().is_head_not_tails()), |e| matches!(e, TraceEvent::Bool(_))) {
+            TraceEvent::Bool(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_hit(&self, probability: f64) -> bool {
+        match self.next(|| TraceEvent::Bool(self.inner().is_hit(probability)), |e| matches!(e, TraceEvent::Bool(_))) {
+            TraceEvent::Bool(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn weighted(&self, weights: &[usize]) -> usize {
+        match self.next(|| TraceEvent::Index(self.inner().weighted(weights)), |e| matches!(e, TraceEvent::Index(_))) {
+            TraceEvent::Index(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_rng(&self) -> RandomGen {
+        // NOTE generator is seeded, so its usages (e.g. shuffle) are deterministic on replay
+        let seed = match self
+            .next(|| TraceEvent::Seed(self.inner().get_rng().next_u64()), |e| matches!(e, TraceEvent::Seed(_)))
+        {
+            TraceEvent::Seed(value) => value,
+            _ => unreachable!(),
+        };
+
+        RandomGen::with_rng(Rc::new(UnsafeCell::new(SmallRng::seed_from_u64(seed))))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::algorithms::gsom::{Input, Network, NetworkConfig, Storage, StorageFactory};
+use crate::algorithms::gsom::{Input, NeighbourhoodFunction, Network, NetworkConfig, Storage, StorageFactory};
 use crate::utils::DefaultRandom;
 use std::fmt::{Display, Formatter};
 use std::ops::RangeBounds;
@@ -70,7 +70,7 @@ impl StorageFactory<Data, DataStorage> for DataStorageFactory {
 
 pub fn create_test_network(has_initial_error: bool) -> Network<Data, DataStorage, DataStorageFactory> {
     Network::new(
-        [
+        vec![
             Data::new(0.23052992, 0.95666552, 0.48200831),
             Data::new(0.40077599, 0.14291798, 0.55551944),
             Data::new(0.26027299, 0.17534256, 0.19371101),
@@ -82,6 +82,8 @@ pub fn create_test_network(has_initial_error: bool) -> Network<Data, DataStorage
             learning_rate: 0.1,
             rebalance_memory: 100,
             has_initial_error,
+            clamp_inputs: false,
+            neighbourhood: NeighbourhoodFunction::default(),
         },
         Arc::new(DefaultRandom::default()),
         DataStorageFactory,
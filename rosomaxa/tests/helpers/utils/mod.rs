@@ -1,6 +1,34 @@
-use crate::utils::{DefaultRandom, Random};
-use std::sync::Arc;
+use crate::utils::{DefaultRandom, DiagnosticEvent, LogLevel, Random, SolverLogger};
+use std::sync::{Arc, Mutex};
 
 pub fn create_test_random() -> Arc<dyn Random + Send + Sync> {
     Arc::new(DefaultRandom::default())
 }
+
+/// A solver logger which collects events with level not lower than the given one.
+pub struct CollectingLogger {
+    min_level: LogLevel,
+    events: Mutex<Vec<(LogLevel, DiagnosticEvent)>>,
+}
+
+impl CollectingLogger {
+    /// Creates a new instance of `CollectingLogger`.
+    pub fn new(min_level: LogLevel) -> Self {
+        Self { min_level, events: Mutex::new(vec![]) }
+    }
+
+    /// Returns collected events.
+    pub fn get_events(&self) -> Vec<(LogLevel, DiagnosticEvent)> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl SolverLogger for CollectingLogger {
+    fn is_enabled(&self, level: LogLevel) -> bool {
+        level >= self.min_level
+    }
+
+    fn log(&self, level: LogLevel, event: DiagnosticEvent) {
+        self.events.lock().unwrap().push((level, event));
+    }
+}
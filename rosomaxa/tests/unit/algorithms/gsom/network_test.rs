@@ -1,4 +1,4 @@
-use crate::algorithms::gsom::{Coordinate, Network};
+use crate::algorithms::gsom::{Coordinate, Input, NeighbourhoodFunction, Network};
 use crate::helpers::algorithms::gsom::{Data, DataStorage, DataStorageFactory};
 use crate::utils::Random;
 
@@ -6,9 +6,11 @@ type NetworkType = Network<Data, DataStorage, DataStorageFactory>;
 
 mod common {
     use super::*;
+    use crate::algorithms::gsom::NetworkConfig;
     use crate::helpers::algorithms::gsom::create_test_network;
     use crate::utils::{compare_floats, DefaultRandom};
     use std::cmp::Ordering;
+    use std::sync::Arc;
 
     #[test]
     fn can_train_network() {
@@ -20,7 +22,7 @@ mod common {
         for j in 1..4 {
             for i in 1..500 {
                 let idx = random.uniform_int(0, samples.len() as i32 - 1) as usize;
-                network.store(samples[idx].clone(), j * i + i);
+                network.store(samples[idx].clone(), j * i + i).unwrap();
             }
 
             network.smooth(4);
@@ -29,7 +31,7 @@ mod common {
 
         assert!(!network.nodes.len() >= 4);
         samples.iter().for_each(|sample| {
-            let node = network.find_bmu(sample);
+            let node = network.find_bmu(sample.weights());
             let node = node.read().unwrap();
 
             assert_eq!(node.storage.data.first().unwrap().values, sample.values);
@@ -37,6 +39,22 @@ mod common {
         });
     }
 
+    #[test]
+    fn can_recenter_nodes() {
+        let mut network = create_test_network(false);
+        let coordinates = network.get_coordinates().collect::<Vec<_>>();
+        let (changed, empty) = (coordinates[0], coordinates[1]);
+        let empty_weights = network.find(&empty).unwrap().read().unwrap().weights.clone();
+        network.find(&changed).unwrap().write().unwrap().storage.data = vec![Data::new(5., 5., 5.)];
+        network.find(&empty).unwrap().write().unwrap().storage.data.clear();
+
+        network.recenter();
+
+        assert_eq!(network.find(&changed).unwrap().read().unwrap().weights, vec![5., 5., 5.]);
+        assert_eq!(network.find(&empty).unwrap().read().unwrap().weights, empty_weights);
+        assert_eq!(network.min_max_weights.1, vec![5., 5., 5.]);
+    }
+
     parameterized_test! {can_use_initial_error_parameter, (has_initial_error, size), {
         can_use_initial_error_parameter_impl(has_initial_error, size);
     }}
@@ -49,11 +67,73 @@ mod common {
     fn can_use_initial_error_parameter_impl(has_initial_error: bool, size: usize) {
         let mut network = create_test_network(has_initial_error);
 
-        network.train(Data::new(1.0, 0.0, 0.0), true);
+        network.train(Data::new(1.0, 0.0, 0.0), true).unwrap();
 
         assert_eq!(network.size(), size);
     }
 
+    fn has_finite_weights(network: &NetworkType) -> bool {
+        network.get_nodes().all(|node| node.read().unwrap().weights.iter().all(|value| value.is_finite()))
+    }
+
+    fn has_finite_data(network: &NetworkType) -> bool {
+        network.get_nodes().all(|node| {
+            node.read().unwrap().storage.data.iter().all(|data| data.values.iter().all(|value| value.is_finite()))
+        })
+    }
+
+    parameterized_test! {can_reject_non_finite_input, value, {
+        can_reject_non_finite_input_impl(value);
+    }}
+
+    can_reject_non_finite_input! {
+        case01_nan: f64::NAN,
+        case02_inf: f64::INFINITY,
+        case03_neg_inf: f64::NEG_INFINITY,
+    }
+
+    fn can_reject_non_finite_input_impl(value: f64) {
+        let mut network = create_test_network(false);
+
+        let result = network.store(Data::new(value, 0.5, 0.5), 1);
+
+        assert!(result.is_err());
+        assert!(has_finite_weights(&network));
+        assert!(has_finite_data(&network));
+    }
+
+    #[test]
+    fn can_store_valid_inputs_from_batch_with_non_finite_input() {
+        let mut network = create_test_network(false);
+        let inputs = vec![Data::new(0.1, 0.2, 0.3), Data::new(f64::NAN, 0.2, 0.3), Data::new(0.3, 0.2, 0.1)];
+
+        let result = network.store_batch(inputs, 1, |data| data);
+
+        assert!(result.is_err());
+        assert!(has_finite_weights(&network));
+        assert!(has_finite_data(&network));
+    }
+
+    parameterized_test! {can_clamp_inputs, (clamp_inputs, is_in_range), {
+        can_clamp_inputs_impl(clamp_inputs, is_in_range);
+    }}
+
+    can_clamp_inputs! {
+        case01_clamp: (true, true),
+        case02_no_clamp: (false, false),
+    }
+
+    fn can_clamp_inputs_impl(clamp_inputs: bool, is_in_range: bool) {
+        let mut network = create_test_network(false);
+        network.clamp_inputs = clamp_inputs;
+
+        network.store(Data::new(1E9, 0.5, 0.5), 1).unwrap();
+
+        let max_weight =
+            network.get_nodes().flat_map(|node| node.read().unwrap().weights.clone()).fold(f64::MIN, f64::max);
+        assert_eq!(max_weight < 2., is_in_range);
+    }
+
     fn get_coord_data(coord: (i32, i32), offset: (i32, i32), network: &NetworkType) -> (Coordinate, Vec<f64>) {
         let node = network.nodes.get(&Coordinate(coord.0 + offset.0, coord.1 + offset.1)).unwrap();
         let node = node.read().unwrap();
@@ -94,6 +174,87 @@ mod common {
         assert_eq!(get_coord_data((0, 1), (1, 0), &network).0, Coordinate(1, 1));
     }
 
+    parameterized_test! {can_create_initial_nodes_from_roots, (size, expected), {
+        can_create_initial_nodes_from_roots_impl(size, expected);
+    }}
+
+    can_create_initial_nodes_from_roots! {
+        case01_two: (2, &[(0, 0), (0, 1)]),
+        case02_three: (3, &[(0, 0), (0, 1), (1, 1)]),
+        case03_four: (4, &[(0, 0), (0, 1), (1, 0), (1, 1)]),
+        case04_five: (5, &[(0, 0), (0, 1), (0, 2), (1, 1), (1, 2)]),
+    }
+
+    fn can_create_initial_nodes_from_roots_impl(size: usize, expected: &[(i32, i32)]) {
+        let roots = (0..size).map(|idx| Data::new(idx as f64, 1., 0.)).collect();
+        let config = NetworkConfig {
+            spread_factor: 0.25,
+            distribution_factor: 0.25,
+            learning_rate: 0.1,
+            rebalance_memory: 100,
+            has_initial_error: false,
+            clamp_inputs: false,
+            neighbourhood: NeighbourhoodFunction::default(),
+        };
+
+        let network = NetworkType::new(roots, config, Arc::new(DefaultRandom::default()), DataStorageFactory);
+
+        let mut coordinates = network.nodes.keys().map(|Coordinate(x, y)| (*x, *y)).collect::<Vec<_>>();
+        coordinates.sort();
+        assert_eq!(coordinates, expected);
+        assert!(network.get_nodes().all(|node| node.read().unwrap().storage.data.len() == 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_create_network_with_non_finite_roots() {
+        let roots = vec![Data::new(0., 1., 0.), Data::new(f64::NAN, 1., 0.)];
+        let config = NetworkConfig {
+            spread_factor: 0.25,
+            distribution_factor: 0.25,
+            learning_rate: 0.1,
+            rebalance_memory: 100,
+            has_initial_error: false,
+            clamp_inputs: false,
+            neighbourhood: NeighbourhoodFunction::default(),
+        };
+
+        NetworkType::new(roots, config, Arc::new(DefaultRandom::default()), DataStorageFactory);
+    }
+
+    parameterized_test! {can_export_network_to_dot, inputs, {
+        can_export_network_to_dot_impl(inputs);
+    }}
+
+    can_export_network_to_dot! {
+        case01_initial: 0,
+        case02_trained: 100,
+    }
+
+    fn can_export_network_to_dot_impl(inputs: usize) {
+        let mut network = create_test_network(true);
+        (0..inputs).for_each(|i| {
+            let value = i as f64 / inputs as f64;
+            network.store(Data::new(value, 1. - value, value * value), i).unwrap()
+        });
+        let coordinates = network.get_coordinates().collect::<Vec<_>>();
+        let expected_edges = coordinates
+            .iter()
+            .flat_map(|Coordinate(x, y)| [Coordinate(x + 1, *y), Coordinate(*x, y + 1)])
+            .filter(|neighbour| coordinates.contains(neighbour))
+            .count();
+
+        let dot = network.to_dot();
+
+        let lines = dot.lines().collect::<Vec<_>>();
+        assert_eq!(lines.first(), Some(&"graph network {"));
+        assert_eq!(lines.last(), Some(&"}"));
+        assert_eq!(lines.iter().filter(|line| line.contains("[label=")).count(), network.get_nodes().count());
+        assert_eq!(lines.iter().filter(|line| line.contains(" -- ")).count(), expected_edges);
+        assert!(expected_edges >= network.get_nodes().count() - 1);
+        assert!(lines.iter().filter(|line| line.contains("[label=")).all(|line| line.contains("size: ")));
+    }
+
     #[test]
     fn can_create_and_update_extended_neighbourhood() {
         let mut network = create_test_network(false);
@@ -145,8 +306,11 @@ mod common {
 
 mod node_growing {
     use super::*;
-    use crate::algorithms::gsom::{NetworkConfig, NodeLink};
+    use crate::algorithms::gsom::{NetworkConfig, NodeLink, Storage};
     use crate::prelude::RandomGen;
+    use rand::prelude::{SeedableRng, SmallRng};
+    use std::cell::UnsafeCell;
+    use std::rc::Rc;
     use std::sync::{Arc, RwLock};
 
     fn create_trivial_network(has_initial_error: bool) -> NetworkType {
@@ -173,11 +337,11 @@ mod node_growing {
             }
 
             fn get_rng(&self) -> RandomGen {
-                unreachable!()
+                RandomGen::with_rng(Rc::new(UnsafeCell::new(SmallRng::seed_from_u64(0))))
             }
         }
         Network::new(
-            [
+            vec![
                 Data::new(1., 4., 8.), // n00
                 Data::new(2., 5., 9.), // n01
                 Data::new(3., 8., 7.), // n11
@@ -189,6 +353,8 @@ mod node_growing {
                 learning_rate: 0.1,
                 rebalance_memory: 500,
                 has_initial_error,
+                clamp_inputs: false,
+                neighbourhood: NeighbourhoodFunction::default(),
             },
             Arc::new(DummyRandom {}),
             DataStorageFactory,
@@ -217,7 +383,7 @@ mod node_growing {
     fn can_grow_initial_nodes_properly_impl(target_coord: (i32, i32), expected_new_nodes: Vec<((i32, i32), Vec<f64>)>) {
         let mut network = create_trivial_network(true);
 
-        network.update(&get_node(target_coord, &network).unwrap(), &Data::new(2., 2., 2.), 2., true);
+        network.update(&get_node(target_coord, &network).unwrap(), Data::new(2., 2., 2.).weights(), 2., true);
 
         assert_eq!(network.nodes.len(), 6);
         expected_new_nodes.into_iter().for_each(|((offset_x, offset_y), weights)| {
@@ -234,7 +400,12 @@ mod node_growing {
         let mut network = create_trivial_network(true);
         network.nodes.insert(w1_coord, Arc::new(RwLock::new(network.create_node(w1_coord, &[3., 6., 10.], 0.))));
 
-        network.update(&get_node((w1_coord.0, w1_coord.1), &network).unwrap(), &Data::new(2., 2., 2.), 6., true);
+        network.update(
+            &get_node((w1_coord.0, w1_coord.1), &network).unwrap(),
+            Data::new(2., 2., 2.).weights(),
+            6.,
+            true,
+        );
 
         [
             ((2, 2), vec![2.948, 3.895, 12.423]),
@@ -249,6 +420,128 @@ mod node_growing {
         });
     }
 
+    #[test]
+    fn can_track_generations_since_growth() {
+        let mut network = create_trivial_network(true);
+        assert_eq!(network.get_generations_since_growth(), 0);
+
+        network.last_growth = Some(2);
+        network.time = 5;
+        assert_eq!(network.get_generations_since_growth(), 3);
+
+        network.update(&get_node((0, 0), &network).unwrap(), Data::new(2., 2., 2.).weights(), 2., true);
+
+        assert_eq!(network.nodes.len(), 6);
+        assert_eq!(network.get_generations_since_growth(), 0);
+    }
+
+    parameterized_test! {can_freeze_network_growth, (is_frozen, expected_size), {
+        can_freeze_network_growth_impl(is_frozen, expected_size);
+    }}
+
+    can_freeze_network_growth! {
+        case01_not_frozen: (false, 6),
+        case02_frozen: (true, 4),
+    }
+
+    fn can_freeze_network_growth_impl(is_frozen: bool, expected_size: usize) {
+        let mut network = create_trivial_network(true);
+        network.set_growth_frozen(is_frozen);
+
+        network.update(&get_node((0, 0), &network).unwrap(), Data::new(2., 2., 2.).weights(), 2., true);
+
+        assert_eq!(network.is_growth_frozen(), is_frozen);
+        assert_eq!(network.nodes.len(), expected_size);
+    }
+
+    parameterized_test! {can_find_nearest_nodes, (k, expected), {
+        can_find_nearest_nodes_impl(k, expected);
+    }}
+
+    can_find_nearest_nodes! {
+        case01_zero: (0, &[]),
+        case02_one: (1, &[(0, 1)]),
+        case03_two: (2, &[(0, 1), (0, 0)]),
+        case04_all: (4, &[(0, 1), (0, 0), (1, 1), (1, 0)]),
+        case05_more_than_nodes: (10, &[(0, 1), (0, 0), (1, 1), (1, 0)]),
+    }
+
+    fn can_find_nearest_nodes_impl(k: usize, expected: &[(i32, i32)]) {
+        let network = create_trivial_network(false);
+        let weights = [2., 5., 8.];
+
+        let nearest = network.nearest(&weights, k);
+
+        let coordinates = nearest.iter().map(|(node, _)| node.read().unwrap().coordinate).collect::<Vec<_>>();
+        let distances = nearest.iter().map(|(_, distance)| *distance).collect::<Vec<_>>();
+        assert_eq!(coordinates, expected.iter().map(|(x, y)| Coordinate(*x, *y)).collect::<Vec<_>>());
+        assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+        nearest.iter().for_each(|(node, distance)| assert_eq!(node.read().unwrap().distance(&weights), *distance));
+    }
+
+    #[test]
+    fn can_find_nearest_nodes_in_empty_network() {
+        let mut network = create_trivial_network(false);
+        network.nodes.clear();
+
+        assert!(network.nearest(&[1., 2., 3.], 3).is_empty());
+    }
+
+    #[test]
+    fn can_break_bmu_ties_by_coordinate() {
+        let mut network = create_trivial_network(false);
+        network.insert(Coordinate(2, 1), &[7., 7., 7.]);
+        network.insert(Coordinate(-1, 0), &[7., 7., 7.]);
+        network.insert(Coordinate(2, 0), &[7., 7., 7.]);
+
+        let bmu = network.find_bmu(&[7., 7., 7.]);
+
+        assert_eq!(bmu.read().unwrap().coordinate, Coordinate(-1, 0));
+        assert!(network.get_coordinates().collect::<Vec<_>>().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn can_prune_stale_nodes() {
+        let mut network = create_trivial_network(false);
+        network.insert(Coordinate(2, 0), &[9., 2., 1.]);
+        network.insert(Coordinate(2, 1), &[8., 1., 1.]);
+        network.nodes.values().for_each(|node| node.write().unwrap().new_hit(95));
+        let stale = get_node((2, 0), &network).unwrap();
+        stale.write().unwrap().last_hits = vec![10].into();
+        // NOTE test storage keeps only the last item, so recycled item should match the empty node
+        stale.write().unwrap().storage.add(Data::new(8., 1., 1.));
+
+        network.prune_stale(100, 50, 4);
+
+        assert_eq!(network.size(), 5);
+        assert!(get_node((2, 0), &network).is_none());
+        let recent = get_node((2, 1), &network).expect("recently hit node should remain");
+        let recent = recent.read().unwrap();
+        assert_eq!(
+            recent.storage.data.iter().map(|data| data.values.clone()).collect::<Vec<_>>(),
+            vec![vec![8., 1., 1.]]
+        );
+    }
+
+    parameterized_test! {can_keep_min_network_size_when_pruning_stale_nodes, (min_size, expected_size), {
+        can_keep_min_network_size_when_pruning_stale_nodes_impl(min_size, expected_size);
+    }}
+
+    can_keep_min_network_size_when_pruning_stale_nodes! {
+        case01_four: (4, 4),
+        case02_two: (2, 2),
+        case03_more_than_nodes: (6, 4),
+    }
+
+    fn can_keep_min_network_size_when_pruning_stale_nodes_impl(min_size: usize, expected_size: usize) {
+        let mut network = create_trivial_network(false);
+        network.nodes.values().for_each(|node| node.write().unwrap().new_hit(10));
+
+        network.prune_stale(100, 50, min_size);
+
+        assert_eq!(network.size(), expected_size);
+    }
+
     #[test]
     fn can_calculate_mse() {
         let mut network = create_trivial_network(false);
@@ -260,3 +553,201 @@ mod node_growing {
         assert!((mse - 0.0001138).abs() < 1E7);
     }
 }
+
+mod learned {
+    use super::*;
+    use crate::algorithms::gsom::{LearnedNetwork, NetworkConfig, NetworkSnapshot};
+    use crate::helpers::algorithms::gsom::create_test_network;
+    use crate::utils::DefaultRandom;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    fn create_trained_network() -> NetworkType {
+        let mut network = create_test_network(false);
+        (0..100).for_each(|i| network.store(Data::new(i as f64 / 100., 1. - i as f64 / 100., 0.5), i).unwrap());
+
+        network
+    }
+
+    fn get_node_states(network: &NetworkType) -> Vec<(Coordinate, Vec<f64>, usize)> {
+        network
+            .iter()
+            .map(|(coordinate, node)| {
+                let node = node.read().unwrap();
+                (*coordinate, node.weights.clone(), node.total_hits)
+            })
+            .collect()
+    }
+
+    fn create_config() -> NetworkConfig {
+        NetworkConfig {
+            spread_factor: 0.25,
+            distribution_factor: 0.25,
+            learning_rate: 0.1,
+            rebalance_memory: 100,
+            has_initial_error: false,
+            clamp_inputs: false,
+            neighbourhood: NeighbourhoodFunction::default(),
+        }
+    }
+
+    #[test]
+    fn can_export_and_import_learned_network() {
+        let mut network = create_test_network(false);
+        (0..100).for_each(|i| network.store(Data::new(i as f64 / 100., 1. - i as f64 / 100., 0.5), i).unwrap());
+        let learned = network.export_learned();
+
+        let restored = LearnedNetwork::from_str(learned.to_string().as_str()).expect("cannot parse learned network");
+        let warm_network = NetworkType::from_learned(
+            &restored,
+            create_config(),
+            Arc::new(DefaultRandom::default()),
+            DataStorageFactory,
+        )
+        .expect("cannot create network from learned");
+
+        assert_eq!(restored, learned);
+        assert_eq!(warm_network.size(), network.size());
+        assert_eq!(warm_network.export_learned(), learned);
+        assert!(warm_network.get_nodes().all(|node| node.read().unwrap().storage.data.is_empty()));
+    }
+
+    #[test]
+    fn can_save_and_restore_topology() {
+        let network = create_trained_network();
+        let snapshot = network.save_topology();
+
+        let restored =
+            NetworkType::restore_topology(snapshot.clone(), Arc::new(DefaultRandom::default()), DataStorageFactory)
+                .expect("cannot restore network from snapshot");
+
+        assert_eq!(get_node_states(&restored), get_node_states(&network));
+        assert_eq!(restored.get_current_time(), network.get_current_time());
+        assert_eq!(restored.get_generations_since_growth(), network.get_generations_since_growth());
+        assert_eq!(restored.save_topology(), snapshot);
+        assert!(restored.get_nodes().all(|node| node.read().unwrap().storage.data.is_empty()));
+    }
+
+    #[test]
+    fn can_detect_invalid_snapshot() {
+        let snapshot = create_trained_network().save_topology();
+        let mut nodes = snapshot.nodes.clone();
+        nodes[0].weights.pop();
+
+        let empty = NetworkType::restore_topology(
+            NetworkSnapshot { nodes: vec![], ..snapshot.clone() },
+            Arc::new(DefaultRandom::default()),
+            DataStorageFactory,
+        );
+        let inconsistent = NetworkType::restore_topology(
+            NetworkSnapshot { nodes, ..snapshot },
+            Arc::new(DefaultRandom::default()),
+            DataStorageFactory,
+        );
+
+        assert!(empty.is_err());
+        assert!(inconsistent.is_err());
+    }
+
+    #[cfg(feature = "checkpoint")]
+    #[test]
+    fn can_round_trip_snapshot_through_bytes() {
+        let snapshot = create_trained_network().save_topology();
+
+        let bytes = snapshot.to_bytes().expect("cannot serialize snapshot");
+        let restored = NetworkSnapshot::from_bytes(bytes.as_slice()).expect("cannot deserialize snapshot");
+
+        assert_eq!(restored, snapshot);
+        assert!(NetworkSnapshot::from_bytes(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn can_train_network_created_from_learned() {
+        let learned = LearnedNetwork::from_str("[(0,0,[0,0,0]),(0,1,[0,1,0]),(1,1,[1,1,0]),(1,0,[1,0,0])]").unwrap();
+        let mut network = NetworkType::from_learned(
+            &learned,
+            create_config(),
+            Arc::new(DefaultRandom::default()),
+            DataStorageFactory,
+        )
+        .unwrap();
+
+        network.store(Data::new(0.9, 0.1, 0.), 1).unwrap();
+
+        let node = network.find(&Coordinate(1, 0)).unwrap().read().unwrap();
+        assert_eq!(node.storage.data.len(), 1);
+    }
+
+    parameterized_test! {can_detect_invalid_learned_network, (value, is_parsed), {
+        can_detect_invalid_learned_network_impl(value, is_parsed);
+    }}
+
+    can_detect_invalid_learned_network! {
+        case01: ("", false),
+        case02: ("[(0,0,[a])]", false),
+        case03: ("[(0,[1])]", false),
+        case04: ("[]", true),
+        case05: ("[(0,0,[1]),(0,1,[1,2])]", true),
+    }
+
+    fn can_detect_invalid_learned_network_impl(value: &str, is_parsed: bool) {
+        let learned = LearnedNetwork::from_str(value);
+
+        assert_eq!(learned.is_ok(), is_parsed);
+        if let Ok(learned) = learned {
+            assert!(NetworkType::from_learned(
+                &learned,
+                create_config(),
+                Arc::new(DefaultRandom::default()),
+                DataStorageFactory
+            )
+            .is_err());
+        }
+    }
+}
+
+mod neighbourhood {
+    use super::*;
+    use crate::helpers::algorithms::gsom::create_test_network;
+
+    parameterized_test! {can_adjust_neighbour_weights, (neighbourhood, expected_factor), {
+        can_adjust_neighbour_weights_impl(neighbourhood, expected_factor);
+    }}
+
+    can_adjust_neighbour_weights! {
+        case01_inverse: (NeighbourhoodFunction::InverseDistance, 0.5),
+        case02_gaussian: (NeighbourhoodFunction::Gaussian { sigma: 1. }, (-2_f64).exp()),
+        case03_bubble: (NeighbourhoodFunction::Bubble { radius: 1 }, 1.),
+    }
+
+    fn can_adjust_neighbour_weights_impl(neighbourhood: NeighbourhoodFunction, expected_factor: f64) {
+        let mut network = create_test_network(false);
+        network.neighbourhood = neighbourhood;
+        let target = [1., 1., 1.];
+        let get_weights = |network: &NetworkType, x: i32, y: i32| {
+            network.find(&Coordinate(x, y)).unwrap().read().unwrap().weights.clone()
+        };
+        let get_ratio = |old: &[f64], new: &[f64]| (new[0] - old[0]) / (target[0] - old[0]);
+        let (old_node, old_neighbour) = (get_weights(&network, 0, 0), get_weights(&network, 1, 1));
+
+        let node = network.find(&Coordinate(0, 0)).unwrap().clone();
+        network.adjust_weights(&node, &target, 1, true);
+
+        let factor = get_ratio(&old_neighbour, &get_weights(&network, 1, 1))
+            / get_ratio(&old_node, &get_weights(&network, 0, 0));
+        assert!((factor - expected_factor).abs() < 1E-6);
+    }
+
+    parameterized_test! {can_validate_neighbourhood, (neighbourhood, expected), {
+        assert_eq!(neighbourhood.is_valid(), expected);
+    }}
+
+    can_validate_neighbourhood! {
+        case01: (NeighbourhoodFunction::InverseDistance, true),
+        case02: (NeighbourhoodFunction::Gaussian { sigma: 0.5 }, true),
+        case03: (NeighbourhoodFunction::Gaussian { sigma: 0. }, false),
+        case04: (NeighbourhoodFunction::Gaussian { sigma: f64::NAN }, false),
+        case05: (NeighbourhoodFunction::Bubble { radius: 2 }, true),
+        case06: (NeighbourhoodFunction::Bubble { radius: 0 }, false),
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+parameterized_test! {can_calculate_normalized_distance, (a, b, expected), {
+    can_calculate_normalized_distance_impl(a, b, expected);
+}}
+
+can_calculate_normalized_distance! {
+    case01_small_scale_axis: (vec![0., 3000.], vec![5., 3000.], 1.),
+    case02_large_scale_axis: (vec![0., 1000.], vec![0., 5000.], 1.),
+    case03_both_axes_half_range: (vec![0., 1000.], vec![2.5, 3000.], 0.5_f64.hypot(0.5)),
+    case04_same_vectors: (vec![2., 2000.], vec![2., 2000.], 0.),
+}
+
+fn can_calculate_normalized_distance_impl(a: Vec<f64>, b: Vec<f64>, expected: f64) {
+    let ranges = vec![(0., 5.), (1000., 5000.)];
+
+    let distance = normalized_distance(a.into_iter(), b.into_iter(), ranges.as_slice());
+
+    assert!((distance - expected).abs() < 1E-9);
+}
+
+#[test]
+fn can_ignore_dimensions_without_range() {
+    let distance = normalized_distance(vec![1., 10.].into_iter(), vec![2., 20.].into_iter(), &[(0., 2.), (5., 5.)]);
+
+    assert_eq!(distance, 0.5);
+}
+
+#[test]
+fn can_get_value_ranges() {
+    let values = [vec![1., 3000.], vec![5., 1000.], vec![0., 2000.]];
+
+    let ranges = get_value_ranges(values.iter().map(|vector| vector.iter().cloned()));
+
+    assert_eq!(ranges, vec![(0., 5.), (1000., 3000.)]);
+}
+
+parameterized_test! {can_use_weighted_euclidean_with_unit_weights_as_euclidean, (a, b), {
+    can_use_weighted_euclidean_with_unit_weights_as_euclidean_impl(a, b);
+}}
+
+can_use_weighted_euclidean_with_unit_weights_as_euclidean! {
+    case01_same_vectors: (vec![1., 2., 3., 4., 5.], vec![1., 2., 3., 4., 5.]),
+    case02_different_vectors: (vec![1., -2., 0.5, 40., 5.], vec![3., 2., 0., 10., -5.]),
+    case03_zero_vector: (vec![0., 0., 0., 0., 0.], vec![3., 4., 0., 0., 12.]),
+}
+
+fn can_use_weighted_euclidean_with_unit_weights_as_euclidean_impl(a: Vec<f64>, b: Vec<f64>) {
+    let weights = vec![1.; a.len()];
+
+    let weighted = DistanceMetric::WeightedEuclidean(weights).distance(a.iter().cloned(), b.iter().cloned());
+    let plain = DistanceMetric::Euclidean.distance(a.iter().cloned(), b.iter().cloned());
+
+    assert!((weighted - plain).abs() < 1E-9);
+}
+
+parameterized_test! {can_calculate_distance_with_metric, (metric, a, b, expected), {
+    can_calculate_distance_with_metric_impl(metric, a, b, expected);
+}}
+
+can_calculate_distance_with_metric! {
+    case01_relative: (DistanceMetric::Relative, vec![2., 4.], vec![1., 4.], 0.5),
+    case02_euclidean: (DistanceMetric::Euclidean, vec![0., 0.], vec![3., 4.], 5.),
+    case03_weighted_euclidean: (DistanceMetric::WeightedEuclidean(vec![4., 0.]), vec![0., 0.], vec![3., 4.], 6.),
+    case04_cosine_same_direction: (DistanceMetric::Cosine, vec![1., 2.], vec![2., 4.], 0.),
+    case05_cosine_orthogonal: (DistanceMetric::Cosine, vec![1., 0.], vec![0., 3.], 1.),
+    case06_cosine_opposite: (DistanceMetric::Cosine, vec![1., 1.], vec![-1., -1.], 2.),
+    case07_cosine_one_zero: (DistanceMetric::Cosine, vec![0., 0.], vec![1., 1.], 1.),
+    case08_cosine_both_zero: (DistanceMetric::Cosine, vec![0., 0.], vec![0., 0.], 0.),
+}
+
+fn can_calculate_distance_with_metric_impl(metric: DistanceMetric, a: Vec<f64>, b: Vec<f64>, expected: f64) {
+    let distance = metric.distance(a.into_iter(), b.into_iter());
+
+    assert!((distance - expected).abs() < 1E-9);
+}
+
+parameterized_test! {can_validate_distance_metric, (metric, expected), {
+    can_validate_distance_metric_impl(metric, expected);
+}}
+
+can_validate_distance_metric! {
+    case01_relative: (DistanceMetric::Relative, true),
+    case02_weighted_valid: (DistanceMetric::WeightedEuclidean(vec![0., 1.5]), true),
+    case03_weighted_negative: (DistanceMetric::WeightedEuclidean(vec![1., -1.]), false),
+    case04_weighted_nan: (DistanceMetric::WeightedEuclidean(vec![f64::NAN]), false),
+}
+
+fn can_validate_distance_metric_impl(metric: DistanceMetric, expected: bool) {
+    assert_eq!(metric.is_valid(), expected);
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::algorithms::nsga2::select_and_rank;
+use crate::example::VectorSolution;
+use crate::helpers::algorithms::nsga2::SliceDimensionObjective;
+use crate::helpers::example::create_example_objective;
+use crate::population::{Elitism, HeuristicPopulation};
+use crate::utils::Environment;
+
+struct VectorDimensionObjective {
+    dimension: usize,
+}
+
+impl Objective for VectorDimensionObjective {
+    type Solution = VectorSolution;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.data[self.dimension]
+    }
+}
+
+fn create_slice_objective(dimensions: usize) -> LexicographicObjective<Vec<f64>> {
+    LexicographicObjective::new(
+        (0..dimensions)
+            .map(|dimension| {
+                let objective: ObjectiveComponent<Vec<f64>> = Arc::new(SliceDimensionObjective::new(dimension));
+                objective
+            })
+            .collect(),
+    )
+}
+
+parameterized_test! {can_compare_lexicographically, (a, b, expected), {
+    can_compare_lexicographically_impl(a, b, expected);
+}}
+
+can_compare_lexicographically! {
+    case01_better_primary_worse_secondary: (vec![1., 100., 100.], vec![2., 0., 0.], Ordering::Less),
+    case02_worse_primary_better_secondary: (vec![2., 0., 0.], vec![1., 100., 100.], Ordering::Greater),
+    case03_equal_primary_better_secondary: (vec![1., 1., 100.], vec![1., 2., 0.], Ordering::Less),
+    case04_equal_primary_and_secondary: (vec![1., 2., 3.], vec![1., 2., 4.], Ordering::Less),
+    case05_all_equal: (vec![1., 2., 3.], vec![1., 2., 3.], Ordering::Equal),
+}
+
+fn can_compare_lexicographically_impl(a: Vec<f64>, b: Vec<f64>, expected: Ordering) {
+    let objective = create_slice_objective(3);
+
+    assert_eq!(objective.total_order(&a, &b), expected);
+}
+
+#[test]
+fn can_rank_solutions_lexicographically() {
+    let objective = create_slice_objective(2);
+    let solutions = vec![vec![2., 0.], vec![1., 100.], vec![1., 50.], vec![3., -100.]];
+
+    let ranked = select_and_rank(solutions.as_slice(), solutions.len(), &objective)
+        .into_iter()
+        .map(|acd| (acd.index, acd.rank))
+        .collect::<Vec<_>>();
+
+    assert_eq!(ranked, vec![(2, 0), (1, 1), (0, 2), (3, 3)]);
+}
+
+#[test]
+fn can_keep_component_order_in_population() {
+    let objective = Arc::new(LexicographicObjective::new(vec![
+        Arc::new(VectorDimensionObjective { dimension: 0 }) as ObjectiveComponent<VectorSolution>,
+        Arc::new(VectorDimensionObjective { dimension: 1 }),
+    ]));
+    let mut population = Elitism::new(objective.clone(), Environment::default().random, 4, 1);
+    let example_objective = create_example_objective();
+    let solutions = vec![vec![2., 0.], vec![1., 100.], vec![1., 50.]]
+        .into_iter()
+        .map(|data| VectorSolution::new(data, example_objective.clone()));
+    population.shuffle_objective();
+    population.add_all(solutions.collect());
+
+    let ranked = population.ranked().map(|(solution, rank)| (solution.data.clone(), rank)).collect::<Vec<_>>();
+    assert_eq!(ranked, vec![(vec![1., 50.], 0), (vec![1., 100.], 1), (vec![2., 0.], 2)]);
+    let (best, worst) = (&ranked[0].0, &ranked[2].0);
+    let best = VectorSolution::new(best.clone(), example_objective.clone());
+    let worst = VectorSolution::new(worst.clone(), example_objective);
+    assert_eq!(population.cmp(&best, &worst), Ordering::Less);
+}
@@ -1,8 +1,10 @@
 use super::*;
 use crate::example::*;
 use crate::helpers::example::{create_default_heuristic_context, create_example_objective};
-use crate::utils::compare_floats;
+use crate::utils::{compare_floats, Environment};
+use crate::{get_default_population, TelemetryHeuristicContext};
 use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
 
 fn compare_statistic(statistics: &HeuristicStatistics, expected: (usize, f64, f64)) {
     assert_eq!(statistics.generation, expected.0);
@@ -30,9 +32,72 @@ fn can_update_statistic() {
     telemetry.on_generation(objective, population, 0., Timer::start(), false);
     compare_statistic(telemetry.get_statistics(), (3, 0.25, 0.25));
 
+    assert_eq!(telemetry.get_statistics().stagnation, 3);
+
     (0..996).for_each(|_| telemetry.on_generation(objective, population, 0., Timer::start(), false));
     compare_statistic(telemetry.get_statistics(), (999, 0.001, 0.001));
 
     telemetry.on_generation(objective, population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
+    assert_eq!(telemetry.get_statistics().stagnation, 0);
+}
+
+parameterized_test! {can_notify_about_new_best_solution, (min_interval_ms, expected), {
+    can_notify_about_new_best_solution_impl(min_interval_ms, expected);
+}}
+
+can_notify_about_new_best_solution! {
+    case01_no_throttling: (0, vec![401., 56.5, 0.]),
+    case02_throttling: (1_000_000, vec![401., 0.]),
+}
+
+fn can_notify_about_new_best_solution_impl(min_interval_ms: usize, expected: Vec<f64>) {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let population = get_default_population(objective.clone(), environment.clone(), 4);
+    let fitness = Arc::new(Mutex::new(Vec::<f64>::new()));
+    let callback_fitness = fitness.clone();
+    let create_solution = |data: Vec<f64>| VectorSolution::new(data, objective.clone());
+    let mut heuristic_ctx =
+        TelemetryHeuristicContext::new(objective.clone(), population, TelemetryMode::None, environment)
+            .with_new_best_callback(
+                Arc::new(move |solution: VectorSolution| callback_fitness.lock().unwrap().push(solution.fitness())),
+                min_interval_ms,
+            );
+
+    heuristic_ctx.on_initial(create_solution(vec![2., 2.]), Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![3., 3.])], 0., Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![1.5, 1.5])], 0., Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![3., 3.])], 0., Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![1., 1.])], 0., Timer::start());
+    heuristic_ctx.on_result().expect("cannot get result");
+
+    assert_eq!(*fitness.lock().unwrap(), expected);
+}
+
+#[test]
+fn can_collect_best_solution_at_checkpoints() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let population = get_default_population(objective.clone(), environment.clone(), 4);
+    let checkpoints = Arc::new(Mutex::new(Vec::<(usize, f64)>::new()));
+    let callback_checkpoints = checkpoints.clone();
+    let create_solution = |data: Vec<f64>| VectorSolution::new(data, objective.clone());
+    let mut heuristic_ctx =
+        TelemetryHeuristicContext::new(objective.clone(), population, TelemetryMode::None, environment)
+            .with_checkpoints(
+                vec![1, 3, 10],
+                Arc::new(move |generation, solution: VectorSolution| {
+                    callback_checkpoints.lock().unwrap().push((generation, solution.fitness()))
+                }),
+            );
+
+    heuristic_ctx.on_initial(create_solution(vec![2., 2.]), Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![3., 3.])], 0., Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![1.5, 1.5])], 0., Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![1., 1.])], 0., Timer::start());
+    heuristic_ctx.on_generation(vec![create_solution(vec![3., 3.])], 0., Timer::start());
+    heuristic_ctx.on_result().expect("cannot get result");
+
+    assert_eq!(*checkpoints.lock().unwrap(), vec![(1, 401.), (3, 0.)]);
 }
@@ -1,4 +1,7 @@
 use super::*;
+use crate::population::Elitism;
+use crate::utils::{Parallelism, ThreadPool};
+use std::sync::Mutex;
 
 fn just_noise(
     probability: f64,
@@ -51,3 +54,106 @@ fn can_solve_rosenbrock() {
     let (_, fitness) = solutions.first().unwrap();
     assert!(*fitness < 0.01);
 }
+
+#[test]
+fn can_reproduce_solution_with_same_seed() {
+    let solve = |seed: u64| {
+        let random = Arc::new(DefaultRandom::with_seed(seed));
+        let (solutions, _) = Solver::default()
+            .use_static_heuristic()
+            .with_seed(seed)
+            .with_fitness_fn(create_rosenbrock_function())
+            .with_init_solutions(vec![vec![2., 2.]])
+            .with_search_operator(just_noise(1., (-0.05, 0.05), random.clone()), "first", 1.)
+            .with_search_operator(dimen_noise(1., (-0.1, 0.1), 0, random.clone()), "second", 0.5)
+            .with_diversify_operator(dimen_noise(1., (-0.5, 0.5), 1, random))
+            .with_termination(None, Some(100), None, None)
+            .solve()
+            .expect("cannot build and use solver");
+
+        solutions
+    };
+    // NOTE solve on a single thread as parallel execution changes the order of random calls
+    let thread_pool = ThreadPool::new(1);
+
+    let (solutions_a, solutions_b) = thread_pool.execute(|| (solve(42), solve(42)));
+
+    assert!(!solutions_a.is_empty());
+    assert_eq!(solutions_a, solutions_b);
+}
+
+struct RandomInitialOperator {
+    created: Arc<Mutex<Vec<Vec<f64>>>>,
+}
+
+impl RandomInitialOperator {
+    fn create_solution(&self, context: &VectorContext, random: &(dyn Random + Send + Sync)) -> VectorSolution {
+        let data = (0..2)
+            .map(|_| {
+                // NOTE sleep to let parallel construction interleave random calls
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                random.uniform_real(-2., 2.)
+            })
+            .collect::<Vec<_>>();
+        self.created.lock().unwrap().push(data.clone());
+
+        VectorSolution::new(data, context.objective.clone())
+    }
+}
+
+impl InitialOperator for RandomInitialOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn create(&self, context: &Self::Context) -> Self::Solution {
+        self.create_solution(context, context.environment().random.as_ref())
+    }
+
+    fn create_with_random(&self, context: &Self::Context, random: Arc<dyn Random + Send + Sync>) -> Self::Solution {
+        self.create_solution(context, random.as_ref())
+    }
+}
+
+#[test]
+fn can_build_same_initial_population_in_parallel_with_same_seed() {
+    let build_initial = |seed: u64| {
+        let environment = Arc::new(Environment {
+            random: Arc::new(DefaultRandom::with_seed(seed)),
+            parallelism: Parallelism::new_with_cpus(4),
+            ..Environment::default()
+        });
+        let objective = Arc::new(VectorObjective::new(create_rosenbrock_function(), Arc::new(|data| data.to_vec())));
+        let population = Box::new(Elitism::new(objective.clone(), environment.random.clone(), 8, 4));
+        let context = VectorContext::new(objective.clone(), population, TelemetryMode::None, environment.clone());
+        let heuristic = Solver::default()
+            .with_search_operator(just_noise(1., (-0.05, 0.05), environment.random.clone()), "first", 1.)
+            .with_diversify_operator(just_noise(1., (-0.5, 0.5), environment.random.clone()))
+            .create_static_heuristic(environment.as_ref());
+        let created = Arc::new(Mutex::new(vec![]));
+        let operators = (0..2)
+            .map::<(TargetInitialOperator, _), _>(|_| (Box::new(RandomInitialOperator { created: created.clone() }), 1))
+            .collect();
+
+        let config = EvolutionConfigBuilder::default()
+            .with_heuristic(heuristic)
+            .with_objective(objective)
+            .with_context(context)
+            .with_max_generations(Some(1))
+            .with_initial(8, 1., operators)
+            .build()
+            .expect("cannot build config");
+        EvolutionSimulator::new(config).expect("cannot create simulator").run().expect("cannot run simulator");
+
+        // NOTE solutions are created in arbitrary order by parallel workers
+        let mut created = created.lock().unwrap().clone();
+        created.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        created
+    };
+    let thread_pool = ThreadPool::new(4);
+
+    let (initial_a, initial_b) = thread_pool.execute(|| (build_initial(42), build_initial(42)));
+
+    assert_eq!(initial_a.len(), 8);
+    assert_eq!(initial_a, initial_b);
+}
@@ -1,6 +1,10 @@
 use super::*;
 use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::get_default_population;
 use crate::helpers::example::{create_default_heuristic_context, create_example_objective};
+use crate::helpers::utils::CollectingLogger;
+use crate::utils::{DiagnosticEvent, LogLevel};
+use crate::TelemetryMode;
 use std::ops::Range;
 
 parameterized_test! {can_evaluate_state_reward, (ratio, value, expected), {
@@ -107,3 +111,50 @@ fn can_display_heuristic_info_impl(is_experimental: bool) {
 
     assert_eq!(!formatted.is_empty(), is_experimental);
 }
+
+#[test]
+fn can_emit_operator_applied_events() {
+    struct FixedHeuristicOperator {
+        data: Vec<f64>,
+        objective: Arc<VectorObjective>,
+    }
+    impl HeuristicSearchOperator for FixedHeuristicOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, _: &Self::Solution) -> Self::Solution {
+            VectorSolution::new(self.data.clone(), self.objective.clone())
+        }
+    }
+    impl HeuristicDiversifyOperator for FixedHeuristicOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+            vec![self.search(heuristic_ctx, solution)]
+        }
+    }
+    let logger = Arc::new(CollectingLogger::new(LogLevel::Trace));
+    let environment = Arc::new(Environment { solver_logger: logger.clone(), ..Environment::default() });
+    let objective = create_example_objective();
+    let population = get_default_population(objective.clone(), environment.clone(), 4);
+    let heuristic_ctx = VectorContext::new(objective.clone(), population, TelemetryMode::None, environment.clone());
+    let solution = VectorSolution::new(vec![-2., 2.], objective.clone());
+    let operator = Arc::new(FixedHeuristicOperator { data: vec![1., 1.], objective });
+    let mut heuristic = DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(
+        vec![(operator.clone(), "optimum".to_string())],
+        vec![operator],
+        environment.as_ref(),
+    );
+
+    heuristic.search(&heuristic_ctx, vec![&solution, &solution]);
+
+    let events = logger.get_events();
+    assert_eq!(events.len(), 2);
+    events.into_iter().for_each(|(level, event)| {
+        assert_eq!(level, LogLevel::Trace);
+        assert!(matches!(event, DiagnosticEvent::OperatorApplied { name, is_improved: true, .. } if name == "optimum"));
+    });
+}
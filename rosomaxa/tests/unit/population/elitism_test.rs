@@ -39,6 +39,24 @@ fn can_maintain_best_order() {
     assert_eq!(get_all_fitness(&population), &[1., 6.5, 58.5]);
 }
 
+#[test]
+fn can_revalidate_individuals() {
+    let (objective, mut population) = create_objective_population(3, 1);
+    population.add_all(vec![
+        VectorSolution::new(vec![0.5, 0.5], objective.clone()),
+        VectorSolution::new(vec![0., 0.], objective),
+    ]);
+    assert_eq!(get_all_fitness(&population), &[1., 6.5]);
+
+    population.revalidate(&|individual| {
+        if individual.data == [0., 0.] {
+            individual.data = vec![-0.5, -0.5];
+        }
+    });
+
+    assert_eq!(get_all_fitness(&population), &[6.5, 58.5]);
+}
+
 #[test]
 fn can_maintain_diversity_with_one_objective() {
     let (objective, mut population) = create_objective_population(4, 1);
@@ -109,6 +127,33 @@ fn can_select_individuals() {
     assert_eq!(parents, 3);
 }
 
+parameterized_test! {can_select_n_individuals, (n, expected), {
+    can_select_n_individuals_impl(n, expected);
+}}
+
+can_select_n_individuals! {
+    case01_none: (0, 0),
+    case02_one: (1, 1),
+    case03_less_than_selection_size: (2, 2),
+    case04_more_than_population_size: (6, 6),
+}
+
+fn can_select_n_individuals_impl(n: usize, expected: usize) {
+    let (objective, mut population) = create_objective_population(4, 3);
+    population.add_all(vec![
+        VectorSolution::new(vec![-1., -1.], objective.clone()),
+        VectorSolution::new(vec![1., 1.], objective.clone()),
+        VectorSolution::new(vec![-2., -2.], objective.clone()),
+    ]);
+
+    let parents = population.select_n(n).collect::<Vec<_>>();
+
+    assert_eq!(parents.len(), expected);
+    if let Some(first) = parents.first() {
+        assert_eq!(objective.fitness(first), 0.);
+    }
+}
+
 #[test]
 fn can_handle_empty() {
     let (_, mut population) = create_objective_population(4, 3);
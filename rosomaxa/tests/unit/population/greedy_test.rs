@@ -24,6 +24,21 @@ fn can_keep_best_solution() {
     assert_eq!(get_best_fitness(&population), 401.);
 }
 
+#[test]
+fn can_report_whether_individual_is_accepted() {
+    let objective = create_example_objective();
+    let mut population = Greedy::<_, _>::new(objective.clone(), 1, None);
+
+    assert!(population.add(VectorSolution::new(vec![2., 2.], objective.clone())));
+    assert!(!population.add(VectorSolution::new(vec![-2., -2.], objective.clone())));
+    assert!(!population.add(VectorSolution::new(vec![2., 2.], objective.clone())));
+    assert!(population.add(VectorSolution::new(vec![1., 1.], objective)));
+
+    assert_eq!(population.size(), 1);
+    assert_eq!(get_best_fitness(&population), 0.);
+    assert_eq!(population.selection_phase(), SelectionPhase::Exploitation);
+}
+
 #[test]
 fn can_format_empty_population() {
     let population = Greedy::<_, _>::new(create_example_objective(), 1, None);
@@ -54,6 +69,17 @@ fn can_select_when_empty() {
     assert_eq!(population.all().count(), 0);
 }
 
+#[test]
+fn can_select_n_best_known() {
+    let objective = create_example_objective();
+    let solution = VectorSolution::new(vec![-1., -1.], objective.clone());
+    let population = Greedy::<_, _>::new(objective, 1, Some(solution));
+
+    assert_eq!(population.select().count(), 1);
+    assert_eq!(population.select_n(3).count(), 3);
+    assert_eq!(population.select_n(0).count(), 0);
+}
+
 #[test]
 fn can_compare_individuals() {
     let objective = create_example_objective();
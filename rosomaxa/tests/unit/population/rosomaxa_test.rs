@@ -1,6 +1,8 @@
 use super::*;
 use crate::example::*;
 use crate::helpers::example::create_example_objective;
+use crate::helpers::utils::CollectingLogger;
+use crate::utils::{DecisionTracer, DefaultRandom, DiagnosticEvent, LogLevel};
 
 fn create_rosomaxa(rebalance_memory: usize) -> (Arc<VectorObjective>, Rosomaxa<VectorObjective, VectorSolution>) {
     let mut config = RosomaxaConfig::new_with_defaults(4);
@@ -51,6 +53,75 @@ fn can_switch_phases() {
     }
 }
 
+parameterized_test! {can_switch_to_exploitation_without_network_growth, (max_generations, expected), {
+    can_switch_to_exploitation_without_network_growth_impl(max_generations, expected);
+}}
+
+can_switch_to_exploitation_without_network_growth! {
+    case01_no_limit: (None, SelectionPhase::Exploration),
+    case02_limit_reached: (Some(3), SelectionPhase::Exploitation),
+    case03_limit_not_reached: (Some(20), SelectionPhase::Exploration),
+}
+
+fn can_switch_to_exploitation_without_network_growth_impl(max_generations: Option<usize>, expected: SelectionPhase) {
+    let config =
+        RosomaxaConfig { max_generations_without_growth: max_generations, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    (0..4).for_each(|_| {
+        rosomaxa.add_all(vec![VectorSolution::new(vec![-1., -1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0., 0))
+    });
+
+    for generation in 1..10 {
+        assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+        rosomaxa.add(VectorSolution::new(vec![-1., -1.], objective.clone()));
+        rosomaxa.update_phase(&create_statistics(0.1, generation));
+        if rosomaxa.selection_phase() == SelectionPhase::Exploitation {
+            break;
+        }
+    }
+
+    assert_eq!(rosomaxa.selection_phase(), expected);
+}
+
+parameterized_test! {can_switch_to_exploitation_on_stagnation, (stagnation_limit, is_improving, expected), {
+    can_switch_to_exploitation_on_stagnation_impl(stagnation_limit, is_improving, expected);
+}}
+
+can_switch_to_exploitation_on_stagnation! {
+    case01_no_limit: (None, false, SelectionPhase::Exploration),
+    case02_limit_reached: (Some(3), false, SelectionPhase::Exploitation),
+    case03_limit_not_reached: (Some(20), false, SelectionPhase::Exploration),
+    case04_improving: (Some(3), true, SelectionPhase::Exploration),
+}
+
+fn can_switch_to_exploitation_on_stagnation_impl(
+    stagnation_limit: Option<usize>,
+    is_improving: bool,
+    expected: SelectionPhase,
+) {
+    let config = RosomaxaConfig { stagnation_limit, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    (0..4).for_each(|_| {
+        rosomaxa.add_all(vec![VectorSolution::new(vec![-1., -1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0., 0))
+    });
+
+    for generation in 1..10 {
+        assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+        let value = if is_improving { -1. + generation as f64 * 0.1 } else { -1. };
+        rosomaxa.add(VectorSolution::new(vec![value, value], objective.clone()));
+        rosomaxa.update_phase(&create_statistics(0.1, generation));
+        if rosomaxa.selection_phase() == SelectionPhase::Exploitation {
+            break;
+        }
+    }
+
+    assert_eq!(rosomaxa.selection_phase(), expected);
+}
+
 #[test]
 fn can_select_individuals_in_different_phases() {
     let (objective, mut rosomaxa) = create_rosomaxa(10);
@@ -69,6 +140,85 @@ fn can_select_individuals_in_different_phases() {
     assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
 }
 
+parameterized_test! {can_enter_exploitation_manually, (size, expected_phase), {
+    can_enter_exploitation_manually_impl(size, expected_phase);
+}}
+
+can_enter_exploitation_manually! {
+    case01_from_initial: (3, SelectionPhase::Initial),
+    case02_from_exploration: (5, SelectionPhase::Exploration),
+}
+
+fn can_enter_exploitation_manually_impl(size: usize, expected_phase: SelectionPhase) {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    // NOTE each next individual is worse than the first one, so only the first is promoted to elite
+    [vec![1., 1.], vec![0., 0.], vec![1.5, 1.5], vec![2., 2.], vec![-1., -1.]]
+        .into_iter()
+        .take(size)
+        .enumerate()
+        .for_each(|(idx, data)| {
+            rosomaxa.add_all(vec![VectorSolution::new(data, objective.clone())]);
+            rosomaxa.update_phase(&create_statistics(0., idx))
+        });
+    assert_eq!(rosomaxa.selection_phase(), expected_phase);
+    assert_eq!(rosomaxa.size(), 1);
+
+    rosomaxa.enter_exploitation();
+
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
+    assert_eq!(rosomaxa.size(), 2);
+    assert_eq!(rosomaxa.all().count(), 2);
+    let best = rosomaxa.ranked().next().map(|(individual, _)| individual.data.clone());
+    assert_eq!(best, Some(vec![1., 1.]));
+
+    rosomaxa.enter_exploitation();
+    rosomaxa.update_phase(&create_statistics(0., size));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
+}
+
+parameterized_test! {can_disable_elite_selection, (disable_elite_selection, expected_elite), {
+    can_disable_elite_selection_impl(disable_elite_selection, expected_elite);
+}}
+
+can_disable_elite_selection! {
+    case01_enabled: (false, true),
+    case02_disabled: (true, false),
+}
+
+fn can_disable_elite_selection_impl(disable_elite_selection: bool, expected_elite: bool) {
+    let config = RosomaxaConfig { disable_elite_selection, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    (0..10).for_each(|idx| {
+        rosomaxa.add_all(vec![VectorSolution::new(vec![-1., -1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.75, idx))
+    });
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+
+    let elite = rosomaxa.elite.select().map(|individual| individual as *const _).collect::<Vec<_>>();
+    let has_elite = rosomaxa.select().any(|individual| elite.contains(&(individual as *const _)));
+
+    assert_eq!(has_elite, expected_elite);
+}
+
+#[test]
+fn can_select_n_individuals_in_different_phases() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    (0..10).for_each(|idx| {
+        rosomaxa.add_all(vec![VectorSolution::new(vec![-1., -1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.75, idx))
+    });
+
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    assert_eq!(rosomaxa.select_n(2).count(), 2);
+    assert_eq!(rosomaxa.select_n(0).count(), 0);
+
+    rosomaxa.update_phase(&create_statistics(0.95, 10));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
+    assert_eq!(rosomaxa.select_n(2).count(), 2);
+    assert_eq!(rosomaxa.select_n(8).count(), 8);
+}
+
 #[test]
 fn can_optimize_network() {
     let termination_estimate = 0.75;
@@ -85,6 +235,193 @@ fn can_optimize_network() {
     assert_eq!(get_network(&rosomaxa).get_nodes().count(), 4);
 }
 
+parameterized_test! {can_limit_node_count, (max_node_count, expected), {
+    can_limit_node_count_impl(max_node_count, expected);
+}}
+
+can_limit_node_count! {
+    case01_no_limit: (None, |size: usize| size > 5),
+    case02_limit: (Some(5), |size: usize| size <= 5),
+}
+
+fn can_limit_node_count_impl(max_node_count: Option<usize>, expected: fn(usize) -> bool) {
+    let config = RosomaxaConfig { max_node_count, ..RosomaxaConfig::new_with_defaults(4) };
+    let node_size = config.node_size;
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+
+    (0..40).for_each(|idx| {
+        let value = idx as f64 - 20.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, -value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+
+    let network = get_network(&rosomaxa);
+    let size = network.size();
+    let stored = network.get_nodes().map(|node| node.read().unwrap().storage.population.size()).sum::<usize>();
+    assert!(expected(size), "unexpected network size: {}", size);
+    assert!(stored <= size * node_size);
+}
+
+#[test]
+fn can_use_distance_metric_when_pruning_network() {
+    let objective = create_example_objective();
+    let mut rosomaxa =
+        Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), RosomaxaConfig::new_with_defaults(4))
+            .unwrap();
+    (0..40).for_each(|idx| {
+        let value = idx as f64 - 20.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, -value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+    let network = match &mut rosomaxa.phase {
+        RosomaxaPhases::Exploration { network, .. } => network,
+        _ => unreachable!(),
+    };
+    // NOTE zero weight makes all non-empty nodes equally distant, so they are kept in coordinate order
+    let metric = DistanceMetric::WeightedEuclidean(vec![0.]);
+    let is_empty = |node: &NodeLink<VectorSolution, IndividualStorage<VectorObjective, VectorSolution>>| {
+        node.read().unwrap().storage.population.size() == 0
+    };
+    let mut expected =
+        network.iter().filter(|(_, node)| !is_empty(node)).map(|(coordinate, _)| *coordinate).collect::<Vec<_>>();
+    expected.truncate(expected.len() - 3);
+    let max_node_count = expected.len();
+
+    Rosomaxa::<VectorObjective, VectorSolution>::prune_network(network, &[1.], max_node_count, &metric);
+
+    assert_eq!(network.get_coordinates().collect::<Vec<_>>(), expected);
+}
+
+parameterized_test! {can_freeze_network_growth_near_transition, (growth_freeze_threshold, expected_growth), {
+    can_freeze_network_growth_near_transition_impl(growth_freeze_threshold, expected_growth);
+}}
+
+can_freeze_network_growth_near_transition! {
+    case01_no_threshold: (None, true),
+    case02_threshold_crossed: (Some(0.3), false),
+    case03_threshold_not_crossed: (Some(0.7), true),
+}
+
+fn can_freeze_network_growth_near_transition_impl(growth_freeze_threshold: Option<f64>, expected_growth: bool) {
+    let config = RosomaxaConfig { growth_freeze_threshold, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    let add_and_update = |rosomaxa: &mut Rosomaxa<VectorObjective, VectorSolution>, idx: usize| {
+        let value = idx as f64 - 20.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, -value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    };
+    (0..5).for_each(|idx| add_and_update(&mut rosomaxa, idx));
+    let size_before = get_network(&rosomaxa).size();
+
+    (5..40).for_each(|idx| add_and_update(&mut rosomaxa, idx));
+
+    assert_eq!(get_network(&rosomaxa).is_growth_frozen(), !expected_growth);
+    assert_eq!(get_network(&rosomaxa).size() > size_before, expected_growth);
+}
+
+parameterized_test! {can_normalize_node_distances, (fitness_normalization, is_balanced), {
+    can_normalize_node_distances_impl(fitness_normalization, is_balanced);
+}}
+
+can_normalize_node_distances! {
+    case01_normalized: (true, true),
+    case02_relative: (false, false),
+}
+
+fn can_normalize_node_distances_impl(fitness_normalization: bool, is_balanced: bool) {
+    let config = RosomaxaConfig { fitness_normalization, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    // NOTE the first axis has small scale (e.g. unassigned jobs), the second one is large (e.g. cost)
+    (0..10).for_each(|idx| {
+        let value = (idx % 5) as f64;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, 1000. * (value + 1.)], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+    let network = get_network(&rosomaxa);
+    let node = network.get_nodes().next().unwrap().read().unwrap();
+    let ((min_x, max_x), (min_y, max_y)) = match rosomaxa.weight_ranges.as_ref() {
+        Some(ranges) => {
+            let ranges = ranges.read().unwrap();
+            (ranges[0], ranges[1])
+        }
+        None => ((0., 4.), (1000., 5000.)),
+    };
+
+    let distance_x = node.storage.distance(&[min_x, min_y], &[max_x, min_y]);
+    let distance_y = node.storage.distance(&[min_x, min_y], &[min_x, max_y]);
+
+    assert_eq!((distance_x - distance_y).abs() < 1E-6, is_balanced);
+}
+
+#[test]
+fn can_sample_diverse_individuals() {
+    let k = 3;
+    let get_distance = |a: &VectorSolution, b: &VectorSolution| {
+        relative_distance(a.weights().iter().cloned(), b.weights().iter().cloned())
+    };
+    let get_min_distance = |individuals: &[&VectorSolution]| {
+        individuals
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, a)| individuals.iter().skip(idx + 1).map(move |b| get_distance(a, b)))
+            .min_by(|a, b| compare_floats(*a, *b))
+            .unwrap()
+    };
+    let (objective, mut rosomaxa) = create_rosomaxa(100);
+    (0..20).for_each(|idx| {
+        let value = idx as f64 / 4.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value + (idx % 3) as f64], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    let mut all = rosomaxa.all().collect::<Vec<_>>();
+    all.sort_by(|a, b| rosomaxa.cmp(a, b));
+
+    let diverse = rosomaxa.sample_diverse(k).collect::<Vec<_>>();
+
+    assert_eq!(diverse.len(), k);
+    assert_eq!(rosomaxa.cmp(diverse[0], all[0]), Ordering::Equal);
+    let median = all[(all.len() - 1) / 2];
+    assert!(diverse.iter().all(|individual| rosomaxa.cmp(individual, median) != Ordering::Greater));
+    assert!(get_min_distance(diverse.as_slice()) > 0.);
+    assert!(get_min_distance(diverse.as_slice()) >= get_min_distance(&all[..k]));
+}
+
+#[test]
+fn can_revalidate_individuals_in_network() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    (0..10).for_each(|idx| {
+        let value = idx as f64 - 5.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    let mut target = VectorSolution::new(vec![100., 100.], objective);
+    target.init_weights();
+    let get_min_distance = |rosomaxa: &Rosomaxa<VectorObjective, VectorSolution>| {
+        get_network(rosomaxa)
+            .get_nodes()
+            .map(|node| {
+                let node = node.read().unwrap();
+                node.weights.iter().zip(target.weights().iter()).map(|(a, b)| (a - b).powi(2)).sum::<f64>().sqrt()
+            })
+            .min_by(|a, b| compare_floats(*a, *b))
+            .unwrap()
+    };
+    let original_distance = get_min_distance(&rosomaxa);
+
+    rosomaxa.revalidate(&|individual| individual.data = vec![100., 100.]);
+
+    assert!(get_min_distance(&rosomaxa) < original_distance);
+    get_network(&rosomaxa).get_nodes().for_each(|node| {
+        node.read().unwrap().storage.iter().for_each(|individual| assert_eq!(individual.weights(), target.weights()));
+    });
+    rosomaxa.all().for_each(|individual| assert_eq!(individual.weights(), target.weights()));
+}
+
 #[test]
 fn can_format_network() {
     let (objective, mut rosomaxa) = create_rosomaxa(4);
@@ -112,3 +449,636 @@ fn can_handle_empty_population() {
         assert_eq!(rosomaxa.selection_phase(), phase)
     }
 }
+
+struct EmphasisObjective {
+    inner: Arc<VectorObjective>,
+    emphasized: Arc<std::sync::Mutex<Vec<usize>>>,
+}
+
+impl HeuristicObjective for EmphasisObjective {}
+
+impl Objective for EmphasisObjective {
+    type Solution = VectorSolution;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.inner.fitness(solution)
+    }
+}
+
+impl MultiObjective for EmphasisObjective {
+    fn objectives<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a (dyn Objective<Solution = Self::Solution> + Send + Sync)> + 'a> {
+        self.inner.objectives()
+    }
+}
+
+impl Shuffled for EmphasisObjective {
+    fn get_shuffled(&self, _: &(dyn Random + Send + Sync)) -> Self {
+        Self { inner: self.inner.clone(), emphasized: self.emphasized.clone() }
+    }
+
+    fn get_emphasized(&self, index: usize) -> Option<Self> {
+        self.emphasized.lock().unwrap().push(index);
+
+        Some(Self { inner: self.inner.clone(), emphasized: self.emphasized.clone() })
+    }
+}
+
+parameterized_test! {can_use_objective_emphasis, objective_emphasis, {
+    can_use_objective_emphasis_impl(objective_emphasis);
+}}
+
+can_use_objective_emphasis! {
+    case01_enabled: true,
+    case02_disabled: false,
+}
+
+fn can_use_objective_emphasis_impl(objective_emphasis: bool) {
+    let inner = create_example_objective();
+    let emphasized = Arc::new(std::sync::Mutex::new(vec![]));
+    let objective = Arc::new(EmphasisObjective { inner: inner.clone(), emphasized: emphasized.clone() });
+    let mut config = RosomaxaConfig::new_with_defaults(4);
+    config.objective_emphasis = objective_emphasis;
+    let mut rosomaxa = Rosomaxa::new(objective, Arc::new(Environment::default()), config).unwrap();
+
+    (0..4).for_each(|idx| {
+        rosomaxa.add(VectorSolution::new(vec![idx as f64, idx as f64], inner.clone()));
+        rosomaxa.update_phase(&create_statistics(0., idx));
+    });
+
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    assert!(rosomaxa.select().next().is_some());
+    let network_size = match &rosomaxa.phase {
+        RosomaxaPhases::Exploration { network, .. } => network.size(),
+        _ => unreachable!(),
+    };
+    assert_eq!(network_size, 4);
+    let emphasized = emphasized.lock().unwrap().clone();
+    if objective_emphasis {
+        assert!(emphasized.len() >= network_size);
+        assert_eq!(emphasized, (0..emphasized.len()).collect::<Vec<_>>());
+    } else {
+        assert!(emphasized.is_empty());
+    }
+}
+
+#[test]
+fn can_break_ties_between_nodes_uniformly() {
+    let draws = 4000;
+    let objective = create_example_objective();
+    let mut rosomaxa =
+        Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), RosomaxaConfig::new_with_defaults(4))
+            .unwrap();
+    (0..4).for_each(|idx| {
+        rosomaxa.add(VectorSolution::new(vec![idx as f64, idx as f64], objective.clone()));
+        rosomaxa.update_phase(&create_statistics(0., idx));
+    });
+    let network = get_network(&rosomaxa);
+    let random = DefaultRandom::default();
+    let mut coordinates = vec![];
+
+    let mut frequencies = std::collections::HashMap::<Coordinate, usize>::new();
+    (0..draws).for_each(|_| {
+        Rosomaxa::<VectorObjective, VectorSolution>::fill_populations(network, &mut coordinates, &random);
+        *frequencies.entry(*coordinates.first().unwrap()).or_insert(0) += 1;
+    });
+
+    assert_eq!(frequencies.len(), network.size());
+    let expected = draws as f64 / network.size() as f64;
+    assert!(frequencies.values().all(|&count| (count as f64 - expected).abs() < expected * 0.2));
+}
+
+#[test]
+fn can_reproduce_selection_with_same_random_decisions() {
+    let objective = create_example_objective();
+    let select_individuals = |tracer: Arc<DecisionTracer>| {
+        let environment = Arc::new(Environment::new_with_tracer(tracer));
+        let config = RosomaxaConfig { rebalance_memory: 10, ..RosomaxaConfig::new_with_defaults(4) };
+        let mut rosomaxa = Rosomaxa::new(objective.clone(), environment, config).unwrap();
+
+        (0..10).for_each(|idx| {
+            let value = idx as f64;
+            rosomaxa.add_all(vec![VectorSolution::new(vec![value, -value], objective.clone())]);
+            rosomaxa.update_phase(&create_statistics(0.75, idx))
+        });
+        assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+
+        (0..5).flat_map(|_| rosomaxa.select().map(|individual| individual.data.clone()).collect::<Vec<_>>()).collect()
+    };
+    let recording = Arc::new(DecisionTracer::new_recording(Arc::new(DefaultRandom::default())));
+
+    let expected: Vec<Vec<f64>> = select_individuals(recording.clone());
+    let actual: Vec<Vec<f64>> = select_individuals(Arc::new(DecisionTracer::new_replaying(recording.get_trace())));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_track_best_node_trajectory() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    (0..4).for_each(|idx| {
+        let value = idx as f64 + 1.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0., idx))
+    });
+    assert!(rosomaxa.get_best_node_trajectory().is_empty());
+
+    (4..8).for_each(|idx| {
+        let value = 5. - idx as f64;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+
+    let trajectory = rosomaxa.get_best_node_trajectory().to_vec();
+    assert_eq!(trajectory.iter().map(|(generation, _)| *generation).collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    let (_, last_coordinate) = trajectory.last().cloned().unwrap();
+    let best_fitness = get_network(&rosomaxa)
+        .find(&last_coordinate)
+        .and_then(|node| node.read().unwrap().storage.population.ranked().next().map(|(i, _)| objective.fitness(i)))
+        .unwrap();
+    let min_fitness = get_network(&rosomaxa)
+        .get_nodes()
+        .filter_map(|node| node.read().unwrap().storage.population.ranked().next().map(|(i, _)| objective.fitness(i)))
+        .min_by(|a, b| compare_floats(*a, *b))
+        .unwrap();
+    assert_eq!(best_fitness, min_fitness);
+
+    rosomaxa.update_phase(&create_statistics(0.95, 8));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
+    assert_eq!(rosomaxa.get_best_node_trajectory().len(), 4);
+}
+
+#[test]
+fn can_return_network_stats() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    assert!(rosomaxa.network_stats().is_none());
+
+    (0..8).for_each(|idx| {
+        let value = idx as f64 + 1.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+
+    let stats = rosomaxa.network_stats().expect("no stats in exploration phase");
+    assert_eq!(stats.phase, SelectionPhase::Exploration);
+    assert_eq!(stats.node_count, get_network(&rosomaxa).size());
+    assert!(stats.node_count >= RosomaxaConfig::new_with_defaults(10).initial_size);
+    assert!(stats.max_hits > 0);
+    assert!(stats.mean_hits > 0. && stats.mean_hits <= stats.max_hits as f64);
+
+    rosomaxa.update_phase(&create_statistics(0.95, 8));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
+    assert!(rosomaxa.network_stats().is_none());
+}
+
+parameterized_test! {can_emit_diagnostic_events, (min_level, has_network_events, expected_rebalances), {
+    can_emit_diagnostic_events_impl(min_level, has_network_events, expected_rebalances);
+}}
+
+can_emit_diagnostic_events! {
+    case01_debug: (LogLevel::Debug, true, (4..20).collect()),
+    case02_info: (LogLevel::Info, false, vec![]),
+}
+
+fn can_emit_diagnostic_events_impl(min_level: LogLevel, has_network_events: bool, expected_rebalances: Vec<usize>) {
+    let logger = Arc::new(CollectingLogger::new(min_level));
+    let environment = Arc::new(Environment { solver_logger: logger.clone(), ..Environment::default() });
+    let config = RosomaxaConfig { rebalance_memory: 2, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), environment, config).unwrap();
+
+    (0..20).for_each(|idx| {
+        let value = 10. - idx as f64;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.75, idx))
+    });
+    rosomaxa.update_phase(&create_statistics(0.95, 20));
+
+    let events = logger.get_events();
+    let transitions = events
+        .iter()
+        .filter_map(|(_, event)| match event {
+            DiagnosticEvent::PhaseTransition { generation, from, to } => Some((*generation, *from, *to)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        transitions,
+        vec![
+            (3, SelectionPhase::Initial, SelectionPhase::Exploration),
+            (20, SelectionPhase::Exploration, SelectionPhase::Exploitation)
+        ]
+    );
+    assert!(events.iter().any(|(_, event)| matches!(event, DiagnosticEvent::NewBest { .. })));
+    assert!(events.iter().all(|(level, _)| *level >= min_level));
+    let has_growth = events.iter().any(|(_, event)| matches!(event, DiagnosticEvent::NetworkGrowth { .. }));
+    let has_rebalance = events.iter().any(|(_, event)| matches!(event, DiagnosticEvent::RebalanceCycle { .. }));
+    let rebalances = events
+        .iter()
+        .filter_map(|(_, event)| match event {
+            DiagnosticEvent::RebalanceCycle { generation, nodes_before, nodes_after } => {
+                Some((*generation, *nodes_before, *nodes_after))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(has_growth, has_network_events);
+    assert_eq!(has_rebalance, has_network_events);
+    // NOTE each exploration generation rebalances the network, even if no node is removed
+    assert_eq!(rebalances.iter().map(|(generation, ..)| *generation).collect::<Vec<_>>(), expected_rebalances);
+    assert!(rebalances.iter().all(|(_, nodes_before, nodes_after)| nodes_after <= nodes_before));
+}
+
+#[test]
+fn can_skip_individuals_with_non_finite_weights_in_initial_phase() {
+    let logger = Arc::new(CollectingLogger::new(LogLevel::Warn));
+    let environment = Arc::new(Environment { solver_logger: logger.clone(), ..Environment::default() });
+    let weight_fn = Arc::new(|data: &[f64]| if data[0] < 0. { vec![f64::NAN, data[1]] } else { data.to_vec() });
+    let objective = Arc::new(VectorObjective::new(create_rosenbrock_function(), weight_fn));
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), environment, RosomaxaConfig::new_with_defaults(4)).unwrap();
+
+    rosomaxa.add_all((0..4).map(|idx| VectorSolution::new(vec![idx as f64 - 1., 1.], objective.clone())).collect());
+    rosomaxa.update_phase(&create_statistics(0.1, 0));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Initial);
+
+    rosomaxa.add_all(vec![VectorSolution::new(vec![3., 1.], objective.clone())]);
+    rosomaxa.update_phase(&create_statistics(0.1, 1));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    assert!(get_network(&rosomaxa).get_nodes().all(|node| node.read().unwrap().weights.iter().all(|w| w.is_finite())));
+    assert_eq!(
+        logger.get_events().iter().filter(|(_, event)| matches!(event, DiagnosticEvent::InputRejected { .. })).count(),
+        1
+    );
+}
+
+parameterized_test! {can_use_clamp_inputs, (clamp_inputs, is_in_range), {
+    can_use_clamp_inputs_impl(clamp_inputs, is_in_range);
+}}
+
+can_use_clamp_inputs! {
+    case01_clamp: (true, true),
+    case02_no_clamp: (false, false),
+}
+
+fn can_use_clamp_inputs_impl(clamp_inputs: bool, is_in_range: bool) {
+    let config = RosomaxaConfig { clamp_inputs, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    rosomaxa.add_all((0..4).map(|idx| VectorSolution::new(vec![idx as f64, 1.], objective.clone())).collect());
+    rosomaxa.update_phase(&create_statistics(0.1, 0));
+
+    rosomaxa.add_all(vec![VectorSolution::new(vec![1E9, 1.], objective.clone())]);
+
+    let max_weight = get_network(&rosomaxa)
+        .get_nodes()
+        .flat_map(|node| node.read().unwrap().weights.clone())
+        .fold(f64::MIN, f64::max);
+    assert_eq!(max_weight < 100., is_in_range);
+}
+
+parameterized_test! {can_use_node_selection_size, (node_selection_size, expected_selection_size), {
+    can_use_node_selection_size_impl(node_selection_size, expected_selection_size);
+}}
+
+can_use_node_selection_size! {
+    case01_default: (None, 4),
+    case02_smaller: (Some(2), 2),
+}
+
+fn can_use_node_selection_size_impl(node_selection_size: Option<usize>, expected_selection_size: usize) {
+    let config = RosomaxaConfig { node_size: 4, node_selection_size, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+
+    (0..40).for_each(|idx| {
+        let value = idx as f64 / 4. - 5.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.1, idx))
+    });
+
+    get_network(&rosomaxa).get_nodes().for_each(|node| {
+        let node = node.read().unwrap();
+        let population = &node.storage.population;
+        let expected_selection_size = if population.size() > 0 { expected_selection_size } else { 0 };
+        assert!(population.size() <= 4);
+        assert_eq!(population.select().count(), expected_selection_size);
+    });
+}
+
+parameterized_test! {can_validate_node_selection_size, (node_selection_size, is_ok), {
+    can_validate_node_selection_size_impl(node_selection_size, is_ok);
+}}
+
+can_validate_node_selection_size! {
+    case01_default: (None, true),
+    case02_valid: (Some(1), true),
+    case03_zero: (Some(0), false),
+    case04_above_node_size: (Some(3), false),
+}
+
+fn can_validate_node_selection_size_impl(node_selection_size: Option<usize>, is_ok: bool) {
+    let config = RosomaxaConfig { node_size: 2, node_selection_size, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_use_node_promotion_size, (node_promotion_size, expected_max_per_node), {
+    can_use_node_promotion_size_impl(node_promotion_size, expected_max_per_node);
+}}
+
+can_use_node_promotion_size! {
+    case01_one: (1, 1),
+    case02_default: (2, 2),
+}
+
+fn can_use_node_promotion_size_impl(node_promotion_size: usize, expected_max_per_node: usize) {
+    let config = RosomaxaConfig {
+        node_size: 4,
+        node_promotion_size,
+        disable_elite_selection: true,
+        ..RosomaxaConfig::new_with_defaults(8)
+    };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+
+    (0..40).for_each(|idx| {
+        let value = idx as f64 / 4. - 5.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.1, idx))
+    });
+
+    let selected = rosomaxa.select().collect::<Vec<_>>();
+    let max_per_node = get_network(&rosomaxa)
+        .get_nodes()
+        .map(|node| {
+            let node = node.read().unwrap();
+            let population = &node.storage.population;
+            selected
+                .iter()
+                .filter(|selected| population.all().any(|individual| std::ptr::eq(individual, **selected)))
+                .count()
+        })
+        .max()
+        .unwrap();
+    assert!(!selected.is_empty());
+    assert!(max_per_node <= expected_max_per_node);
+}
+
+parameterized_test! {can_report_invalid_sizes, (elite_size, node_size, selection_size, expected), {
+    can_report_invalid_sizes_impl(elite_size, node_size, selection_size, expected);
+}}
+
+can_report_invalid_sizes! {
+    case01_elite_size: (0, 2, 4, "elite size to be at least 1, got 0"),
+    case02_node_size: (2, 0, 4, "node size to be at least 1, got 0"),
+    case03_selection_size: (2, 2, 1, "selection size to be at least 2, got 1"),
+}
+
+fn can_report_invalid_sizes_impl(elite_size: usize, node_size: usize, selection_size: usize, expected: &str) {
+    let config = RosomaxaConfig { elite_size, node_size, ..RosomaxaConfig::new_with_defaults(selection_size) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    match result {
+        Err(err) => assert!(err.contains(expected), "unexpected error: {}", err),
+        Ok(_) => unreachable!("expected error"),
+    }
+}
+
+parameterized_test! {can_validate_selection_sizes, (elite_selection_size, node_promotion_size, is_ok), {
+    can_validate_selection_sizes_impl(elite_selection_size, node_promotion_size, is_ok);
+}}
+
+can_validate_selection_sizes! {
+    case01_default: (2, 2, true),
+    case02_valid: (3, 1, true),
+    case03_zero_elite: (0, 2, false),
+    case04_zero_node: (2, 0, false),
+}
+
+fn can_validate_selection_sizes_impl(elite_selection_size: usize, node_promotion_size: usize, is_ok: bool) {
+    let config = RosomaxaConfig { elite_selection_size, node_promotion_size, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_use_initial_size, initial_size, {
+    can_use_initial_size_impl(initial_size);
+}}
+
+can_use_initial_size! {
+    case01_two: 2,
+    case02_four: 4,
+    case03_six: 6,
+}
+
+fn can_use_initial_size_impl(initial_size: usize) {
+    let objective = create_example_objective();
+    let config = RosomaxaConfig { initial_size, ..RosomaxaConfig::new_with_defaults(4) };
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+
+    (0..initial_size).for_each(|idx| {
+        assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Initial);
+        rosomaxa.add_all(vec![VectorSolution::new(vec![-1. - idx as f64, -1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0., idx))
+    });
+
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    assert_eq!(get_network(&rosomaxa).size(), initial_size);
+}
+
+parameterized_test! {can_validate_initial_size, (initial_size, is_ok), {
+    can_validate_initial_size_impl(initial_size, is_ok);
+}}
+
+can_validate_initial_size! {
+    case01_zero: (0, false),
+    case02_one: (1, false),
+    case03_two: (2, true),
+}
+
+fn can_validate_initial_size_impl(initial_size: usize, is_ok: bool) {
+    let config = RosomaxaConfig { initial_size, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_use_distance_metric, metric, {
+    can_use_distance_metric_impl(metric);
+}}
+
+can_use_distance_metric! {
+    case01_relative: DistanceMetric::Relative,
+    case02_euclidean: DistanceMetric::Euclidean,
+    case03_cosine: DistanceMetric::Cosine,
+    case04_weighted_euclidean: DistanceMetric::WeightedEuclidean(vec![2., 0.5]),
+}
+
+fn can_use_distance_metric_impl(metric: DistanceMetric) {
+    let objective = create_example_objective();
+    let config = RosomaxaConfig { distance_metric: metric.clone(), ..RosomaxaConfig::new_with_defaults(4) };
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    (0..10).for_each(|idx| {
+        let value = idx as f64 - 5.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value * value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.1, idx))
+    });
+    let (a, b) = ([1., 3.], [-2., 7.]);
+
+    let node = get_network(&rosomaxa).get_nodes().next().unwrap().read().unwrap();
+
+    assert_eq!(node.storage.distance(&a, &b), metric.distance(a.iter().cloned(), b.iter().cloned()));
+}
+
+parameterized_test! {can_validate_distance_metric, (metric, is_ok), {
+    can_validate_distance_metric_impl(metric, is_ok);
+}}
+
+can_validate_distance_metric! {
+    case01_default: (DistanceMetric::default(), true),
+    case02_weighted_valid: (DistanceMetric::WeightedEuclidean(vec![1., 0.]), true),
+    case03_weighted_negative: (DistanceMetric::WeightedEuclidean(vec![1., -0.1]), false),
+}
+
+fn can_validate_distance_metric_impl(distance_metric: DistanceMetric, is_ok: bool) {
+    let config = RosomaxaConfig { distance_metric, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_validate_neighbourhood, (neighbourhood, is_ok), {
+    can_validate_neighbourhood_impl(neighbourhood, is_ok);
+}}
+
+can_validate_neighbourhood! {
+    case01_default: (NeighbourhoodFunction::default(), true),
+    case02_gaussian_valid: (NeighbourhoodFunction::Gaussian { sigma: 1. }, true),
+    case03_gaussian_zero: (NeighbourhoodFunction::Gaussian { sigma: 0. }, false),
+    case04_gaussian_nan: (NeighbourhoodFunction::Gaussian { sigma: f64::NAN }, false),
+    case05_bubble_zero: (NeighbourhoodFunction::Bubble { radius: 0 }, false),
+}
+
+fn can_validate_neighbourhood_impl(neighbourhood: NeighbourhoodFunction, is_ok: bool) {
+    let config = RosomaxaConfig { neighbourhood, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_use_explore_exploit_ratio, (explore_exploit_ratio, expected), {
+    can_use_explore_exploit_ratio_impl(explore_exploit_ratio, expected);
+}}
+
+can_use_explore_exploit_ratio! {
+    case01_default: (None, (1, 3)),
+    case02_balanced: (Some(0.5), (2, 2)),
+    case03_greedy: (Some(0.), (4, 0)),
+    case04_diverse: (Some(1.), (0, 4)),
+}
+
+fn can_use_explore_exploit_ratio_impl(explore_exploit_ratio: Option<f64>, expected: (usize, usize)) {
+    let config = RosomaxaConfig { elite_size: 4, explore_exploit_ratio, ..RosomaxaConfig::new_with_defaults(4) };
+    let objective = create_example_objective();
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+    (0..40).for_each(|idx| {
+        let value = idx as f64 / 4. - 5.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.1, idx))
+    });
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+
+    let elite = rosomaxa.elite.all().map(|individual| individual as *const _).collect::<Vec<_>>();
+    let (elite_count, network_count) = rosomaxa.select().fold((0, 0), |(elite_count, network_count), individual| {
+        if elite.contains(&(individual as *const _)) {
+            (elite_count + 1, network_count)
+        } else {
+            (elite_count, network_count + 1)
+        }
+    });
+
+    assert_eq!((elite_count, network_count), expected);
+}
+
+parameterized_test! {can_validate_explore_exploit_ratio, (explore_exploit_ratio, is_ok), {
+    can_validate_explore_exploit_ratio_impl(explore_exploit_ratio, is_ok);
+}}
+
+can_validate_explore_exploit_ratio! {
+    case01_default: (None, true),
+    case02_lower_bound: (Some(0.), true),
+    case03_upper_bound: (Some(1.), true),
+    case04_negative: (Some(-0.1), false),
+    case05_above_one: (Some(1.1), false),
+}
+
+fn can_validate_explore_exploit_ratio_impl(explore_exploit_ratio: Option<f64>, is_ok: bool) {
+    let config = RosomaxaConfig { explore_exploit_ratio, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_validate_float_epsilon, (float_epsilon, is_ok), {
+    can_validate_float_epsilon_impl(float_epsilon, is_ok);
+}}
+
+can_validate_float_epsilon! {
+    case01_default: (FLOAT_EPSILON, true),
+    case02_zero: (0., true),
+    case03_negative: (-1E-9, false),
+    case04_nan: (f64::NAN, false),
+    case05_infinity: (f64::INFINITY, false),
+}
+
+fn can_validate_float_epsilon_impl(float_epsilon: f64, is_ok: bool) {
+    let config = RosomaxaConfig { float_epsilon, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_validate_growth_freeze_threshold, (growth_freeze_threshold, is_ok), {
+    can_validate_growth_freeze_threshold_impl(growth_freeze_threshold, is_ok);
+}}
+
+can_validate_growth_freeze_threshold! {
+    case01_default: (None, true),
+    case02_in_range: (Some(0.8), true),
+    case03_negative: (Some(-0.1), false),
+    case04_above_one: (Some(1.1), false),
+}
+
+fn can_validate_growth_freeze_threshold_impl(growth_freeze_threshold: Option<f64>, is_ok: bool) {
+    let config = RosomaxaConfig { growth_freeze_threshold, ..RosomaxaConfig::new_with_defaults(4) };
+
+    let result = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+#[test]
+fn can_return_settings() {
+    let config =
+        RosomaxaConfig { node_size: 4, explore_exploit_ratio: Some(0.5), ..RosomaxaConfig::new_with_defaults(4) };
+    let rosomaxa = Rosomaxa::new(create_example_objective(), Arc::new(Environment::default()), config).unwrap();
+
+    match rosomaxa.settings() {
+        Some(PopulationSettings::Rosomaxa(config)) => {
+            assert_eq!(config.node_size, 4);
+            assert_eq!(config.explore_exploit_ratio, Some(0.5));
+        }
+        settings => unreachable!("unexpected settings: {:?}", settings),
+    }
+}
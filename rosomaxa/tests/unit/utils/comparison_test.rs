@@ -0,0 +1,77 @@
+use super::*;
+
+parameterized_test! {can_compare_floats, (a, b, expected), {
+    can_compare_floats_impl(a, b, expected);
+}}
+
+can_compare_floats! {
+    case_01: (1., 2., Ordering::Less),
+    case_02: (2., 1., Ordering::Greater),
+    case_03: (1., 1., Ordering::Equal),
+    case_04: (f64::NAN, 1., Ordering::Greater),
+    case_05: (1., f64::NAN, Ordering::Less),
+    case_06: (f64::NAN, f64::NAN, Ordering::Equal),
+    case_07: (f64::NAN, f64::INFINITY, Ordering::Greater),
+    case_08: (1., 1. + 1E-12, Ordering::Less),
+}
+
+fn can_compare_floats_impl(a: f64, b: f64, expected: Ordering) {
+    assert_eq!(compare_floats(a, b), expected);
+}
+
+parameterized_test! {can_compare_floats_with_epsilon, (a, b, expected), {
+    can_compare_floats_with_epsilon_impl(a, b, expected);
+}}
+
+can_compare_floats_with_epsilon! {
+    case_01: (1., 1. + 1E-12, Ordering::Equal),
+    case_02: (1E-12, 0., Ordering::Equal),
+    case_03: (1E6, 1E6 + 1E-6, Ordering::Equal),
+    case_04: (1., 1. + 1E-6, Ordering::Less),
+    case_05: (1. + 1E-6, 1., Ordering::Greater),
+    case_06: (f64::INFINITY, f64::INFINITY, Ordering::Equal),
+    case_07: (f64::INFINITY, f64::MAX, Ordering::Greater),
+    case_08: (f64::NAN, 1., Ordering::Greater),
+    case_09: (1., f64::NAN, Ordering::Less),
+    case_10: (f64::NAN, f64::NAN, Ordering::Equal),
+}
+
+fn can_compare_floats_with_epsilon_impl(a: f64, b: f64, expected: Ordering) {
+    assert_eq!(compare_floats_with_epsilon(a, b, FLOAT_EPSILON), expected);
+}
+
+#[test]
+fn can_sort_nan_to_the_end() {
+    let mut values = [3., f64::NAN, 1., f64::NEG_INFINITY, f64::NAN, 2.];
+
+    values.sort_by(|a, b| compare_floats(*a, *b));
+
+    assert_eq!(values[..4], [f64::NEG_INFINITY, 1., 2., 3.]);
+    assert!(values[4..].iter().all(|value| value.is_nan()));
+}
+
+parameterized_test! {can_compare_floats_rounded, (a, b, epsilon, expected), {
+    can_compare_floats_rounded_impl(a, b, epsilon, expected);
+}}
+
+can_compare_floats_rounded! {
+    case_01: (1., 1. + 1E-12, FLOAT_EPSILON, Ordering::Equal),
+    case_02: (1., 1. + 1E-6, FLOAT_EPSILON, Ordering::Less),
+    case_03: (1. + 1E-6, 1., FLOAT_EPSILON, Ordering::Greater),
+    case_04: (1., 1. + 1E-12, 0., Ordering::Less),
+    case_05: (f64::NAN, 1., FLOAT_EPSILON, Ordering::Greater),
+    case_06: (f64::NAN, f64::NAN, FLOAT_EPSILON, Ordering::Equal),
+}
+
+fn can_compare_floats_rounded_impl(a: f64, b: f64, epsilon: f64, expected: Ordering) {
+    assert_eq!(compare_floats_rounded(a, b, epsilon), expected);
+}
+
+#[test]
+fn can_sort_near_equal_values_stably_with_epsilon() {
+    let mut values = [(2., 0), (1. + 1E-12, 1), (1., 2), (1. - 1E-12, 3)];
+
+    values.sort_by(|(a, _), (b, _)| compare_floats_rounded(*a, *b, FLOAT_EPSILON));
+
+    assert_eq!(values.iter().map(|(_, idx)| *idx).collect::<Vec<_>>(), vec![1, 2, 3, 0]);
+}
@@ -20,3 +20,95 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+parameterized_test! {can_return_weighted_index, weights, {
+    can_return_weighted_index_impl(weights);
+}}
+
+can_return_weighted_index! {
+    case01_different: vec![5., 3., 2.],
+    case02_same: vec![1., 1., 1., 1.],
+    case03_with_zero: vec![0., 7., 0., 3.],
+    case04_single: vec![2.],
+}
+
+fn can_return_weighted_index_impl(weights: Vec<f64>) {
+    let random = DefaultRandom::default();
+    let experiments = 10000_usize;
+    let total_sum = weights.iter().sum::<f64>();
+    let mut counter = vec![0_usize; weights.len()];
+
+    (0..experiments).for_each(|_| {
+        let idx = random.weighted_index(weights.as_slice());
+        *counter.get_mut(idx).unwrap() += 1;
+    });
+
+    weights.iter().enumerate().for_each(|(idx, weight)| {
+        let actual_ratio = counter[idx] as f64 / experiments as f64;
+        let expected_ratio = *weight / total_sum;
+
+        assert!((actual_ratio - expected_ratio).abs() < 0.05);
+    });
+}
+
+#[test]
+#[should_panic]
+fn can_panic_on_empty_weighted_index() {
+    DefaultRandom::default().weighted_index(&[]);
+}
+
+fn get_values(random: &DefaultRandom) -> Vec<f64> {
+    let mut rng = random.get_rng();
+    (0..10)
+        .map(|idx| match idx % 3 {
+            0 => random.uniform_int(0, 1000) as f64,
+            1 => random.uniform_real(0., 1.),
+            _ => rng.next_u64() as f64,
+        })
+        .collect()
+}
+
+parameterized_test! {can_produce_deterministic_stream_with_seed, (seeds, is_same), {
+    can_produce_deterministic_stream_with_seed_impl(seeds, is_same);
+}}
+
+can_produce_deterministic_stream_with_seed! {
+    case01_same_seed: ((42, 42), true),
+    case02_different_seed: ((42, 43), false),
+}
+
+fn can_produce_deterministic_stream_with_seed_impl(seeds: (u64, u64), is_same: bool) {
+    let values_a = get_values(&DefaultRandom::with_seed(seeds.0));
+    let values_b = get_values(&DefaultRandom::with_seed(seeds.1));
+
+    assert_eq!(values_a == values_b, is_same);
+}
+
+parameterized_test! {can_fork_independent_stream_with_seed, (streams, is_same), {
+    can_fork_independent_stream_with_seed_impl(streams, is_same);
+}}
+
+can_fork_independent_stream_with_seed! {
+    case01_same_stream: ((1, 1), true),
+    case02_different_stream: ((1, 2), false),
+}
+
+fn can_fork_independent_stream_with_seed_impl(streams: (u64, u64), is_same: bool) {
+    let get_stream_values =
+        |random: Arc<dyn Random + Send + Sync>| (0..10).map(|_| random.uniform_real(0., 1.)).collect::<Vec<_>>();
+    let master = DefaultRandom::with_seed(42);
+    let values_a = get_stream_values(master.fork(streams.0).unwrap());
+    // NOTE usage of master generator does not affect forked streams
+    master.uniform_real(0., 1.);
+    let values_b = get_stream_values(master.fork(streams.1).unwrap());
+
+    assert_eq!(values_a == values_b, is_same);
+    assert!(DefaultRandom::default().fork(streams.0).is_none());
+}
+
+#[test]
+fn can_get_seed() {
+    assert_eq!(DefaultRandom::with_seed(42).seed(), Some(42));
+    assert!(DefaultRandom::with_seed(42).fork(1).unwrap().seed().is_some());
+    assert!(DefaultRandom::default().seed().is_none());
+}
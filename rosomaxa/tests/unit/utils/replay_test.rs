@@ -0,0 +1,69 @@
+use super::*;
+use crate::utils::DefaultRandom;
+
+fn make_decisions(random: &(dyn Random + Send + Sync)) -> (i32, f64, bool, bool, usize, Vec<usize>) {
+    let mut values = (0..10).collect::<Vec<_>>();
+    values.shuffle(&mut random.get_rng());
+
+    (
+        random.uniform_int(0, 100),
+        random.uniform_real(0., 1.),
+        random.is_head_not_tails(),
+        random.is_hit(0.5),
+        random.weighted(&[1, 2, 3]),
+        values,
+    )
+}
+
+#[test]
+fn can_replay_recorded_decisions() {
+    let recording = DecisionTracer::new_recording(Arc::new(DefaultRandom::default()));
+    let expected = make_decisions(&recording);
+    recording.on_operator("ruin_recreate", true);
+
+    let replaying = DecisionTracer::new_replaying(recording.get_trace());
+    assert!(!replaying.is_completed());
+    let actual = make_decisions(&replaying);
+    replaying.on_operator("ruin_recreate", true);
+
+    assert_eq!(actual, expected);
+    assert!(replaying.is_completed());
+}
+
+#[test]
+#[should_panic(expected = "cannot replay decision trace")]
+fn can_detect_diverged_operator() {
+    let recording = DecisionTracer::new_recording(Arc::new(DefaultRandom::default()));
+    recording.on_operator("ruin_recreate", true);
+
+    DecisionTracer::new_replaying(recording.get_trace()).on_operator("local_search", true);
+}
+
+#[test]
+#[should_panic(expected = "cannot replay decision trace")]
+fn can_detect_exhausted_trace() {
+    let recording = DecisionTracer::new_recording(Arc::new(DefaultRandom::default()));
+    recording.uniform_int(0, 10);
+
+    let replaying = DecisionTracer::new_replaying(recording.get_trace());
+    replaying.uniform_int(0, 10);
+    replaying.uniform_int(0, 10);
+}
+
+#[test]
+fn can_save_and_load_trace() {
+    let recording = DecisionTracer::new_recording(Arc::new(DefaultRandom::default()));
+    make_decisions(&recording);
+    recording.on_operator("local search", false);
+    let trace = recording.get_trace();
+
+    let result = trace.to_string().parse::<DecisionTrace>();
+
+    assert_eq!(result, Ok(trace));
+}
+
+#[test]
+fn can_return_error_when_loading_invalid_trace() {
+    assert_eq!("int 1\nreal abc".parse::<DecisionTrace>(), Err("cannot parse value 'abc' at line 2".to_string()));
+    assert_eq!("dice 6".parse::<DecisionTrace>(), Err("unknown event kind 'dice' at line 1".to_string()));
+}
@@ -68,6 +68,8 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                mandatory: job_proto.mandatory,
+                split: job_proto.split.clone(),
             }
         })
         .collect();
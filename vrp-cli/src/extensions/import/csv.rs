@@ -102,6 +102,8 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                mandatory: None,
+                split: None,
             })
             .collect();
 
@@ -118,7 +120,13 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        waiting: None,
+                        service: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
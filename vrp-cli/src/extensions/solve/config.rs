@@ -12,7 +12,7 @@ use serde::Deserialize;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::models::common::SingleDimLoad;
+use vrp_core::models::common::{has_multi_dim_demand, MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::ProblemObjective;
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
@@ -47,6 +47,7 @@ pub struct EvolutionConfig {
 }
 
 #[derive(Clone, Deserialize, Debug)]
+#[allow(clippy::large_enum_variant)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
 pub enum PopulationType {
@@ -79,18 +80,38 @@ pub enum PopulationType {
         max_elite_size: Option<usize>,
         /// Node population size. Default is 4.
         max_node_size: Option<usize>,
+        /// Node selection size. Default is the same as node population size.
+        node_selection_size: Option<usize>,
         /// Spread factor. Default is 0.75.
         spread_factor: Option<f64>,
         /// Distribution factor. Default is 0.75.
         distribution_factor: Option<f64>,
         /// Objective reshuffling. Default is 0.01.
         objective_reshuffling: Option<f64>,
+        /// Objective emphasis alternation across network nodes. Default is false.
+        objective_emphasis: Option<bool>,
         /// Learning rate. Default is 0.1.
         learning_rate: Option<f64>,
         /// A rebalance memory. Default is 100.
         rebalance_memory: Option<usize>,
         /// An exploration phase ratio. Default is 0.9.
         exploration_ratio: Option<f64>,
+        /// A max amount of generations without network growth to end exploration phase. Default is no limit.
+        max_generations_without_growth: Option<usize>,
+        /// A max amount of generations without improvement to end exploration phase. Default is no limit.
+        stagnation_limit: Option<usize>,
+        /// Disables elite promotion in exploration phase selection. Default is false.
+        disable_elite_selection: Option<bool>,
+        /// A soft cap on amount of network nodes. Default is no limit.
+        max_node_count: Option<usize>,
+        /// A share of exploration phase selection drawn from network nodes. Default is built-in rules.
+        explore_exploit_ratio: Option<f64>,
+        /// A termination estimate after which network growth is frozen. Default is never.
+        growth_freeze_threshold: Option<f64>,
+        /// Normalizes node distances by observed value range per objective. Default is false.
+        fitness_normalization: Option<bool>,
+        /// Clamps weights of stored individuals to the observed range of node weights. Default is false.
+        clamp_inputs: Option<bool>,
     },
 }
 
@@ -175,9 +196,26 @@ pub enum SearchOperatorType {
         ruins: Vec<RuinGroupConfig>,
         /// Recreate methods.
         recreates: Vec<RecreateMethod>,
+        /// Adaptive ruin size settings.
+        adaptive: Option<AdaptiveRuinConfig>,
     },
 }
 
+/// An adaptive ruin size configuration: ruin limits grow and acceptance of worse solutions
+/// loosens after consecutive non-improving generations.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaptiveRuinConfig {
+    /// Amount of non-improving generations needed to reach max scale.
+    pub max_stagnation: usize,
+    /// Max scale applied to ruin limits.
+    pub max_scale: f64,
+    /// Probability to accept a worse solution while search improves.
+    pub min_acceptance: f64,
+    /// Probability to accept a worse solution when search is stuck.
+    pub max_acceptance: f64,
+}
+
 /// A operator probability type
 #[derive(Clone, Deserialize, Debug)]
 #[serde(untagged)]
@@ -251,6 +289,9 @@ pub enum RuinMethod {
     /// Neighbour jobs method
     #[serde(rename(deserialize = "neighbour"))]
     Neighbour { probability: f64, min: usize, max: usize, threshold: f64 },
+    /// Related jobs method.
+    #[serde(rename(deserialize = "related"))]
+    Related { probability: f64, min: usize, max: usize, threshold: f64, weights: RelatednessWeightsConfig },
     /// Random job removal method.
     #[serde(rename(deserialize = "random-job"))]
     RandomJob { probability: f64, min: usize, max: usize, threshold: f64 },
@@ -274,6 +315,17 @@ pub enum RuinMethod {
     Cluster { probability: f64, min: usize, max: usize, threshold: f64, min_items: usize },
 }
 
+/// Specifies weights of job relatedness components.
+#[derive(Clone, Deserialize, Debug)]
+pub struct RelatednessWeightsConfig {
+    /// A weight of distance between job locations.
+    pub distance: f64,
+    /// A weight of time windows overlap.
+    pub time: f64,
+    /// A weight of demand difference.
+    pub demand: f64,
+}
+
 /// Specifies recreate methods with their probability weight and specific parameters.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(tag = "type")]
@@ -328,6 +380,21 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "relocate-job"))]
+    RelocateJob { weight: usize },
+
+    #[serde(rename(deserialize = "reverse-segment"))]
+    #[serde(rename_all = "camelCase")]
+    ReverseSegment { weight: usize, max_segment_size: usize },
+
+    #[serde(rename(deserialize = "redistribute-unassigned"))]
+    #[serde(rename_all = "camelCase")]
+    RedistributeUnassigned { weight: usize, max_removed: usize },
+
+    #[serde(rename(deserialize = "utilize-spare-vehicle"))]
+    #[serde(rename_all = "camelCase")]
+    UtilizeSpareVehicle { weight: usize, max_jobs: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -485,13 +552,23 @@ fn configure_from_evolution(
                 PopulationType::Rosomaxa {
                     max_elite_size,
                     max_node_size,
+                    node_selection_size,
                     spread_factor,
                     distribution_factor,
                     objective_reshuffling,
+                    objective_emphasis,
                     learning_rate,
                     selection_size,
                     rebalance_memory,
                     exploration_ratio,
+                    max_generations_without_growth,
+                    stagnation_limit,
+                    disable_elite_selection,
+                    max_node_count,
+                    explore_exploit_ratio,
+                    growth_freeze_threshold,
+                    fitness_normalization,
+                    clamp_inputs,
                 } => {
                     let mut config = RosomaxaConfig::new_with_defaults(default_selection_size);
                     if let Some(selection_size) = selection_size {
@@ -503,6 +580,9 @@ fn configure_from_evolution(
                     if let Some(max_node_size) = max_node_size {
                         config.node_size = *max_node_size;
                     }
+                    if let Some(node_selection_size) = node_selection_size {
+                        config.node_selection_size = Some(*node_selection_size);
+                    }
                     if let Some(spread_factor) = spread_factor {
                         config.spread_factor = *spread_factor;
                     }
@@ -512,6 +592,9 @@ fn configure_from_evolution(
                     if let Some(objective_reshuffling) = objective_reshuffling {
                         config.objective_reshuffling = *objective_reshuffling;
                     }
+                    if let Some(objective_emphasis) = objective_emphasis {
+                        config.objective_emphasis = *objective_emphasis;
+                    }
                     if let Some(learning_rate) = learning_rate {
                         config.learning_rate = *learning_rate;
                     }
@@ -521,6 +604,30 @@ fn configure_from_evolution(
                     if let Some(exploration_ratio) = exploration_ratio {
                         config.exploration_ratio = *exploration_ratio;
                     }
+                    if let Some(max_generations_without_growth) = max_generations_without_growth {
+                        config.max_generations_without_growth = Some(*max_generations_without_growth);
+                    }
+                    if let Some(stagnation_limit) = stagnation_limit {
+                        config.stagnation_limit = Some(*stagnation_limit);
+                    }
+                    if let Some(disable_elite_selection) = disable_elite_selection {
+                        config.disable_elite_selection = *disable_elite_selection;
+                    }
+                    if let Some(max_node_count) = max_node_count {
+                        config.max_node_count = Some(*max_node_count);
+                    }
+                    if let Some(explore_exploit_ratio) = explore_exploit_ratio {
+                        config.explore_exploit_ratio = Some(*explore_exploit_ratio);
+                    }
+                    if let Some(growth_freeze_threshold) = growth_freeze_threshold {
+                        config.growth_freeze_threshold = Some(*growth_freeze_threshold);
+                    }
+                    if let Some(fitness_normalization) = fitness_normalization {
+                        config.fitness_normalization = *fitness_normalization;
+                    }
+                    if let Some(clamp_inputs) = clamp_inputs {
+                        config.clamp_inputs = *clamp_inputs;
+                    }
 
                     Box::new(RosomaxaPopulation::new(problem.objective.clone(), environment.clone(), config)?)
                 }
@@ -611,17 +718,24 @@ fn create_operator(
     operator: &SearchOperatorType,
 ) -> Result<(TargetSearchOperator, TargetHeuristicProbability), String> {
     Ok(match operator {
-        SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
+        SearchOperatorType::RuinRecreate { probability, ruins, recreates, adaptive } => {
+            let adaptive_size = adaptive.as_ref().map(create_adaptive_ruin_size).transpose()?;
             let ruin = Arc::new(WeightedRuin::new(
-                ruins.iter().map(|g| create_ruin_group(&problem, environment.clone(), g)).collect(),
+                ruins
+                    .iter()
+                    .map(|g| create_ruin_group(&problem, environment.clone(), g, adaptive_size.as_ref()))
+                    .collect(),
             ));
             let recreate = Arc::new(WeightedRecreate::new(
                 recreates.iter().map(|r| create_recreate_method(r, environment.clone())).collect(),
             ));
-            (
-                Arc::new(RuinAndRecreate::new(ruin, recreate)),
-                create_operator_probability(probability, environment.random.clone()),
-            )
+            let ruin_recreate = RuinAndRecreate::new(ruin, recreate);
+            let ruin_recreate = match adaptive_size {
+                Some(adaptive_size) => ruin_recreate.with_adaptive_acceptance(adaptive_size),
+                None => ruin_recreate,
+            };
+
+            (Arc::new(ruin_recreate), create_operator_probability(probability, environment.random.clone()))
         }
         SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
             let operator = create_local_search(times, inners, environment.random.clone());
@@ -666,37 +780,83 @@ fn create_operator_probability(
     }
 }
 
-fn create_ruin_group(problem: &Arc<Problem>, environment: Arc<Environment>, group: &RuinGroupConfig) -> RuinGroup {
-    (group.methods.iter().map(|r| create_ruin_method(problem, environment.clone(), r)).collect(), group.weight)
+fn create_adaptive_ruin_size(config: &AdaptiveRuinConfig) -> Result<AdaptiveRuinSize, String> {
+    let AdaptiveRuinConfig { max_stagnation, max_scale, min_acceptance, max_acceptance } = config.clone();
+
+    if max_stagnation < 1 {
+        return Err(format!("max stagnation must be greater than 0. Specified: {}", max_stagnation));
+    }
+    if max_scale.is_nan() || max_scale < 1. {
+        return Err(format!("max scale must be not less than 1. Specified: {}", max_scale));
+    }
+    if !(0. ..=max_acceptance).contains(&min_acceptance) || !(0. ..=1.).contains(&max_acceptance) {
+        return Err(format!(
+            "acceptance must be in [0, 1] range with min not greater than max. Specified: {}, {}",
+            min_acceptance, max_acceptance
+        ));
+    }
+
+    Ok(AdaptiveRuinSize::new(max_stagnation, max_scale, (min_acceptance, max_acceptance)))
+}
+
+fn create_ruin_group(
+    problem: &Arc<Problem>,
+    environment: Arc<Environment>,
+    group: &RuinGroupConfig,
+    adaptive_size: Option<&AdaptiveRuinSize>,
+) -> RuinGroup {
+    (
+        group.methods.iter().map(|r| create_ruin_method(problem, environment.clone(), r, adaptive_size)).collect(),
+        group.weight,
+    )
 }
 
 fn create_ruin_method(
     problem: &Arc<Problem>,
     environment: Arc<Environment>,
     method: &RuinMethod,
+    adaptive_size: Option<&AdaptiveRuinSize>,
 ) -> (Arc<dyn Ruin + Send + Sync>, f64) {
+    let create_limits = |min: usize, max: usize, threshold: f64| {
+        let limits = RuinLimits::new(min, max, threshold, 8);
+        match adaptive_size {
+            Some(adaptive_size) => limits.with_adaptive_size(adaptive_size.clone()),
+            None => limits,
+        }
+    };
+
     match method {
         RuinMethod::AdjustedString { probability, lmax, cavg, alpha } => {
             (Arc::new(AdjustedStringRemoval::new(*lmax, *cavg, *alpha)), *probability)
         }
         RuinMethod::Neighbour { probability, min, max, threshold } => {
-            (Arc::new(NeighbourRemoval::new(RuinLimits::new(*min, *max, *threshold, 8))), *probability)
+            (Arc::new(NeighbourRemoval::new(create_limits(*min, *max, *threshold))), *probability)
+        }
+        RuinMethod::Related { probability, min, max, threshold, weights } => {
+            let weights = RelatednessWeights { distance: weights.distance, time: weights.time, demand: weights.demand };
+            let limits = create_limits(*min, *max, *threshold);
+            let ruin: Arc<dyn Ruin + Send + Sync> = if has_multi_dim_demand(problem.as_ref()) {
+                Arc::new(RelatedJobRemoval::<MultiDimLoad>::new(weights, limits))
+            } else {
+                Arc::new(RelatedJobRemoval::<SingleDimLoad>::new(weights, limits))
+            };
+            (ruin, *probability)
         }
         RuinMethod::RandomJob { probability, min, max, threshold } => {
-            (Arc::new(RandomJobRemoval::new(RuinLimits::new(*min, *max, *threshold, 8))), *probability)
+            (Arc::new(RandomJobRemoval::new(create_limits(*min, *max, *threshold))), *probability)
         }
         RuinMethod::RandomRoute { probability, min, max, threshold } => {
             (Arc::new(RandomRouteRemoval::new(*min, *max, *threshold)), *probability)
         }
         RuinMethod::WorstJob { probability, min, max, threshold, skip: worst_skip } => {
-            (Arc::new(WorstJobRemoval::new(*worst_skip, RuinLimits::new(*min, *max, *threshold, 8))), *probability)
+            (Arc::new(WorstJobRemoval::new(*worst_skip, create_limits(*min, *max, *threshold))), *probability)
         }
         RuinMethod::Cluster { probability, min, max, threshold, min_items } => (
             Arc::new(ClusterRemoval::new(
                 problem.clone(),
                 environment,
                 *min_items,
-                RuinLimits::new(*min, *max, *threshold, 8),
+                create_limits(*min, *max, *threshold),
             )),
             *probability,
         ),
@@ -713,18 +873,41 @@ fn create_local_search(
 ) -> Arc<dyn LocalOperator + Send + Sync> {
     let operators = inners
         .iter()
-        .map::<(Arc<dyn LocalOperator + Send + Sync>, usize), _>(|op| match op {
-            LocalOperatorType::SwapStar { weight } => (Arc::new(ExchangeSwapStar::new(random.clone())), *weight),
-            LocalOperatorType::InterRouteBest { weight, noise } => {
-                (Arc::new(ExchangeInterRouteBest::new(noise.probability, noise.min, noise.max)), *weight)
+        .map::<(&str, Arc<dyn LocalOperator + Send + Sync>, usize), _>(|op| match op {
+            LocalOperatorType::SwapStar { weight } => {
+                ("swap_star", Arc::new(ExchangeSwapStar::new(random.clone())), *weight)
+            }
+            LocalOperatorType::InterRouteBest { weight, noise } => (
+                "exchange_inter_route_best",
+                Arc::new(ExchangeInterRouteBest::new(noise.probability, noise.min, noise.max)),
+                *weight,
+            ),
+            LocalOperatorType::InterRouteRandom { weight, noise } => (
+                "exchange_inter_route_random",
+                Arc::new(ExchangeInterRouteRandom::new(noise.probability, noise.min, noise.max)),
+                *weight,
+            ),
+            LocalOperatorType::IntraRouteRandom { weight, noise } => (
+                "exchange_intra_route_random",
+                Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)),
+                *weight,
+            ),
+            LocalOperatorType::Sequence { weight } => {
+                ("exchange_sequence", Arc::new(ExchangeSequence::default()), *weight)
             }
-            LocalOperatorType::InterRouteRandom { weight, noise } => {
-                (Arc::new(ExchangeInterRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
+            LocalOperatorType::RelocateJob { weight } => ("relocate_job", Arc::new(RelocateJob::default()), *weight),
+            LocalOperatorType::ReverseSegment { weight, max_segment_size } => {
+                ("reverse_segment", Arc::new(ReverseSegment::new(*max_segment_size)), *weight)
             }
-            LocalOperatorType::IntraRouteRandom { weight, noise } => {
-                (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
+            LocalOperatorType::RedistributeUnassigned { weight, max_removed } => {
+                ("redistribute_unassigned", Arc::new(RedistributeUnassigned::new(*max_removed)), *weight)
             }
-            LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::UtilizeSpareVehicle { weight, max_jobs } => {
+                ("utilize_spare_vehicle", Arc::new(UtilizeSpareVehicle::new(*max_jobs)), *weight)
+            }
+        })
+        .map::<(Arc<dyn LocalOperator + Send + Sync>, usize), _>(|(name, operator, weight)| {
+            (Arc::new(CountedLocalOperator::new(name, operator)), weight)
         })
         .collect::<Vec<_>>();
 
@@ -35,22 +35,42 @@ fn can_read_full_config() {
             selection_size,
             max_elite_size,
             max_node_size,
+            node_selection_size,
             spread_factor,
             distribution_factor,
             objective_reshuffling,
+            objective_emphasis,
             learning_rate,
             rebalance_memory,
             exploration_ratio,
+            max_generations_without_growth,
+            stagnation_limit,
+            disable_elite_selection,
+            max_node_count,
+            explore_exploit_ratio,
+            growth_freeze_threshold,
+            fitness_normalization,
+            clamp_inputs,
         } => {
             assert_eq!(selection_size, Some(8));
             assert_eq!(max_elite_size, Some(2));
             assert_eq!(max_node_size, Some(2));
+            assert_eq!(node_selection_size, None);
             assert_eq!(spread_factor, Some(0.75));
             assert_eq!(distribution_factor, Some(0.75));
             assert_eq!(objective_reshuffling, Some(0.01));
+            assert_eq!(objective_emphasis, Some(false));
             assert_eq!(learning_rate, Some(0.1));
             assert_eq!(rebalance_memory, Some(100));
             assert_eq!(exploration_ratio, Some(0.9));
+            assert_eq!(max_generations_without_growth, None);
+            assert_eq!(stagnation_limit, None);
+            assert_eq!(disable_elite_selection, Some(false));
+            assert_eq!(max_node_count, None);
+            assert_eq!(explore_exploit_ratio, None);
+            assert_eq!(growth_freeze_threshold, None);
+            assert_eq!(fitness_normalization, None);
+            assert_eq!(clamp_inputs, None);
         }
         _ => unreachable!(),
     }
@@ -87,10 +107,13 @@ fn can_read_full_config() {
             }
 
             match operators.get(2).unwrap() {
-                SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
+                SearchOperatorType::RuinRecreate { probability, ruins, recreates, adaptive } => {
                     assert_eq!(as_scalar_probability(probability), 1.);
-                    assert_eq!(ruins.len(), 7);
+                    assert_eq!(ruins.len(), 8);
                     assert_eq!(recreates.len(), 12);
+                    let adaptive = adaptive.as_ref().expect("no adaptive ruin config");
+                    assert_eq!(adaptive.max_stagnation, 200);
+                    assert_eq!(adaptive.max_acceptance, 1.);
                 }
                 _ => unreachable!(),
             }
@@ -99,7 +122,7 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 7);
                 }
                 _ => unreachable!(),
             }
@@ -157,6 +180,28 @@ fn can_configure_telemetry_metrics() {
     assert_eq!(metrics.evolution.len(), 10 + 1);
 }
 
+parameterized_test! {can_validate_adaptive_ruin_config, (max_stagnation, max_scale, acceptance, is_ok), {
+    can_validate_adaptive_ruin_config_impl(max_stagnation, max_scale, acceptance, is_ok);
+}}
+
+can_validate_adaptive_ruin_config! {
+    case01_valid: (100, 2., (0.5, 1.), true),
+    case02_zero_stagnation: (0, 2., (0.5, 1.), false),
+    case03_small_scale: (100, 0.5, (0.5, 1.), false),
+    case04_nan_scale: (100, f64::NAN, (0.5, 1.), false),
+    case05_inverted_acceptance: (100, 2., (1., 0.5), false),
+    case06_large_acceptance: (100, 2., (0.5, 2.), false),
+}
+
+fn can_validate_adaptive_ruin_config_impl(max_stagnation: usize, max_scale: f64, acceptance: (f64, f64), is_ok: bool) {
+    let config =
+        AdaptiveRuinConfig { max_stagnation, max_scale, min_acceptance: acceptance.0, max_acceptance: acceptance.1 };
+
+    let result = create_adaptive_ruin_size(&config);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
     match probability {
         OperatorProbabilityType::Scalar { scalar } => *scalar,
@@ -20,6 +20,11 @@ pub fn create_job_clusters(
     min_points: Option<usize>,
     epsilon: Option<f64>,
 ) -> Vec<Vec<Job>> {
+    // NOTE there are no profiles to estimate distances when fleet is empty
+    if problem.fleet.profiles.is_empty() {
+        return vec![];
+    }
+
     let min_points = min_points.unwrap_or(3).max(2);
     let epsilon = epsilon.unwrap_or_else(|| estimate_epsilon(problem, min_points));
 
@@ -9,10 +9,25 @@ use crate::models::common::*;
 use crate::models::problem::{Job, Single};
 use crate::models::solution::Activity;
 use std::iter::once;
+use std::marker::PhantomData;
 use std::slice::Iter;
 use std::sync::Arc;
 
+/// Specifies how capacity limit of a load dimension is treated.
+#[derive(Clone, Debug)]
+pub enum CapacityMode {
+    /// Capacity cannot be exceeded.
+    Hard,
+    /// Capacity can be exceeded with given penalty per each unit of excessive load.
+    Soft {
+        /// A penalty per unit of excessive load.
+        penalty: f64,
+    },
+}
+
 /// A module which ensures vehicle capacity limitation while serving customer's demand.
+/// Deliveries and pickups (e.g. returns) can be mixed in one tour: the load is checked at every
+/// point of the tour, so the order of activities matters for feasibility.
 pub struct CapacityConstraintModule<T: LoadOps> {
     code: i32,
     state_keys: Vec<i32>,
@@ -29,6 +44,41 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
 
     /// Creates a new instance of `CapacityConstraintModule` with multi trip (reload) functionality
     pub fn new_with_multi_trip(code: i32, multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>) -> Self {
+        Self::new_with_capacity_modes(code, multi_trip, vec![])
+    }
+
+    /// Creates a new instance of `CapacityConstraintModule` with multi trip (reload) functionality
+    /// and capacity modes specified per load dimension. Dimensions without mode are hard.
+    /// Use `CapacityOverflow` objective to account penalties of soft dimensions in solution fitness.
+    pub fn new_with_capacity_modes(
+        code: i32,
+        multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+        modes: Vec<CapacityMode>,
+    ) -> Self {
+        let penalties = get_soft_penalties(modes.as_slice());
+        let soft_dimens = Arc::new(penalties.iter().map(|(idx, _)| *idx).collect::<Vec<_>>());
+
+        let mut constraints = vec![
+            ConstraintVariant::SoftRoute(Arc::new(CapacitySoftRouteConstraint { multi_trip: multi_trip.clone() })),
+            ConstraintVariant::HardRoute(Arc::new(CapacityHardRouteConstraint::<T> {
+                code,
+                soft_dimens: soft_dimens.clone(),
+                multi_trip: multi_trip.clone(),
+            })),
+            ConstraintVariant::HardActivity(Arc::new(CapacityHardActivityConstraint::<T> {
+                code,
+                soft_dimens,
+                multi_trip: multi_trip.clone(),
+            })),
+        ];
+
+        if !penalties.is_empty() {
+            constraints.push(ConstraintVariant::SoftActivity(Arc::new(CapacitySoftActivityConstraint::<T> {
+                penalties,
+                phantom: PhantomData,
+            })));
+        }
+
         Self {
             code,
             state_keys: vec![CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY],
@@ -44,17 +94,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
                     move |_, _, job| multi_trip.is_marker_job(job)
                 },
             })),
-            constraints: vec![
-                ConstraintVariant::SoftRoute(Arc::new(CapacitySoftRouteConstraint { multi_trip: multi_trip.clone() })),
-                ConstraintVariant::HardRoute(Arc::new(CapacityHardRouteConstraint::<T> {
-                    code,
-                    multi_trip: multi_trip.clone(),
-                })),
-                ConstraintVariant::HardActivity(Arc::new(CapacityHardActivityConstraint::<T> {
-                    code,
-                    multi_trip: multi_trip.clone(),
-                })),
-            ],
+            constraints,
             multi_trip,
         }
     }
@@ -166,17 +206,13 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
     fn can_handle_demand_on_intervals(
         ctx: &RouteContext,
         multi_trip: &(dyn MultiTrip<Constraint = T> + Send + Sync),
+        soft_dimens: &[usize],
         demand: Option<&Demand<T>>,
         insert_idx: Option<usize>,
     ) -> bool {
+        let capacity = Self::get_hard_capacity(ctx, soft_dimens);
         let has_demand_violation = |activity: &Activity| {
-            CapacityConstraintModule::<T>::has_demand_violation(
-                &ctx.state,
-                activity,
-                ctx.route.actor.vehicle.dimens.get_capacity(),
-                demand,
-                true,
-            )
+            CapacityConstraintModule::<T>::has_demand_violation(&ctx.state, activity, capacity.as_ref(), demand, true)
         };
 
         multi_trip
@@ -198,6 +234,13 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
     fn get_demand(activity: &Activity) -> Option<&Demand<T>> {
         activity.job.as_ref().and_then(|job| job.dimens.get_demand())
     }
+
+    /// Returns vehicle capacity which limits only hard dimensions.
+    fn get_hard_capacity(ctx: &RouteContext, soft_dimens: &[usize]) -> Option<T> {
+        let capacity: Option<&T> = ctx.route.actor.vehicle.dimens.get_capacity();
+
+        capacity.map(|capacity| if soft_dimens.is_empty() { *capacity } else { capacity.unbounded(soft_dimens) })
+    }
 }
 
 impl<T: LoadOps> ConstraintModule for CapacityConstraintModule<T> {
@@ -270,9 +313,67 @@ impl<T: LoadOps> SoftRouteConstraint for CapacitySoftRouteConstraint<T> {
     }
 }
 
+/// Penalizes excessive load of soft capacity dimensions.
+struct CapacitySoftActivityConstraint<T: LoadOps> {
+    penalties: Vec<(usize, f64)>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> CapacitySoftActivityConstraint<T> {
+    fn get_penalty(&self, capacity: &T, before: T, change: T) -> Cost {
+        let after = before + change;
+
+        self.penalties
+            .iter()
+            .map(|&(idx, penalty)| {
+                let excess = |load: &T| (load.dimension(idx) - capacity.dimension(idx)).max(0) as f64;
+                (excess(&after) - excess(&before)).max(0.) * penalty
+            })
+            .sum()
+    }
+}
+
+impl<T: LoadOps> SoftActivityConstraint for CapacitySoftActivityConstraint<T> {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let capacity: Option<&T> = route_ctx.route.actor.vehicle.dimens.get_capacity();
+        let demand = CapacityConstraintModule::<T>::get_demand(activity_ctx.target);
+
+        let (capacity, demand) = match (capacity, demand) {
+            (Some(capacity), Some(demand)) => (capacity, demand),
+            _ => return 0.,
+        };
+
+        let default = T::default();
+        let state = &route_ctx.state;
+        let pivot = activity_ctx.prev;
+        let past = *state.get_activity_state(MAX_PAST_CAPACITY_KEY, pivot).unwrap_or(&default);
+        let future = *state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot).unwrap_or(&default);
+
+        // NOTE mirror checks done by hard activity constraint for hard dimensions
+        let static_delivery = self.get_penalty(capacity, past, demand.delivery.0);
+        let static_pickup = self.get_penalty(capacity, future, demand.pickup.0);
+        let change = self.get_penalty(capacity, future, demand.change());
+
+        static_delivery + static_pickup + change
+    }
+}
+
+/// Returns penalties of soft capacity dimensions as _(dimension index, penalty)_.
+pub(crate) fn get_soft_penalties(modes: &[CapacityMode]) -> Vec<(usize, f64)> {
+    modes
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, mode)| match mode {
+            CapacityMode::Hard => None,
+            CapacityMode::Soft { penalty } => Some((idx, *penalty)),
+        })
+        .collect()
+}
+
 /// Locks reload jobs to specific vehicles
 struct CapacityHardRouteConstraint<T: LoadOps> {
     code: i32,
+    soft_dimens: Arc<Vec<usize>>,
     multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
 }
 
@@ -290,6 +391,7 @@ impl<T: LoadOps> HardRouteConstraint for CapacityHardRouteConstraint<T> {
             Job::Single(job) => CapacityConstraintModule::<T>::can_handle_demand_on_intervals(
                 ctx,
                 self.multi_trip.as_ref(),
+                self.soft_dimens.as_slice(),
                 job.dimens.get_demand(),
                 None,
             ),
@@ -297,6 +399,7 @@ impl<T: LoadOps> HardRouteConstraint for CapacityHardRouteConstraint<T> {
                 CapacityConstraintModule::<T>::can_handle_demand_on_intervals(
                     ctx,
                     self.multi_trip.as_ref(),
+                    self.soft_dimens.as_slice(),
                     job.dimens.get_demand(),
                     None,
                 )
@@ -313,6 +416,7 @@ impl<T: LoadOps> HardRouteConstraint for CapacityHardRouteConstraint<T> {
 
 struct CapacityHardActivityConstraint<T: LoadOps> {
     code: i32,
+    soft_dimens: Arc<Vec<usize>>,
     multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
 }
 
@@ -346,6 +450,7 @@ impl<T: LoadOps> HardActivityConstraint for CapacityHardActivityConstraint<T> {
             if CapacityConstraintModule::<T>::can_handle_demand_on_intervals(
                 route_ctx,
                 self.multi_trip.as_ref(),
+                self.soft_dimens.as_slice(),
                 demand,
                 Some(activity_ctx.index),
             ) {
@@ -357,7 +462,7 @@ impl<T: LoadOps> HardActivityConstraint for CapacityHardActivityConstraint<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &route_ctx.state,
                 activity_ctx.prev,
-                route_ctx.route.actor.vehicle.dimens.get_capacity(),
+                CapacityConstraintModule::<T>::get_hard_capacity(route_ctx, self.soft_dimens.as_slice()).as_ref(),
                 demand,
                 !self.multi_trip.has_markers(route_ctx),
             )
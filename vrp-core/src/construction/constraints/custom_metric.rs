@@ -0,0 +1,188 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/custom_metric_test.rs"]
+mod custom_metric_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::*;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns a metric contribution of a single leg traveled by the actor with
+/// given distance and duration, e.g. CO2 emissions of the vehicle.
+pub type LegMetricFn = Arc<dyn Fn(&Actor, Distance, Duration) -> f64 + Send + Sync>;
+
+/// A function which returns a limit of the route metric for given actor.
+pub type MetricLimitFn = Arc<dyn Fn(&Actor) -> Option<f64> + Send + Sync>;
+
+/// A module which accumulates a custom metric along the route as a sum of leg contributions and
+/// keeps its total in the route state under given key. Optionally, the total value can be limited
+/// per actor. Use `get_route_metric` to read the metric value of the route.
+pub struct CustomMetricModule {
+    state_key: i32,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    metric: LegMetric,
+}
+
+impl CustomMetricModule {
+    /// Creates a new instance of `CustomMetricModule`.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        leg_fn: LegMetricFn,
+        limit_fn: MetricLimitFn,
+        state_key: i32,
+        code: i32,
+    ) -> Self {
+        Self::new_with_constraints(LegMetric { transport, leg_fn }, limit_fn, state_key, code, false)
+    }
+
+    /// Creates a new instance of `CustomMetricModule` which also estimates metric change as a soft
+    /// constraint, so insertion heuristics prefer placements with lower metric values.
+    pub(crate) fn new_minimized(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        leg_fn: LegMetricFn,
+        limit_fn: MetricLimitFn,
+        state_key: i32,
+        code: i32,
+    ) -> Self {
+        Self::new_with_constraints(LegMetric { transport, leg_fn }, limit_fn, state_key, code, true)
+    }
+
+    fn new_with_constraints(
+        metric: LegMetric,
+        limit_fn: MetricLimitFn,
+        state_key: i32,
+        code: i32,
+        is_minimized: bool,
+    ) -> Self {
+        let hard = ConstraintVariant::HardActivity(Arc::new(CustomMetricHardActivityConstraint {
+            code,
+            state_key,
+            limit_fn,
+            metric: metric.clone(),
+        }));
+        let soft =
+            ConstraintVariant::SoftActivity(Arc::new(CustomMetricSoftActivityConstraint { metric: metric.clone() }));
+
+        Self {
+            state_key,
+            state_keys: vec![state_key],
+            constraints: if is_minimized { vec![hard, soft] } else { vec![hard] },
+            metric,
+        }
+    }
+}
+
+impl ConstraintModule for CustomMetricModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let total = route_ctx.route.tour.legs().fold(0., |acc, (activities, _)| match activities {
+            [from, to] => acc + self.metric.estimate(route_ctx.route.as_ref(), from, to, from.schedule.departure),
+            _ => acc,
+        });
+
+        route_ctx.state_mut().put_route_state(self.state_key, total);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Returns a value of the custom metric accumulated for the route under given state key.
+pub fn get_route_metric(route_ctx: &RouteContext, state_key: i32) -> f64 {
+    route_ctx.state.get_route_state::<f64>(state_key).cloned().unwrap_or(0.)
+}
+
+#[derive(Clone)]
+struct LegMetric {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    leg_fn: LegMetricFn,
+}
+
+impl LegMetric {
+    /// Estimates metric of the leg between two activities.
+    fn estimate(&self, route: &Route, from: &Activity, to: &Activity, departure: Timestamp) -> f64 {
+        let (from, to, travel_time) = (from.place.location, to.place.location, TravelTime::Departure(departure));
+        let distance = self.transport.distance(route, from, to, travel_time);
+        let duration = self.transport.duration(route, from, to, travel_time);
+
+        self.leg_fn.deref()(route.actor.as_ref(), distance, duration)
+    }
+
+    /// Estimates metric change caused by insertion of the target activity.
+    fn estimate_change(&self, route: &Route, activity_ctx: &ActivityContext) -> f64 {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let prev_to_target = self.estimate(route, prev, target, prev.schedule.departure);
+
+        match activity_ctx.next {
+            Some(next) => {
+                let target_arrival = prev.schedule.departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(prev.schedule.departure),
+                    );
+                let target_departure = target_arrival.max(target.place.time.start) + target.place.duration;
+
+                prev_to_target + self.estimate(route, target, next, target_departure)
+                    - self.estimate(route, prev, next, prev.schedule.departure)
+            }
+            None => prev_to_target,
+        }
+    }
+}
+
+struct CustomMetricHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    limit_fn: MetricLimitFn,
+    metric: LegMetric,
+}
+
+impl HardActivityConstraint for CustomMetricHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let limit = self.limit_fn.deref()(route_ctx.route.actor.as_ref())?;
+
+        let total = get_route_metric(route_ctx, self.state_key)
+            + self.metric.estimate_change(route_ctx.route.as_ref(), activity_ctx);
+
+        if total > limit {
+            stop(self.code)
+        } else {
+            success()
+        }
+    }
+}
+
+struct CustomMetricSoftActivityConstraint {
+    metric: LegMetric,
+}
+
+impl SoftActivityConstraint for CustomMetricSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        self.metric.estimate_change(route_ctx.route.as_ref(), activity_ctx)
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/max_waiting_test.rs"]
+mod max_waiting_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::*;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+type LimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+
+/// A module which limits waiting time of actor's route: total waiting time in the tour and/or
+/// waiting time at a single stop. It relies on waiting state maintained by `TransportConstraintModule`.
+pub struct MaxWaitingModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for MaxWaitingModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+impl MaxWaitingModule {
+    /// Creates a new instance of `MaxWaitingModule`.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        total_waiting_limit: LimitFn<Duration>,
+        stop_waiting_limit: LimitFn<Duration>,
+        code: i32,
+    ) -> Self {
+        Self {
+            state_keys: Vec::default(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(MaxWaitingHardActivityConstraint {
+                code,
+                transport,
+                total_waiting_limit,
+                stop_waiting_limit,
+            }))],
+        }
+    }
+}
+
+struct MaxWaitingHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    total_waiting_limit: LimitFn<Duration>,
+    stop_waiting_limit: LimitFn<Duration>,
+}
+
+impl HardActivityConstraint for MaxWaitingHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let actor = route_ctx.route.actor.as_ref();
+        let total_waiting_limit = self.total_waiting_limit.deref()(actor);
+        let stop_waiting_limit = self.stop_waiting_limit.deref()(actor);
+
+        if total_waiting_limit.is_none() && stop_waiting_limit.is_none() {
+            return success();
+        }
+
+        let route = route_ctx.route.as_ref();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let tar_arr = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let tar_wait = (target.place.time.start - tar_arr).max(0.);
+        let tar_dep = tar_arr + tar_wait + target.place.duration;
+
+        // NOTE waiting time at the tour end is not considered
+        let (next_wait, future_waiting, new_future_waiting) = match activity_ctx.next {
+            Some(next) if next.job.is_some() => {
+                let next_arr = tar_dep
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(tar_dep),
+                    );
+                let next_wait = (next.place.time.start - next_arr).max(0.);
+                let future_waiting = get_waiting(route_ctx, next);
+
+                // NOTE a delay at the next activity is absorbed by waiting times of the rest of the tour,
+                // for earlier arrival only waiting time of the next activity is adjusted
+                let new_future_waiting = match next_arr - next.schedule.arrival {
+                    delay if delay >= 0. => (future_waiting - delay).max(0.),
+                    _ => future_waiting - (next.place.time.start - next.schedule.arrival).max(0.) + next_wait,
+                };
+
+                (next_wait, future_waiting, new_future_waiting)
+            }
+            _ => (0., 0., 0.),
+        };
+
+        if let Some(stop_waiting_limit) = stop_waiting_limit {
+            if tar_wait > stop_waiting_limit || next_wait > stop_waiting_limit {
+                return stop(self.code);
+            }
+        }
+
+        if let Some(total_waiting_limit) = total_waiting_limit {
+            let total_waiting = route
+                .tour
+                .all_activities()
+                .find(|activity| activity.job.is_some())
+                .map_or(0., |first| get_waiting(route_ctx, first));
+
+            let new_total_waiting = total_waiting - future_waiting + tar_wait + new_future_waiting;
+
+            if new_total_waiting > total_waiting_limit {
+                return stop(self.code);
+            }
+        }
+
+        success()
+    }
+}
+
+fn get_waiting(route_ctx: &RouteContext, activity: &Activity) -> Duration {
+    route_ctx.state.get_activity_state::<Duration>(WAITING_KEY, activity).cloned().unwrap_or(0.)
+}
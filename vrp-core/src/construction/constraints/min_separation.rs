@@ -0,0 +1,164 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/min_separation_test.rs"]
+mod min_separation_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{Job, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::compare_floats;
+use std::iter::once;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a minimum time which should elapse between completion of job `a` and service start
+/// of job `b` when both jobs are served by the same route.
+#[derive(Clone)]
+pub struct MinSeparation {
+    /// A job which should be served first.
+    pub a: Job,
+    /// A job which should be served after the first one.
+    pub b: Job,
+    /// A minimum duration between completion of `a` and service start of `b`.
+    pub delta: Duration,
+}
+
+/// Enforces minimum temporal separation between specific jobs served by the same route: if both
+/// jobs of a pair are in the route, the second one cannot be served earlier than the first one
+/// plus a required delta. As vehicle waits only for time window start, such jobs are either spaced
+/// by other activities and time windows, or served by different routes.
+pub struct MinSeparationModule {
+    code: i32,
+    separations: Arc<Vec<MinSeparation>>,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl MinSeparationModule {
+    /// Creates a new instance of `MinSeparationModule`.
+    pub fn new(separations: Vec<MinSeparation>, transport: Arc<dyn TransportCost + Send + Sync>, code: i32) -> Self {
+        let separations = Arc::new(separations);
+
+        Self {
+            code,
+            separations: separations.clone(),
+            state_keys: Vec::default(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(MinSeparationHardActivityConstraint {
+                code,
+                separations,
+                transport,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for MinSeparationModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        let is_separated =
+            |job: &Job| self.separations.iter().any(|separation| &separation.a == job || &separation.b == job);
+
+        if is_separated(&source) || is_separated(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct MinSeparationHardActivityConstraint {
+    code: i32,
+    separations: Arc<Vec<MinSeparation>>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for MinSeparationHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let tour = &route_ctx.route.tour;
+        let is_present = |job: &Job| tour.contains(job) || activity_ctx.target.has_same_job(job);
+
+        let separations = self
+            .separations
+            .iter()
+            .filter(|separation| is_present(&separation.a) && is_present(&separation.b))
+            .collect::<Vec<_>>();
+
+        if separations.is_empty() {
+            return success();
+        }
+
+        // NOTE insertion delays all activities after the target, so schedule is estimated for the whole tour
+        let schedules = get_schedules(self.transport.as_ref(), route_ctx.route.as_ref(), activity_ctx);
+
+        let is_feasible = separations.iter().all(|separation| {
+            let completion = schedules
+                .iter()
+                .filter(|(activity, _, _)| activity.has_same_job(&separation.a))
+                .map(|(_, _, departure)| *departure)
+                .max_by(|a, b| compare_floats(*a, *b));
+
+            let start = schedules
+                .iter()
+                .filter(|(activity, _, _)| activity.has_same_job(&separation.b))
+                .map(|(_, start, _)| *start)
+                .min_by(|a, b| compare_floats(*a, *b));
+
+            match (completion, start) {
+                (Some(completion), Some(start)) => start >= completion + separation.delta,
+                _ => true,
+            }
+        });
+
+        if is_feasible {
+            success()
+        } else {
+            stop(self.code)
+        }
+    }
+}
+
+/// Returns activities of the tour with the target inserted as _(activity, service start, departure)_.
+fn get_schedules<'a>(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &'a Route,
+    activity_ctx: &'a ActivityContext,
+) -> Vec<(&'a Activity, Timestamp, Timestamp)> {
+    let prev = activity_ctx.prev;
+    let before = route.tour.all_activities().take(activity_ctx.index + 1).map(|activity| {
+        (activity, activity.schedule.arrival.max(activity.place.time.start), activity.schedule.departure)
+    });
+
+    let after = once(activity_ctx.target).chain(route.tour.all_activities().skip(activity_ctx.index + 1)).scan(
+        (prev.place.location, prev.schedule.departure),
+        |(location, departure), activity| {
+            let arrival = *departure
+                + transport.duration(route, *location, activity.place.location, TravelTime::Departure(*departure));
+            let start = arrival.max(activity.place.time.start);
+
+            *location = activity.place.location;
+            *departure = start + activity.place.duration;
+
+            Some((activity, start, *departure))
+        },
+    );
+
+    before.chain(after).collect()
+}
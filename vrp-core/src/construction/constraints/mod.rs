@@ -65,6 +65,12 @@ pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
 /// A key which tracks global duration limit.
 pub const LIMIT_DURATION_KEY: i32 = 5;
+/// A key which tracks start times of visits with frequency requirement.
+pub const VISIT_SCHEDULE_KEY: i32 = 6;
+/// A key which tracks accumulated arrival time variance.
+pub const ARRIVAL_VARIANCE_KEY: i32 = 7;
+/// A key which tracks minimum arrival variance slack of the following activities.
+pub const ARRIVAL_VARIANCE_SLACK_KEY: i32 = 8;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -118,3 +124,21 @@ pub use self::fleet_usage::*;
 
 mod travel_limit;
 pub use self::travel_limit::*;
+
+mod max_waiting;
+pub use self::max_waiting::*;
+
+mod transition;
+pub use self::transition::*;
+
+mod visit_frequency;
+pub use self::visit_frequency::*;
+
+mod min_separation;
+pub use self::min_separation::*;
+
+mod custom_metric;
+pub use self::custom_metric::*;
+
+mod travel_variance;
+pub use self::travel_variance::*;
@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/transition_test.rs"]
+mod transition_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::problem::{Job, Single};
+use crate::models::solution::Activity;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns true if actor is not allowed to go directly from the first job to the second one.
+pub type TransitionResolver = Arc<dyn Fn(&Single, &Single) -> bool + Sync + Send>;
+
+/// A module which forbids specific consecutive job activities in the tour, e.g. delivering product B
+/// right after product A. Such transition is still possible if some other activity, e.g. a cleaning
+/// stop, is performed in between.
+pub struct TransitionModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl TransitionModule {
+    /// Creates a new instance of `TransitionModule`.
+    pub fn new(is_forbidden: TransitionResolver, code: i32) -> Self {
+        Self {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TransitionHardActivityConstraint {
+                code,
+                is_forbidden,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for TransitionModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TransitionHardActivityConstraint {
+    code: i32,
+    is_forbidden: TransitionResolver,
+}
+
+impl TransitionHardActivityConstraint {
+    fn is_forbidden(&self, from: &Activity, to: &Activity) -> bool {
+        match (from.job.as_ref(), to.job.as_ref()) {
+            (Some(from), Some(to)) => self.is_forbidden.deref()(from.as_ref(), to.as_ref()),
+            _ => false,
+        }
+    }
+}
+
+impl HardActivityConstraint for TransitionHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        _: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let is_forbidden_prev = self.is_forbidden(activity_ctx.prev, activity_ctx.target);
+        let is_forbidden_next = match activity_ctx.next {
+            Some(next) => self.is_forbidden(activity_ctx.target, next),
+            None => false,
+        };
+
+        if is_forbidden_prev || is_forbidden_next {
+            stop(self.code)
+        } else {
+            success()
+        }
+    }
+}
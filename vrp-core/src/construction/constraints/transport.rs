@@ -316,6 +316,8 @@ struct RouteCostSoftRouteConstraint {}
 
 impl SoftRouteConstraint for RouteCostSoftRouteConstraint {
     fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, _job: &Job) -> f64 {
+        // NOTE fixed cost is charged only when the route transitions from empty to non-empty, so
+        // insertion evaluator prefers already used vehicles over opening new ones
         if ctx.route.tour.job_count() == 0 {
             ctx.route.actor.driver.costs.fixed + ctx.route.actor.vehicle.costs.fixed
         } else {
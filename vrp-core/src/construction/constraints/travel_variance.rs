@@ -0,0 +1,233 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/travel_variance_test.rs"]
+mod travel_variance_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Location, Timestamp};
+use crate::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns travel time variance of the leg between two locations.
+pub type TravelVarianceFn = Arc<dyn Fn(&Route, Location, Location, TravelTime) -> f64 + Send + Sync>;
+
+/// A module which propagates travel time variance through the route schedule and keeps the
+/// accumulated arrival time variance as activity state. By default, it has no constraints, but it
+/// can be created with chance constrained time windows. It relies on schedule maintained by
+/// `TransportConstraintModule`, so it should be added after it.
+///
+/// Leg variances are assumed to be independent and waiting time is not used to absorb uncertainty,
+/// so estimated arrival ranges are conservative.
+pub struct TravelVarianceModule {
+    variance_fn: TravelVarianceFn,
+    z_score: Option<f64>,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl TravelVarianceModule {
+    /// Creates a new instance of `TravelVarianceModule`.
+    pub fn new(variance_fn: TravelVarianceFn) -> Self {
+        Self { variance_fn, z_score: None, state_keys: vec![ARRIVAL_VARIANCE_KEY], constraints: vec![] }
+    }
+
+    /// Creates a new instance of `TravelVarianceModule` with chance constrained time windows: a time
+    /// window is considered as satisfied only if arrival happens before its end with probability
+    /// not less than `confidence`, assuming normally distributed arrival times. Confidence of 0.5
+    /// corresponds to deterministic time windows. Panics if confidence is not in `[0.5, 1)` range.
+    pub fn new_chance_constrained(
+        variance_fn: TravelVarianceFn,
+        confidence: f64,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        assert!((0.5..1.).contains(&confidence), "confidence should be in [0.5, 1) range");
+
+        let z_score = get_z_score(confidence);
+
+        Self {
+            variance_fn: variance_fn.clone(),
+            z_score: Some(z_score),
+            state_keys: vec![ARRIVAL_VARIANCE_KEY, ARRIVAL_VARIANCE_SLACK_KEY],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(ChanceTimeHardActivityConstraint {
+                code,
+                z_score,
+                variance_fn,
+                transport,
+                activity,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for TravelVarianceModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let (route, state) = route_ctx.as_mut();
+
+        let start = match route.tour.start() {
+            Some(start) => start,
+            None => return,
+        };
+        let init = (start.place.location, start.schedule.departure, 0.);
+
+        route.tour.all_activities().skip(1).fold(init, |(location, departure, variance), activity| {
+            let leg_variance =
+                self.variance_fn.deref()(route, location, activity.place.location, TravelTime::Departure(departure));
+            let variance = variance + leg_variance.max(0.);
+
+            state.put_activity_state(ARRIVAL_VARIANCE_KEY, activity, variance);
+
+            (activity.place.location, activity.schedule.departure, variance)
+        });
+
+        if let Some(z_score) = self.z_score {
+            // NOTE slack of an activity is the minimum variance slack of all activities after it
+            route.tour.all_activities().rev().fold(f64::INFINITY, |slack_after, activity| {
+                state.put_activity_state(ARRIVAL_VARIANCE_SLACK_KEY, activity, slack_after);
+
+                let variance = state.get_activity_state::<f64>(ARRIVAL_VARIANCE_KEY, activity).cloned().unwrap_or(0.);
+                let margin = activity.place.time.end - activity.schedule.arrival;
+
+                slack_after.min(get_variance_slack(z_score, margin, variance))
+            });
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks that time windows are satisfied with required confidence when target activity is inserted.
+struct ChanceTimeHardActivityConstraint {
+    code: i32,
+    z_score: f64,
+    variance_fn: TravelVarianceFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for ChanceTimeHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let prev = activity_ctx.prev;
+        let variance = route_ctx.state.get_activity_state::<f64>(ARRIVAL_VARIANCE_KEY, prev).cloned().unwrap_or(0.);
+        let init = (prev.place.location, prev.schedule.departure, variance);
+
+        let mut state = match self.visit(route_ctx, init, activity_ctx.target) {
+            Some(state) => state,
+            None => return stop(self.code),
+        };
+
+        // NOTE simulate schedule of the following activities until it becomes the same as before
+        // insertion, then rely on variance slack as remaining arrival times are not changed
+        for activity in route_ctx.route.tour.all_activities().skip(activity_ctx.index + 1) {
+            let old_variance = route_ctx.state.get_activity_state::<f64>(ARRIVAL_VARIANCE_KEY, activity);
+
+            state = match self.visit(route_ctx, state, activity) {
+                Some(new_state) => new_state,
+                None => return stop(self.code),
+            };
+
+            if state.1 <= activity.schedule.departure {
+                let delta = state.2 - old_variance.cloned().unwrap_or(0.);
+                let slack = route_ctx
+                    .state
+                    .get_activity_state::<f64>(ARRIVAL_VARIANCE_SLACK_KEY, activity)
+                    .cloned()
+                    .unwrap_or(f64::INFINITY);
+
+                return if delta <= slack { success() } else { stop(self.code) };
+            }
+        }
+
+        success()
+    }
+}
+
+impl ChanceTimeHardActivityConstraint {
+    /// Visits activity from the previous location and returns the new state if its time window end
+    /// is met with required confidence.
+    fn visit(
+        &self,
+        route_ctx: &RouteContext,
+        state: (Location, Timestamp, f64),
+        activity: &Activity,
+    ) -> Option<(Location, Timestamp, f64)> {
+        let route = route_ctx.route.as_ref();
+        let (location, departure, variance) = state;
+        let to = activity.place.location;
+
+        let arrival = departure + self.transport.duration(route, location, to, TravelTime::Departure(departure));
+        let variance =
+            variance + self.variance_fn.deref()(route, location, to, TravelTime::Departure(departure)).max(0.);
+
+        if arrival + self.z_score * variance.sqrt() > activity.place.time.end {
+            return None;
+        }
+
+        Some((to, self.activity.estimate_departure(route, activity, arrival), variance))
+    }
+}
+
+/// Returns how much arrival variance can be added to the activity while its time window end is
+/// still met with required confidence.
+fn get_variance_slack(z_score: f64, margin: Timestamp, variance: f64) -> f64 {
+    if margin < 0. {
+        f64::NEG_INFINITY
+    } else if z_score > 0. {
+        (margin / z_score).powi(2) - variance
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Returns z-score for given confidence level using rational approximation of the inverse normal
+/// distribution function (Abramowitz and Stegun, 26.2.23), absolute error is less than 4.5e-4.
+fn get_z_score(confidence: f64) -> f64 {
+    let t = (-2. * (1. - confidence).ln()).sqrt();
+    let z = t
+        - (2.515517 + 0.802853 * t + 0.010328 * t * t) / (1. + 1.432788 * t + 0.189269 * t * t + 0.001308 * t * t * t);
+
+    z.max(0.)
+}
+
+/// Returns arrival time range for each activity of the route as mean arrival plus/minus given
+/// amount of standard deviations, e.g. `z_score` of 1.96 gives 95% confidence interval for
+/// normally distributed travel times. Activities without variance state get `None`.
+pub fn get_arrival_ranges(route_ctx: &RouteContext, z_score: f64) -> Vec<Option<(Timestamp, Timestamp)>> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| {
+            route_ctx.state.get_activity_state::<f64>(ARRIVAL_VARIANCE_KEY, activity).map(|variance| {
+                let delta = z_score * variance.sqrt();
+                let arrival = activity.schedule.arrival;
+
+                (arrival - delta, arrival + delta)
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,254 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/visit_frequency_test.rs"]
+mod visit_frequency_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Location, TimeWindow, Timestamp};
+use crate::models::problem::{Actor, Job, Single, TransportCost, TravelTime, VisitFrequency};
+use crate::models::solution::{Activity, Route};
+use std::iter::once;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A visit schedule: start times of all visits with frequency requirement in the solution
+/// together with actors which serve them.
+type VisitSchedule = Arc<Vec<(Arc<VisitFrequency>, Arc<Actor>, Timestamp)>>;
+
+/// Enforces minimum spacing between start times of visits which belong to the same job.
+/// See `Single::new_visits` to create such visits.
+pub struct VisitFrequencyModule {
+    code: i32,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl VisitFrequencyModule {
+    /// Creates a new instance of `VisitFrequencyModule`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, code: i32) -> Self {
+        Self {
+            code,
+            state_keys: vec![VISIT_SCHEDULE_KEY],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(VisitFrequencyHardRouteConstraint {
+                    code,
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(VisitFrequencyHardActivityConstraint { code, transport })),
+            ],
+        }
+    }
+
+    fn update_schedule(&self, solution_ctx: &mut SolutionContext) {
+        let schedule: VisitSchedule = Arc::new(
+            solution_ctx
+                .routes
+                .iter()
+                .flat_map(|route_ctx| {
+                    let actor = &route_ctx.route.actor;
+                    route_ctx.route.tour.all_activities().filter_map(move |activity| {
+                        activity.job.as_ref().and_then(|job| job.visit_frequency()).map(|frequency| {
+                            (frequency.clone(), actor.clone(), activity.schedule.arrival.max(activity.place.time.start))
+                        })
+                    })
+                })
+                .collect(),
+        );
+
+        let has_visits = !schedule.is_empty();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            if has_visits || route_ctx.state.get_route_state::<VisitSchedule>(VISIT_SCHEDULE_KEY).is_some() {
+                route_ctx.state_mut().put_route_state(VISIT_SCHEDULE_KEY, schedule.clone());
+            }
+        });
+    }
+}
+
+impl ConstraintModule for VisitFrequencyModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        // NOTE any insertion can shift visit start times, so schedule is refreshed for all routes
+        self.update_schedule(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_schedule(solution_ctx);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.as_single().and_then(|s| s.visit_frequency()), candidate.to_single().visit_frequency()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct VisitFrequencyHardRouteConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardRouteConstraint for VisitFrequencyHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let single = job.as_single()?;
+        let frequency = single.visit_frequency()?;
+
+        // NOTE route state is available for routes which are already part of the solution
+        if route_ctx.state.get_route_state::<VisitSchedule>(VISIT_SCHEDULE_KEY).is_some() {
+            return None;
+        }
+
+        let siblings = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+            .filter_map(|activity| {
+                activity
+                    .job
+                    .as_ref()
+                    .and_then(|job| job.visit_frequency())
+                    .filter(|other| Arc::ptr_eq(other, frequency))
+                    .map(|_| activity.schedule.arrival.max(activity.place.time.start))
+            })
+            .collect::<Vec<_>>();
+
+        if siblings.is_empty() {
+            return None;
+        }
+
+        // NOTE for a new route, be conservative and require that all job's options are feasible
+        let start = route_ctx.route.tour.start()?;
+        let is_feasible = get_visit_options(single, start).all(|(location, time)| {
+            let start_time = get_visit_start(self.transport.as_ref(), &route_ctx.route, start, location, &time);
+            siblings.iter().all(|&other| is_spaced(start_time, other, frequency))
+        });
+
+        if is_feasible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct VisitFrequencyHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for VisitFrequencyHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let schedule = route_ctx.state.get_route_state::<VisitSchedule>(VISIT_SCHEDULE_KEY)?;
+
+        // NOTE insertion shifts start times of all visits after it, so they have to be checked too
+        let visits = get_route_visits(self.transport.as_ref(), &route_ctx.route, activity_ctx);
+        let others = schedule
+            .iter()
+            .filter(|(_, actor, _)| !Arc::ptr_eq(actor, &route_ctx.route.actor))
+            .map(|(frequency, _, start)| (frequency, *start))
+            .collect::<Vec<_>>();
+
+        let is_feasible = visits.iter().enumerate().filter(|(_, (_, _, is_shifted))| *is_shifted).all(
+            |(idx, (frequency, start, _))| {
+                let own = visits
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_idx, _)| *other_idx != idx)
+                    .map(|(_, (other, start, _))| (*other, *start));
+
+                others
+                    .iter()
+                    .cloned()
+                    .chain(own)
+                    .filter(|(other, _)| Arc::ptr_eq(other, frequency))
+                    .all(|(_, other)| is_spaced(*start, other, frequency))
+            },
+        );
+
+        if is_feasible {
+            None
+        } else {
+            stop(self.code)
+        }
+    }
+}
+
+fn get_visit_options<'a>(single: &'a Single, start: &'a Activity) -> impl Iterator<Item = (Location, TimeWindow)> + 'a {
+    single.places.iter().flat_map(move |place| {
+        let location = place.location.unwrap_or(start.place.location);
+        place.times.iter().map(move |time| (location, time.to_time_window(start.schedule.departure)))
+    })
+}
+
+fn get_visit_start(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    prev: &Activity,
+    location: Location,
+    time: &TimeWindow,
+) -> Timestamp {
+    let departure = prev.schedule.departure;
+    let arrival =
+        departure + transport.duration(route, prev.place.location, location, TravelTime::Departure(departure));
+
+    arrival.max(time.start)
+}
+
+/// Returns visits with frequency requirement of the tour with the target inserted as
+/// _(frequency, service start, is shifted)_. Visits starting from the target are shifted.
+fn get_route_visits<'a>(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &'a Route,
+    activity_ctx: &'a ActivityContext,
+) -> Vec<(&'a Arc<VisitFrequency>, Timestamp, bool)> {
+    let prev = activity_ctx.prev;
+    let before = route
+        .tour
+        .all_activities()
+        .take(activity_ctx.index + 1)
+        .map(|activity| (activity, activity.schedule.arrival.max(activity.place.time.start), false));
+
+    let after = once(activity_ctx.target).chain(route.tour.all_activities().skip(activity_ctx.index + 1)).scan(
+        (prev.place.location, prev.schedule.departure),
+        |(location, departure), activity| {
+            let arrival = *departure
+                + transport.duration(route, *location, activity.place.location, TravelTime::Departure(*departure));
+            let start = arrival.max(activity.place.time.start);
+
+            *location = activity.place.location;
+            *departure = start + activity.place.duration;
+
+            Some((activity, start, true))
+        },
+    );
+
+    before
+        .chain(after)
+        .filter_map(|(activity, start, is_shifted)| {
+            activity.job.as_ref().and_then(|job| job.visit_frequency()).map(|frequency| (frequency, start, is_shifted))
+        })
+        .collect()
+}
+
+fn is_spaced(start_time: Timestamp, other: Timestamp, frequency: &VisitFrequency) -> bool {
+    (start_time - other).abs() >= frequency.min_spacing
+}
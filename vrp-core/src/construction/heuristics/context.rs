@@ -4,7 +4,8 @@ mod context_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::factories::*;
-use crate::models::common::Cost;
+use crate::construction::heuristics::replay_route_on_actor;
+use crate::models::common::{Cost, IdDimension, MultiDimLoad, SingleDimLoad, Timestamp};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Extras, Problem, Solution};
@@ -15,8 +16,9 @@ use rosomaxa::prelude::*;
 use rustc_hash::FxHasher;
 use std::any::Any;
 use std::hash::BuildHasherDefault;
-use std::ops::Deref;
-use std::sync::Arc;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 
 /// A context which contains information needed for heuristic and metaheuristic.
 pub struct InsertionContext {
@@ -53,6 +55,17 @@ impl InsertionContext {
         ctx
     }
 
+    /// Creates a copy of insertion context which shares routes with the original one: a route is
+    /// copied only once it is modified using one of `RouteContext` mutable methods. This makes
+    /// copying cheap when only few routes are changed afterwards.
+    pub fn cow_copy(&self) -> Self {
+        InsertionContext {
+            problem: self.problem.clone(),
+            solution: self.solution.cow_copy(),
+            environment: self.environment.clone(),
+        }
+    }
+
     /// Restores valid context state.
     pub fn restore(&mut self) {
         let constraint = self.problem.constraint.clone();
@@ -68,6 +81,55 @@ impl InsertionContext {
         });
     }
 
+    /// Revalidates solution against given problem which has the same jobs and fleet, but might
+    /// have different constraints. Jobs which violate hard constraints are moved to unassigned.
+    pub fn revalidate(&mut self, problem: Arc<Problem>) {
+        self.problem = problem;
+
+        let constraint = self.problem.constraint.clone();
+        let violations = self
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, route_ctx)| {
+                get_route_violations(&self.solution, route_ctx, constraint.as_ref())
+                    .into_iter()
+                    .map(move |(job, code)| (idx, job, code))
+            })
+            .collect::<Vec<_>>();
+
+        violations.into_iter().for_each(|(idx, job, code)| {
+            self.solution.routes[idx].route_mut().tour.remove(&job);
+            self.solution.locked.remove(&job);
+            self.solution.unassigned.insert(job, UnassignmentInfo::Simple(code));
+        });
+
+        self.restore();
+    }
+
+    /// Returns route assignments of the solution as plain data.
+    pub fn route_plan(&self) -> Vec<RoutePlan> {
+        self.solution
+            .routes
+            .iter()
+            .map(|route_ctx| RoutePlan {
+                vehicle_id: route_ctx.route.actor.vehicle.dimens.get_id().cloned(),
+                activities: route_ctx
+                    .route
+                    .tour
+                    .all_activities()
+                    .map(|activity| ActivityPlan {
+                        job_id: activity.retrieve_job().and_then(|job| job.dimens().get_id().cloned()),
+                        arrival: activity.schedule.arrival,
+                        departure: activity.schedule.departure,
+                        load: get_activity_load(route_ctx, activity),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     /// Removes empty routes from solution context.
     fn remove_empty_routes(&mut self) {
         let registry = &mut self.solution.registry;
@@ -84,7 +146,12 @@ impl InsertionContext {
 
 impl HeuristicSolution for InsertionContext {
     fn get_fitness<'a>(&'a self) -> Box<dyn Iterator<Item = f64> + 'a> {
-        Box::new(self.problem.objective.objectives().map(move |objective| objective.fitness(self)))
+        let version = self.solution.get_version();
+        let values = self.solution.fitness_cache.get_or_insert_with(version, || {
+            self.problem.objective.objectives().map(|objective| objective.fitness(self)).collect()
+        });
+
+        Box::new(values.into_iter())
     }
 
     fn deep_copy(&self) -> Self {
@@ -96,6 +163,53 @@ impl HeuristicSolution for InsertionContext {
     }
 }
 
+/// Replays route's activities on an empty route of the same actor and returns jobs which violate
+/// hard constraints within violation codes.
+fn get_route_violations(
+    solution_ctx: &SolutionContext,
+    route_ctx: &RouteContext,
+    constraint: &ConstraintPipeline,
+) -> Vec<(Job, i32)> {
+    let (_, violations) =
+        replay_route_on_actor(solution_ctx, constraint, route_ctx, route_ctx.route.actor.clone(), false);
+
+    violations
+}
+
+/// A route assignment represented as plain data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoutePlan {
+    /// Id of the vehicle used by the route.
+    pub vehicle_id: Option<String>,
+    /// Ordered list of activities including route start and end.
+    pub activities: Vec<ActivityPlan>,
+}
+
+/// An activity of the route plan.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActivityPlan {
+    /// Id of the job served by the activity, if any.
+    pub job_id: Option<String>,
+    /// Arrival time.
+    pub arrival: Timestamp,
+    /// Departure time.
+    pub departure: Timestamp,
+    /// Vehicle load after the activity, empty when capacity is not tracked.
+    pub load: Vec<i32>,
+}
+
+fn get_activity_load(route_ctx: &RouteContext, activity: &Activity) -> Vec<i32> {
+    let state = &route_ctx.state;
+
+    state
+        .get_activity_state::<MultiDimLoad>(CURRENT_CAPACITY_KEY, activity)
+        .map(|load| load.as_vec())
+        .or_else(|| {
+            state.get_activity_state::<SingleDimLoad>(CURRENT_CAPACITY_KEY, activity).map(|load| vec![load.value])
+        })
+        .unwrap_or_default()
+}
+
 /// A any state value.
 pub type StateValue = Arc<dyn Any + Send + Sync>;
 
@@ -113,25 +227,28 @@ pub enum UnassignmentInfo {
 /// Contains information regarding discovered solution.
 pub struct SolutionContext {
     /// List of jobs which require permanent assignment.
-    pub required: Vec<Job>,
+    pub required: Versioned<Vec<Job>>,
 
     /// List of jobs which at the moment does not require assignment and might be ignored.
-    pub ignored: Vec<Job>,
+    pub ignored: Versioned<Vec<Job>>,
 
     /// Map of jobs which cannot be assigned and within reason code.
-    pub unassigned: HashMap<Job, UnassignmentInfo>,
+    pub unassigned: Versioned<HashMap<Job, UnassignmentInfo>>,
 
     /// Specifies jobs which should not be affected by ruin.
-    pub locked: HashSet<Job>,
+    pub locked: Versioned<HashSet<Job>>,
 
     /// Set of routes within their state.
-    pub routes: Vec<RouteContext>,
+    pub routes: Versioned<Vec<RouteContext>>,
 
     /// Keeps track of used routes and resources.
     pub registry: RegistryContext,
 
     /// A collection of data associated with solution.
-    pub state: HashMap<i32, StateValue>,
+    pub state: Versioned<HashMap<i32, StateValue>>,
+
+    /// Keeps fitness values calculated for the current solution.
+    pub fitness_cache: FitnessCache,
 }
 
 impl SolutionContext {
@@ -170,6 +287,21 @@ impl SolutionContext {
         self.unassigned.len() + required + self.ignored.len() + assigned
     }
 
+    /// Returns a version which is changed on any modification of solution jobs, state or routes.
+    /// It is the latest version of solution parts as each modification assigns a new unique one.
+    pub fn get_version(&self) -> usize {
+        self.routes.iter().map(|route_ctx| route_ctx.get_version()).fold(
+            self.required
+                .version()
+                .max(self.ignored.version())
+                .max(self.unassigned.version())
+                .max(self.locked.version())
+                .max(self.routes.version())
+                .max(self.state.version()),
+            |acc, version| acc.max(version),
+        )
+    }
+
     /// Creates a deep copy of `SolutionContext`.
     pub fn deep_copy(&self) -> Self {
         Self {
@@ -180,10 +312,131 @@ impl SolutionContext {
             routes: self.routes.iter().map(|rc| rc.deep_copy()).collect(),
             registry: self.registry.deep_copy(),
             state: self.state.clone(),
+            fitness_cache: FitnessCache::default(),
+        }
+    }
+
+    /// Creates a copy of `SolutionContext` with routes shared in copy-on-write manner.
+    pub fn cow_copy(&self) -> Self {
+        Self {
+            required: self.required.clone(),
+            ignored: self.ignored.clone(),
+            unassigned: self.unassigned.clone(),
+            locked: self.locked.clone(),
+            routes: self.routes.iter().map(|rc| rc.cow_copy()).collect(),
+            registry: self.registry.deep_copy(),
+            state: self.state.clone(),
+            fitness_cache: FitnessCache::default(),
         }
     }
 }
 
+/// Keeps fitness values of a solution to avoid their recalculation when solution is not changed.
+/// Values are associated with a solution version, so they are recalculated once solution is
+/// modified, see `SolutionContext::get_version`.
+#[derive(Default)]
+pub struct FitnessCache {
+    values: RwLock<Option<(usize, Vec<f64>)>>,
+}
+
+impl FitnessCache {
+    /// Returns fitness values cached for given solution version or calculates and caches them using
+    /// given function.
+    pub fn get_or_insert_with<F>(&self, version: usize, func: F) -> Vec<f64>
+    where
+        F: FnOnce() -> Vec<f64>,
+    {
+        if let Some((_, values)) =
+            self.values.read().unwrap().as_ref().filter(|(cached_version, _)| *cached_version == version)
+        {
+            return values.clone();
+        }
+
+        let values = func();
+        *self.values.write().unwrap() = Some((version, values.clone()));
+
+        values
+    }
+
+    /// Removes cached fitness values.
+    pub fn invalidate(&mut self) {
+        *self.values.get_mut().unwrap() = None;
+    }
+}
+
+/// Wraps a part of solution and gives it a new unique version on each mutable access, so
+/// modification can be detected without inspecting the content.
+pub struct Versioned<T> {
+    value: T,
+    version: usize,
+}
+
+impl<T> Versioned<T> {
+    /// Creates a new instance of `Versioned`.
+    pub fn new(value: T) -> Self {
+        Self { value, version: next_route_version() }
+    }
+
+    /// Returns a version which is changed on any mutable access.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for Versioned<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<A, T: FromIterator<A>> FromIterator<A> for Versioned<T> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        Self::new(T::from_iter(iter))
+    }
+}
+
+impl<T: IntoIterator> IntoIterator for Versioned<T> {
+    type Item = T::Item;
+    type IntoIter = T::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.value.into_iter()
+    }
+}
+
+impl<T: Default> Default for Versioned<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Clone> Clone for Versioned<T> {
+    fn clone(&self) -> Self {
+        // NOTE a clone can be assigned to another solution, so it should not reuse the version
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T> Deref for Versioned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Versioned<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.version = next_route_version();
+        &mut self.value
+    }
+}
+
 /// Specifies insertion context for route.
 #[derive(Clone)]
 pub struct RouteContext {
@@ -225,7 +478,7 @@ impl RouteContext {
 
     /// Creates a new instance of `RouteContext` with arguments provided.
     pub fn new_with_state(route: Arc<Route>, state: Arc<RouteState>) -> Self {
-        RouteContext { route, state, cache: Arc::new(RouteCache { is_stale: true }) }
+        RouteContext { route, state, cache: Arc::new(RouteCache::new(true, false)) }
     }
 
     /// Creates a deep copy of `RouteContext`.
@@ -236,7 +489,20 @@ impl RouteContext {
         RouteContext {
             route: Arc::new(new_route),
             state: Arc::new(new_state),
-            cache: Arc::new(RouteCache { is_stale: self.cache.is_stale }),
+            cache: Arc::new(RouteCache::new(self.cache.is_stale, false)),
+        }
+    }
+
+    /// Creates a copy of `RouteContext` which shares route and its state with the original one
+    /// until any of them is modified by mutable methods. Please note, that other shallow clones
+    /// of the original do not observe its modifications after the first one.
+    pub fn cow_copy(&self) -> Self {
+        self.cache.is_shared.store(true, Ordering::Relaxed);
+
+        RouteContext {
+            route: self.route.clone(),
+            state: self.state.clone(),
+            cache: Arc::new(RouteCache::new(self.cache.is_stale, true)),
         }
     }
 
@@ -263,6 +529,7 @@ impl RouteContext {
     /// Unwraps given `RouteContext` as pair of mutable references.
     /// Marks context as stale.
     pub fn as_mut(&mut self) -> (&mut Route, &mut RouteState) {
+        self.detach();
         self.mark_stale(true);
 
         let route: &mut Route = unsafe { as_mut(&self.route) };
@@ -274,6 +541,7 @@ impl RouteContext {
     /// Returns mutable reference to used `Route`.
     /// Marks context as stale.
     pub fn route_mut(&mut self) -> &mut Route {
+        self.detach();
         self.mark_stale(true);
         unsafe { as_mut(&self.route) }
     }
@@ -281,6 +549,7 @@ impl RouteContext {
     /// Returns mutable reference to used `RouteState`.
     /// Marks context as stale.
     pub fn state_mut(&mut self) -> &mut RouteState {
+        self.detach();
         self.mark_stale(true);
         unsafe { as_mut(&self.state) }
     }
@@ -295,6 +564,23 @@ impl RouteContext {
     pub(crate) fn mark_stale(&mut self, is_stale: bool) {
         let cache: &mut RouteCache = unsafe { as_mut(&self.cache) };
         cache.is_stale = is_stale;
+        cache.version = next_route_version();
+    }
+
+    /// Returns a version which is changed on any modification done via `mut` methods.
+    fn get_version(&self) -> usize {
+        self.cache.version
+    }
+
+    /// Copies route and its state if they are shared with copy-on-write copies.
+    fn detach(&mut self) {
+        if self.cache.is_shared.load(Ordering::Relaxed) {
+            if Arc::strong_count(&self.route) > 1 || Arc::strong_count(&self.state) > 1 {
+                *self = self.deep_copy();
+            } else {
+                self.cache.is_shared.store(false, Ordering::Relaxed);
+            }
+        }
     }
 }
 
@@ -437,6 +723,21 @@ impl RouteState {
 
 struct RouteCache {
     is_stale: bool,
+    is_shared: AtomicBool,
+    version: usize,
+}
+
+impl RouteCache {
+    fn new(is_stale: bool, is_shared: bool) -> Self {
+        Self { is_stale, is_shared: AtomicBool::new(is_shared), version: next_route_version() }
+    }
+}
+
+/// Returns a unique version, so versions are not reused by different routes or solution parts.
+fn next_route_version() -> usize {
+    static ROUTE_VERSION: AtomicUsize = AtomicUsize::new(0);
+
+    ROUTE_VERSION.fetch_add(1, Ordering::Relaxed)
 }
 
 /// A wrapper around route context modifier function.
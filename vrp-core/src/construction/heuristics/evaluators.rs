@@ -4,13 +4,15 @@ mod evaluators_test;
 
 use std::sync::Arc;
 
-use crate::construction::constraints::{ActivityConstraintViolation, ConstraintPipeline};
+use crate::construction::constraints::{ActivityConstraintViolation, ConstraintPipeline, RouteConstraintViolation};
 use crate::construction::heuristics::*;
-use crate::models::common::Cost;
-use crate::models::problem::{Job, Multi, Single};
+use crate::models::common::{Cost, IdDimension, TimeOffset, TimeSpan, TimeWindow};
+use crate::models::problem::{Actor, FixedJobPermutation, Job, Multi, Place as JobPlace, Single};
 use crate::models::solution::{Activity, Leg, Place};
+use crate::models::Problem;
 use crate::utils::Either;
-use rosomaxa::utils::unwrap_from_result;
+use rosomaxa::prelude::Environment;
+use rosomaxa::utils::{parallel_into_collect, unwrap_from_result};
 
 /// Specifies an evaluation context data.
 pub struct EvaluationContext<'a> {
@@ -116,6 +118,89 @@ pub(crate) fn evaluate_single_constraint_in_route(
     }
 }
 
+/// Describes an outcome of insertion attempt at specific route leg.
+#[derive(Clone)]
+pub struct InsertionAttempt {
+    /// A sub job for which attempt was made. Same as evaluated job for single jobs.
+    pub single: Arc<Single>,
+    /// Route leg index.
+    pub index: usize,
+    /// Activity place tried.
+    pub place: Place,
+    /// Hard activity constraint violation, if any.
+    pub violation: Option<ActivityConstraintViolation>,
+    /// Insertion cost if no constraint is violated.
+    pub cost: Option<Cost>,
+}
+
+/// Explains why given job can or cannot be inserted into the route.
+#[derive(Clone)]
+pub struct InsertionExplanation {
+    /// Hard route constraint violation. If set, no attempts on activity level are made.
+    pub route_violation: Option<RouteConstraintViolation>,
+    /// All attempts made on activity level.
+    pub attempts: Vec<InsertionAttempt>,
+}
+
+/// Evaluates job insertion into all legs of given route with all possible places and time windows
+/// without early termination and returns detailed outcome of each attempt. Sub jobs of a multi job
+/// are evaluated independently of each other. Intended to be used for debugging purposes.
+pub fn explain_insertion(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    job: &Job,
+) -> InsertionExplanation {
+    let constraint = &insertion_ctx.problem.constraint;
+
+    if let Some(violation) = constraint.evaluate_hard_route(&insertion_ctx.solution, route_ctx, job) {
+        return InsertionExplanation { route_violation: Some(violation), attempts: vec![] };
+    }
+
+    let route_costs = constraint.evaluate_soft_route(&insertion_ctx.solution, route_ctx, job);
+    let start_time = route_ctx.route.tour.start().map_or(0., |start| start.schedule.departure);
+
+    let singles = match job {
+        Job::Single(single) => vec![single.clone()],
+        Job::Multi(multi) => multi.jobs.clone(),
+    };
+
+    let attempts = singles
+        .iter()
+        .flat_map(|single| {
+            route_ctx.route.tour.legs().flat_map(move |(items, index)| {
+                let (prev, next) = match items {
+                    [prev] => (prev, None),
+                    [prev, next] => (prev, Some(next)),
+                    _ => panic!("Unexpected route leg configuration."),
+                };
+
+                single.places.iter().flat_map(move |detail| {
+                    detail.times.iter().map(move |time| {
+                        let mut target = Activity::new_with_job(single.clone());
+                        target.place = Place {
+                            location: detail.location.unwrap_or(prev.place.location),
+                            duration: detail.duration,
+                            time: time.to_time_window(start_time),
+                        };
+
+                        let activity_ctx = ActivityContext { index, prev, target: &target, next };
+                        let violation = constraint.evaluate_hard_activity(route_ctx, &activity_ctx);
+                        let cost = if violation.is_none() {
+                            Some(constraint.evaluate_soft_activity(route_ctx, &activity_ctx) + route_costs)
+                        } else {
+                            None
+                        };
+
+                        InsertionAttempt { single: single.clone(), index, place: target.place.clone(), violation, cost }
+                    })
+                })
+            })
+        })
+        .collect();
+
+    InsertionExplanation { route_violation: None, attempts }
+}
+
 fn evaluate_single(
     eval_ctx: &EvaluationContext,
     route_ctx: &RouteContext,
@@ -483,3 +568,248 @@ fn concat_activities(
 
     activities
 }
+
+/// Evaluates whether the same ordered sequence of jobs as in given route can be served by another
+/// actor (e.g. a different vehicle type). Returns route cost with the new actor if all hard
+/// constraints are fulfilled or code of the first violated constraint otherwise.
+pub fn evaluate_route_on_vehicle(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    actor: Arc<Actor>,
+) -> Result<Cost, i32> {
+    let (new_route_ctx, violations) =
+        replay_route_on_actor(&insertion_ctx.solution, &insertion_ctx.problem.constraint, route_ctx, actor, true);
+
+    match violations.first() {
+        Some((_, code)) => Err(*code),
+        None => Ok(new_route_ctx.get_route_cost()),
+    }
+}
+
+/// Replays the ordered sequence of job activities of given route on an empty route of given actor.
+/// Returns the new route and jobs which violate hard constraints within violation codes. Violated
+/// jobs are skipped, unless `is_fail_fast` is set: then replay stops on the first violation.
+pub(crate) fn replay_route_on_actor(
+    solution_ctx: &SolutionContext,
+    constraint: &ConstraintPipeline,
+    route_ctx: &RouteContext,
+    actor: Arc<Actor>,
+    is_fail_fast: bool,
+) -> (RouteContext, Vec<(Job, i32)>) {
+    let mut new_route_ctx = RouteContext::new(actor);
+    constraint.accept_route_state(&mut new_route_ctx);
+
+    let mut violations: Vec<(Job, i32)> = vec![];
+
+    let _ = route_ctx.route.tour.all_activities().filter(|activity| activity.job.is_some()).try_for_each(|activity| {
+        let job = activity.retrieve_job().expect("activity without job");
+        if violations.iter().any(|(violated, _)| *violated == job) {
+            return Ok(());
+        }
+
+        let route_violation = if new_route_ctx.route.tour.contains(&job) {
+            None
+        } else {
+            constraint.evaluate_hard_route(solution_ctx, &new_route_ctx, &job).map(|violation| violation.code)
+        };
+
+        let target = activity.deep_copy();
+        let index = new_route_ctx.route.tour.job_activity_count();
+
+        let violation = route_violation.or_else(|| {
+            let tour = &new_route_ctx.route.tour;
+            let activity_ctx = ActivityContext {
+                index,
+                prev: tour.get(index).expect("no previous activity"),
+                target: &target,
+                next: tour.get(index + 1),
+            };
+
+            constraint.evaluate_hard_activity(&new_route_ctx, &activity_ctx).map(|violation| violation.code)
+        });
+
+        match violation {
+            Some(code) => {
+                if new_route_ctx.route_mut().tour.remove(&job) {
+                    constraint.accept_route_state(&mut new_route_ctx);
+                }
+                violations.push((job, code));
+
+                if is_fail_fast {
+                    Err(())
+                } else {
+                    Ok(())
+                }
+            }
+            None => {
+                new_route_ctx.route_mut().tour.insert_at(target, index + 1);
+                constraint.accept_route_state(&mut new_route_ctx);
+
+                Ok(())
+            }
+        }
+    });
+
+    (new_route_ctx, violations)
+}
+
+/// Returns sorted ids of vehicles which can serve given job when it is inserted into an empty route,
+/// i.e. ignoring the rest of the plan. A vehicle is returned if at least one of its shifts fits.
+/// Can be used to distinguish jobs which no vehicle can serve from jobs which do not fit the plan.
+pub fn feasible_vehicles(problem: Arc<Problem>, job: &Job) -> Vec<String> {
+    let insertion_ctx = InsertionContext::new_empty(problem.clone(), Arc::new(Environment::default()));
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    let mut vehicle_ids = problem
+        .fleet
+        .actors
+        .iter()
+        .filter(|actor| {
+            let mut route_ctx = RouteContext::new((*actor).clone());
+            problem.constraint.accept_route_state(&mut route_ctx);
+
+            let result = evaluate_job_insertion_in_route(
+                &insertion_ctx,
+                &eval_ctx,
+                &route_ctx,
+                InsertionPosition::Any,
+                InsertionResult::make_failure(),
+            );
+
+            matches!(result, InsertionResult::Success(_))
+        })
+        .filter_map(|actor| actor.vehicle.dimens.get_id().cloned())
+        .collect::<Vec<_>>();
+
+    vehicle_ids.sort();
+    vehicle_ids.dedup();
+
+    vehicle_ids
+}
+
+/// Suggests how much time windows of unassigned jobs should be widened, in minutes, to make their
+/// insertion into the given solution feasible. Windows are widened on both sides and the minimal
+/// relaxation is found with one minute precision using insertion evaluation against solution's
+/// routes and unused vehicles. Jobs which can be inserted without relaxation or which cannot be
+/// inserted even with windows widened by the whole planning horizon are not returned.
+/// NOTE time is assumed to be in seconds.
+pub fn suggest_window_relaxation(problem: &Problem, insertion_ctx: &InsertionContext) -> Vec<(Job, f64)> {
+    let horizon = problem.fleet.actors.iter().fold(None, |acc: Option<(f64, f64)>, actor| {
+        let time = &actor.detail.time;
+        Some(acc.map_or((time.start, time.end), |(start, end)| (start.min(time.start), end.max(time.end))))
+    });
+    let max_minutes = match horizon {
+        Some((start, end)) if end > start && (end - start).is_finite() => ((end - start) / 60.).ceil() as usize,
+        _ => return vec![],
+    };
+
+    let mut jobs = insertion_ctx.solution.unassigned.keys().cloned().collect::<Vec<_>>();
+    jobs.sort_by(|a, b| a.dimens().get_id().cmp(&b.dimens().get_id()));
+
+    parallel_into_collect(jobs, |job| {
+        let is_feasible = |minutes: usize| is_feasible_insertion(problem, insertion_ctx, &relax_job(&job, minutes));
+
+        if is_feasible(0) {
+            return None;
+        }
+
+        let mut infeasible = 0;
+        let mut feasible = 1;
+        while !is_feasible(feasible) {
+            if feasible == max_minutes {
+                return None;
+            }
+            infeasible = feasible;
+            feasible = (feasible * 2).min(max_minutes);
+        }
+
+        while feasible - infeasible > 1 {
+            let middle = infeasible + (feasible - infeasible) / 2;
+            if is_feasible(middle) {
+                feasible = middle;
+            } else {
+                infeasible = middle;
+            }
+        }
+
+        Some((job, feasible as f64))
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn is_feasible_insertion(problem: &Problem, insertion_ctx: &InsertionContext, job: &Job) -> bool {
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    insertion_ctx.solution.routes.iter().cloned().chain(insertion_ctx.solution.registry.next()).any(|route_ctx| {
+        let result = evaluate_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            &route_ctx,
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+        );
+
+        matches!(result, InsertionResult::Success(_))
+    })
+}
+
+/// Creates a copy of the job with all time windows widened on both sides by given amount of minutes.
+fn relax_job(job: &Job, minutes: usize) -> Job {
+    let relax_single = |single: &Single| Single {
+        places: single
+            .places
+            .iter()
+            .map(|place| JobPlace {
+                location: place.location,
+                duration: place.duration,
+                times: place.times.iter().map(|time| relax_time_span(time, minutes as f64 * 60.)).collect(),
+            })
+            .collect(),
+        dimens: single.dimens.clone(),
+    };
+
+    match job {
+        Job::Single(single) => Job::Single(Arc::new(relax_single(single))),
+        Job::Multi(multi) => {
+            let permutations = multi
+                .permutations()
+                .iter()
+                .map(|permutation| {
+                    permutation
+                        .iter()
+                        .filter_map(|single| multi.jobs.iter().position(|other| Arc::ptr_eq(other, single)))
+                        .collect()
+                })
+                .collect();
+
+            Job::Multi(Multi::new_shared_with_permutator(
+                multi.jobs.iter().map(|single| Arc::new(relax_single(single))).collect(),
+                multi.dimens.clone(),
+                Box::new(FixedJobPermutation::new(permutations)),
+            ))
+        }
+    }
+}
+
+fn relax_time_span(time: &TimeSpan, delta: f64) -> TimeSpan {
+    match time {
+        TimeSpan::Window(tw) => TimeSpan::Window(TimeWindow::new(tw.start - delta, tw.end + delta)),
+        TimeSpan::Offset(offset) => TimeSpan::Offset(TimeOffset::new(offset.start - delta, offset.end + delta)),
+    }
+}
@@ -106,7 +106,16 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
 
     let mut insertion_ctx = InsertionContext {
         problem,
-        solution: SolutionContext { required, ignored: vec![], unassigned, locked, routes, registry, state },
+        solution: SolutionContext {
+            required,
+            ignored: Default::default(),
+            unassigned: unassigned.into(),
+            locked: locked.into(),
+            routes: routes.into(),
+            registry,
+            state,
+            fitness_cache: Default::default(),
+        },
         environment,
     };
 
@@ -145,12 +154,13 @@ pub fn create_insertion_context_from_solution(
         problem,
         solution: SolutionContext {
             required,
-            ignored: vec![],
+            ignored: Default::default(),
             unassigned: Default::default(),
-            locked,
-            routes,
+            locked: locked.into(),
+            routes: routes.into(),
             registry,
             state,
+            fitness_cache: Default::default(),
         },
         environment,
     };
@@ -165,16 +175,17 @@ pub fn create_empty_insertion_context(problem: Arc<Problem>, environment: Arc<En
     InsertionContext {
         problem: problem.clone(),
         solution: SolutionContext {
-            required: vec![],
-            ignored: vec![],
+            required: Default::default(),
+            ignored: Default::default(),
             unassigned: Default::default(),
             locked: Default::default(),
-            routes: vec![],
+            routes: Default::default(),
             registry: create_registry_context(
                 problem.as_ref(),
                 Registry::new(problem.fleet.as_ref(), environment.random.clone()),
             ),
             state: Default::default(),
+            fitness_cache: Default::default(),
         },
         environment,
     }
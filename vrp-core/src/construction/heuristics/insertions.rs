@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/insertions_test.rs"]
+mod insertions_test;
+
 use crate::construction::heuristics::*;
 use crate::models::common::Cost;
 use crate::models::problem::Job;
@@ -139,6 +143,11 @@ impl InsertionResult {
         }
     }
 
+    /// Returns delta cost change of the insertion if it is successful.
+    pub fn cost(&self) -> Option<Cost> {
+        self.as_success().map(|success| success.cost)
+    }
+
     /// Returns insertion result as success.
     pub fn as_success(&self) -> Option<&InsertionSuccess> {
         match self {
@@ -4,11 +4,16 @@ mod metrics_test;
 
 use super::InsertionContext;
 use crate::construction::constraints::{MAX_LOAD_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY, WAITING_KEY};
-use crate::construction::heuristics::RouteContext;
-use crate::models::problem::{TransportCost, TravelTime};
+use crate::construction::heuristics::*;
+use crate::models::common::{CapacityDimension, Cost, Demand, DemandDimension, LoadOps, Location};
+use crate::models::problem::{Actor, Job, TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use crate::models::Problem;
 use rosomaxa::algorithms::math::*;
-use rosomaxa::prelude::compare_floats;
+use rosomaxa::prelude::{compare_floats, HeuristicSolution};
+use rosomaxa::utils::parallel_collect;
 use std::cmp::Ordering;
+use std::sync::Arc;
 
 /// Gets max load variance in tours.
 pub fn get_max_load_variance(insertion_ctx: &InsertionContext) -> f64 {
@@ -108,8 +113,77 @@ pub fn get_longest_distance_between_depot_customer_mean(insertion_ctx: &Insertio
     }))
 }
 
+/// A function which returns weight of activity performed by actor. It is used to calculate weighted route medoid.
+pub type ActivityWeightFn = Arc<dyn Fn(&Actor, &Activity) -> f64 + Send + Sync>;
+
 /// Gets average distance between routes using medoids (S4).
 pub fn get_distance_gravity_mean(insertion_ctx: &InsertionContext) -> f64 {
+    get_gravity_mean(insertion_ctx, &|route_ctx, transport| get_medoid(route_ctx, transport))
+}
+
+/// Gets average distance between routes using medoids weighted by activity weight, e.g. by job demand,
+/// so heavy stops pull the route gravity center.
+pub fn get_weighted_distance_gravity_mean(insertion_ctx: &InsertionContext, activity_weight: &ActivityWeightFn) -> f64 {
+    get_gravity_mean(insertion_ctx, &|route_ctx, transport| {
+        get_weighted_medoid(route_ctx, transport, activity_weight.as_ref())
+    })
+}
+
+/// Gets medoid location of given route context.
+pub fn get_medoid(route_ctx: &RouteContext, transport: &(dyn TransportCost + Send + Sync)) -> Option<usize> {
+    get_weighted_medoid(route_ctx, transport, &|_, _| 1.)
+}
+
+/// Gets medoid location of given route context using activity weights: a distance to each activity
+/// is multiplied by its weight.
+pub fn get_weighted_medoid(
+    route_ctx: &RouteContext,
+    transport: &(dyn TransportCost + Send + Sync),
+    activity_weight: &(dyn Fn(&Actor, &Activity) -> f64 + Send + Sync),
+) -> Option<usize> {
+    let actor = route_ctx.route.actor.as_ref();
+    let profile = &actor.vehicle.profile;
+    let locations = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| (activity.place.location, activity_weight(actor, activity)))
+        .collect::<Vec<_>>();
+
+    locations
+        .iter()
+        .map(|(outer_loc, _)| {
+            let sum = locations
+                .iter()
+                .map(|(inner_loc, weight)| weight * transport.distance_approx(profile, *outer_loc, *inner_loc))
+                .sum::<f64>();
+            (sum, *outer_loc)
+        })
+        .min_by(|(sum_a, _), (sum_b, _)| compare_floats(*sum_a, *sum_b))
+        .map(|(_, location)| location)
+}
+
+/// Creates activity weight function which uses job demand relative to vehicle capacity.
+/// Activities without demand or capacity have weight equal to one.
+pub fn create_demand_activity_weight<T: LoadOps>() -> ActivityWeightFn {
+    Arc::new(|actor, activity| {
+        let capacity: Option<&T> = actor.vehicle.dimens.get_capacity();
+        let demand: Option<&Demand<T>> = activity.job.as_ref().and_then(|job| job.dimens.get_demand());
+
+        match (capacity, demand) {
+            (Some(capacity), Some(demand)) if capacity.is_not_empty() => {
+                let total = demand.pickup.0 + demand.pickup.1 + demand.delivery.0 + demand.delivery.1;
+                1. + total.ratio(capacity)
+            }
+            _ => 1.,
+        }
+    })
+}
+
+fn get_gravity_mean(
+    insertion_ctx: &InsertionContext,
+    medoid_fn: &dyn Fn(&RouteContext, &(dyn TransportCost + Send + Sync)) -> Option<usize>,
+) -> f64 {
     let transport = insertion_ctx.problem.transport.as_ref();
     let profile = insertion_ctx.solution.routes.first().map(|route_ctx| &route_ctx.route.actor.vehicle.profile);
 
@@ -118,7 +192,7 @@ pub fn get_distance_gravity_mean(insertion_ctx: &InsertionContext) -> f64 {
             .solution
             .routes
             .iter()
-            .filter_map(|route_ctx| get_medoid(route_ctx, transport))
+            .filter_map(|route_ctx| medoid_fn(route_ctx, transport))
             .collect::<Vec<_>>();
 
         let mut distances = Vec::with_capacity(medoids.len() * 2);
@@ -137,23 +211,6 @@ pub fn get_distance_gravity_mean(insertion_ctx: &InsertionContext) -> f64 {
     }
 }
 
-/// Gets medoid location of given route context.
-pub fn get_medoid(route_ctx: &RouteContext, transport: &(dyn TransportCost + Send + Sync)) -> Option<usize> {
-    let profile = &route_ctx.route.actor.vehicle.profile;
-    let locations = route_ctx.route.tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>();
-    locations
-        .iter()
-        .map(|outer_loc| {
-            let sum = locations
-                .iter()
-                .map(|inner_loc| transport.distance_approx(profile, *outer_loc, *inner_loc))
-                .sum::<f64>();
-            (sum, *outer_loc)
-        })
-        .min_by(|(sum_a, _), (sum_b, _)| compare_floats(*sum_a, *sum_b))
-        .map(|(_, location)| location)
-}
-
 /// A type which represents routes grouped by their proximity.
 pub type RouteProximityGroup = Option<Vec<Vec<(usize, Option<f64>)>>>;
 
@@ -205,6 +262,94 @@ pub fn group_routes_by_proximity(insertion_ctx: &InsertionContext) -> RouteProxi
     )
 }
 
+/// Represents marginal cost of the route in the solution.
+pub struct RouteMarginalCost {
+    /// An actor of the route.
+    pub actor: Arc<Actor>,
+    /// A total cost saved by eliminating the route. It can be negative when the route's jobs are
+    /// more expensive to serve by remaining routes.
+    pub saving: Cost,
+    /// Jobs of the route which cannot be reassigned to remaining routes.
+    pub unassigned: Vec<Job>,
+}
+
+/// Estimates marginal cost of each route: how much total cost drops if the route is eliminated
+/// and its jobs are reassigned to remaining routes using best insertion. Original solution is not
+/// modified and no new route is opened, so it helps to detect whether the fleet is too big.
+pub fn get_route_marginal_costs(insertion_ctx: &InsertionContext) -> Vec<RouteMarginalCost> {
+    let total_cost = insertion_ctx.solution.get_total_cost();
+
+    parallel_collect(&insertion_ctx.solution.routes, |route_ctx| {
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        let jobs = route_ctx.route.tour.jobs().collect::<Vec<_>>();
+
+        new_insertion_ctx.solution.routes.retain(|rc| rc.route.actor != route_ctx.route.actor);
+        new_insertion_ctx.solution.registry.free_route(route_ctx);
+        new_insertion_ctx.solution.required.extend(jobs.iter().cloned());
+
+        let leg_selector = VariableLegSelector::new(new_insertion_ctx.environment.random.clone());
+        let new_insertion_ctx = InsertionHeuristic::default().process(
+            new_insertion_ctx,
+            &AllJobSelector::default(),
+            &ExistingRouteSelector::default(),
+            &leg_selector,
+            &BestResultSelector::default(),
+        );
+
+        RouteMarginalCost {
+            actor: route_ctx.route.actor.clone(),
+            saving: total_cost - new_insertion_ctx.solution.get_total_cost(),
+            unassigned: jobs
+                .into_iter()
+                .filter(|job| new_insertion_ctx.solution.unassigned.contains_key(job))
+                .collect(),
+        }
+    })
+}
+
+/// Represents geometry of the route: its center and spread of its activities around the center.
+pub struct RouteGeometry {
+    /// An actor of the route.
+    pub actor: Arc<Actor>,
+    /// A centroid of the route which is its medoid location, the same as used by gravity mean.
+    pub centroid: Option<Location>,
+    /// A standard distance: square root of the mean squared distance between route activities
+    /// and its centroid.
+    pub spread: f64,
+}
+
+/// Returns geometry of each route in the solution. Activities include depots, so routes without
+/// jobs have centroid at their depots. Solution is not modified.
+pub fn get_route_geometry(insertion_ctx: &InsertionContext) -> Vec<RouteGeometry> {
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            let centroid = get_medoid(route_ctx, transport);
+            let profile = &route_ctx.route.actor.vehicle.profile;
+
+            let spread = centroid.map_or(0., |centroid| {
+                let squares = route_ctx
+                    .route
+                    .tour
+                    .all_activities()
+                    .map(|activity| {
+                        // NOTE assume that negative distance is used between unroutable locations
+                        transport.distance_approx(profile, centroid, activity.place.location).max(0.).powi(2)
+                    })
+                    .collect::<Vec<_>>();
+
+                get_mean_slice(squares.as_slice()).sqrt()
+            });
+
+            RouteGeometry { actor: route_ctx.route.actor.clone(), centroid, spread }
+        })
+        .collect()
+}
+
 fn get_values_from_route_state(insertion_ctx: &InsertionContext, state_key: i32) -> impl Iterator<Item = f64> + '_ {
     insertion_ctx
         .solution
@@ -212,3 +357,64 @@ fn get_values_from_route_state(insertion_ctx: &InsertionContext, state_key: i32)
         .iter()
         .map(move |route| route.state.get_route_state::<f64>(state_key).cloned().unwrap_or(0.))
 }
+
+/// Represents a breakdown of the route cost by its components.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteCostBreakdown {
+    /// A fixed cost of the actor.
+    pub fixed: Cost,
+    /// A cost of travelled distance.
+    pub distance: Cost,
+    /// A cost of driving time.
+    pub driving: Cost,
+    /// A cost of waiting time.
+    pub waiting: Cost,
+    /// A cost of service time.
+    pub service: Cost,
+}
+
+impl RouteCostBreakdown {
+    /// Returns a total cost of the route.
+    pub fn total(&self) -> Cost {
+        self.fixed + self.distance + self.driving + self.waiting + self.service
+    }
+}
+
+/// Calculates a full cost of the single route independently from the rest of the solution.
+pub fn route_cost(route_ctx: &RouteContext, problem: &Problem) -> Cost {
+    route_cost_breakdown(route_ctx, problem).total()
+}
+
+/// Calculates cost components of the single route from its tour and schedule, so, unlike
+/// `RouteContext::get_route_cost`, it does not rely on route state and takes into account
+/// different timing costs. Costs of both vehicle and driver are included.
+pub fn route_cost_breakdown(route_ctx: &RouteContext, problem: &Problem) -> RouteCostBreakdown {
+    let route = route_ctx.route.as_ref();
+    let actor = route.actor.as_ref();
+    let (vehicle, driver) = (&actor.vehicle.costs, &actor.driver.costs);
+    let transport = problem.transport.as_ref();
+
+    let breakdown = RouteCostBreakdown { fixed: vehicle.fixed + driver.fixed, ..RouteCostBreakdown::default() };
+
+    let breakdown = route.tour.legs().fold(breakdown, |mut acc, (activities, _)| {
+        if let [from, to] = activities {
+            let travel_time = TravelTime::Departure(from.schedule.departure);
+            let distance = transport.distance(route, from.place.location, to.place.location, travel_time);
+            let duration = transport.duration(route, from.place.location, to.place.location, travel_time);
+
+            acc.distance += distance * (vehicle.per_distance + driver.per_distance);
+            acc.driving += duration * (vehicle.per_driving_time + driver.per_driving_time);
+        }
+
+        acc
+    });
+
+    route.tour.all_activities().fold(breakdown, |mut acc, activity| {
+        let waiting = (activity.place.time.start - activity.schedule.arrival).max(0.);
+
+        acc.waiting += waiting * (vehicle.per_waiting_time + driver.per_waiting_time);
+        acc.service += activity.place.duration * (vehicle.per_service_time + driver.per_service_time);
+
+        acc
+    })
+}
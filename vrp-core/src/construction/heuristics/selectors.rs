@@ -36,6 +36,20 @@ impl RouteSelector for AllRouteSelector {
     }
 }
 
+/// Returns only routes which are already present in the solution, so no new route is opened.
+#[derive(Default)]
+pub struct ExistingRouteSelector {}
+
+impl RouteSelector for ExistingRouteSelector {
+    fn select<'a>(
+        &'a self,
+        insertion_ctx: &'a mut InsertionContext,
+        _jobs: &[Job],
+    ) -> Box<dyn Iterator<Item = RouteContext> + 'a> {
+        Box::new(insertion_ctx.solution.routes.iter().cloned())
+    }
+}
+
 /// On each insertion step, selects a list of jobs to be inserted.
 /// It is up to implementation to decide whether list consists of all jobs or just some subset.
 pub trait JobSelector {
@@ -51,6 +51,39 @@ pub fn repair_solution_from_unknown(
     new_insertion_ctx
 }
 
+/// Repairs given, potentially infeasible, solution in place: jobs which violate constraints are
+/// removed from their routes and reinserted using best insertion. Returns jobs which cannot be
+/// reinserted, they are kept as unassigned.
+pub fn repair(insertion_ctx: &mut InsertionContext) -> Vec<Job> {
+    let assigned_jobs = get_assigned_jobs(insertion_ctx);
+    let mut new_insertion_ctx = repair_solution_from_unknown(insertion_ctx, &|| {
+        InsertionContext::new(insertion_ctx.problem.clone(), insertion_ctx.environment.clone())
+    });
+
+    let removed_jobs = assigned_jobs
+        .into_iter()
+        .filter(|job| new_insertion_ctx.solution.unassigned.contains_key(job))
+        .collect::<Vec<_>>();
+
+    new_insertion_ctx.solution.unassigned.retain(|job, _| !removed_jobs.contains(job));
+    new_insertion_ctx.solution.required.extend(removed_jobs.iter().cloned());
+
+    let new_insertion_ctx = InsertionHeuristic::default().process(
+        new_insertion_ctx,
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &AllLegSelector::default(),
+        &BestResultSelector::default(),
+    );
+
+    let unrepaired =
+        removed_jobs.into_iter().filter(|job| new_insertion_ctx.solution.unassigned.contains_key(job)).collect();
+
+    *insertion_ctx = new_insertion_ctx;
+
+    unrepaired
+}
+
 fn get_new_route_ctx_idx(new_insertion_ctx: &mut InsertionContext, route_ctx: &RouteContext) -> usize {
     if let Some(idx) = new_insertion_ctx
         .solution
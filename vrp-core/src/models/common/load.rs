@@ -27,6 +27,17 @@ pub trait Load: Add + Sub + PartialOrd + Copy + Default + Debug + Send + Sync {
 
     /// Returns ratio.
     fn ratio(&self, other: &Self) -> f64;
+
+    /// Returns value of given dimension. Default implementation has no addressable dimensions.
+    fn dimension(&self, _idx: usize) -> i32 {
+        0
+    }
+
+    /// Returns a copy where given dimensions are set to max possible value, so they never limit
+    /// `can_fit` check. Default implementation keeps all dimensions bounded.
+    fn unbounded(self, _dimens: &[usize]) -> Self {
+        self
+    }
 }
 
 /// Specifies constraints on Load operations.
@@ -145,6 +156,22 @@ impl Load for SingleDimLoad {
     fn ratio(&self, other: &Self) -> f64 {
         self.value as f64 / other.value as f64
     }
+
+    fn dimension(&self, idx: usize) -> i32 {
+        if idx == 0 {
+            self.value
+        } else {
+            0
+        }
+    }
+
+    fn unbounded(self, dimens: &[usize]) -> Self {
+        if dimens.contains(&0) {
+            Self::new(i32::MAX)
+        } else {
+            self
+        }
+    }
 }
 
 impl Add for SingleDimLoad {
@@ -254,6 +281,20 @@ impl Load for MultiDimLoad {
     fn ratio(&self, other: &Self) -> f64 {
         self.load.iter().zip(other.load.iter()).fold(0., |acc, (a, b)| (*a as f64 / *b as f64).max(acc))
     }
+
+    fn dimension(&self, idx: usize) -> i32 {
+        self.load.get(idx).cloned().unwrap_or(0)
+    }
+
+    fn unbounded(self, dimens: &[usize]) -> Self {
+        let mut result = self;
+        dimens.iter().filter(|&&idx| idx < LOAD_DIMENSION_SIZE).for_each(|&idx| {
+            result.load[idx] = i32::MAX;
+            result.size = result.size.max(idx + 1);
+        });
+
+        result
+    }
 }
 
 impl LoadOps for MultiDimLoad {}
@@ -13,3 +13,6 @@ pub mod examples;
 
 pub mod problem;
 pub mod solution;
+
+mod validation;
+pub use self::validation::*;
@@ -69,6 +69,20 @@ impl Shuffled for ProblemObjective {
 
         Self { objectives }
     }
+
+    /// Returns a new instance of `ProblemObjective` with the objective at given index moved to the top
+    /// of the hierarchy, the rest is kept in the original order.
+    fn get_emphasized(&self, index: usize) -> Option<Self> {
+        if self.objectives.len() < 2 {
+            return None;
+        }
+
+        let mut objectives = self.objectives.clone();
+        let emphasized = objectives.remove(index % objectives.len());
+        objectives.insert(0, emphasized);
+
+        Some(Self { objectives })
+    }
 }
 
 impl Default for ProblemObjective {
@@ -209,6 +223,16 @@ pub trait TransportCost {
 
     /// Returns time-dependent travel distance between locations specific for given actor.
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance;
+
+    /// Returns amount of locations known to the transport costs, if it is limited.
+    fn size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Checks whether routing data is available for given profile index.
+    fn has_profile(&self, _profile_index: usize) -> bool {
+        true
+    }
 }
 
 /// Provides way to calculate transport costs which might contain reserved time.
@@ -251,6 +275,98 @@ impl TransportCost for DynamicTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
         self.inner.distance(route, from, to, travel_time)
     }
+
+    fn size(&self) -> Option<usize> {
+        self.inner.size()
+    }
+
+    fn has_profile(&self, profile_index: usize) -> bool {
+        self.inner.has_profile(profile_index)
+    }
+}
+
+/// Specifies traffic profile as travel duration factors applied within time buckets. Each bucket
+/// starts at its timestamp and lasts till the start of the next one, the last bucket is unbounded.
+/// Before the first bucket, durations are not changed.
+pub struct TrafficProfile {
+    /// A routing profile index.
+    pub index: usize,
+    /// Bucket start timestamps with duration factors.
+    pub buckets: Vec<(Timestamp, f64)>,
+}
+
+/// Provides way to calculate transport costs which depend on departure time using traffic profiles.
+pub struct TrafficTransportCost {
+    profiles: HashMap<usize, Vec<(Timestamp, f64)>>,
+    inner: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TrafficTransportCost {
+    /// Creates a new instance of `TrafficTransportCost`.
+    pub fn new(profiles: Vec<TrafficProfile>, inner: Arc<dyn TransportCost + Send + Sync>) -> Result<Self, String> {
+        let profiles = profiles.into_iter().try_fold(HashMap::new(), |mut acc, profile| {
+            let mut buckets = profile.buckets;
+            buckets.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+            if buckets.iter().any(|(_, factor)| !factor.is_finite() || *factor <= 0.) {
+                return Err(format!(
+                    "traffic profile {} has non-positive or non-finite duration factor",
+                    profile.index
+                ));
+            }
+
+            if buckets.windows(2).any(|pair| compare_floats(pair[0].0, pair[1].0) == Ordering::Equal) {
+                return Err(format!("traffic profile {} has buckets with the same start time", profile.index));
+            }
+
+            if acc.insert(profile.index, buckets).is_some() {
+                return Err(format!("duplicate traffic profile: {}", profile.index));
+            }
+
+            Ok(acc)
+        })?;
+
+        Ok(Self { profiles, inner })
+    }
+
+    /// Returns duration factor for given profile at given time.
+    fn get_factor(&self, profile: &Profile, travel_time: TravelTime) -> f64 {
+        let timestamp = match travel_time {
+            TravelTime::Arrival(arrival) => arrival,
+            TravelTime::Departure(departure) => departure,
+        };
+
+        self.profiles
+            .get(&profile.index)
+            .and_then(|buckets| buckets.iter().rev().find(|(start, _)| *start <= timestamp))
+            .map_or(1., |(_, factor)| *factor)
+    }
+}
+
+impl TransportCost for TrafficTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration(route, from, to, travel_time) * self.get_factor(&route.actor.vehicle.profile, travel_time)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.inner.size()
+    }
+
+    fn has_profile(&self, profile_index: usize) -> bool {
+        self.inner.has_profile(profile_index)
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -347,6 +463,14 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
         self.distance_approx(&route.actor.vehicle.profile, from, to)
     }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.size)
+    }
+
+    fn has_profile(&self, profile_index: usize) -> bool {
+        profile_index < self.durations.len()
+    }
 }
 
 /// A time aware matrix costs.
@@ -461,6 +585,14 @@ impl TransportCost for TimeAwareMatrixTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
         self.interpolate_distance(&route.actor.vehicle.profile, from, to, travel_time)
     }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.size)
+    }
+
+    fn has_profile(&self, profile_index: usize) -> bool {
+        self.costs.contains_key(&profile_index)
+    }
 }
 
 fn create_reserved_times_func(reserved_times_index: ReservedTimesIndex) -> Result<ReservedTimesFunc, String> {
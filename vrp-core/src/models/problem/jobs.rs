@@ -82,6 +82,44 @@ pub struct Single {
     pub dimens: Dimensions,
 }
 
+/// Specifies a requirement to visit the same customer multiple times within planning horizon.
+pub struct VisitFrequency {
+    /// Amount of required visits.
+    pub visits: usize,
+    /// Minimum time between start times of any two visits.
+    pub min_spacing: Duration,
+}
+
+impl Single {
+    /// Creates given amount of visits using the same job details. Each visit is a separate job
+    /// which can be served by any vehicle, but start times of any two visits should be at least
+    /// `min_spacing` apart. Use `VisitFrequencyModule` to enforce this requirement.
+    pub fn new_visits(
+        places: Vec<Place>,
+        dimens: Dimensions,
+        visits: usize,
+        min_spacing: Duration,
+    ) -> Vec<Arc<Single>> {
+        let frequency = Arc::new(VisitFrequency { visits, min_spacing });
+
+        (0..visits)
+            .map(|_| {
+                let mut dimens = dimens.clone();
+                dimens.set_value(VISIT_FREQUENCY_DIMENSION_KEY, frequency.clone());
+
+                Arc::new(Single { places: places.clone(), dimens })
+            })
+            .collect()
+    }
+
+    /// Returns visit frequency requirement shared by all visits of the same job.
+    pub fn visit_frequency(&self) -> Option<&Arc<VisitFrequency>> {
+        self.dimens.get_value::<Arc<VisitFrequency>>(VISIT_FREQUENCY_DIMENSION_KEY)
+    }
+}
+
+const VISIT_FREQUENCY_DIMENSION_KEY: &str = "visit_frequency";
+
 /// Represents a job which consists of multiple sub jobs.
 /// All of these jobs must be performed or none of them. Order can be controlled
 /// via specific dimension value.
@@ -179,6 +217,26 @@ impl Multi {
     }
 }
 
+/// A trait to get or set job fixed cost: a handling cost which is paid when the job is assigned,
+/// regardless of routing.
+pub trait JobFixedCostDimension {
+    /// Sets job fixed cost.
+    fn set_job_fixed_cost(&mut self, cost: Cost) -> &mut Self;
+    /// Gets job fixed cost if present.
+    fn get_job_fixed_cost(&self) -> Option<Cost>;
+}
+
+impl JobFixedCostDimension for Dimensions {
+    fn set_job_fixed_cost(&mut self, cost: Cost) -> &mut Self {
+        self.set_value("fixed_cost", cost);
+        self
+    }
+
+    fn get_job_fixed_cost(&self) -> Option<Cost> {
+        self.get_value("fixed_cost").cloned()
+    }
+}
+
 type JobIndex = HashMap<Job, (Vec<(Job, Cost)>, HashMap<Job, Cost>, Cost)>;
 
 /// Stores all jobs taking into account their neighborhood.
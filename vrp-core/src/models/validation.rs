@@ -0,0 +1,125 @@
+#[cfg(test)]
+#[path = "../../tests/unit/models/validation_test.rs"]
+mod validation_test;
+
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::Job;
+use crate::models::Problem;
+use std::fmt::{Display, Formatter};
+
+/// Specifies a problem definition inconsistency.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProblemError {
+    /// Fleet has no vehicles.
+    EmptyFleet,
+    /// Vehicle uses a profile which has no routing data.
+    UnknownProfile {
+        /// Vehicle id.
+        vehicle_id: Option<String>,
+        /// Profile index.
+        profile_index: usize,
+    },
+    /// Vehicle start or end location is outside of routing data.
+    VehicleLocationOutOfRange {
+        /// Vehicle id.
+        vehicle_id: Option<String>,
+        /// Location index.
+        location: Location,
+    },
+    /// Job location is outside of routing data.
+    JobLocationOutOfRange {
+        /// Job id.
+        job_id: Option<String>,
+        /// Location index.
+        location: Location,
+    },
+    /// Job has no place or time to be visited.
+    JobWithoutVisits {
+        /// Job id.
+        job_id: Option<String>,
+    },
+}
+
+impl Display for ProblemError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let id = |id: &Option<String>| id.clone().unwrap_or_else(|| "<no id>".to_string());
+
+        match self {
+            ProblemError::EmptyFleet => write!(f, "fleet has no vehicles"),
+            ProblemError::UnknownProfile { vehicle_id, profile_index } => {
+                write!(f, "vehicle '{}' uses unknown profile with index {}", id(vehicle_id), profile_index)
+            }
+            ProblemError::VehicleLocationOutOfRange { vehicle_id, location } => {
+                write!(f, "vehicle '{}' has location {} outside of routing data", id(vehicle_id), location)
+            }
+            ProblemError::JobLocationOutOfRange { job_id, location } => {
+                write!(f, "job '{}' has location {} outside of routing data", id(job_id), location)
+            }
+            ProblemError::JobWithoutVisits { job_id } => {
+                write!(f, "job '{}' has no place or time to visit", id(job_id))
+            }
+        }
+    }
+}
+
+impl Problem {
+    /// Checks problem definition consistency: non empty fleet, known profiles, location indices,
+    /// jobs which can be visited. Returns a list of all found errors.
+    pub fn validate(&self) -> Result<(), Vec<ProblemError>> {
+        let size = self.transport.size();
+        let is_out_of_range = |location: Location| matches!(size, Some(size) if location >= size);
+
+        let mut errors = vec![];
+
+        if self.fleet.vehicles.is_empty() {
+            errors.push(ProblemError::EmptyFleet);
+        }
+
+        self.fleet.vehicles.iter().for_each(|vehicle| {
+            let vehicle_id = vehicle.dimens.get_id().cloned();
+
+            if !self.transport.has_profile(vehicle.profile.index) {
+                errors.push(ProblemError::UnknownProfile {
+                    vehicle_id: vehicle_id.clone(),
+                    profile_index: vehicle.profile.index,
+                });
+            }
+
+            vehicle
+                .details
+                .iter()
+                .flat_map(|detail| detail.start.iter().chain(detail.end.iter()))
+                .map(|place| place.location)
+                .filter(|&location| is_out_of_range(location))
+                .for_each(|location| {
+                    errors.push(ProblemError::VehicleLocationOutOfRange { vehicle_id: vehicle_id.clone(), location })
+                });
+        });
+
+        self.jobs.all().for_each(|job| {
+            let job_id = job.dimens().get_id().cloned();
+
+            let has_visits = match &job {
+                Job::Single(single) => single.places.iter().any(|place| !place.times.is_empty()),
+                Job::Multi(multi) => {
+                    !multi.jobs.is_empty()
+                        && multi.jobs.iter().all(|single| single.places.iter().any(|place| !place.times.is_empty()))
+                }
+            };
+
+            if !has_visits {
+                errors.push(ProblemError::JobWithoutVisits { job_id: job_id.clone() });
+            }
+
+            job.places().filter_map(|place| place.location).filter(|&location| is_out_of_range(location)).for_each(
+                |location| errors.push(ProblemError::JobLocationOutOfRange { job_id: job_id.clone(), location }),
+            );
+        });
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
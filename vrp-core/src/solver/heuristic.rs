@@ -1,9 +1,11 @@
 use super::*;
 use crate::construction::heuristics::*;
-use crate::models::common::{has_multi_dim_demand, MultiDimLoad, SingleDimLoad};
+use crate::models::common::{has_multi_dim_demand, MultiDimLoad, SingleDimLoad, ValueDimension};
 use crate::models::problem::ProblemObjective;
+use crate::models::Extras;
 use crate::rosomaxa::get_default_selection_size;
 use crate::solver::heuristic::dynamic::create_inner_heuristic_operator;
+use crate::solver::objectives::has_work_balance;
 use crate::solver::search::*;
 use rosomaxa::algorithms::gsom::Input;
 use rosomaxa::hyper::*;
@@ -66,6 +68,24 @@ pub fn create_default_config_builder(
         .with_processing(create_default_processing())
 }
 
+/// Creates config builder for large problems: initial solutions are built by splitting jobs into
+/// given amount of geographical clusters and serving each cluster by separate vehicles, then default
+/// refinement improves them, e.g. by exchanging jobs between clusters.
+pub fn create_decomposition_config_builder(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    telemetry_mode: TelemetryMode,
+    cluster_count: usize,
+) -> ProblemConfigBuilder {
+    let recreate = Arc::new(RecreateWithClusters::new(cluster_count, environment.random.clone()));
+
+    create_default_config_builder(problem, environment, telemetry_mode).with_initial(
+        4,
+        0.05,
+        vec![(Box::new(RecreateInitialOperator::new(recreate)), 1)],
+    )
+}
+
 /// Creates default telemetry mode.B
 pub fn get_default_telemetry_mode(logger: InfoLogger) -> TelemetryMode {
     TelemetryMode::OnlyLogging { logger, log_best: 100, log_population: 1000, dump_population: false }
@@ -79,7 +99,8 @@ pub fn get_default_heuristic(problem: Arc<Problem>, environment: Arc<Environment
 /// Gets static heuristic using default settings.
 pub fn get_static_heuristic(problem: Arc<Problem>, environment: Arc<Environment>) -> TargetHeuristic {
     let default_operator = statik::create_default_heuristic_operator(problem.clone(), environment.clone());
-    let local_search = statik::create_default_local_search(environment.clone());
+    let local_search = statik::create_default_local_search(problem.as_ref(), environment.clone());
+    let (decompose_weight, local_search_weight, default_weight) = (0.05, 0.05, 1.);
 
     let heuristic_group: TargetHeuristicGroup = vec![
         (
@@ -87,16 +108,28 @@ pub fn get_static_heuristic(problem: Arc<Problem>, environment: Arc<Environment>
             create_context_operator_probability(
                 300,
                 10,
-                vec![(SelectionPhase::Exploration, 0.05), (SelectionPhase::Exploitation, 0.05)],
+                vec![(SelectionPhase::Exploration, decompose_weight), (SelectionPhase::Exploitation, decompose_weight)],
                 environment.random.clone(),
             ),
         ),
-        (local_search.clone(), create_scalar_operator_probability(0.05, environment.random.clone())),
-        (default_operator.clone(), create_scalar_operator_probability(1., environment.random.clone())),
-        (local_search, create_scalar_operator_probability(0.05, environment.random.clone())),
+        (local_search.clone(), create_scalar_operator_probability(local_search_weight, environment.random.clone())),
+        (default_operator.clone(), create_scalar_operator_probability(default_weight, environment.random.clone())),
+        (local_search, create_scalar_operator_probability(local_search_weight, environment.random.clone())),
+    ];
+    let search_weights = vec![
+        ("decompose-search".to_string(), decompose_weight),
+        ("local-search".to_string(), local_search_weight),
+        ("ruin-recreate".to_string(), default_weight),
+        ("local-search".to_string(), local_search_weight),
     ];
 
-    get_static_heuristic_from_heuristic_group(problem, environment, heuristic_group)
+    Box::new(
+        StaticSelective::<RefinementContext, ProblemObjective, InsertionContext>::new(
+            heuristic_group,
+            create_diversify_operators(problem, environment),
+        )
+        .with_search_weights(search_weights),
+    )
 }
 
 /// Gets static heuristic using heuristic group.
@@ -129,22 +162,100 @@ pub fn create_elitism_population(objective: Arc<ProblemObjective>, environment:
     Box::new(Elitism::new(objective, environment.random.clone(), 4, selection_size))
 }
 
+/// Revalidates all individuals of the population against given problem, e.g. after a constraint
+/// change. Jobs which violate hard constraints become unassigned, so affected individuals sink.
+/// Problem is expected to have the same jobs and fleet as the one used to create individuals.
+pub fn revalidate_population(population: &mut TargetPopulation, problem: Arc<Problem>) {
+    population.revalidate(&|insertion_ctx| insertion_ctx.revalidate(problem.clone()));
+}
+
+/// A trait to get or set activity weight used to calculate route medoids for gravity mean feature
+/// of the solution. When it is not set, all activities have the same weight.
+pub trait GravityWeightDimension {
+    /// Sets activity weight function.
+    fn set_gravity_weight(&mut self, activity_weight: ActivityWeightFn) -> &mut Self;
+    /// Gets activity weight function.
+    fn get_gravity_weight(&self) -> Option<&ActivityWeightFn>;
+}
+
+impl GravityWeightDimension for Extras {
+    fn set_gravity_weight(&mut self, activity_weight: ActivityWeightFn) -> &mut Self {
+        self.set_value("gravity_weight", activity_weight);
+        self
+    }
+
+    fn get_gravity_weight(&self) -> Option<&ActivityWeightFn> {
+        self.get_value("gravity_weight")
+    }
+}
+
+/// Extracts features of the solution which are used as weights to distinguish solutions in
+/// rosomaxa population.
+pub trait SolutionFeatures {
+    /// Returns feature values of the given solution. Amount of features should be the same for
+    /// all solutions of the problem.
+    fn extract(&self, insertion_ctx: &InsertionContext) -> Vec<f64>;
+}
+
+/// Extracts default solution features: various route statistics, total cost, amount of routes
+/// and unassigned jobs.
+#[derive(Default)]
+pub struct DefaultSolutionFeatures {}
+
+impl SolutionFeatures for DefaultSolutionFeatures {
+    fn extract(&self, insertion_ctx: &InsertionContext) -> Vec<f64> {
+        let gravity_mean = match insertion_ctx.problem.extras.get_gravity_weight() {
+            Some(activity_weight) => get_weighted_distance_gravity_mean(insertion_ctx, activity_weight),
+            None => get_distance_gravity_mean(insertion_ctx),
+        };
+
+        vec![
+            get_max_load_variance(insertion_ctx),
+            get_duration_mean(insertion_ctx),
+            get_distance_mean(insertion_ctx),
+            get_waiting_mean(insertion_ctx),
+            get_longest_distance_between_customers_mean(insertion_ctx),
+            get_average_distance_between_depot_customer_mean(insertion_ctx),
+            gravity_mean,
+            get_customers_deviation(insertion_ctx),
+            get_longest_distance_between_depot_customer_mean(insertion_ctx),
+            insertion_ctx.solution.get_total_cost(),
+            insertion_ctx.solution.routes.len() as f64,
+            insertion_ctx.solution.unassigned.len() as f64,
+        ]
+    }
+}
+
+/// A type alias for shared solution features extractor.
+pub type SolutionFeaturesFn = Arc<dyn SolutionFeatures + Send + Sync>;
+
+/// A trait to get or set solution features extractor. When it is not set, default solution
+/// features are used.
+pub trait SolutionFeaturesDimension {
+    /// Sets solution features extractor.
+    fn set_solution_features(&mut self, features: SolutionFeaturesFn) -> &mut Self;
+    /// Gets solution features extractor.
+    fn get_solution_features(&self) -> Option<&SolutionFeaturesFn>;
+}
+
+impl SolutionFeaturesDimension for Extras {
+    fn set_solution_features(&mut self, features: SolutionFeaturesFn) -> &mut Self {
+        self.set_value("solution_features", features);
+        self
+    }
+
+    fn get_solution_features(&self) -> Option<&SolutionFeaturesFn> {
+        self.get_value("solution_features")
+    }
+}
+
 impl RosomaxaWeighted for InsertionContext {
     fn init_weights(&mut self) {
-        let weights = vec![
-            get_max_load_variance(self),
-            get_duration_mean(self),
-            get_distance_mean(self),
-            get_waiting_mean(self),
-            get_longest_distance_between_customers_mean(self),
-            get_average_distance_between_depot_customer_mean(self),
-            get_distance_gravity_mean(self),
-            get_customers_deviation(self),
-            get_longest_distance_between_depot_customer_mean(self),
-            self.solution.get_total_cost(),
-            self.solution.routes.len() as f64,
-            self.solution.unassigned.len() as f64,
-        ];
+        let weights = match self.problem.extras.get_solution_features() {
+            Some(features) => features.extract(self),
+            None => DefaultSolutionFeatures::default().extract(self),
+        };
+
         self.solution.state.insert(SOLUTION_WEIGHTS_KEY, Arc::new(weights));
     }
 }
@@ -253,6 +364,22 @@ fn create_recreate_with_blinks(
     }
 }
 
+fn create_related_job_removal(problem: &Problem, limits: RuinLimits) -> Arc<dyn Ruin + Send + Sync> {
+    if has_multi_dim_demand(problem) {
+        Arc::new(RelatedJobRemoval::<MultiDimLoad>::new(RelatednessWeights::default(), limits))
+    } else {
+        Arc::new(RelatedJobRemoval::<SingleDimLoad>::new(RelatednessWeights::default(), limits))
+    }
+}
+
+/// Wraps local search operator to track its usage statistics under given name.
+fn create_counted_operator(
+    name: &str,
+    operator: Arc<dyn LocalOperator + Send + Sync>,
+) -> Arc<dyn LocalOperator + Send + Sync> {
+    Arc::new(CountedLocalOperator::new(name, operator))
+}
+
 fn create_diversify_operators(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
@@ -272,7 +399,7 @@ fn create_diversify_operators(
     let redistribute_search = Arc::new(RedistributeSearch::new(Arc::new(WeightedRecreate::new(recreates))));
     let infeasible_search = Arc::new(InfeasibleSearch::new(inner_search, 2, (0.05, 0.2), (0.05, 0.33)));
     let local_search = Arc::new(LocalSearch::new(Arc::new(CompositeLocalOperator::new(
-        vec![(Arc::new(ExchangeSequence::new(8, 0.25, 0.1)), 1)],
+        vec![(create_counted_operator("exchange_sequence", Arc::new(ExchangeSequence::new(8, 0.25, 0.1))), 1)],
         2,
         4,
     ))));
@@ -316,19 +443,22 @@ mod statik {
         ]));
 
         // initialize ruin
+        let adaptive_size = AdaptiveRuinSize::default();
+        let limits = RuinLimits::default().with_adaptive_size(adaptive_size.clone());
         let close_route = Arc::new(CloseRouteRemoval::default());
         let worst_route = Arc::new(WorstRouteRemoval::default());
         let random_route = Arc::new(RandomRouteRemoval::default());
-        let random_job = Arc::new(RandomJobRemoval::new(RuinLimits::default()));
+        let random_job = Arc::new(RandomJobRemoval::new(limits.clone()));
         let random_ruin = create_default_random_ruin();
 
         let ruin = Arc::new(WeightedRuin::new(vec![
             (vec![(Arc::new(AdjustedStringRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 100),
-            (vec![(Arc::new(NeighbourRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
-            (vec![(Arc::new(WorstJobRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
+            (vec![(Arc::new(NeighbourRemoval::new(limits.clone())), 1.), (random_ruin.clone(), 0.1)], 10),
+            (vec![(create_related_job_removal(problem.as_ref(), limits.clone()), 1.), (random_ruin.clone(), 0.1)], 10),
+            (vec![(Arc::new(WorstJobRemoval::new(4, limits.clone())), 1.), (random_ruin.clone(), 0.1)], 10),
             (
                 vec![
-                    (Arc::new(ClusterRemoval::new_with_defaults(problem, environment.clone())), 1.),
+                    (Arc::new(ClusterRemoval::new(problem.clone(), environment.clone(), 3, limits)), 1.),
                     (random_ruin, 0.1),
                 ],
                 5,
@@ -339,7 +469,10 @@ mod statik {
         ]));
 
         Arc::new(WeightedHeuristicOperator::new(
-            vec![Arc::new(RuinAndRecreate::new(ruin, recreate)), create_default_local_search(environment)],
+            vec![
+                Arc::new(RuinAndRecreate::new(ruin, recreate).with_adaptive_acceptance(adaptive_size)),
+                create_default_local_search(problem.as_ref(), environment),
+            ],
             vec![100, 10],
         ))
     }
@@ -355,18 +488,43 @@ mod statik {
     }
 
     /// Creates default local search operator.
-    pub fn create_default_local_search(environment: Arc<Environment>) -> TargetSearchOperator {
+    pub fn create_default_local_search(problem: &Problem, environment: Arc<Environment>) -> TargetSearchOperator {
         let random = environment.random.clone();
 
+        let spare_vehicle: Option<(Arc<dyn LocalOperator + Send + Sync>, usize)> = if has_work_balance(problem) {
+            Some((create_counted_operator("utilize_spare_vehicle", Arc::new(UtilizeSpareVehicle::default())), 20))
+        } else {
+            None
+        };
+
         Arc::new(LocalSearch::new(Arc::new(CompositeLocalOperator::new(
             vec![
-                (Arc::new(ExchangeSwapStar::new(random)), 200),
-                (Arc::new(ExchangeInterRouteBest::default()), 100),
-                (Arc::new(ExchangeSequence::default()), 100),
-                (Arc::new(ExchangeInterRouteRandom::default()), 30),
-                (Arc::new(ExchangeIntraRouteRandom::default()), 30),
-                (Arc::new(RescheduleDeparture::default()), 20),
-            ],
+                (create_counted_operator("swap_star", Arc::new(ExchangeSwapStar::new(random))), 200),
+                (
+                    create_counted_operator("exchange_inter_route_best", Arc::new(ExchangeInterRouteBest::default())),
+                    100,
+                ),
+                (create_counted_operator("exchange_sequence", Arc::new(ExchangeSequence::default())), 100),
+                (
+                    create_counted_operator(
+                        "exchange_inter_route_random",
+                        Arc::new(ExchangeInterRouteRandom::default()),
+                    ),
+                    30,
+                ),
+                (
+                    create_counted_operator(
+                        "exchange_intra_route_random",
+                        Arc::new(ExchangeIntraRouteRandom::default()),
+                    ),
+                    30,
+                ),
+                (create_counted_operator("reschedule_departure", Arc::new(RescheduleDeparture::default())), 20),
+                (create_counted_operator("redistribute_unassigned", Arc::new(RedistributeUnassigned::default())), 20),
+            ]
+            .into_iter()
+            .chain(spare_vehicle)
+            .collect(),
             1,
             2,
         ))))
@@ -400,6 +558,7 @@ mod dynamic {
         let ruins: Vec<(Arc<dyn Ruin + Send + Sync>, String)> = vec![
             (Arc::new(AdjustedStringRemoval::default()), "asr".to_string()),
             (Arc::new(NeighbourRemoval::default()), "neighbour_removal".to_string()),
+            (create_related_job_removal(problem.as_ref(), RuinLimits::default()), "related_job_removal".to_string()),
             (
                 Arc::new(ClusterRemoval::new_with_defaults(problem.clone(), environment.clone())),
                 "cluster_removal".to_string(),
@@ -420,30 +579,64 @@ mod dynamic {
             })
             .collect::<Vec<_>>();
 
+        let spare_vehicle: Option<(TargetSearchOperator, String)> = if has_work_balance(problem.as_ref()) {
+            Some((
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "utilize_spare_vehicle",
+                    Arc::new(UtilizeSpareVehicle::default()),
+                ))),
+                "local_utilize_spare_vehicle".to_string(),
+            ))
+        } else {
+            None
+        };
+
         let inner_search = create_inner_heuristic_operator(problem, environment);
 
         let mutations: Vec<(TargetSearchOperator, String)> = vec![
             (
-                Arc::new(LocalSearch::new(Arc::new(ExchangeInterRouteBest::default()))),
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "exchange_inter_route_best",
+                    Arc::new(ExchangeInterRouteBest::default()),
+                ))),
                 "local_exch_inter_route_best".to_string(),
             ),
             (
-                Arc::new(LocalSearch::new(Arc::new(ExchangeInterRouteRandom::default()))),
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "exchange_inter_route_random",
+                    Arc::new(ExchangeInterRouteRandom::default()),
+                ))),
                 "local_exch_inter_route_random".to_string(),
             ),
             (
-                Arc::new(LocalSearch::new(Arc::new(ExchangeIntraRouteRandom::default()))),
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "exchange_intra_route_random",
+                    Arc::new(ExchangeIntraRouteRandom::default()),
+                ))),
                 "local_exch_intra_route_random".to_string(),
             ),
             (
-                Arc::new(LocalSearch::new(Arc::new(RescheduleDeparture::default()))),
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "reschedule_departure",
+                    Arc::new(RescheduleDeparture::default()),
+                ))),
                 "local_reschedule_departure".to_string(),
             ),
             (Arc::new(DecomposeSearch::new(inner_search, (2, 4), 2)), "decompose_search".to_string()),
             (
-                Arc::new(LocalSearch::new(Arc::new(ExchangeSwapStar::new(random.clone())))),
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "swap_star",
+                    Arc::new(ExchangeSwapStar::new(random.clone())),
+                ))),
                 "local_swap_star".to_string(),
             ),
+            (
+                Arc::new(LocalSearch::new(create_counted_operator(
+                    "redistribute_unassigned",
+                    Arc::new(RedistributeUnassigned::default()),
+                ))),
+                "local_redistribute_unassigned".to_string(),
+            ),
         ];
 
         recreates
@@ -457,6 +650,7 @@ mod dynamic {
                 })
             })
             .chain(mutations.into_iter())
+            .chain(spare_vehicle)
             .collect::<Vec<_>>()
     }
 
@@ -492,6 +686,13 @@ mod dynamic {
         let ruin = Arc::new(WeightedRuin::new(vec![
             (vec![(Arc::new(AdjustedStringRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
             (vec![(Arc::new(NeighbourRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
+            (
+                vec![
+                    (create_related_job_removal(problem.as_ref(), RuinLimits::default()), 1.),
+                    (random_ruin.clone(), 0.1),
+                ],
+                10,
+            ),
             (vec![(Arc::new(WorstJobRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
             (
                 vec![
@@ -507,10 +708,22 @@ mod dynamic {
         // initialize local search
         let local_search = Arc::new(LocalSearch::new(Arc::new(CompositeLocalOperator::new(
             vec![
-                (Arc::new(ExchangeSwapStar::new(random)), 1),
-                (Arc::new(ExchangeInterRouteBest::default()), 1),
-                (Arc::new(ExchangeInterRouteRandom::default()), 1),
-                (Arc::new(ExchangeIntraRouteRandom::default()), 1),
+                (create_counted_operator("swap_star", Arc::new(ExchangeSwapStar::new(random))), 1),
+                (create_counted_operator("exchange_inter_route_best", Arc::new(ExchangeInterRouteBest::default())), 1),
+                (
+                    create_counted_operator(
+                        "exchange_inter_route_random",
+                        Arc::new(ExchangeInterRouteRandom::default()),
+                    ),
+                    1,
+                ),
+                (
+                    create_counted_operator(
+                        "exchange_intra_route_random",
+                        Arc::new(ExchangeIntraRouteRandom::default()),
+                    ),
+                    1,
+                ),
             ],
             1,
             1,
@@ -90,22 +90,29 @@
 //! [`Solver`]: ./struct.Solver.html
 //!
 
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
+
 extern crate rand;
 
-use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::{finalize_insertion_ctx, InsertionContext};
 use crate::models::common::Cost;
 use crate::models::problem::ProblemObjective;
 use crate::models::{Problem, Solution};
-use crate::solver::search::Recreate;
+use crate::solver::search::{LocalOperatorCounters, Recreate, RecreateWithCheapest};
 use hashbrown::HashMap;
 use rosomaxa::evolution::*;
 use rosomaxa::prelude::*;
-use rosomaxa::{get_default_population, DynHeuristicPopulation, TelemetryHeuristicContext};
+use rosomaxa::{
+    get_default_population, CheckpointCallback, DynHeuristicPopulation, NewBestCallback, TelemetryHeuristicContext,
+};
 use std::any::Any;
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 pub use self::heuristic::*;
+pub use self::savings_construction::SavingsConstruction;
 use rosomaxa::population::Rosomaxa;
 use rosomaxa::utils::Timer;
 
@@ -114,6 +121,7 @@ pub mod processing;
 pub mod search;
 
 mod heuristic;
+mod savings_construction;
 
 /// A key to store solution order information.
 const SOLUTION_ORDER_KEY: i32 = 1;
@@ -125,6 +133,9 @@ const BALANCE_MAX_LOAD_KEY: i32 = 20;
 const BALANCE_ACTIVITY_KEY: i32 = 21;
 const BALANCE_DISTANCE_KEY: i32 = 22;
 const BALANCE_DURATION_KEY: i32 = 23;
+const BALANCE_JOB_COUNT_KEY: i32 = 25;
+/// A key for minimum route utilization objective.
+const MIN_ROUTE_UTILIZATION_KEY: i32 = 24;
 
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
@@ -136,6 +147,8 @@ pub struct RefinementContext {
     pub state: HashMap<String, Box<dyn Any + Sync + Send>>,
     /// Provides some basic implementation of context functionality.
     inner_context: TelemetryHeuristicContext<ProblemObjective, InsertionContext>,
+    /// Keeps track of local search operators usage.
+    operator_counters: Arc<LocalOperatorCounters>,
 }
 
 /// Defines instant refinement speed type.
@@ -158,7 +171,37 @@ impl RefinementContext {
     ) -> Self {
         let inner_context =
             TelemetryHeuristicContext::new(problem.objective.clone(), population, telemetry_mode, environment.clone());
-        Self { problem, environment, inner_context, state: Default::default() }
+        Self { problem, environment, inner_context, state: Default::default(), operator_counters: Default::default() }
+    }
+
+    /// Returns usage counters of local search operators.
+    pub fn operator_counters(&self) -> &LocalOperatorCounters {
+        self.operator_counters.as_ref()
+    }
+
+    /// Shares usage counters of local search operators with another context, e.g. with the one
+    /// used to refine a part of the solution.
+    pub(crate) fn with_operator_counters_of(mut self, other: &RefinementContext) -> Self {
+        self.operator_counters = other.operator_counters.clone();
+        self
+    }
+
+    /// Sets a callback which is called with a copy of the best known solution when it is improved.
+    /// Calls are made not more often than once per given interval in milliseconds.
+    pub fn with_new_best_callback(
+        mut self,
+        callback: NewBestCallback<InsertionContext>,
+        min_interval_ms: usize,
+    ) -> Self {
+        self.inner_context = self.inner_context.with_new_best_callback(callback, min_interval_ms);
+        self
+    }
+
+    /// Sets a callback which is called with a copy of the best known solution once amount of completed
+    /// generations reaches one of the given checkpoints.
+    pub fn with_checkpoints(mut self, generations: Vec<usize>, callback: CheckpointCallback<InsertionContext>) -> Self {
+        self.inner_context = self.inner_context.with_checkpoints(generations, callback);
+        self
     }
 
     /// Adds solution to population.
@@ -196,7 +239,11 @@ impl HeuristicContext for RefinementContext {
     }
 
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
-        self.inner_context.on_result()
+        let operators = self.operator_counters.get_statistics();
+
+        self.inner_context
+            .on_result()
+            .map(|(population, metrics)| (population, metrics.map(|metrics| TelemetryMetrics { operators, ..metrics })))
     }
 }
 
@@ -235,11 +282,134 @@ impl InitialOperator for RecreateInitialOperator {
     type Solution = InsertionContext;
 
     fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution {
-        let insertion_ctx = InsertionContext::new(heuristic_ctx.problem.clone(), heuristic_ctx.environment.clone());
-        self.recreate.run(heuristic_ctx, insertion_ctx)
+        create_initial_solution(heuristic_ctx, heuristic_ctx.environment.clone(), |insertion_ctx| {
+            self.recreate.run(heuristic_ctx, insertion_ctx)
+        })
+    }
+
+    fn create_with_random(
+        &self,
+        heuristic_ctx: &Self::Context,
+        random: Arc<dyn Random + Send + Sync>,
+    ) -> Self::Solution {
+        create_initial_solution(heuristic_ctx, with_random(heuristic_ctx, random), |insertion_ctx| {
+            self.recreate.run(heuristic_ctx, insertion_ctx)
+        })
+    }
+}
+
+/// Specifies a construction heuristic which builds an initial solution from scratch. It can be used
+/// to inject a domain specific seeding logic into initial phase of the search.
+pub trait ConstructionHeuristic {
+    /// Constructs a new solution from the given empty one.
+    fn construct(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
+}
+
+/// Any recreate method can be used as construction heuristic.
+impl<T: Recreate + ?Sized> ConstructionHeuristic for T {
+    fn construct(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.run(refinement_ctx, insertion_ctx)
     }
 }
 
+/// Gets default construction heuristic which is based on cheapest insertion.
+pub fn get_default_construction_heuristic(environment: &Environment) -> Arc<dyn ConstructionHeuristic + Send + Sync> {
+    Arc::new(RecreateWithCheapest::new(environment.random.clone()))
+}
+
+/// Wraps construction heuristic as `InitialOperator`.
+pub struct ConstructionInitialOperator {
+    construction: Arc<dyn ConstructionHeuristic + Send + Sync>,
+}
+
+impl ConstructionInitialOperator {
+    /// Creates a new instance of `ConstructionInitialOperator`.
+    pub fn new(construction: Arc<dyn ConstructionHeuristic + Send + Sync>) -> Self {
+        Self { construction }
+    }
+}
+
+impl InitialOperator for ConstructionInitialOperator {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution {
+        create_initial_solution(heuristic_ctx, heuristic_ctx.environment.clone(), |insertion_ctx| {
+            self.construction.construct(heuristic_ctx, insertion_ctx)
+        })
+    }
+
+    fn create_with_random(
+        &self,
+        heuristic_ctx: &Self::Context,
+        random: Arc<dyn Random + Send + Sync>,
+    ) -> Self::Solution {
+        create_initial_solution(heuristic_ctx, with_random(heuristic_ctx, random), |insertion_ctx| {
+            self.construction.construct(heuristic_ctx, insertion_ctx)
+        })
+    }
+}
+
+/// Creates an initial solution within given environment using construction function.
+fn create_initial_solution<F>(
+    heuristic_ctx: &RefinementContext,
+    environment: Arc<Environment>,
+    construct: F,
+) -> InsertionContext
+where
+    F: FnOnce(InsertionContext) -> InsertionContext,
+{
+    create_degenerate_solution(heuristic_ctx.problem.clone(), environment.clone())
+        .unwrap_or_else(|| construct(InsertionContext::new(heuristic_ctx.problem.clone(), environment)))
+}
+
+/// Returns a copy of context environment which uses given random generator.
+fn with_random(heuristic_ctx: &RefinementContext, random: Arc<dyn Random + Send + Sync>) -> Arc<Environment> {
+    Arc::new(Environment { random, ..heuristic_ctx.environment.as_ref().clone() })
+}
+
+/// Returns a trivial solution of a degenerate problem which has no jobs or no actors to serve them:
+/// all jobs are unassigned. Returns `None` if problem is not degenerate.
+fn create_degenerate_solution(problem: Arc<Problem>, environment: Arc<Environment>) -> Option<InsertionContext> {
+    if problem.jobs.size() > 0 && !problem.fleet.actors.is_empty() {
+        return None;
+    }
+
+    let mut insertion_ctx = InsertionContext::new(problem, environment);
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    Some(insertion_ctx)
+}
+
+/// A result of solver run which includes best known solutions collected at generation checkpoints.
+pub type SolverCheckpointResult =
+    Result<(Solution, Cost, Option<TelemetryMetrics>, Vec<(usize, InsertionContext)>), String>;
+
+/// A snapshot of effective solver parameters which makes a solver run self-documenting.
+///
+/// Learned parameters, such as dynamic operator weights, are not fixed before the run and are not
+/// included.
+#[derive(Clone, Debug)]
+pub struct SolverParameters {
+    /// Settings of the population, if it reports them.
+    pub population: Option<PopulationSettings>,
+    /// Settings of the hyper heuristic, if it reports them.
+    pub heuristic: Option<HeuristicSettings>,
+    /// Settings of termination criteria.
+    pub termination: Vec<TerminationSettings>,
+    /// A seed of random generator, if it is created with it.
+    pub seed: Option<u64>,
+    /// Amount of available cpus.
+    pub available_cpus: usize,
+    /// Amount of thread pools used for data parallelism.
+    pub thread_pool_size: usize,
+    /// True if experimental behavior is allowed.
+    pub is_experimental: bool,
+    /// True if execution quota is set.
+    pub has_quota: bool,
+}
+
 /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
 /// or error description, if solution cannot be found.
 ///
@@ -291,15 +461,71 @@ impl Solver {
     }
 
     /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
-    /// or error description, if solution cannot be found.
+    /// or error description, if solution cannot be found or problem is not valid.
     pub fn solve(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
+        self.solve_with_checkpoints(vec![]).map(|(solution, cost, metrics, _)| (solution, cost, metrics))
+    }
+
+    /// Solves a Vehicle Routing Problem as `solve` does, but additionally returns effective solver
+    /// parameters, so the result can be reproduced later.
+    pub fn solve_with_parameters(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>, SolverParameters), String> {
+        let parameters = self.get_parameters();
+
+        self.solve().map(|(solution, cost, metrics)| (solution, cost, metrics, parameters))
+    }
+
+    /// Returns effective solver parameters taken from its config.
+    pub fn get_parameters(&self) -> SolverParameters {
+        let context = &self.config.context;
+        let environment = context.environment.as_ref();
+
+        SolverParameters {
+            population: context.population().settings(),
+            heuristic: self.config.heuristic.settings(),
+            termination: self.config.termination.settings(),
+            seed: environment.random.seed(),
+            available_cpus: environment.parallelism.available_cpus(),
+            thread_pool_size: environment.parallelism.thread_pool_size(),
+            is_experimental: environment.is_experimental,
+            has_quota: environment.quota.is_some(),
+        }
+    }
+
+    /// Solves a Vehicle Routing Problem as `solve` does, but additionally returns copies of the best
+    /// known solution taken once amount of completed generations reaches one of the given checkpoints.
+    /// Checkpoints which are not reached due to termination are omitted.
+    pub fn solve_with_checkpoints(self, generations: Vec<usize>) -> SolverCheckpointResult {
         self.config.context.environment.logger.deref()(&format!(
             "total jobs: {}, actors: {}",
             self.problem.jobs.size(),
             self.problem.fleet.actors.len()
         ));
 
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        // NOTE there is nothing to search for, so skip evolution and return the trivial solution. This
+        // happens before validation as an empty fleet is reported there as an error.
+        let environment = self.config.context.environment.clone();
+        if let Some(insertion_ctx) = create_degenerate_solution(self.problem.clone(), environment) {
+            let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
+            let cost = self.problem.objective.fitness(&insertion_ctx);
+
+            return Ok((solution, cost, None, vec![]));
+        }
+
+        self.problem.validate().map_err(|errors| {
+            format!("invalid problem: {}", errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join(", "))
+        })?;
+
+        let checkpoints = Arc::new(Mutex::new(Vec::new()));
+        let mut config = self.config;
+        if !generations.is_empty() {
+            let sink = checkpoints.clone();
+            config.context = config.context.with_checkpoints(
+                generations,
+                Arc::new(move |generation, insertion_ctx| sink.lock().unwrap().push((generation, insertion_ctx))),
+            );
+        }
+
+        let (mut solutions, metrics) = EvolutionSimulator::new(config)?.run()?;
 
         // NOTE select the first best individual from population
         let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
@@ -307,7 +533,8 @@ impl Solver {
 
         let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
         let cost = self.problem.objective.fitness(&insertion_ctx);
+        let checkpoints = std::mem::take(&mut *checkpoints.lock().unwrap());
 
-        Ok((solution, cost, metrics))
+        Ok((solution, cost, metrics, checkpoints))
     }
 }
@@ -0,0 +1,63 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/capacity_overflow_test.rs"]
+mod capacity_overflow_test;
+
+use crate::construction::constraints::*;
+use crate::construction::extensions::MultiTrip;
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::{CapacityDimension, Cost, LoadOps};
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+/// An objective function which minimizes total penalty of excessive load on soft capacity dimensions,
+/// see `CapacityMode::Soft`. Excessive load is measured at the load peak of each tour interval
+/// between reloads, the same way as `CapacityConstraintModule` estimates insertion cost.
+pub struct CapacityOverflow<T: LoadOps> {
+    penalties: Vec<(usize, f64)>,
+    multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+}
+
+impl<T: LoadOps> CapacityOverflow<T> {
+    /// Creates a new instance of `CapacityOverflow` using the same multi trip and capacity modes
+    /// as capacity constraint module.
+    pub fn new(multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>, modes: &[CapacityMode]) -> Self {
+        Self { penalties: get_soft_penalties(modes), multi_trip }
+    }
+
+    fn get_route_penalty(&self, route_ctx: &RouteContext) -> Cost {
+        let capacity: &T = match route_ctx.route.actor.vehicle.dimens.get_capacity() {
+            Some(capacity) => capacity,
+            None => return 0.,
+        };
+
+        let intervals = self
+            .multi_trip
+            .get_marker_intervals(route_ctx)
+            .cloned()
+            .unwrap_or_else(|| vec![(0, route_ctx.route.tour.total() - 1)]);
+
+        intervals
+            .into_iter()
+            .filter_map(|(start_idx, _)| route_ctx.route.tour.get(start_idx))
+            .filter_map(|activity| route_ctx.state.get_activity_state::<T>(MAX_FUTURE_CAPACITY_KEY, activity))
+            .map(|peak| {
+                self.penalties
+                    .iter()
+                    .map(|&(idx, penalty)| (peak.dimension(idx) - capacity.dimension(idx)).max(0) as f64 * penalty)
+                    .sum::<Cost>()
+            })
+            .sum()
+    }
+}
+
+impl<T: LoadOps> Objective for CapacityOverflow<T> {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        if self.penalties.is_empty() {
+            return 0.;
+        }
+
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_penalty(route_ctx)).sum()
+    }
+}
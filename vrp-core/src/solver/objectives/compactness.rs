@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/compactness_test.rs"]
+mod compactness_test;
+
+use super::*;
+use crate::construction::heuristics::{get_medoid, RouteContext};
+use crate::models::problem::{TargetObjective, TransportCost};
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+/// An objective function which penalizes route sprawl: it prefers solutions where route stops are
+/// kept close to their route medoid even when total distance is the same.
+pub struct Compactness {
+    weight: f64,
+}
+
+impl Compactness {
+    /// Creates an objective to minimize routes dispersion multiplied by given weight.
+    pub fn minimize(weight: f64) -> TargetObjective {
+        assert!(weight > 0.);
+
+        Arc::new(Self { weight })
+    }
+}
+
+impl Objective for Compactness {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let transport = solution.problem.transport.as_ref();
+
+        let total = solution
+            .solution
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route.tour.has_jobs())
+            .map(|route_ctx| get_route_dispersion(route_ctx, transport))
+            .sum::<f64>();
+
+        self.weight * total
+    }
+}
+
+/// Returns an average distance from route activities to the route medoid.
+fn get_route_dispersion(route_ctx: &RouteContext, transport: &(dyn TransportCost + Send + Sync)) -> f64 {
+    let profile = &route_ctx.route.actor.vehicle.profile;
+    let tour = &route_ctx.route.tour;
+
+    get_medoid(route_ctx, transport).map_or(0., |medoid| {
+        let total = tour
+            .all_activities()
+            .map(|activity| transport.distance_approx(profile, medoid, activity.place.location))
+            .sum::<f64>();
+
+        total / tour.total() as f64
+    })
+}
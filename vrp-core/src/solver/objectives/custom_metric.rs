@@ -0,0 +1,39 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/custom_metric_test.rs"]
+mod custom_metric_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::{TargetConstraint, TargetObjective, TransportCost};
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+/// An objective function which minimizes total value of a custom metric accumulated along routes,
+/// e.g. CO2 emissions. See `CustomMetricModule` for details.
+pub struct TotalCustomMetric {
+    state_key: i32,
+}
+
+impl TotalCustomMetric {
+    /// Creates _(constraint, objective)_ type pair which provides logic to minimize the custom metric.
+    /// The metric of each route is limited by `limit_fn`, if it returns a value for route's actor.
+    pub fn minimize(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        leg_fn: LegMetricFn,
+        limit_fn: MetricLimitFn,
+        state_key: i32,
+        code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = CustomMetricModule::new_minimized(transport, leg_fn, limit_fn, state_key, code);
+
+        (Arc::new(constraint), Arc::new(Self { state_key }))
+    }
+}
+
+impl Objective for TotalCustomMetric {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| get_route_metric(route_ctx, self.state_key)).sum()
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/min_route_utilization_test.rs"]
+mod min_route_utilization_test;
+
+use crate::construction::constraints::MAX_LOAD_KEY;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::problem::{TargetConstraint, TargetObjective};
+use crate::solver::objectives::{GenericValue, RouteValueFn};
+use crate::solver::MIN_ROUTE_UTILIZATION_KEY;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A type which discourages routes with too small load or amount of jobs, e.g. a route opened
+/// for a single job far from everything else. Route utilization is specified by shortage function
+/// which returns zero when route is utilized enough and positive value, normally in (0, 1], otherwise.
+pub struct MinRouteUtilization {}
+
+impl MinRouteUtilization {
+    /// Creates _(constraint, objective)_ type pair which penalizes underutilized routes.
+    /// Use `MinRouteUtilizationRejection` post processing to reject such routes as a hard rule.
+    pub fn new_penalized(shortage_fn: RouteValueFn) -> (TargetConstraint, TargetObjective) {
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new(|source, _| Ok(source)),
+            shortage_fn.clone(),
+            Arc::new(move |ctx: &SolutionContext| {
+                ctx.routes
+                    .iter()
+                    .filter(|route_ctx| route_ctx.route.tour.has_jobs())
+                    .map(|route_ctx| shortage_fn.deref()(route_ctx))
+                    .sum()
+            }),
+            Arc::new(|solution_ctx, route_ctx, _, value| {
+                // NOTE penalize only opening of a new route: insertion into existing one reduces shortage
+                if route_ctx.route.tour.has_jobs() {
+                    0.
+                } else {
+                    value * solution_ctx.get_max_cost()
+                }
+            }),
+            MIN_ROUTE_UTILIZATION_KEY,
+        )
+    }
+
+    /// Creates a shortage function which requires at least `min_jobs` job activities in the route.
+    pub fn create_job_count_shortage(min_jobs: usize) -> RouteValueFn {
+        Arc::new(move |route_ctx: &RouteContext| {
+            let count = route_ctx.route.tour.job_activity_count();
+
+            if count >= min_jobs {
+                0.
+            } else {
+                (min_jobs - count) as f64 / min_jobs as f64
+            }
+        })
+    }
+
+    /// Creates a shortage function which requires max load of the route to be at least `min_ratio`
+    /// of vehicle capacity. Relies on route state maintained by capacity constraint module.
+    pub fn create_load_shortage(min_ratio: f64) -> RouteValueFn {
+        Arc::new(move |route_ctx: &RouteContext| {
+            let ratio = route_ctx.state.get_route_state::<f64>(MAX_LOAD_KEY).cloned().unwrap_or(0.);
+
+            if ratio >= min_ratio {
+                0.
+            } else {
+                (min_ratio - ratio) / min_ratio
+            }
+        })
+    }
+}
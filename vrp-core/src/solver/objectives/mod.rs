@@ -3,12 +3,33 @@
 use crate::construction::heuristics::InsertionContext;
 use std::cmp::Ordering;
 
+mod capacity_overflow;
+pub use self::capacity_overflow::CapacityOverflow;
+
+mod compactness;
+pub use self::compactness::Compactness;
+
+mod custom_metric;
+pub use self::custom_metric::TotalCustomMetric;
+
 mod generic_value;
 pub use self::generic_value::*;
 
+mod min_route_utilization;
+pub use self::min_route_utilization::*;
+
 mod minimize_arrival_time;
 pub use self::minimize_arrival_time::*;
 
+mod overtime;
+pub use self::overtime::{Overtime, OvertimeFn};
+
+mod profit;
+pub use self::profit::ProfitObjective;
+
+mod ranking;
+pub use self::ranking::rank_solutions;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
@@ -16,7 +37,7 @@ mod total_transport;
 pub use self::total_transport::*;
 
 mod total_unassigned_jobs;
-pub use self::total_unassigned_jobs::TotalUnassignedJobs;
+pub use self::total_unassigned_jobs::{MandatoryJobFn, TotalUnassignedJobs};
 
 mod total_value;
 pub use self::total_value::*;
@@ -25,4 +46,5 @@ mod tour_order;
 pub use self::tour_order::*;
 
 mod work_balance;
-pub use self::work_balance::WorkBalance;
+pub(crate) use self::work_balance::has_work_balance;
+pub use self::work_balance::{JobCountBalance, WorkBalance};
@@ -0,0 +1,141 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/overtime_test.rs"]
+mod overtime_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Timestamp};
+use crate::models::problem::{ActivityCost, Actor, Job, TargetConstraint, TargetObjective, TransportCost, TravelTime};
+use rosomaxa::prelude::*;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns shift end and overtime cost per time unit for given actor.
+/// Actors without overtime rules should get `None`.
+pub type OvertimeFn = Arc<dyn Fn(&Actor) -> Option<(Timestamp, Cost)> + Send + Sync>;
+
+/// An objective function which minimizes total overtime cost: time spent by each route after its
+/// shift end multiplied by overtime rate of the route's actor. Unlike time window constraint,
+/// late finishes are allowed, but penalized. The fitness is measured in cost units, so the objective
+/// can be used together with total cost minimization.
+pub struct Overtime {
+    overtime_fn: OvertimeFn,
+}
+
+impl Overtime {
+    /// Creates _(constraint, objective)_ type pair which provides logic to minimize overtime cost.
+    /// The constraint has no hard rules: it only guides insertion by estimating extra overtime.
+    pub fn minimize(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        overtime_fn: OvertimeFn,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = OvertimeModule {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(OvertimeSoftActivityConstraint {
+                transport,
+                activity,
+                overtime_fn: overtime_fn.clone(),
+            }))],
+            keys: vec![],
+        };
+
+        (Arc::new(constraint), Arc::new(Overtime { overtime_fn }))
+    }
+}
+
+impl Objective for Overtime {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                route_ctx
+                    .route
+                    .tour
+                    .end()
+                    .map(|end| get_overtime_cost(&self.overtime_fn, route_ctx, end.schedule.arrival))
+            })
+            .sum()
+    }
+}
+
+struct OvertimeModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for OvertimeModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct OvertimeSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    overtime_fn: OvertimeFn,
+}
+
+impl SoftActivityConstraint for OvertimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let end = match route.tour.end() {
+            Some(end) => end,
+            None => return 0.,
+        };
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+
+        // NOTE delay of the next activity is propagated to the route end, but can be absorbed by waiting
+        let new_end_arrival = if let Some(next) = activity_ctx.next {
+            let departure = self.activity.estimate_departure(route, target, arrival);
+            let next_arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let waiting = *route_ctx.state.get_activity_state(WAITING_KEY, next).unwrap_or(&0.);
+            let delay = (next_arrival - next.schedule.arrival - waiting).max(0.);
+
+            end.schedule.arrival + delay
+        } else {
+            arrival
+        };
+
+        get_overtime_cost(&self.overtime_fn, route_ctx, new_end_arrival)
+            - get_overtime_cost(&self.overtime_fn, route_ctx, end.schedule.arrival)
+    }
+}
+
+fn get_overtime_cost(overtime_fn: &OvertimeFn, route_ctx: &RouteContext, end_arrival: Timestamp) -> Cost {
+    overtime_fn.deref()(route_ctx.route.actor.as_ref())
+        .map_or(0., |(shift_end, rate)| (end_arrival - shift_end).max(0.) * rate)
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/profit_test.rs"]
+mod profit_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::{
+    Job, JobFixedCostDimension, TargetConstraint, TargetObjective, TransportCost, TravelTime,
+};
+use crate::solver::objectives::SimpleValueFn;
+use rosomaxa::prelude::*;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// An objective function which maximizes net profit: total revenue of served jobs minus total cost
+/// including fixed costs of served jobs (see `JobFixedCostDimension`). It is supposed to be used
+/// instead of cost and unassigned jobs minimization, so jobs which cost more to serve than they
+/// yield are left unassigned.
+pub struct ProfitObjective {
+    revenue_fn: SimpleValueFn,
+}
+
+impl ProfitObjective {
+    /// Creates _(constraint, objective)_ type pair which provides logic to maximize net profit.
+    /// The constraint rejects insertions of jobs which marginal transport cost together with job
+    /// fixed cost exceeds their revenue with given code.
+    pub fn maximize(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        revenue_fn: SimpleValueFn,
+        code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = ProfitModule {
+            constraints: vec![
+                ConstraintVariant::HardActivity(Arc::new(ProfitHardActivityConstraint {
+                    transport,
+                    revenue_fn: revenue_fn.clone(),
+                    code,
+                })),
+                ConstraintVariant::SoftRoute(Arc::new(ProfitSoftRouteConstraint { revenue_fn: revenue_fn.clone() })),
+            ],
+            keys: vec![],
+        };
+
+        (Arc::new(constraint), Arc::new(ProfitObjective { revenue_fn }))
+    }
+}
+
+impl Objective for ProfitObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let (revenue, fixed_cost) =
+            solution.solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).fold(
+                (0., 0.),
+                |(revenue, fixed_cost), job| {
+                    (revenue + self.revenue_fn.deref()(&job), fixed_cost + get_job_fixed_cost(&job))
+                },
+            );
+
+        solution.solution.get_total_cost() + fixed_cost - revenue
+    }
+}
+
+struct ProfitModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for ProfitModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Rejects a single job when its detour and fixed cost are higher than its revenue.
+struct ProfitHardActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    revenue_fn: SimpleValueFn,
+    code: i32,
+}
+
+impl HardActivityConstraint for ProfitHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        // NOTE revenue of a multi job cannot be attributed to its single activity
+        let job = match activity_ctx.target.retrieve_job() {
+            Some(job @ Job::Single(_)) => job,
+            _ => return None,
+        };
+
+        let route = route_ctx.route.as_ref();
+        let travel_time = TravelTime::Departure(activity_ctx.prev.schedule.departure);
+        let cost = |from, to| -> Cost { self.transport.cost(route, from, to, travel_time) };
+
+        let prev = activity_ctx.prev.place.location;
+        let target = activity_ctx.target.place.location;
+        let detour = match activity_ctx.next {
+            Some(next) => cost(prev, target) + cost(target, next.place.location) - cost(prev, next.place.location),
+            None => cost(prev, target),
+        };
+
+        if compare_floats(detour + get_job_fixed_cost(&job), self.revenue_fn.deref()(&job))
+            == std::cmp::Ordering::Greater
+        {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Makes insertion of jobs with higher revenue and lower fixed cost more preferable.
+struct ProfitSoftRouteConstraint {
+    revenue_fn: SimpleValueFn,
+}
+
+impl SoftRouteConstraint for ProfitSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> f64 {
+        get_job_fixed_cost(job) - self.revenue_fn.deref()(job)
+    }
+}
+
+fn get_job_fixed_cost(job: &Job) -> Cost {
+    job.dimens().get_job_fixed_cost().unwrap_or(0.)
+}
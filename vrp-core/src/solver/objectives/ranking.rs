@@ -0,0 +1,22 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/ranking_test.rs"]
+mod ranking_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::Problem;
+use rosomaxa::prelude::*;
+
+/// Ranks given solutions using problem's objective, e.g. to compare solutions produced externally
+/// with ones found by the solver. Returns solution indices with their fitness values per each
+/// objective, sorted from the best to the worst. Equal solutions keep their original order.
+pub fn rank_solutions(problem: &Problem, solutions: &[InsertionContext]) -> Vec<(usize, Vec<f64>)> {
+    let objective = problem.objective.as_ref();
+
+    let mut ranked = solutions.iter().enumerate().collect::<Vec<_>>();
+    ranked.sort_by(|(_, a), (_, b)| objective.total_order(a, b));
+
+    ranked
+        .into_iter()
+        .map(|(idx, solution)| (idx, objective.objectives().map(|objective| objective.fitness(solution)).collect()))
+        .collect()
+}
@@ -12,15 +12,35 @@ use std::sync::Arc;
 /// A type which allows to control how job is estimated in objective fitness
 pub type UnassignedJobEstimator = Arc<dyn Fn(&InsertionContext, &Job, &UnassignmentInfo) -> f64 + Send + Sync>;
 
+/// A type which specifies whether job is mandatory, i.e. has to be assigned.
+pub type MandatoryJobFn = Arc<dyn Fn(&Job) -> bool + Send + Sync>;
+
 /// An objective function which minimizes amount of unassigned jobs as a target.
 pub struct TotalUnassignedJobs {
     unassigned_job_estimator: UnassignedJobEstimator,
+    mandatory_job_fn: Option<MandatoryJobFn>,
 }
 
 impl TotalUnassignedJobs {
     /// Creates a new instance of `TotalUnassignedJobs`.
     pub fn new(unassigned_job_estimator: UnassignedJobEstimator) -> Self {
-        Self { unassigned_job_estimator }
+        Self { unassigned_job_estimator, mandatory_job_fn: None }
+    }
+
+    /// Creates a new instance of `TotalUnassignedJobs` which treats a solution with unassigned
+    /// mandatory jobs as worse than any solution with less of them, regardless of estimated fitness.
+    pub fn new_with_mandatory(
+        unassigned_job_estimator: UnassignedJobEstimator,
+        mandatory_job_fn: MandatoryJobFn,
+    ) -> Self {
+        Self { unassigned_job_estimator, mandatory_job_fn: Some(mandatory_job_fn) }
+    }
+
+    /// Returns amount of unassigned mandatory jobs.
+    fn get_unassigned_mandatory(&self, solution: &<TotalUnassignedJobs as Objective>::Solution) -> usize {
+        self.mandatory_job_fn.as_ref().map_or(0, |mandatory_job_fn| {
+            solution.solution.unassigned.keys().filter(|job| mandatory_job_fn.deref()(job)).count()
+        })
     }
 
     /// Checks the edge case when at least one solution has no routes and amount of unassigned is
@@ -49,6 +69,11 @@ impl Objective for TotalUnassignedJobs {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        match self.get_unassigned_mandatory(a).cmp(&self.get_unassigned_mandatory(b)) {
+            Ordering::Equal => {}
+            order => return order,
+        }
+
         let fitness_a = self.fitness(a);
         let fitness_b = self.fitness(b);
 
@@ -61,11 +86,15 @@ impl Objective for TotalUnassignedJobs {
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
+        // NOTE penalize each unassigned mandatory job as heavily as leaving all jobs unassigned
+        let mandatory_penalty = (self.get_unassigned_mandatory(solution) * solution.problem.jobs.size()) as f64;
+
         solution
             .solution
             .unassigned
             .iter()
             .map(|(job, code)| self.unassigned_job_estimator.deref()(solution, job, code))
             .sum::<f64>()
+            + mandatory_penalty
     }
 }
@@ -1,10 +1,15 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/work_balance_test.rs"]
+mod work_balance_test;
+
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{RouteContext, SolutionContext};
 use crate::models::common::{CapacityDimension, LoadOps};
 use crate::models::problem::{TargetConstraint, TargetObjective};
+use crate::models::Problem;
 use crate::solver::objectives::GenericValue;
 use crate::solver::*;
-use rosomaxa::algorithms::math::get_cv_safe;
+use rosomaxa::algorithms::math::{get_cv_safe, get_variance};
 use std::cmp::Ordering;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -114,3 +119,52 @@ impl WorkBalance {
         )
     }
 }
+
+/// A type which provides functionality needed to balance amount of jobs across non-empty routes.
+pub struct JobCountBalance {}
+
+impl JobCountBalance {
+    /// Creates _(constraint, objective)_  type pair which minimizes variance of job counts across
+    /// non-empty routes. The variance is multiplied by given weight, so it can be traded for cost.
+    pub fn new_balanced(weight: f64) -> (TargetConstraint, TargetObjective) {
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new(|source, _| Ok(source)),
+            Arc::new(|rc: &RouteContext| rc.route.tour.job_count() as f64),
+            Arc::new(move |ctx: &SolutionContext| weight * get_job_count_variance(ctx, None)),
+            Arc::new(move |solution_ctx, route_ctx, _, _| {
+                weight
+                    * (get_job_count_variance(solution_ctx, Some(route_ctx))
+                        - get_job_count_variance(solution_ctx, None))
+            }),
+            BALANCE_JOB_COUNT_KEY,
+        )
+    }
+}
+
+/// Checks whether any of work balance objectives is used by the problem.
+pub(crate) fn has_work_balance(problem: &Problem) -> bool {
+    [BALANCE_MAX_LOAD_KEY, BALANCE_ACTIVITY_KEY, BALANCE_DISTANCE_KEY, BALANCE_DURATION_KEY, BALANCE_JOB_COUNT_KEY]
+        .iter()
+        .any(|key| problem.constraint.state_keys.contains(key))
+}
+
+/// Returns variance of job counts across non-empty routes assuming that target route, if specified,
+/// has one more job.
+fn get_job_count_variance(solution_ctx: &SolutionContext, target: Option<&RouteContext>) -> f64 {
+    let counts = solution_ctx
+        .routes
+        .iter()
+        .filter(|rc| target != Some(*rc))
+        .map(|rc| rc.route.tour.job_count())
+        .chain(target.map(|rc| rc.route.tour.job_count() + 1))
+        .filter(|count| *count > 0)
+        .map(|count| count as f64)
+        .collect::<Vec<_>>();
+
+    if counts.is_empty() {
+        0.
+    } else {
+        get_variance(counts.as_slice())
+    }
+}
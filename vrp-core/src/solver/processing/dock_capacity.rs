@@ -0,0 +1,174 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/dock_capacity_test.rs"]
+mod dock_capacity_test;
+
+use super::*;
+use crate::construction::constraints::{TransportConstraintModule, LIMIT_DURATION_KEY, TOTAL_DURATION_KEY};
+use crate::construction::heuristics::*;
+use crate::models::common::{Duration, Location, Timestamp};
+use std::cmp::Ordering;
+
+/// Specifies a loading dock at depot location which can load a limited amount of vehicles simultaneously.
+pub struct LoadingDock {
+    /// A depot location where vehicles start their tours.
+    pub location: Location,
+    /// Amount of vehicles which can be loaded at the same time.
+    pub capacity: usize,
+    /// A loading duration which precedes vehicle departure.
+    pub duration: Duration,
+}
+
+/// Staggers route departures at depots with limited loading dock capacity. Each vehicle occupies a dock
+/// for loading duration right before its departure, so concurrent departures above dock capacity are
+/// postponed. When postponing is not feasible, jobs of the route are inserted into remaining routes
+/// when possible, otherwise they are kept unassigned with given code. Departures are staggered again
+/// after reinsertion, so the result respects dock capacity.
+pub struct DockCapacity {
+    docks: Vec<LoadingDock>,
+    code: i32,
+}
+
+impl DockCapacity {
+    /// Creates a new instance of `DockCapacity`.
+    pub fn new(docks: Vec<LoadingDock>, code: i32) -> Self {
+        assert!(docks.iter().all(|dock| dock.capacity > 0));
+
+        Self { docks, code }
+    }
+
+    /// Returns indices of routes which cannot be departed from the dock in time.
+    fn stagger_departures(&self, insertion_ctx: &mut InsertionContext, dock: &LoadingDock) -> Vec<usize> {
+        let problem = insertion_ctx.problem.clone();
+        let routes = &mut insertion_ctx.solution.routes;
+
+        let mut route_indices = routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route_ctx)| {
+                route_ctx.route.tour.has_jobs()
+                    && route_ctx.route.actor.detail.start.as_ref().map(|start| start.location) == Some(dock.location)
+            })
+            .map(|(idx, route_ctx)| (idx, get_departure(route_ctx)))
+            .collect::<Vec<_>>();
+        route_indices.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+        let mut free_times = vec![f64::NEG_INFINITY; dock.capacity];
+        let mut rejected = vec![];
+
+        route_indices.into_iter().for_each(|(route_idx, departure)| {
+            let (slot, free_time) = free_times
+                .iter()
+                .cloned()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+                .expect("no dock slots");
+
+            let new_departure = (departure - dock.duration).max(free_time) + dock.duration;
+
+            if compare_floats(new_departure, departure) != Ordering::Greater {
+                free_times[slot] = departure;
+                return;
+            }
+
+            let mut route_ctx = routes[route_idx].deep_copy();
+            let (activity, transport) = (problem.activity.as_ref(), problem.transport.as_ref());
+            TransportConstraintModule::update_route_departure(&mut route_ctx, activity, transport, new_departure);
+
+            if is_feasible_schedule(&route_ctx) {
+                routes[route_idx] = route_ctx;
+                free_times[slot] = new_departure;
+            } else {
+                rejected.push(route_idx);
+            }
+        });
+
+        rejected
+    }
+}
+
+impl HeuristicSolutionProcessing for DockCapacity {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        if self.docks.is_empty() {
+            return solution;
+        }
+
+        let mut insertion_ctx = solution.deep_copy();
+        let mut removed_jobs = vec![];
+
+        // NOTE reinsertion changes remaining routes, so departures are checked again till no route is
+        // rejected. Jobs are inserted into existing routes only, so amount of routes decreases each time
+        loop {
+            let mut rejected = self
+                .docks
+                .iter()
+                .flat_map(|dock| self.stagger_departures(&mut insertion_ctx, dock))
+                .collect::<Vec<_>>();
+
+            if rejected.is_empty() {
+                insertion_ctx.problem.constraint.clone().accept_solution_state(&mut insertion_ctx.solution);
+                break;
+            }
+
+            rejected.sort_unstable();
+            rejected.dedup();
+
+            let jobs = rejected
+                .iter()
+                .flat_map(|&idx| insertion_ctx.solution.routes[idx].route.tour.jobs().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            rejected.iter().rev().for_each(|&idx| {
+                let route_ctx = insertion_ctx.solution.routes.remove(idx);
+                insertion_ctx.solution.registry.free_route(&route_ctx);
+            });
+            jobs.iter().for_each(|job| {
+                insertion_ctx.solution.locked.remove(job);
+            });
+            insertion_ctx.solution.required.extend(jobs.iter().cloned());
+            removed_jobs.extend(jobs);
+
+            let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+            insertion_ctx = InsertionHeuristic::default().process(
+                insertion_ctx,
+                &AllJobSelector::default(),
+                &ExistingRouteSelector::default(),
+                &leg_selector,
+                &BestResultSelector::default(),
+            );
+        }
+
+        removed_jobs.iter().for_each(|job| {
+            if let Some(code) = insertion_ctx.solution.unassigned.get_mut(job) {
+                *code = UnassignmentInfo::Simple(self.code);
+            }
+        });
+
+        insertion_ctx
+    }
+}
+
+fn get_departure(route_ctx: &RouteContext) -> Timestamp {
+    route_ctx.route.tour.start().map(|start| start.schedule.departure).unwrap_or(0.)
+}
+
+fn is_feasible_schedule(route_ctx: &RouteContext) -> bool {
+    let route = route_ctx.route.as_ref();
+
+    let latest_departure = route.actor.detail.start.as_ref().and_then(|start| start.time.latest).unwrap_or(f64::MAX);
+    let is_departure_in_time = compare_floats(get_departure(route_ctx), latest_departure) != Ordering::Greater;
+
+    let are_activities_in_time = route
+        .tour
+        .all_activities()
+        .all(|activity| compare_floats(activity.schedule.arrival, activity.place.time.end) != Ordering::Greater);
+
+    let is_duration_in_limit = route_ctx
+        .state
+        .get_route_state::<f64>(TOTAL_DURATION_KEY)
+        .zip(route_ctx.state.get_route_state::<f64>(LIMIT_DURATION_KEY))
+        .map(|(&total, &limit)| compare_floats(total, limit) != Ordering::Greater)
+        .unwrap_or(true);
+
+    is_departure_in_time && are_activities_in_time && is_duration_in_limit
+}
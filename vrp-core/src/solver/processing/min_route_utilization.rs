@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/min_route_utilization_test.rs"]
+mod min_route_utilization_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::solver::objectives::RouteValueFn;
+use std::ops::Deref;
+
+/// Rejects routes which are not utilized enough in the final solution, see `MinRouteUtilization`
+/// for shortage functions. Jobs of rejected routes are inserted into remaining routes when possible,
+/// otherwise they are kept unassigned with given code.
+pub struct MinRouteUtilizationRejection {
+    shortage_fn: RouteValueFn,
+    code: i32,
+}
+
+impl MinRouteUtilizationRejection {
+    /// Creates a new instance of `MinRouteUtilizationRejection`.
+    pub fn new(shortage_fn: RouteValueFn, code: i32) -> Self {
+        Self { shortage_fn, code }
+    }
+
+    fn is_underutilized(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> bool {
+        route_ctx.route.tour.has_jobs()
+            && self.shortage_fn.deref()(route_ctx) > 0.
+            && !route_ctx.route.tour.jobs().any(|job| solution_ctx.locked.contains(&job))
+    }
+}
+
+impl HeuristicSolutionProcessing for MinRouteUtilizationRejection {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        if !solution.solution.routes.iter().any(|route_ctx| self.is_underutilized(&solution.solution, route_ctx)) {
+            return solution;
+        }
+
+        let mut insertion_ctx = solution.deep_copy();
+
+        let (rejected, routes): (Vec<_>, Vec<_>) = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .cloned()
+            .partition(|route_ctx| self.is_underutilized(&insertion_ctx.solution, route_ctx));
+
+        let jobs = rejected.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<Vec<_>>();
+        rejected.iter().for_each(|route_ctx| insertion_ctx.solution.registry.free_route(route_ctx));
+        insertion_ctx.solution.routes = routes.into();
+        insertion_ctx.solution.required.extend(jobs.iter().cloned());
+
+        let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+        let mut insertion_ctx = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &ExistingRouteSelector::default(),
+            &leg_selector,
+            &BestResultSelector::default(),
+        );
+
+        jobs.iter().for_each(|job| {
+            if let Some(code) = insertion_ctx.solution.unassigned.get_mut(job) {
+                *code = UnassignmentInfo::Simple(self.code);
+            }
+        });
+
+        insertion_ctx
+    }
+}
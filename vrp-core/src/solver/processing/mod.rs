@@ -6,6 +6,12 @@ use rosomaxa::prelude::*;
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod dock_capacity;
+pub use self::dock_capacity::{DockCapacity, LoadingDock};
+
+mod min_route_utilization;
+pub use self::min_route_utilization::MinRouteUtilizationRejection;
+
 mod unassignment_reason;
 pub use self::unassignment_reason::UnassignmentReason;
 
@@ -0,0 +1,219 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/savings_construction_test.rs"]
+mod savings_construction_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::Job;
+use crate::solver::search::Recreate;
+use crate::solver::{ConstructionHeuristic, RefinementContext};
+use rosomaxa::prelude::compare_floats;
+use std::sync::Arc;
+
+/// A construction heuristic which implements Clarke-Wright savings algorithm: each job starts in its
+/// own route, then routes are merged greedily in order of savings
+/// _s(i,j) = d(i,depot) + d(depot,j) - d(i,j)_, where _i_ ends one route and _j_ starts another one.
+/// A merge happens only if the merged route is feasible, so capacity, time and other hard constraints
+/// are respected.
+///
+/// Savings are estimated using the first available vehicle, so the heuristic works best with the
+/// fleet where vehicles share depot and profile. Jobs which are not single jobs with one location,
+/// or which cannot be placed into built routes, are inserted using fallback recreate method.
+pub struct SavingsConstruction {
+    max_neighbours: usize,
+    fallback: Arc<dyn Recreate + Send + Sync>,
+}
+
+impl SavingsConstruction {
+    /// Creates a new instance of `SavingsConstruction`. Only `max_neighbours` merges with the highest
+    /// savings are considered for each job, this limits the amount of merge attempts.
+    pub fn new(max_neighbours: usize, fallback: Arc<dyn Recreate + Send + Sync>) -> Self {
+        assert!(max_neighbours > 0);
+
+        Self { max_neighbours, fallback }
+    }
+}
+
+impl ConstructionHeuristic for SavingsConstruction {
+    fn construct(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+        prepare_insertion_ctx(&mut insertion_ctx);
+
+        // NOTE deep copy is required as registry shares empty routes
+        let template = insertion_ctx.solution.registry.next().next().map(|route_ctx| route_ctx.deep_copy());
+
+        if let Some(template) = template {
+            let mut sequences = self.merge_routes(&insertion_ctx, &template);
+            sequences.sort_by_key(|jobs| std::cmp::Reverse(jobs.len()));
+            sequences.into_iter().for_each(|jobs| materialize_route(&mut insertion_ctx, jobs.as_slice()));
+        }
+
+        self.fallback.run(refinement_ctx, insertion_ctx)
+    }
+}
+
+impl SavingsConstruction {
+    /// Returns sequences of jobs which form feasible routes for the template actor.
+    fn merge_routes(&self, insertion_ctx: &InsertionContext, template: &RouteContext) -> Vec<Vec<Job>> {
+        let (jobs, mut routes): (Vec<_>, Vec<_>) = get_candidates(insertion_ctx)
+            .into_iter()
+            .filter_map(|(job, location)| {
+                append_job(insertion_ctx, template.deep_copy(), &job).map(|route_ctx| ((job, location), route_ctx))
+            })
+            .unzip();
+
+        let mut sequences = (0..jobs.len()).map(|idx| vec![idx]).collect::<Vec<_>>();
+        let mut sequence_of = (0..jobs.len()).collect::<Vec<_>>();
+
+        self.get_savings(insertion_ctx, template, jobs.iter().map(|(_, location)| *location).collect())
+            .into_iter()
+            .for_each(|(_, i, j)| {
+                let (a, b) = (sequence_of[i], sequence_of[j]);
+                if a == b || sequences[a].last() != Some(&i) || sequences[b].first() != Some(&j) {
+                    return;
+                }
+
+                let merged = sequences[b].iter().try_fold(routes[a].deep_copy(), |route_ctx, &idx| {
+                    append_job(insertion_ctx, route_ctx, &jobs[idx].0)
+                });
+
+                if let Some(merged) = merged {
+                    routes[a] = merged;
+                    let moved = std::mem::take(&mut sequences[b]);
+                    moved.iter().for_each(|&idx| sequence_of[idx] = a);
+                    sequences[a].extend(moved);
+                }
+            });
+
+        sequences
+            .into_iter()
+            .filter(|sequence| !sequence.is_empty())
+            .map(|sequence| sequence.into_iter().map(|idx| jobs[idx].0.clone()).collect())
+            .collect()
+    }
+
+    /// Returns savings sorted in descending order as _(saving, i, j)_ for merging a route which ends
+    /// at location `i` with a route which starts at location `j`.
+    fn get_savings(
+        &self,
+        insertion_ctx: &InsertionContext,
+        template: &RouteContext,
+        locations: Vec<Location>,
+    ) -> Vec<(f64, usize, usize)> {
+        let transport = insertion_ctx.problem.transport.as_ref();
+        let actor = template.route.actor.as_ref();
+        let profile = &actor.vehicle.profile;
+        let start = actor.detail.start.as_ref().map(|place| place.location);
+        let end = actor.detail.end.as_ref().map(|place| place.location);
+
+        let distance = |from: Option<Location>, to: Option<Location>| {
+            from.zip(to).map_or(0., |(from, to)| transport.distance_approx(profile, from, to))
+        };
+
+        let mut savings = locations
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &from)| {
+                let mut neighbours = locations
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| i != *j)
+                    .map(|(j, &to)| {
+                        let saving =
+                            distance(Some(from), end) + distance(start, Some(to)) - distance(Some(from), Some(to));
+                        (saving, i, j)
+                    })
+                    .filter(|(saving, _, _)| *saving > 0.)
+                    .collect::<Vec<_>>();
+
+                neighbours.sort_by(|(a, _, _), (b, _, _)| compare_floats(*b, *a));
+                neighbours.truncate(self.max_neighbours);
+
+                neighbours.into_iter()
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE ties are broken by indices to keep the result deterministic
+        savings
+            .sort_by(|(a, a_i, a_j), (b, b_i, b_j)| compare_floats(*b, *a).then_with(|| (a_i, a_j).cmp(&(b_i, b_j))));
+
+        savings
+    }
+}
+
+/// Returns required jobs which can participate in merges: single jobs with exactly one location.
+fn get_candidates(insertion_ctx: &InsertionContext) -> Vec<(Job, Location)> {
+    let mut candidates = insertion_ctx
+        .solution
+        .required
+        .iter()
+        .filter_map(|job| match job {
+            Job::Single(single) => match single.places.as_slice() {
+                [place] => place.location.map(|location| (job.clone(), location)),
+                _ => None,
+            },
+            Job::Multi(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE required jobs can be shuffled, so sort them to keep the result deterministic
+    candidates.sort_by(|(a_job, a_location), (b_job, b_location)| {
+        a_location.cmp(b_location).then_with(|| a_job.dimens().get_id().cmp(&b_job.dimens().get_id()))
+    });
+
+    candidates
+}
+
+/// Inserts jobs in given order into the first available route which can serve all of them. If there
+/// is no such route, jobs are kept as required.
+fn materialize_route(insertion_ctx: &mut InsertionContext, jobs: &[Job]) {
+    let route_ctx = insertion_ctx.solution.registry.next().find(|route_ctx| {
+        jobs.iter()
+            .try_fold(route_ctx.deep_copy(), |route_ctx, job| append_job(insertion_ctx, route_ctx, job))
+            .is_some()
+    });
+
+    if let Some(route_ctx) = route_ctx {
+        let actor = route_ctx.route.actor.clone();
+        jobs.iter().try_fold(route_ctx, |route_ctx, job| {
+            let success = evaluate_last(insertion_ctx, &route_ctx, job)?;
+            apply_insertion_success(insertion_ctx, success);
+
+            insertion_ctx.solution.routes.iter().find(|route_ctx| route_ctx.route.actor == actor).cloned()
+        });
+    }
+}
+
+/// Appends job to the end of the route if all hard constraints are fulfilled.
+fn append_job(insertion_ctx: &InsertionContext, route_ctx: RouteContext, job: &Job) -> Option<RouteContext> {
+    let InsertionSuccess { activities, .. } = evaluate_last(insertion_ctx, &route_ctx, job)?;
+
+    let mut route_ctx = route_ctx;
+    let route = route_ctx.route_mut();
+    activities.into_iter().for_each(|(activity, index)| {
+        route.tour.insert_at(activity, index + 1);
+    });
+    insertion_ctx.problem.constraint.accept_route_state(&mut route_ctx);
+
+    Some(route_ctx)
+}
+
+fn evaluate_last(insertion_ctx: &InsertionContext, route_ctx: &RouteContext, job: &Job) -> Option<InsertionSuccess> {
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        route_ctx,
+        InsertionPosition::Last,
+        InsertionResult::make_failure(),
+    )
+    .into_success()
+}
@@ -159,9 +159,10 @@ fn create_partial_insertion_ctx(
                     let jobs = routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<HashSet<_>>();
                     solution.locked.iter().filter(|job| jobs.contains(job)).cloned().collect()
                 },
-                routes,
+                routes: routes.into(),
                 registry,
                 state: Default::default(),
+                fitness_cache: Default::default(),
             },
             environment: insertion_ctx.environment.clone(),
         },
@@ -195,6 +196,7 @@ fn create_empty_insertion_ctxs(
                     routes: Default::default(),
                     registry: solution.registry.deep_copy(),
                     state: Default::default(),
+                    fitness_cache: Default::default(),
                 },
                 environment: insertion_ctx.environment.clone(),
             },
@@ -219,7 +221,8 @@ fn decompose_insertion_context(
                             create_population(insertion_ctx),
                             TelemetryMode::None,
                             refinement_ctx.environment.clone(),
-                        ),
+                        )
+                        .with_operator_counters_of(refinement_ctx),
                         indices,
                     )
                 })
@@ -42,7 +42,8 @@ impl HeuristicSearchOperator for InfeasibleSearch {
             self.shuffle_objectives_probability,
             self.skip_constraint_check_probability,
         );
-        let mut new_refinement_ctx = create_relaxed_refinement_ctx(&new_insertion_ctx);
+        let mut new_refinement_ctx =
+            create_relaxed_refinement_ctx(&new_insertion_ctx).with_operator_counters_of(refinement_ctx);
 
         let repeat_count = refinement_ctx.environment.random.uniform_int(1, self.repeat_count as i32);
 
@@ -0,0 +1,69 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/budget_aware_operator_test.rs"]
+mod budget_aware_operator_test;
+
+use super::*;
+
+/// Runs multiple local search operators with different probability as `CompositeLocalOperator`, but
+/// skips operators with high cost hint when search approaches its termination. The progress is
+/// estimated using `termination_estimate` from heuristic statistics, so it follows time budget when
+/// max time termination is used.
+pub struct BudgetAwareLocalOperator {
+    operators: Vec<(Arc<dyn LocalOperator + Send + Sync>, usize)>,
+    times: (i32, i32),
+    thresholds: (f64, f64),
+}
+
+impl BudgetAwareLocalOperator {
+    /// Creates a new instance of `BudgetAwareLocalOperator`. Expensive operators are skipped when
+    /// termination estimate reaches `expensive_threshold`, moderate ones after `moderate_threshold`.
+    pub fn new(
+        operators: Vec<(Arc<dyn LocalOperator + Send + Sync>, usize)>,
+        min: usize,
+        max: usize,
+        expensive_threshold: f64,
+        moderate_threshold: f64,
+    ) -> Self {
+        assert!(expensive_threshold <= moderate_threshold);
+
+        Self { operators, times: (min as i32, max as i32), thresholds: (expensive_threshold, moderate_threshold) }
+    }
+
+    /// Returns the most expensive operator cost allowed at given termination estimate.
+    pub fn get_allowed_cost(&self, termination_estimate: f64) -> OperatorCost {
+        let (expensive_threshold, moderate_threshold) = self.thresholds;
+
+        match termination_estimate {
+            estimate if estimate >= moderate_threshold => OperatorCost::Cheap,
+            estimate if estimate >= expensive_threshold => OperatorCost::Moderate,
+            _ => OperatorCost::Expensive,
+        }
+    }
+}
+
+impl LocalOperator for BudgetAwareLocalOperator {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let allowed_cost = self.get_allowed_cost(refinement_ctx.statistics().termination_estimate);
+
+        let (operators, weights): (Vec<_>, Vec<_>) = self
+            .operators
+            .iter()
+            .filter(|(operator, _)| operator.cost_hint() <= allowed_cost)
+            .map(|(operator, weight)| (operator.clone(), *weight))
+            .unzip();
+
+        if operators.is_empty() {
+            return None;
+        }
+
+        explore_with_weights(refinement_ctx, insertion_ctx, operators.as_slice(), weights.as_slice(), self.times)
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        self.operators.iter().map(|(operator, _)| operator.cost_hint()).max().unwrap_or(OperatorCost::Cheap)
+    }
+}
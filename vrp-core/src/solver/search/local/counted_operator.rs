@@ -0,0 +1,95 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/counted_operator_test.rs"]
+mod counted_operator_test;
+
+use super::*;
+use rosomaxa::evolution::TelemetryOperator;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::RwLock;
+
+/// Keeps track of local search operators usage. An instance is owned by `RefinementContext`.
+#[derive(Default)]
+pub struct LocalOperatorCounters {
+    entries: RwLock<Vec<(String, Arc<OperatorCounter>)>>,
+}
+
+#[derive(Default)]
+struct OperatorCounter {
+    calls: AtomicUsize,
+    explored: AtomicUsize,
+    improved: AtomicUsize,
+}
+
+impl LocalOperatorCounters {
+    /// Returns usage statistics of all registered operators in order of their registration.
+    pub fn get_statistics(&self) -> Vec<TelemetryOperator> {
+        self.entries
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(name, counter)| TelemetryOperator {
+                name: name.clone(),
+                calls: counter.calls.load(AtomicOrdering::Relaxed),
+                explored: counter.explored.load(AtomicOrdering::Relaxed),
+                improved: counter.improved.load(AtomicOrdering::Relaxed),
+            })
+            .collect()
+    }
+
+    fn register(&self, name: &str) -> Arc<OperatorCounter> {
+        if let Some((_, counter)) = self.entries.read().unwrap().iter().find(|(existing, _)| existing == name) {
+            return counter.clone();
+        }
+
+        let mut entries = self.entries.write().unwrap();
+
+        if let Some((_, counter)) = entries.iter().find(|(existing, _)| existing == name) {
+            counter.clone()
+        } else {
+            let counter = Arc::new(OperatorCounter::default());
+            entries.push((name.to_string(), counter.clone()));
+            counter
+        }
+    }
+}
+
+/// A local search operator decorator which counts how many times inner operator was called,
+/// returned a new solution and returned an improved solution. Counts are kept by refinement context.
+pub struct CountedLocalOperator {
+    name: String,
+    inner: Arc<dyn LocalOperator + Send + Sync>,
+}
+
+impl CountedLocalOperator {
+    /// Creates a new instance of `CountedLocalOperator`. Operators with the same name share counters.
+    pub fn new(name: &str, inner: Arc<dyn LocalOperator + Send + Sync>) -> Self {
+        Self { name: name.to_string(), inner }
+    }
+}
+
+impl LocalOperator for CountedLocalOperator {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let counter = refinement_ctx.operator_counters().register(self.name.as_str());
+        counter.calls.fetch_add(1, AtomicOrdering::Relaxed);
+
+        let result = self.inner.explore(refinement_ctx, insertion_ctx);
+
+        if let Some(new_insertion_ctx) = result.as_ref() {
+            counter.explored.fetch_add(1, AtomicOrdering::Relaxed);
+
+            if refinement_ctx.problem.objective.total_order(insertion_ctx, new_insertion_ctx) == Ordering::Greater {
+                counter.improved.fetch_add(1, AtomicOrdering::Relaxed);
+            }
+        }
+
+        result
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        self.inner.cost_hint()
+    }
+}
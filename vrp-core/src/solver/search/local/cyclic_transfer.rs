@@ -0,0 +1,127 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/cyclic_transfer_test.rs"]
+mod cyclic_transfer_test;
+
+use super::*;
+use crate::models::common::{Cost, IdDimension};
+use crate::models::problem::Job;
+use crate::solver::search::get_routes_cost_savings;
+use rand::prelude::SliceRandom;
+
+/// A local search operator which performs a cyclic transfer of single jobs between three routes:
+/// a job from route _A_ is moved to _B_, a job from _B_ to _C_ and a job from _C_ to _A_. On each
+/// step, a job with the best difference between its removal saving and insertion cost is selected.
+/// Both directions of the cycle are evaluated and a new solution is returned only if it is better
+/// than the original one. Locked jobs are never moved.
+#[derive(Default)]
+pub struct CyclicTransfer {}
+
+impl LocalOperator for CyclicTransfer {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let [a, b, c] = get_random_route_indices(insertion_ctx)?;
+        let objective = &insertion_ctx.problem.objective;
+
+        [[a, b, c], [a, c, b]]
+            .into_iter()
+            .filter_map(|cycle| apply_cycle(insertion_ctx, cycle))
+            .filter(|new_insertion_ctx| objective.total_order(insertion_ctx, new_insertion_ctx) == Ordering::Greater)
+            .min_by(|a, b| objective.total_order(a, b))
+    }
+}
+
+/// Returns indices of three random routes which have at least one unlocked job.
+fn get_random_route_indices(insertion_ctx: &InsertionContext) -> Option<[usize; 3]> {
+    let locked = &insertion_ctx.solution.locked;
+
+    let mut indices = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.jobs().any(|job| !locked.contains(&job)))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    indices.shuffle(&mut insertion_ctx.environment.random.get_rng());
+
+    match indices.as_slice() {
+        [a, b, c, ..] => Some([*a, *b, *c]),
+        _ => None,
+    }
+}
+
+/// Moves one job along each edge of the cycle. Returns `None` if any of transfers is not possible.
+fn apply_cycle(insertion_ctx: &InsertionContext, cycle: [usize; 3]) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.cow_copy();
+
+    (0..cycle.len()).try_fold(None, |moved: Option<Job>, idx| {
+        let (from, to) = (cycle[idx], cycle[(idx + 1) % cycle.len()]);
+        // NOTE a job which has just arrived to the route should not leave it
+        let (job, success) = get_best_transfer(&new_insertion_ctx, from, to, moved.as_ref())?;
+
+        let route_ctx = new_insertion_ctx.solution.routes.get_mut(from)?;
+        if !route_ctx.route_mut().tour.remove(&job) {
+            return None;
+        }
+        new_insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+        apply_insertion(&mut new_insertion_ctx, success);
+
+        Some(Some(job))
+    })?;
+
+    finalize_insertion_ctx(&mut new_insertion_ctx);
+
+    Some(new_insertion_ctx)
+}
+
+/// Returns an unlocked job of the `from` route which has the largest difference between its
+/// removal saving and the cost of insertion into the `to` route.
+fn get_best_transfer(
+    insertion_ctx: &InsertionContext,
+    from: usize,
+    to: usize,
+    excluded: Option<&Job>,
+) -> Option<(Job, InsertionSuccess)> {
+    let locked = &insertion_ctx.solution.locked;
+    let target_ctx = insertion_ctx.solution.routes.get(to)?;
+    let (_, savings) = get_routes_cost_savings(insertion_ctx).into_iter().nth(from)?;
+
+    savings
+        .into_iter()
+        .filter(|(job, _)| !locked.contains(job) && excluded != Some(job))
+        .filter_map(|(job, saving)| {
+            get_best_insertion(insertion_ctx, target_ctx, &job).map(|success| {
+                let delta: Cost = saving - success.cost;
+                (job, delta, success)
+            })
+        })
+        // NOTE ties are broken by job id to keep the result deterministic
+        .max_by(|(a_job, a, _), (b_job, b, _)| {
+            compare_floats(*a, *b).then_with(|| b_job.dimens().get_id().cmp(&a_job.dimens().get_id()))
+        })
+        .map(|(job, _, success)| (job, success))
+}
+
+/// Returns the cheapest insertion of the job evaluated at every position of the route.
+fn get_best_insertion(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    job: &Job,
+) -> Option<InsertionSuccess> {
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        route_ctx,
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    )
+    .into_success()
+}
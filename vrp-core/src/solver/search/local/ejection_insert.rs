@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/ejection_insert_test.rs"]
+mod ejection_insert_test;
+
+use super::*;
+use crate::models::common::IdDimension;
+use crate::solver::search::get_assigned_neighbours;
+use hashbrown::HashMap;
+
+/// A local search operator which implements one level ejection chain: to insert an unassigned job,
+/// it ejects an assigned job from its neighbourhood, inserts the unassigned job and then places the
+/// ejected job elsewhere. A new solution is returned only if both jobs are assigned.
+pub struct EjectionInsert {
+    max_candidates: usize,
+}
+
+impl EjectionInsert {
+    /// Creates a new instance of `EjectionInsert` which tries to eject up to `max_candidates`
+    /// neighbouring jobs, one at a time.
+    pub fn new(max_candidates: usize) -> Self {
+        assert!(max_candidates > 0);
+
+        Self { max_candidates }
+    }
+}
+
+impl Default for EjectionInsert {
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
+impl LocalOperator for EjectionInsert {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let mut unassigned = insertion_ctx.solution.unassigned.keys().cloned().collect::<Vec<_>>();
+        if unassigned.is_empty() {
+            return None;
+        }
+
+        // NOTE sort to keep selection reproducible for the same random seed
+        unassigned.sort_by_key(|job| job.dimens().get_id().cloned());
+        let random = &insertion_ctx.environment.random;
+        let seed_job = unassigned.get(random.uniform_int(0, unassigned.len() as i32 - 1) as usize)?.clone();
+
+        get_assigned_neighbours(insertion_ctx, &seed_job, self.max_candidates).into_iter().find_map(
+            |(ejected_job, route_idx)| {
+                // NOTE only one or two routes are changed, so routes are copied lazily
+                let mut new_insertion_ctx = insertion_ctx.cow_copy();
+                new_insertion_ctx.solution.routes[route_idx].route_mut().tour.remove(&ejected_job);
+
+                let other_unassigned = new_insertion_ctx
+                    .solution
+                    .unassigned
+                    .drain()
+                    .filter(|(job, _)| *job != seed_job)
+                    .collect::<HashMap<_, _>>();
+
+                new_insertion_ctx.solution.required.push(seed_job.clone());
+                let mut new_insertion_ctx = insert_required(new_insertion_ctx);
+                if !new_insertion_ctx.solution.unassigned.is_empty() {
+                    return None;
+                }
+
+                // NOTE ejection depth is limited to one: ejected job is inserted without ejecting others
+                new_insertion_ctx.solution.required.push(ejected_job);
+                let mut new_insertion_ctx = insert_required(new_insertion_ctx);
+                if !new_insertion_ctx.solution.unassigned.is_empty() {
+                    return None;
+                }
+
+                new_insertion_ctx.solution.unassigned.extend(other_unassigned);
+                new_insertion_ctx.restore();
+
+                Some(new_insertion_ctx)
+            },
+        )
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Expensive
+    }
+}
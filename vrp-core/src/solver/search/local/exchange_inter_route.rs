@@ -43,6 +43,10 @@ impl LocalOperator for ExchangeInterRouteBest {
             Box::new(|_| true),
         )
     }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Expensive
+    }
 }
 
 impl ExchangeInterRouteRandom {
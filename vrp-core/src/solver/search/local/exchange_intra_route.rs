@@ -1,6 +1,6 @@
 use crate::construction::heuristics::*;
 use crate::models::problem::Job;
-use crate::solver::search::LocalOperator;
+use crate::solver::search::{LocalOperator, OperatorCost};
 use crate::solver::RefinementContext;
 use crate::utils::Noise;
 use rand::prelude::SliceRandom;
@@ -72,6 +72,10 @@ impl LocalOperator for ExchangeIntraRouteRandom {
 
         None
     }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Cheap
+    }
 }
 
 fn get_shuffled_jobs(insertion_ctx: &InsertionContext, route_ctx: &RouteContext) -> Vec<Job> {
@@ -3,10 +3,12 @@
 mod exchange_sequence_test;
 
 use crate::construction::heuristics::*;
-use crate::models::problem::Job;
+use crate::models::common::Cost;
+use crate::models::problem::{Job, TravelTime};
+use crate::models::Problem;
 use crate::solver::search::LocalOperator;
 use crate::solver::RefinementContext;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use rand::prelude::SliceRandom;
 use rosomaxa::prelude::*;
 
@@ -17,14 +19,43 @@ pub struct ExchangeSequence {
     max_sequence_size: usize,
     reverse_prob: f64,
     shuffle_prob: f64,
+    is_guided: bool,
+    is_size_biased: bool,
+    keep_contiguous: bool,
 }
 
 impl ExchangeSequence {
-    /// Creates a new instance of `ExchangeSequence`.
+    /// Creates a new instance of `ExchangeSequence` which selects sequences randomly.
     pub fn new(max_sequence_size: usize, reverse_prob: f64, shuffle_prob: f64) -> Self {
         assert!(max_sequence_size >= MIN_JOBS);
 
-        Self { max_sequence_size, reverse_prob, shuffle_prob }
+        Self {
+            max_sequence_size,
+            reverse_prob,
+            shuffle_prob,
+            is_guided: false,
+            is_size_biased: false,
+            keep_contiguous: false,
+        }
+    }
+
+    /// Creates a new instance of `ExchangeSequence` which, instead of random selection, extracts
+    /// the sequence with the highest detour cost contribution from the route.
+    pub fn new_guided(max_sequence_size: usize, reverse_prob: f64, shuffle_prob: f64) -> Self {
+        Self { is_guided: true, ..Self::new(max_sequence_size, reverse_prob, shuffle_prob) }
+    }
+
+    /// Makes operator to select routes with probability proportional to their job count instead
+    /// of uniform selection.
+    pub fn with_size_bias(self) -> Self {
+        Self { is_size_biased: true, ..self }
+    }
+
+    /// Makes operator to insert extracted sequence as one block: the first job is inserted at its
+    /// best position and the rest right after it. Jobs which cannot be inserted there are inserted
+    /// one by one as usual.
+    pub fn with_keep_contiguous(self) -> Self {
+        Self { keep_contiguous: true, ..self }
     }
 }
 
@@ -44,13 +75,7 @@ impl LocalOperator for ExchangeSequence {
 
         let mut insertion_ctx = insertion_ctx.deep_copy();
 
-        exchange_jobs(
-            &mut insertion_ctx,
-            route_indices.as_slice(),
-            self.max_sequence_size,
-            self.reverse_prob,
-            self.shuffle_prob,
-        );
+        exchange_jobs(&mut insertion_ctx, route_indices.as_slice(), self)?;
 
         Some(insertion_ctx)
     }
@@ -79,46 +104,61 @@ fn get_route_indices(insertion_ctx: &InsertionContext) -> Vec<usize> {
 fn exchange_jobs(
     insertion_ctx: &mut InsertionContext,
     route_indices: &[usize],
-    max_sequence_size: usize,
-    reverse_prob: f64,
-    shuffle_prob: f64,
-) {
+    settings: &ExchangeSequence,
+) -> Option<()> {
     let get_route_idx = |insertion_ctx: &InsertionContext| {
-        let idx = insertion_ctx.environment.random.uniform_int(0, route_indices.len() as i32 - 1) as usize;
+        let random = &insertion_ctx.environment.random;
+        let idx = if settings.is_size_biased {
+            let weights = route_indices
+                .iter()
+                .map(|&route_idx| get_route_ctx(insertion_ctx, route_idx).route.tour.job_count() as f64)
+                .collect::<Vec<_>>();
+            random.weighted_index(weights.as_slice())
+        } else {
+            random.uniform_int(0, route_indices.len() as i32 - 1) as usize
+        };
+
         route_indices.get(idx).cloned().unwrap()
     };
 
     let get_sequence_size = |insertion_ctx: &InsertionContext, route_idx: usize| {
-        let job_count = get_route_ctx(insertion_ctx, route_idx).route.tour.job_count().min(max_sequence_size);
+        let job_count = get_route_ctx(insertion_ctx, route_idx).route.tour.job_count().min(settings.max_sequence_size);
         insertion_ctx.environment.random.uniform_int(MIN_JOBS as i32, job_count as i32) as usize
     };
 
     let first_route_idx = get_route_idx(insertion_ctx);
     let first_sequence_size = get_sequence_size(insertion_ctx, first_route_idx);
-    let first_jobs = extract_jobs(insertion_ctx, first_route_idx, first_sequence_size);
+    let first_jobs = extract_jobs(insertion_ctx, first_route_idx, first_sequence_size, settings.is_guided)?;
 
     let second_route_idx = get_route_idx(insertion_ctx);
 
     if first_route_idx != second_route_idx {
         let second_sequence_size = get_sequence_size(insertion_ctx, second_route_idx);
-        let second_jobs = extract_jobs(insertion_ctx, second_route_idx, second_sequence_size);
+        let second_jobs = extract_jobs(insertion_ctx, second_route_idx, second_sequence_size, settings.is_guided)?;
 
-        insert_jobs(insertion_ctx, first_route_idx, second_jobs, reverse_prob, shuffle_prob);
-        insert_jobs(insertion_ctx, second_route_idx, first_jobs, reverse_prob, shuffle_prob);
+        insert_jobs(insertion_ctx, first_route_idx, second_jobs, settings);
+        insert_jobs(insertion_ctx, second_route_idx, first_jobs, settings);
     } else {
-        insert_jobs(insertion_ctx, first_route_idx, first_jobs, reverse_prob, shuffle_prob);
+        insert_jobs(insertion_ctx, first_route_idx, first_jobs, settings);
     }
 
     finalize_insertion_ctx(insertion_ctx);
+
+    Some(())
 }
 
-fn extract_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, sequence_size: usize) -> Vec<Job> {
+/// Extracts a sequence of unlocked jobs from the route. Returns `None` when the route has not
+/// enough unlocked jobs to form a sequence.
+fn extract_jobs(
+    insertion_ctx: &mut InsertionContext,
+    route_idx: usize,
+    sequence_size: usize,
+    is_guided: bool,
+) -> Option<Vec<Job>> {
     let locked = &insertion_ctx.solution.locked;
-    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx)?;
     let job_count = route_ctx.route.tour.job_count();
 
-    assert!(job_count >= sequence_size);
-
     // get jobs in the exact order as they appear first time in the tour
     let (_, jobs) = route_ctx.route.tour.all_activities().filter_map(|activity| activity.retrieve_job()).fold(
         (HashSet::<Job>::default(), Vec::with_capacity(job_count)),
@@ -132,9 +172,18 @@ fn extract_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, sequence
         },
     );
 
-    let sequence_size = sequence_size.min(jobs.len());
+    if jobs.len() < MIN_JOBS {
+        return None;
+    }
+
+    // NOTE start index is sampled from [0, last_index], so the sequence can cover all unlocked jobs
+    let sequence_size = sequence_size.clamp(MIN_JOBS, jobs.len());
     let last_index = jobs.len() - sequence_size;
-    let start_index = insertion_ctx.environment.random.uniform_int(0, last_index as i32) as usize;
+    let start_index = if is_guided {
+        get_most_expensive_sequence_start(insertion_ctx.problem.as_ref(), route_ctx, jobs.as_slice(), sequence_size)
+    } else {
+        insertion_ctx.environment.random.uniform_int(0, last_index as i32) as usize
+    };
 
     let removed =
         (start_index..(start_index + sequence_size)).fold(Vec::with_capacity(sequence_size), |mut acc, index| {
@@ -147,22 +196,66 @@ fn extract_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, sequence
 
     insertion_ctx.problem.constraint.accept_route_state(route_ctx);
 
-    removed
+    Some(removed)
 }
 
-fn insert_jobs(
-    insertion_ctx: &mut InsertionContext,
-    route_idx: usize,
-    jobs: Vec<Job>,
-    reverse_prob: f64,
-    shuffle_prob: f64,
-) {
-    let random = &insertion_ctx.environment.random;
+/// Returns start index of the jobs sequence which has the highest total detour cost.
+fn get_most_expensive_sequence_start(
+    problem: &Problem,
+    route_ctx: &RouteContext,
+    jobs: &[Job],
+    sequence_size: usize,
+) -> usize {
+    let detours = get_job_detours(problem, route_ctx);
+    let costs = jobs.iter().map(|job| detours.get(job).cloned().unwrap_or(0.)).collect::<Vec<_>>();
+
+    // NOTE the first window with the highest cost wins in case of ties
+    costs
+        .windows(sequence_size)
+        .map(|window| window.iter().sum::<Cost>())
+        .enumerate()
+        .fold(
+            (0, Cost::MIN),
+            |(best_idx, best_cost), (idx, cost)| {
+                if cost > best_cost {
+                    (idx, cost)
+                } else {
+                    (best_idx, best_cost)
+                }
+            },
+        )
+        .0
+}
+
+/// Estimates cost contribution of each job in the route as a sum of detours of its activities.
+fn get_job_detours(problem: &Problem, route_ctx: &RouteContext) -> HashMap<Job, Cost> {
+    let route = route_ctx.route.as_ref();
+    let transport = problem.transport.as_ref();
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    activities.windows(3).fold(HashMap::default(), |mut detours, window| {
+        let (prev, current, next) = (window[0], window[1], window[2]);
+
+        if let Some(job) = current.retrieve_job() {
+            let departure = TravelTime::Departure(prev.schedule.departure);
+            let detour = transport.cost(route, prev.place.location, current.place.location, departure)
+                + transport.cost(route, current.place.location, next.place.location, departure)
+                - transport.cost(route, prev.place.location, next.place.location, departure);
+
+            *detours.entry(job).or_insert(0.) += detour;
+        }
+
+        detours
+    })
+}
+
+fn insert_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: Vec<Job>, settings: &ExchangeSequence) {
+    let random = insertion_ctx.environment.random.clone();
     let leg_selector = VariableLegSelector::new(random.clone());
     let result_selector = BestResultSelector::default();
 
     let mut jobs = jobs;
-    match (random.is_hit(reverse_prob), random.is_hit(shuffle_prob)) {
+    match (random.is_hit(settings.reverse_prob), random.is_hit(settings.shuffle_prob)) {
         (true, _) => {
             jobs.reverse();
         }
@@ -172,6 +265,8 @@ fn insert_jobs(
         _ => {}
     };
 
+    let jobs = if settings.keep_contiguous { insert_block(insertion_ctx, route_idx, jobs) } else { jobs };
+
     let start_index =
         random.uniform_int(0, get_route_ctx(insertion_ctx, route_idx).route.tour.job_activity_count() as i32) as usize;
 
@@ -222,6 +317,46 @@ fn insert_jobs(
     }));
 }
 
+/// Inserts jobs as one block: the first job is inserted at its best position in the route and each next
+/// one right after the previously inserted job. Returns jobs which cannot be inserted there.
+fn insert_block(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: Vec<Job>) -> Vec<Job> {
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+
+    let (failures, _) = jobs.into_iter().fold((Vec::new(), None), |(mut failures, anchor), job| {
+        let eval_ctx = EvaluationContext {
+            constraint: &insertion_ctx.problem.constraint,
+            job: &job,
+            leg_selector: &leg_selector,
+            result_selector: &result_selector,
+        };
+
+        let insertion = evaluate_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            get_route_ctx(insertion_ctx, route_idx),
+            anchor.map_or(InsertionPosition::Any, InsertionPosition::Concrete),
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => {
+                // NOTE activity inserted on leg with index i is placed at index i + 1, next leg starts there
+                let anchor = success.activities.iter().map(|(_, index)| index + 1).max().or(anchor);
+                apply_insertion_success(insertion_ctx, success);
+
+                (failures, anchor)
+            }
+            InsertionResult::Failure(_) => {
+                failures.push(job);
+                (failures, anchor)
+            }
+        }
+    });
+
+    failures
+}
+
 fn get_route_ctx(insertion_ctx: &InsertionContext, route_idx: usize) -> &RouteContext {
     insertion_ctx.solution.routes.get(route_idx).unwrap()
 }
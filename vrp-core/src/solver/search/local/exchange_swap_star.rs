@@ -59,6 +59,10 @@ impl LocalOperator for ExchangeSwapStar {
 
         Some(insertion_ctx)
     }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Expensive
+    }
 }
 
 /// Encapsulates common data used by search phase.
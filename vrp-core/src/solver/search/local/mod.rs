@@ -6,6 +6,18 @@ use rosomaxa::prelude::*;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
+mod budget_aware_operator;
+pub use self::budget_aware_operator::*;
+
+mod counted_operator;
+pub use self::counted_operator::*;
+
+mod cyclic_transfer;
+pub use self::cyclic_transfer::*;
+
+mod ejection_insert;
+pub use self::ejection_insert::*;
+
 mod exchange_inter_route;
 pub use self::exchange_inter_route::*;
 
@@ -18,15 +30,46 @@ pub use self::exchange_sequence::*;
 mod exchange_swap_star;
 pub use self::exchange_swap_star::*;
 
+mod redistribute_unassigned;
+pub use self::redistribute_unassigned::*;
+
+mod relocate_job;
+pub use self::relocate_job::*;
+
+mod relocate_worst;
+pub use self::relocate_worst::*;
+
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
+mod reverse_segment;
+pub use self::reverse_segment::*;
+
+mod utilize_spare_vehicle;
+pub use self::utilize_spare_vehicle::*;
+
+/// Specifies an approximate computational cost of a local search operator's single run.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum OperatorCost {
+    /// Operator is fast and can be run often.
+    Cheap,
+    /// Operator has moderate cost.
+    Moderate,
+    /// Operator is slow, its usage should be limited when time is short.
+    Expensive,
+}
+
 /// Specifies behavior of a local search operator.
 pub trait LocalOperator {
     /// Applies local search operator to passed solution in order to explore possible
     /// small move in solution space which leads to a different solution.
     fn explore(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext)
         -> Option<InsertionContext>;
+
+    /// Returns an approximate cost of the operator.
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Moderate
+    }
 }
 
 /// Provides the way to run multiple local search operators with different probability.
@@ -52,26 +95,47 @@ impl LocalOperator for CompositeLocalOperator {
         refinement_ctx: &RefinementContext,
         insertion_ctx: &InsertionContext,
     ) -> Option<InsertionContext> {
-        let random = insertion_ctx.environment.random.as_ref();
-        let times = random.uniform_int(self.times.0, self.times.1);
+        explore_with_weights(
+            refinement_ctx,
+            insertion_ctx,
+            self.operators.as_slice(),
+            self.weights.as_slice(),
+            self.times,
+        )
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        self.operators.iter().map(|operator| operator.cost_hint()).max().unwrap_or(OperatorCost::Cheap)
+    }
+}
+
+/// Runs operators selected by their weights random amount of times within given range.
+fn explore_with_weights(
+    refinement_ctx: &RefinementContext,
+    insertion_ctx: &InsertionContext,
+    operators: &[Arc<dyn LocalOperator + Send + Sync>],
+    weights: &[usize],
+    times: (i32, i32),
+) -> Option<InsertionContext> {
+    let random = insertion_ctx.environment.random.as_ref();
+    let times = random.uniform_int(times.0, times.1);
 
-        let mut old_result = insertion_ctx.deep_copy();
+    let mut old_result = insertion_ctx.deep_copy();
 
-        for _ in 0..times {
-            let index = random.weighted(self.weights.as_slice());
-            let new_result = self.operators.get(index).unwrap().explore(refinement_ctx, &old_result);
+    for _ in 0..times {
+        let index = random.weighted(weights);
+        let new_result = operators.get(index).unwrap().explore(refinement_ctx, &old_result);
 
-            if let Some(new_result) = new_result {
-                if refinement_ctx.problem.objective.total_order(insertion_ctx, &new_result) == Ordering::Greater {
-                    return Some(new_result);
-                } else {
-                    old_result = new_result;
-                }
+        if let Some(new_result) = new_result {
+            if refinement_ctx.problem.objective.total_order(insertion_ctx, &new_result) == Ordering::Greater {
+                return Some(new_result);
+            } else {
+                old_result = new_result;
             }
         }
-
-        Some(old_result)
     }
+
+    Some(old_result)
 }
 
 /// Applies insertion success by creating a new route context from it.
@@ -79,9 +143,22 @@ fn apply_insertion(insertion_ctx: &mut InsertionContext, success: InsertionSucce
     let route_index =
         insertion_ctx.solution.routes.iter().position(|ctx| ctx.route.actor == success.context.route.actor).unwrap();
 
-    // NOTE replace existing route context with the different
-    insertion_ctx.solution.routes[route_index] =
-        RouteContext::new_with_state(success.context.route.clone(), success.context.state.clone());
+    // NOTE replace existing route context with the different one: it is copied on write as its route
+    // can be shared with the origin of copy-on-write insertion context
+    insertion_ctx.solution.routes[route_index] = success.context.cow_copy();
 
     apply_insertion_success(insertion_ctx, success)
 }
+
+/// Inserts required jobs into given solution using default insertion heuristic.
+fn insert_required(insertion_ctx: InsertionContext) -> InsertionContext {
+    let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+
+    InsertionHeuristic::default().process(
+        insertion_ctx,
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &leg_selector,
+        &BestResultSelector::default(),
+    )
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/redistribute_unassigned_test.rs"]
+mod redistribute_unassigned_test;
+
+use super::*;
+use crate::models::common::IdDimension;
+use crate::solver::search::get_assigned_neighbours;
+use hashbrown::HashMap;
+
+/// A local search operator which tries to make room for an unassigned job: it removes assigned jobs
+/// from its neighbourhood and reinserts them after the unassigned job is inserted first.
+pub struct RedistributeUnassigned {
+    max_removed: usize,
+}
+
+impl RedistributeUnassigned {
+    /// Creates a new instance of `RedistributeUnassigned`.
+    pub fn new(max_removed: usize) -> Self {
+        assert!(max_removed > 0);
+
+        Self { max_removed }
+    }
+}
+
+impl Default for RedistributeUnassigned {
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl LocalOperator for RedistributeUnassigned {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let mut unassigned = insertion_ctx.solution.unassigned.keys().cloned().collect::<Vec<_>>();
+        if unassigned.is_empty() {
+            return None;
+        }
+
+        // NOTE sort to keep selection reproducible for the same random seed
+        unassigned.sort_by_key(|job| job.dimens().get_id().cloned());
+        let random = &insertion_ctx.environment.random;
+        let seed_job = unassigned.get(random.uniform_int(0, unassigned.len() as i32 - 1) as usize)?.clone();
+
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+        let neighbours = get_assigned_neighbours(&insertion_ctx, &seed_job, self.max_removed);
+        if neighbours.is_empty() {
+            return None;
+        }
+
+        neighbours.iter().for_each(|(job, route_idx)| {
+            insertion_ctx.solution.routes[*route_idx].route_mut().tour.remove(job);
+        });
+
+        // insert seed job first, then try to reinsert removed jobs together with the rest of unassigned
+        let other_unassigned =
+            insertion_ctx.solution.unassigned.drain().filter(|(job, _)| *job != seed_job).collect::<HashMap<_, _>>();
+        insertion_ctx.solution.required.push(seed_job);
+        let mut insertion_ctx = insert_required(insertion_ctx);
+
+        insertion_ctx.solution.unassigned.extend(other_unassigned);
+        insertion_ctx.solution.required.extend(neighbours.into_iter().map(|(job, _)| job));
+        let mut insertion_ctx = insert_required(insertion_ctx);
+
+        insertion_ctx.restore();
+
+        Some(insertion_ctx)
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Expensive
+    }
+}
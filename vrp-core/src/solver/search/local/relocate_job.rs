@@ -0,0 +1,75 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/relocate_job_test.rs"]
+mod relocate_job_test;
+
+use super::*;
+use crate::models::problem::Job;
+
+/// A local search operator which removes a random unlocked job from a random route and inserts it
+/// at the best position across all other routes. Unlike `ExchangeSequence`, it moves exactly one
+/// job and always takes the best insertion found.
+#[derive(Default)]
+pub struct RelocateJob {}
+
+impl LocalOperator for RelocateJob {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let random = &insertion_ctx.environment.random;
+
+        let route_indices = get_route_indices(insertion_ctx);
+        if route_indices.is_empty() {
+            return None;
+        }
+        let route_idx = route_indices[random.uniform_int(0, route_indices.len() as i32 - 1) as usize];
+
+        let jobs = get_movable_jobs(insertion_ctx, route_idx);
+        let job = jobs[random.uniform_int(0, jobs.len() as i32 - 1) as usize].clone();
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        let route_ctx = new_insertion_ctx.solution.routes.get_mut(route_idx)?;
+        assert!(route_ctx.route_mut().tour.remove(&job));
+        new_insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+
+        let success = get_best_insertion(&new_insertion_ctx, route_idx, &job)?;
+        apply_insertion_success(&mut new_insertion_ctx, success);
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        Some(new_insertion_ctx)
+    }
+}
+
+/// Returns indices of routes which have at least one movable job.
+fn get_route_indices(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    (0..insertion_ctx.solution.routes.len())
+        .filter(|&route_idx| !get_movable_jobs(insertion_ctx, route_idx).is_empty())
+        .collect()
+}
+
+/// Returns jobs of the route which are not locked.
+fn get_movable_jobs(insertion_ctx: &InsertionContext, route_idx: usize) -> Vec<Job> {
+    let locked = &insertion_ctx.solution.locked;
+
+    insertion_ctx.solution.routes[route_idx].route.tour.jobs().filter(|job| !locked.contains(job)).collect()
+}
+
+/// Returns the best insertion of the job evaluated in every route except the one with given index.
+fn get_best_insertion(insertion_ctx: &InsertionContext, skip_idx: usize, job: &Job) -> Option<InsertionSuccess> {
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(route_idx, _)| *route_idx != skip_idx)
+        .fold(InsertionResult::make_failure(), |acc, (_, route_ctx)| {
+            evaluate_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, InsertionPosition::Any, acc)
+        })
+        .into_success()
+}
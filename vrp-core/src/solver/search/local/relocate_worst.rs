@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/relocate_worst_test.rs"]
+mod relocate_worst_test;
+
+use super::*;
+use crate::models::common::IdDimension;
+use crate::models::problem::Job;
+use crate::solver::search::get_routes_cost_savings;
+
+/// A local search operator which finds an assigned job with the largest removal saving across
+/// the whole solution and relocates it to its best position in any of the existing routes.
+/// A new solution is returned only if it is better than the original one. Locked jobs are
+/// never moved.
+#[derive(Default)]
+pub struct RelocateWorst {}
+
+impl LocalOperator for RelocateWorst {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let (route_idx, job) = get_worst_job(insertion_ctx)?;
+
+        let mut new_insertion_ctx = insertion_ctx.cow_copy();
+        let route_ctx = new_insertion_ctx.solution.routes.get_mut(route_idx)?;
+        if !route_ctx.route_mut().tour.remove(&job) {
+            return None;
+        }
+        new_insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+
+        let success = get_best_insertion(&new_insertion_ctx, &job)?;
+        apply_insertion(&mut new_insertion_ctx, success);
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        let is_improved =
+            insertion_ctx.problem.objective.total_order(insertion_ctx, &new_insertion_ctx) == Ordering::Greater;
+
+        if is_improved {
+            Some(new_insertion_ctx)
+        } else {
+            None
+        }
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Cheap
+    }
+}
+
+/// Returns a route index and a job which is not locked and has the largest removal saving.
+fn get_worst_job(insertion_ctx: &InsertionContext) -> Option<(usize, Job)> {
+    let locked = &insertion_ctx.solution.locked;
+
+    get_routes_cost_savings(insertion_ctx)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(route_idx, (_, savings))| savings.into_iter().map(move |(job, saving)| (route_idx, job, saving)))
+        .filter(|(_, job, _)| !locked.contains(job))
+        // NOTE ties are broken by job id to keep the result deterministic
+        .max_by(|(_, a_job, a), (_, b_job, b)| {
+            compare_floats(*a, *b).then_with(|| b_job.dimens().get_id().cmp(&a_job.dimens().get_id()))
+        })
+        .map(|(route_idx, job, _)| (route_idx, job))
+}
+
+/// Returns the cheapest insertion of the job evaluated at every position of every route.
+fn get_best_insertion(insertion_ctx: &InsertionContext, job: &Job) -> Option<InsertionSuccess> {
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .fold(InsertionResult::make_failure(), |acc, route_ctx| {
+            evaluate_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, InsertionPosition::Any, acc)
+        })
+        .into_success()
+}
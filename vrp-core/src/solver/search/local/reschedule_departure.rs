@@ -1,7 +1,7 @@
 use crate::construction::extensions::{advance_departure_time, recede_departure_time};
 use crate::construction::heuristics::InsertionContext;
 use crate::models::solution::Activity;
-use crate::solver::search::LocalOperator;
+use crate::solver::search::{LocalOperator, OperatorCost};
 use crate::solver::RefinementContext;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
@@ -41,6 +41,10 @@ impl LocalOperator for RescheduleDeparture {
 
         Some(insertion_ctx)
     }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Cheap
+    }
 }
 
 fn can_recede_departure(start: &Activity, earliest: f64) -> bool {
@@ -0,0 +1,140 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/reverse_segment_test.rs"]
+mod reverse_segment_test;
+
+use super::*;
+use crate::models::problem::Job;
+
+const MIN_JOBS: usize = 3;
+const MIN_SEGMENT_SIZE: usize = 2;
+
+/// A local search operator which reverses a contiguous segment of activities inside one route
+/// (2-opt move). Reversed jobs are inserted back one by one, so hard constraints are respected.
+/// The move is rejected if it makes the route more expensive.
+pub struct ReverseSegment {
+    max_segment_size: usize,
+}
+
+impl ReverseSegment {
+    /// Creates a new instance of `ReverseSegment` which reverses segments of at most
+    /// `max_segment_size` activities.
+    pub fn new(max_segment_size: usize) -> Self {
+        assert!(max_segment_size >= MIN_SEGMENT_SIZE);
+
+        Self { max_segment_size }
+    }
+}
+
+impl Default for ReverseSegment {
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
+impl LocalOperator for ReverseSegment {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let random = &insertion_ctx.environment.random;
+
+        let route_indices = get_route_indices(insertion_ctx);
+        if route_indices.is_empty() {
+            return None;
+        }
+        let route_idx = route_indices[random.uniform_int(0, route_indices.len() as i32 - 1) as usize];
+
+        let activity_count = insertion_ctx.solution.routes[route_idx].route.tour.job_activity_count();
+        let segment_size =
+            random.uniform_int(MIN_SEGMENT_SIZE as i32, self.max_segment_size.min(activity_count) as i32) as usize;
+        let start_index = random.uniform_int(1, (activity_count - segment_size + 1) as i32) as usize;
+
+        let jobs = get_segment_jobs(insertion_ctx, route_idx, start_index, segment_size)?;
+        let original_cost = insertion_ctx.solution.routes[route_idx].get_route_cost();
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        reverse_jobs(&mut new_insertion_ctx, route_idx, start_index, jobs)?;
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        let new_cost = new_insertion_ctx.solution.routes[route_idx].get_route_cost();
+
+        if compare_floats(new_cost, original_cost) == Ordering::Greater {
+            None
+        } else {
+            Some(new_insertion_ctx)
+        }
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Cheap
+    }
+}
+
+/// Returns indices of routes which have enough jobs to reverse a segment.
+fn get_route_indices(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.job_count() >= MIN_JOBS)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Returns jobs of the segment in the tour order. Returns `None` if any activity of the segment
+/// belongs to a locked job or to a job with multiple activities as such segment cannot be reversed.
+fn get_segment_jobs(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    start_index: usize,
+    segment_size: usize,
+) -> Option<Vec<Job>> {
+    let tour = &insertion_ctx.solution.routes[route_idx].route.tour;
+
+    tour.activities_slice(start_index, start_index + segment_size - 1)
+        .iter()
+        .map(|activity| {
+            activity
+                .retrieve_job()
+                .filter(|job| matches!(job, Job::Single(_)) && !insertion_ctx.solution.locked.contains(job))
+        })
+        .collect()
+}
+
+/// Removes jobs from the route and inserts them back in reversed order starting from given index.
+fn reverse_jobs(
+    insertion_ctx: &mut InsertionContext,
+    route_idx: usize,
+    start_index: usize,
+    jobs: Vec<Job>,
+) -> Option<()> {
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx)?;
+    jobs.iter().for_each(|job| {
+        assert!(route_ctx.route_mut().tour.remove(job));
+    });
+    insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+
+    jobs.iter().rev().enumerate().try_for_each(|(offset, job)| {
+        let eval_ctx = EvaluationContext {
+            constraint: &insertion_ctx.problem.constraint,
+            job,
+            leg_selector: &leg_selector,
+            result_selector: &result_selector,
+        };
+
+        // NOTE insertion on leg with index i places the activity at index i + 1
+        let success = evaluate_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.get(route_idx)?,
+            InsertionPosition::Concrete(start_index + offset - 1),
+            InsertionResult::make_failure(),
+        )
+        .into_success()?;
+
+        apply_insertion_success(insertion_ctx, success);
+
+        Some(())
+    })
+}
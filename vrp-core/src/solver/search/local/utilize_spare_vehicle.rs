@@ -0,0 +1,155 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/utilize_spare_vehicle_test.rs"]
+mod utilize_spare_vehicle_test;
+
+use super::*;
+use crate::construction::constraints::TOTAL_DURATION_KEY;
+use crate::models::common::IdDimension;
+use crate::models::problem::{Actor, Job};
+use crate::solver::objectives::has_work_balance;
+use hashbrown::HashSet;
+use std::iter::once;
+
+/// A local search operator which moves a cluster of jobs from the busiest route to a spare (unused)
+/// vehicle. It is applied only when the problem has a work balance objective and the move is accepted
+/// only if it improves the solution in terms of the problem objective.
+pub struct UtilizeSpareVehicle {
+    max_jobs: usize,
+}
+
+impl UtilizeSpareVehicle {
+    /// Creates a new instance of `UtilizeSpareVehicle`.
+    pub fn new(max_jobs: usize) -> Self {
+        assert!(max_jobs > 0);
+
+        Self { max_jobs }
+    }
+}
+
+impl Default for UtilizeSpareVehicle {
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl LocalOperator for UtilizeSpareVehicle {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        if !has_work_balance(insertion_ctx.problem.as_ref()) {
+            return None;
+        }
+
+        let random = insertion_ctx.environment.random.clone();
+
+        // NOTE registry returns only actors which can be used respecting fleet limits
+        let mut spare_actors =
+            insertion_ctx.solution.registry.next().map(|route_ctx| route_ctx.route.actor.clone()).collect::<Vec<_>>();
+        if spare_actors.is_empty() {
+            return None;
+        }
+        spare_actors.sort_by_key(|actor| actor.vehicle.dimens.get_id().cloned());
+        let spare_actor = spare_actors.get(random.uniform_int(0, spare_actors.len() as i32 - 1) as usize)?.clone();
+
+        let (route_idx, movable) = get_busiest_route(insertion_ctx)?;
+        let seed_job = movable.get(random.uniform_int(0, movable.len() as i32 - 1) as usize)?.clone();
+        let max_jobs = (movable.len() / 2).max(1).min(self.max_jobs);
+        let cluster_size = random.uniform_int(1, max_jobs as i32) as usize;
+
+        let cluster = get_cluster(insertion_ctx, route_idx, &movable, seed_job, cluster_size);
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        cluster.iter().for_each(|job| {
+            new_insertion_ctx.solution.routes[route_idx].route_mut().tour.remove(job);
+        });
+        new_insertion_ctx.solution.required.extend(cluster.iter().cloned());
+
+        let leg_selector = VariableLegSelector::new(random);
+        let mut new_insertion_ctx = InsertionHeuristic::default().process(
+            new_insertion_ctx,
+            &AllJobSelector::default(),
+            &SpareRouteSelector { actor: spare_actor },
+            &leg_selector,
+            &BestResultSelector::default(),
+        );
+
+        if cluster.iter().any(|job| new_insertion_ctx.solution.unassigned.contains_key(job)) {
+            return None;
+        }
+
+        new_insertion_ctx.restore();
+
+        match insertion_ctx.problem.objective.total_order(&new_insertion_ctx, insertion_ctx) {
+            Ordering::Less => Some(new_insertion_ctx),
+            _ => None,
+        }
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Moderate
+    }
+}
+
+/// Returns only the route of the spare actor.
+struct SpareRouteSelector {
+    actor: Arc<Actor>,
+}
+
+impl RouteSelector for SpareRouteSelector {
+    fn select<'a>(
+        &'a self,
+        insertion_ctx: &'a mut InsertionContext,
+        _jobs: &[Job],
+    ) -> Box<dyn Iterator<Item = RouteContext> + 'a> {
+        let solution = &insertion_ctx.solution;
+        let route_ctx = solution
+            .routes
+            .iter()
+            .find(|route_ctx| route_ctx.route.actor == self.actor)
+            .cloned()
+            .or_else(|| solution.registry.next_with_actor(self.actor.as_ref()));
+
+        Box::new(route_ctx.into_iter())
+    }
+}
+
+/// Returns index of the route with the longest duration and its jobs which can be moved.
+fn get_busiest_route(insertion_ctx: &InsertionContext) -> Option<(usize, Vec<Job>)> {
+    let solution = &insertion_ctx.solution;
+
+    solution
+        .routes
+        .iter()
+        .enumerate()
+        .map(|(idx, route_ctx)| {
+            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+            let movable = route_ctx.route.tour.jobs().filter(|job| !solution.locked.contains(job)).collect::<Vec<_>>();
+
+            (idx, duration, movable)
+        })
+        .filter(|(_, _, movable)| !movable.is_empty())
+        .max_by(|(_, a, _), (_, b, _)| compare_floats(*a, *b))
+        .map(|(idx, _, movable)| (idx, movable))
+}
+
+/// Returns the seed job with its closest neighbours from the same route.
+fn get_cluster(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    movable: &[Job],
+    seed_job: Job,
+    cluster_size: usize,
+) -> Vec<Job> {
+    let route_ctx = &insertion_ctx.solution.routes[route_idx];
+    let profile = &route_ctx.route.actor.vehicle.profile;
+    let movable = movable.iter().collect::<HashSet<_>>();
+
+    let neighbours = insertion_ctx
+        .problem
+        .jobs
+        .neighbors(profile, &seed_job, Default::default())
+        .map(|(job, _)| job)
+        .filter(|job| movable.contains(job))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    once(seed_job).chain(neighbours).take(cluster_size).collect()
+}
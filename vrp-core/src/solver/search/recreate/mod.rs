@@ -19,6 +19,9 @@ pub use self::recreate_with_blinks::RecreateWithBlinks;
 mod recreate_with_cheapest;
 pub use self::recreate_with_cheapest::RecreateWithCheapest;
 
+mod recreate_with_clusters;
+pub use self::recreate_with_clusters::RecreateWithClusters;
+
 mod recreate_with_farthest;
 pub use self::recreate_with_farthest::RecreateWithFarthest;
 
@@ -43,6 +46,9 @@ pub use self::recreate_with_skip_random::RecreateWithSkipRandom;
 mod recreate_with_slice;
 pub use self::recreate_with_slice::RecreateWithSlice;
 
+mod recreate_with_unassigned_retry;
+pub use self::recreate_with_unassigned_retry::RecreateWithUnassignedRetry;
+
 /// Provides the way to run one of multiple recreate methods.
 pub struct WeightedRecreate {
     recreates: Vec<Arc<dyn Recreate + Send + Sync>>,
@@ -0,0 +1,225 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/recreate/recreate_with_clusters_test.rs"]
+mod recreate_with_clusters_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Distance, Location, Profile};
+use crate::models::problem::{get_job_locations, Actor, Job};
+use crate::solver::search::recreate::Recreate;
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+/// A recreate method which splits jobs into geographical clusters and inserts each cluster using
+/// only routes which are not used by other clusters, so each vehicle serves jobs of one cluster.
+/// Jobs which cannot be served this way are inserted afterwards using all routes. Intended to be
+/// used as a coarse construction phase for large problems: refinement then is responsible to
+/// exchange jobs between clusters.
+pub struct RecreateWithClusters {
+    cluster_count: usize,
+    random: Arc<dyn Random + Send + Sync>,
+}
+
+impl RecreateWithClusters {
+    /// Creates a new instance of `RecreateWithClusters`.
+    pub fn new(cluster_count: usize, random: Arc<dyn Random + Send + Sync>) -> Self {
+        assert!(cluster_count > 0);
+
+        Self { cluster_count, random }
+    }
+}
+
+impl Recreate for RecreateWithClusters {
+    fn run(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+
+        let jobs = insertion_ctx
+            .solution
+            .required
+            .drain(0..)
+            .chain(insertion_ctx.solution.unassigned.drain().map(|(job, _)| job))
+            .collect::<Vec<_>>();
+
+        let clusters = match insertion_ctx.problem.fleet.profiles.first() {
+            Some(profile) => create_clusters(&insertion_ctx, profile, jobs, self.cluster_count),
+            None => vec![jobs],
+        };
+
+        let leg_selector = VariableLegSelector::new(self.random.clone());
+        let heuristic = InsertionHeuristic::default();
+        let mut used_actors = HashSet::<Arc<Actor>>::new();
+        let mut unassigned = Vec::new();
+
+        for cluster in clusters {
+            insertion_ctx.solution.required.extend(cluster);
+
+            let route_selector = ExcludedActorsRouteSelector { actors: used_actors.clone() };
+            insertion_ctx = heuristic.process(
+                insertion_ctx,
+                &AllJobSelector::default(),
+                &route_selector,
+                &leg_selector,
+                &BestResultSelector::default(),
+            );
+
+            used_actors.extend(insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.actor.clone()));
+            unassigned.extend(insertion_ctx.solution.unassigned.drain().map(|(job, _)| job));
+        }
+
+        if unassigned.is_empty() {
+            return insertion_ctx;
+        }
+
+        // NOTE jobs are returned as required without unassignment code as, otherwise, insertion
+        //      evaluator skips them for routes which are not modified since the last attempt
+        insertion_ctx.solution.required.extend(unassigned);
+
+        heuristic.process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &leg_selector,
+            &BestResultSelector::default(),
+        )
+    }
+}
+
+/// Returns routes which actors are not excluded and new routes.
+struct ExcludedActorsRouteSelector {
+    actors: HashSet<Arc<Actor>>,
+}
+
+impl RouteSelector for ExcludedActorsRouteSelector {
+    fn select<'a>(
+        &'a self,
+        insertion_ctx: &'a mut InsertionContext,
+        _jobs: &[Job],
+    ) -> Box<dyn Iterator<Item = RouteContext> + 'a> {
+        insertion_ctx.solution.routes.shuffle(&mut insertion_ctx.environment.random.get_rng());
+
+        Box::new(
+            insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .filter(|route_ctx| !self.actors.contains(&route_ctx.route.actor))
+                .cloned()
+                .chain(insertion_ctx.solution.registry.next()),
+        )
+    }
+}
+
+/// Specifies maximum amount of iterations used to improve cluster medoids.
+const MAX_CLUSTER_ITERATIONS: usize = 4;
+
+/// Splits jobs into clusters using k-medoids algorithm on job locations. Jobs without location
+/// form a separate cluster which goes last. Clusters are returned in descending order of their size.
+fn create_clusters(
+    insertion_ctx: &InsertionContext,
+    profile: &Profile,
+    jobs: Vec<Job>,
+    cluster_count: usize,
+) -> Vec<Vec<Job>> {
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let distance = |from: Location, to: Location| -> Distance { transport.distance_approx(profile, from, to) };
+
+    let (located, not_located): (Vec<_>, Vec<_>) = jobs
+        .into_iter()
+        .map(|job| {
+            let location = get_job_locations(&job).flatten().next();
+            (location, job)
+        })
+        .partition(|(location, _)| location.is_some());
+    let located = located.into_iter().map(|(location, job)| (location.unwrap(), job)).collect::<Vec<_>>();
+    let not_located = not_located.into_iter().map(|(_, job)| job).collect::<Vec<_>>();
+
+    let cluster_count = cluster_count.min(located.len());
+    let mut medoids =
+        get_initial_medoids(&located, cluster_count, &distance, insertion_ctx.environment.random.as_ref());
+
+    let assign = |medoids: &[Location]| {
+        located
+            .iter()
+            .map(|(location, _)| {
+                medoids
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, &a), (_, &b)| compare_floats(distance(a, *location), distance(b, *location)))
+                    .map_or(0, |(idx, _)| idx)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut assignment = assign(medoids.as_slice());
+    for _ in 0..MAX_CLUSTER_ITERATIONS {
+        let new_medoids = (0..medoids.len())
+            .map(|cluster_idx| {
+                let locations = located
+                    .iter()
+                    .zip(assignment.iter())
+                    .filter(|(_, &idx)| idx == cluster_idx)
+                    .map(|((location, _), _)| *location)
+                    .collect::<Vec<_>>();
+
+                locations
+                    .iter()
+                    .map(|&candidate| {
+                        (candidate, locations.iter().map(|&other| distance(candidate, other)).sum::<f64>())
+                    })
+                    .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+                    .map_or(medoids[cluster_idx], |(candidate, _)| candidate)
+            })
+            .collect::<Vec<_>>();
+
+        if new_medoids == medoids {
+            break;
+        }
+
+        medoids = new_medoids;
+        assignment = assign(medoids.as_slice());
+    }
+
+    let mut clusters = vec![Vec::new(); medoids.len()];
+    located.into_iter().zip(assignment).for_each(|((_, job), idx)| clusters[idx].push(job));
+    clusters.retain(|cluster| !cluster.is_empty());
+    clusters.sort_by_key(|cluster| std::cmp::Reverse(cluster.len()));
+
+    if !not_located.is_empty() {
+        clusters.push(not_located);
+    }
+
+    clusters
+}
+
+/// Selects initial medoids: the first one is random, others are the farthest from already selected.
+fn get_initial_medoids(
+    located: &[(Location, Job)],
+    cluster_count: usize,
+    distance: &dyn Fn(Location, Location) -> Distance,
+    random: &(dyn Random + Send + Sync),
+) -> Vec<Location> {
+    if located.is_empty() || cluster_count == 0 {
+        return vec![];
+    }
+
+    let first = located[random.uniform_int(0, located.len() as i32 - 1) as usize].0;
+
+    (1..cluster_count).fold(vec![first], |mut medoids, _| {
+        let farthest = located
+            .iter()
+            .map(|(location, _)| {
+                let min_distance = medoids.iter().map(|&medoid| distance(medoid, *location)).fold(f64::MAX, f64::min);
+                (*location, min_distance)
+            })
+            .max_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .filter(|(_, min_distance)| *min_distance > 0.);
+
+        if let Some((location, _)) = farthest {
+            medoids.push(location);
+        }
+
+        medoids
+    })
+}
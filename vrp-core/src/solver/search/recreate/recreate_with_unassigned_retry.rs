@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/recreate/recreate_with_unassigned_retry_test.rs"]
+mod recreate_with_unassigned_retry_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::IdDimension;
+use crate::solver::search::get_assigned_neighbours;
+use crate::solver::search::recreate::Recreate;
+use crate::solver::RefinementContext;
+use hashbrown::HashMap;
+use rosomaxa::HeuristicSolution;
+use std::sync::Arc;
+
+/// A recreate method which runs another recreate method and then tries to assign jobs which are
+/// still unassigned: it removes assigned jobs from the neighbourhood of each unassigned job, inserts
+/// the unassigned job first and then reinserts removed ones. A new solution is accepted only when
+/// it has less unassigned jobs. Each job is retried limited amount of times to bound runtime.
+pub struct RecreateWithUnassignedRetry {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    max_removed: usize,
+    max_attempts: usize,
+}
+
+impl RecreateWithUnassignedRetry {
+    /// Creates a new instance of `RecreateWithUnassignedRetry`.
+    pub fn new(recreate: Arc<dyn Recreate + Send + Sync>, max_removed: usize, max_attempts: usize) -> Self {
+        assert!(max_removed > 0);
+
+        Self { recreate, max_removed, max_attempts }
+    }
+}
+
+impl Recreate for RecreateWithUnassignedRetry {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = self.recreate.run(refinement_ctx, insertion_ctx);
+        let mut attempts = HashMap::new();
+
+        loop {
+            // NOTE sort to keep order reproducible
+            let mut candidates = insertion_ctx
+                .solution
+                .unassigned
+                .keys()
+                .filter(|job| attempts.get(*job).cloned().unwrap_or(0) < self.max_attempts)
+                .cloned()
+                .collect::<Vec<_>>();
+            candidates.sort_by_key(|job| job.dimens().get_id().cloned());
+
+            let seed_job = match candidates.first() {
+                Some(job) => job.clone(),
+                None => break,
+            };
+            *attempts.entry(seed_job.clone()).or_insert(0) += 1;
+
+            let neighbours = get_assigned_neighbours(&insertion_ctx, &seed_job, self.max_removed);
+            if neighbours.is_empty() {
+                attempts.insert(seed_job, self.max_attempts);
+                continue;
+            }
+
+            let mut new_insertion_ctx = insertion_ctx.deep_copy();
+            neighbours.iter().for_each(|(job, route_idx)| {
+                new_insertion_ctx.solution.routes[*route_idx].route_mut().tour.remove(job);
+            });
+
+            // insert seed job first, then try to reinsert removed jobs together with the rest of unassigned
+            let other_unassigned = new_insertion_ctx
+                .solution
+                .unassigned
+                .drain()
+                .filter(|(job, _)| *job != seed_job)
+                .collect::<HashMap<_, _>>();
+            new_insertion_ctx.solution.required.push(seed_job);
+            let mut new_insertion_ctx = self.recreate.run(refinement_ctx, new_insertion_ctx);
+
+            new_insertion_ctx.solution.unassigned.extend(other_unassigned);
+            new_insertion_ctx.solution.required.extend(neighbours.into_iter().map(|(job, _)| job));
+            let mut new_insertion_ctx = self.recreate.run(refinement_ctx, new_insertion_ctx);
+
+            new_insertion_ctx.restore();
+
+            if new_insertion_ctx.solution.unassigned.len() < insertion_ctx.solution.unassigned.len() {
+                insertion_ctx = new_insertion_ctx;
+            }
+        }
+
+        insertion_ctx
+    }
+}
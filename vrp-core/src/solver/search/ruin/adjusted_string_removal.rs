@@ -55,8 +55,8 @@ impl Default for AdjustedStringRemoval {
 }
 
 impl Ruin for AdjustedStringRemoval {
-    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
-        let routes: Vec<RouteContext> = insertion_ctx.solution.routes.clone();
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let routes: Vec<RouteContext> = insertion_ctx.solution.routes.to_vec();
         let problem = insertion_ctx.problem.clone();
         let locked = insertion_ctx.solution.locked.clone();
         let random = insertion_ctx.environment.random.clone();
@@ -64,7 +64,7 @@ impl Ruin for AdjustedStringRemoval {
         let (lsmax, ks) = self.calculate_limits(&routes, &random);
 
         let limits = RuinLimits::default();
-        let tracker = limits.get_tracker();
+        let tracker = limits.get_tracker(refinement_ctx);
 
         select_seed_jobs(&problem, &routes, &random)
             .filter(|job| !tracker.is_removed_job(job))
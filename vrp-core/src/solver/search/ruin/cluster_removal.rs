@@ -36,12 +36,12 @@ impl ClusterRemoval {
 }
 
 impl Ruin for ClusterRemoval {
-    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
         let locked = insertion_ctx.solution.locked.clone();
 
         let mut route_jobs = get_route_jobs(&insertion_ctx.solution);
-        let max_removed_activities = self.limits.get_chunk_size(&insertion_ctx);
-        let tracker = self.limits.get_tracker();
+        let max_removed_activities = self.limits.get_chunk_size(refinement_ctx, &insertion_ctx);
+        let tracker = self.limits.get_tracker(refinement_ctx);
 
         let mut indices = (0..self.clusters.len()).into_iter().collect::<Vec<usize>>();
         indices.shuffle(&mut insertion_ctx.environment.random.get_rng());
@@ -1,8 +1,13 @@
 //! The ruin module contains various strategies to destroy small, medium or large parts of an
 //! existing solution.
 
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/ruin_test.rs"]
+mod ruin_test;
+
 use crate::construction::heuristics::InsertionContext;
 use crate::solver::RefinementContext;
+use rosomaxa::prelude::{HeuristicContext, HeuristicStatistics};
 use std::sync::{Arc, RwLock};
 
 /// A trait which specifies logic to destroy parts of solution.
@@ -20,6 +25,9 @@ pub use self::cluster_removal::ClusterRemoval;
 mod neighbour_removal;
 pub use self::neighbour_removal::NeighbourRemoval;
 
+mod related_job_removal;
+pub use self::related_job_removal::{RelatedJobRemoval, RelatednessWeights};
+
 mod route_removal;
 pub use self::route_removal::*;
 
@@ -27,6 +35,7 @@ mod random_job_removal;
 pub use self::random_job_removal::RandomJobRemoval;
 
 mod worst_jobs_removal;
+pub(crate) use self::worst_jobs_removal::get_routes_cost_savings;
 pub use self::worst_jobs_removal::WorstJobRemoval;
 use crate::models::problem::{Actor, Job};
 use hashbrown::HashSet;
@@ -41,6 +50,7 @@ pub struct WeightedRuin {
 }
 
 /// Specifies a limit for amount of jobs to be removed.
+#[derive(Clone)]
 pub struct RuinLimits {
     /// Specifies minimum amount of ruined (removed) jobs.
     pub min_ruined_jobs: usize,
@@ -50,6 +60,59 @@ pub struct RuinLimits {
     pub ruined_activities_threshold: f64,
     /// Specifies maximum amount of affected routes.
     pub max_affected_routes: usize,
+    /// Specifies optional policy which grows ruin size when search is stuck.
+    pub adaptive_size: Option<AdaptiveRuinSize>,
+}
+
+/// Specifies a policy which keeps ruin size small while search improves the best known solution
+/// and ramps it up after consecutive non-improving generations. It resets back once a new best
+/// solution is found. The same stagnation ratio loosens acceptance of worse solutions, so large
+/// ruins coincide with loose acceptance.
+#[derive(Clone, Debug)]
+pub struct AdaptiveRuinSize {
+    /// Specifies amount of non-improving generations needed to reach max scale.
+    pub max_stagnation: usize,
+    /// Specifies max scale applied to ruin limits.
+    pub max_scale: f64,
+    /// Specifies a probability range to accept a worse solution: the first value is used while
+    /// search improves, the second one when it is stuck.
+    pub acceptance: (f64, f64),
+}
+
+impl AdaptiveRuinSize {
+    /// Creates a new instance of `AdaptiveRuinSize`.
+    pub fn new(max_stagnation: usize, max_scale: f64, acceptance: (f64, f64)) -> Self {
+        assert!(max_stagnation > 0);
+        assert!(max_scale >= 1.);
+        assert!(acceptance.0 >= 0. && acceptance.0 <= acceptance.1 && acceptance.1 <= 1.);
+
+        Self { max_stagnation, max_scale, acceptance }
+    }
+
+    /// Returns a stagnation ratio in [0, 1] range. It can be used to coordinate other search
+    /// parameters (e.g. acceptance strictness) with ruin size.
+    pub fn get_stagnation_ratio(&self, statistics: &HeuristicStatistics) -> f64 {
+        (statistics.stagnation as f64 / self.max_stagnation as f64).min(1.)
+    }
+
+    /// Returns a scale to be applied to ruin limits.
+    pub fn get_scale(&self, statistics: &HeuristicStatistics) -> f64 {
+        1. + (self.max_scale - 1.) * self.get_stagnation_ratio(statistics)
+    }
+
+    /// Returns a probability to accept a worse solution.
+    pub fn get_acceptance_probability(&self, statistics: &HeuristicStatistics) -> f64 {
+        let (min, max) = self.acceptance;
+
+        min + (max - min) * self.get_stagnation_ratio(statistics)
+    }
+}
+
+impl Default for AdaptiveRuinSize {
+    fn default() -> Self {
+        // NOTE acceptance is neutral by default, so only ruin size is adapted
+        Self::new(200, 2., (1., 1.))
+    }
 }
 
 impl RuinLimits {
@@ -65,47 +128,78 @@ impl RuinLimits {
             max_ruined_activities,
             ruined_activities_threshold: ruined_jobs_threshold,
             max_affected_routes,
+            adaptive_size: None,
         }
     }
 
+    /// Sets adaptive ruin size policy.
+    pub fn with_adaptive_size(mut self, adaptive_size: AdaptiveRuinSize) -> Self {
+        self.adaptive_size = Some(adaptive_size);
+        self
+    }
+
     /// Gets chunk size based on limits.
-    pub fn get_chunk_size(&self, ctx: &InsertionContext) -> usize {
+    pub fn get_chunk_size(&self, refinement_ctx: &RefinementContext, ctx: &InsertionContext) -> usize {
         let total = ctx.problem.jobs.size() - ctx.solution.unassigned.len() - ctx.solution.ignored.len();
+        let (min_ruined_jobs, max_ruined_activities) = self.get_scaled_limits(refinement_ctx);
 
-        let max_limit = (total as f64 * self.ruined_activities_threshold)
-            .max(self.min_ruined_jobs as f64)
-            .min(self.max_ruined_activities as f64)
+        let max_limit = (total as f64 * self.ruined_activities_threshold * self.get_scale(refinement_ctx))
+            .max(min_ruined_jobs as f64)
+            .min(max_ruined_activities as f64)
             .round() as usize;
 
-        ctx.environment
-            .random
-            .uniform_int(self.min_ruined_jobs as i32, self.max_ruined_activities as i32)
-            .min(max_limit as i32) as usize
+        ctx.environment.random.uniform_int(min_ruined_jobs as i32, max_ruined_activities as i32).min(max_limit as i32)
+            as usize
     }
 
     /// Gets a tracker of affected routes and jobs.
-    pub(crate) fn get_tracker(&self) -> AffectedTracker {
+    pub(crate) fn get_tracker(&self, refinement_ctx: &RefinementContext) -> AffectedTracker {
+        let (_, max_ruined_activities) = self.get_scaled_limits(refinement_ctx);
+        let max_affected_routes = (self.max_affected_routes as f64 * self.get_scale(refinement_ctx)).round() as usize;
+
         AffectedTracker {
             affected_actors: RwLock::new(HashSet::default()),
             removed_jobs: RwLock::new(HashSet::default()),
-            limits: self,
+            max_ruined_activities,
+            max_affected_routes,
         }
     }
+
+    /// Gets min and max amount of ruined activities scaled by adaptive ruin size policy.
+    fn get_scaled_limits(&self, refinement_ctx: &RefinementContext) -> (usize, usize) {
+        let scale = self.get_scale(refinement_ctx);
+
+        let min_ruined_jobs = (self.min_ruined_jobs as f64 * scale).round() as usize;
+        let max_ruined_activities = (self.max_ruined_activities as f64 * scale).round() as usize;
+
+        (min_ruined_jobs, max_ruined_activities)
+    }
+
+    fn get_scale(&self, refinement_ctx: &RefinementContext) -> f64 {
+        self.adaptive_size.as_ref().map_or(1., |adaptive_size| adaptive_size.get_scale(refinement_ctx.statistics()))
+    }
 }
 
 impl Default for RuinLimits {
     fn default() -> Self {
-        Self { min_ruined_jobs: 8, max_ruined_activities: 16, ruined_activities_threshold: 0.1, max_affected_routes: 8 }
+        Self {
+            min_ruined_jobs: 8,
+            max_ruined_activities: 16,
+            ruined_activities_threshold: 0.1,
+            max_affected_routes: 8,
+            adaptive_size: None,
+        }
     }
 }
 
-pub(crate) struct AffectedTracker<'a> {
+pub(crate) struct AffectedTracker {
     affected_actors: RwLock<HashSet<Arc<Actor>>>,
     removed_jobs: RwLock<HashSet<Job>>,
-    limits: &'a RuinLimits,
+    max_ruined_activities: usize,
+    max_affected_routes: usize,
 }
 
-impl<'a> AffectedTracker<'a> {
+impl AffectedTracker {
     pub fn add_job(&self, job: Job) {
         self.removed_jobs.write().unwrap().insert(job);
     }
@@ -126,9 +220,9 @@ impl<'a> AffectedTracker<'a> {
         let removed_activities = self.get_removed_activities();
         let affected_routes = self.get_affected_actors();
 
-        removed_activities < self.limits.max_ruined_activities
+        removed_activities < self.max_ruined_activities
             && removed_activities < max_removed_activities
-            && affected_routes < self.limits.max_affected_routes
+            && affected_routes < self.max_affected_routes
     }
 
     pub fn get_affected_actors(&self) -> usize {
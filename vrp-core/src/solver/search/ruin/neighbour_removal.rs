@@ -23,15 +23,15 @@ impl Default for NeighbourRemoval {
 }
 
 impl Ruin for NeighbourRemoval {
-    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
         let problem = insertion_ctx.problem.clone();
         let random = insertion_ctx.environment.random.clone();
 
         let routes = insertion_ctx.solution.routes.clone();
         let locked = insertion_ctx.solution.locked.clone();
 
-        let max_removed_activities = self.limits.get_chunk_size(&insertion_ctx);
-        let tracker = self.limits.get_tracker();
+        let max_removed_activities = self.limits.get_chunk_size(refinement_ctx, &insertion_ctx);
+        let tracker = self.limits.get_tracker(refinement_ctx);
 
         select_seed_jobs(&problem, &routes, &random)
             .filter(|job| !locked.contains(job))
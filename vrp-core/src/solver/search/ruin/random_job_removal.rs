@@ -23,12 +23,12 @@ impl Default for RandomJobRemoval {
 }
 
 impl Ruin for RandomJobRemoval {
-    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
         if insertion_ctx.solution.routes.is_empty() {
             return insertion_ctx;
         }
 
-        let affected = self.limits.get_chunk_size(&insertion_ctx);
+        let affected = self.limits.get_chunk_size(refinement_ctx, &insertion_ctx);
 
         (0..affected).for_each(|_| {
             let solution = &mut insertion_ctx.solution;
@@ -0,0 +1,177 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/related_job_removal_test.rs"]
+mod related_job_removal_test;
+
+use super::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::{CapacityDimension, Demand, DemandDimension, LoadOps, Location, TimeWindow};
+use crate::models::problem::Job;
+use crate::models::solution::Activity;
+use crate::solver::search::select_seed_job;
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+use rosomaxa::prelude::compare_floats;
+use std::iter::once;
+use std::marker::PhantomData;
+
+/// Specifies weights of job relatedness components used by `RelatedJobRemoval`.
+#[derive(Clone, Debug)]
+pub struct RelatednessWeights {
+    /// A weight of distance between job locations.
+    pub distance: f64,
+    /// A weight of time windows overlap.
+    pub time: f64,
+    /// A weight of demand difference.
+    pub demand: f64,
+}
+
+impl Default for RelatednessWeights {
+    fn default() -> Self {
+        Self { distance: 9., time: 3., demand: 2. }
+    }
+}
+
+/// A ruin strategy which removes jobs related to randomly selected seed job (inclusive). Relatedness
+/// is estimated as weighted sum of normalized distance, time windows overlap and demand difference,
+/// as described in "Using Constraint Programming and Local Search Methods to Solve Vehicle Routing
+/// Problems" paper by Paul Shaw.
+pub struct RelatedJobRemoval<T: LoadOps> {
+    /// Specifies weights of relatedness components.
+    weights: RelatednessWeights,
+    /// Specifies limitation for job removal.
+    limits: RuinLimits,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> RelatedJobRemoval<T> {
+    /// Creates a new instance of `RelatedJobRemoval`.
+    pub fn new(weights: RelatednessWeights, limits: RuinLimits) -> Self {
+        Self { weights, limits, phantom: PhantomData }
+    }
+}
+
+impl<T: LoadOps> Default for RelatedJobRemoval<T> {
+    fn default() -> Self {
+        Self::new(RelatednessWeights::default(), RuinLimits::default())
+    }
+}
+
+impl<T: LoadOps> Ruin for RelatedJobRemoval<T> {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = insertion_ctx.environment.random.clone();
+        let transport = insertion_ctx.problem.transport.clone();
+
+        let seed = select_seed_job(insertion_ctx.solution.routes.as_slice(), &random);
+        let features = get_job_features::<T>(insertion_ctx.solution.routes.as_slice(), &insertion_ctx.solution.locked);
+
+        let (seed_route_idx, seed_job, seed_features) = match seed {
+            Some((route_idx, job)) => match features.iter().find(|(other, _)| *other == job) {
+                Some((_, seed_features)) => (route_idx, job, seed_features),
+                None => return insertion_ctx,
+            },
+            None => return insertion_ctx,
+        };
+
+        let profile = insertion_ctx.solution.routes[seed_route_idx].route.actor.vehicle.profile.clone();
+        let components = features
+            .iter()
+            .filter(|(job, _)| *job != seed_job)
+            .map(|(job, features)| {
+                let distance = transport.distance_approx(&profile, seed_features.location, features.location).max(0.);
+                let time = get_time_difference(&seed_features.time, &features.time);
+                let demand = (seed_features.demand - features.demand).abs();
+
+                (job, distance, time, demand)
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE normalize distance to have it in the same range as other components
+        let max_distance = components.iter().map(|(_, distance, _, _)| *distance).fold(0., f64::max);
+        let mut related = components
+            .into_iter()
+            .map(|(job, distance, time, demand)| {
+                let distance = if max_distance > 0. { distance / max_distance } else { 0. };
+                let relatedness =
+                    self.weights.distance * distance + self.weights.time * time + self.weights.demand * demand;
+
+                (job.clone(), relatedness)
+            })
+            .collect::<Vec<_>>();
+        related.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+        let max_removed_activities = self.limits.get_chunk_size(refinement_ctx, &insertion_ctx);
+        let tracker = self.limits.get_tracker(refinement_ctx);
+
+        once(seed_job)
+            .chain(related.into_iter().map(|(job, _)| job))
+            .take_while(|_| tracker.is_not_limit(max_removed_activities))
+            .for_each(|job| {
+                let route = insertion_ctx.solution.routes.iter_mut().find(|rc| rc.route.tour.contains(&job));
+
+                if let Some(rc) = route {
+                    rc.route_mut().tour.remove(&job);
+
+                    tracker.add_actor(rc.route.actor.clone());
+                    tracker.add_job(job);
+                }
+            });
+
+        tracker.iterate_removed_jobs(|job| insertion_ctx.solution.required.push(job.clone()));
+
+        insertion_ctx
+    }
+}
+
+/// Keeps job properties used to estimate relatedness.
+struct JobFeatures {
+    location: Location,
+    time: TimeWindow,
+    demand: f64,
+}
+
+/// Collects features of all assigned jobs using their first activity in the tour.
+fn get_job_features<T: LoadOps>(routes: &[RouteContext], locked: &HashSet<Job>) -> Vec<(Job, JobFeatures)> {
+    let mut visited = HashSet::new();
+
+    routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let capacity: Option<&T> = route_ctx.route.actor.vehicle.dimens.get_capacity();
+
+            route_ctx.route.tour.all_activities().filter_map(move |activity| {
+                activity.retrieve_job().filter(|job| !locked.contains(job)).map(|job| {
+                    let features = JobFeatures {
+                        location: activity.place.location,
+                        time: activity.place.time.clone(),
+                        demand: get_demand_ratio(activity, capacity),
+                    };
+                    (job, features)
+                })
+            })
+        })
+        .filter(|(job, _)| visited.insert(job.clone()))
+        .collect()
+}
+
+fn get_demand_ratio<T: LoadOps>(activity: &Activity, capacity: Option<&T>) -> f64 {
+    let demand: Option<&Demand<T>> = activity.job.as_ref().and_then(|job| job.dimens.get_demand());
+
+    match (demand, capacity) {
+        (Some(demand), Some(capacity)) if capacity.is_not_empty() => {
+            let total = demand.pickup.0 + demand.pickup.1 + demand.delivery.0 + demand.delivery.1;
+            total.ratio(capacity)
+        }
+        _ => 0.,
+    }
+}
+
+/// Returns time difference in [0, 1] range: zero when time windows fully overlap.
+fn get_time_difference(a: &TimeWindow, b: &TimeWindow) -> f64 {
+    let shortest = a.duration().min(b.duration());
+
+    match a.overlapping(b) {
+        Some(overlap) if shortest > 0. && shortest.is_finite() => 1. - (overlap.duration() / shortest).min(1.),
+        Some(_) => 0.,
+        None => 1.,
+    }
+}
@@ -39,7 +39,7 @@ impl Default for WorstJobRemoval {
 }
 
 impl Ruin for WorstJobRemoval {
-    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
         let problem = insertion_ctx.problem.clone();
         let random = insertion_ctx.environment.random.clone();
 
@@ -53,15 +53,15 @@ impl Ruin for WorstJobRemoval {
 
         routes_savings.shuffle(&mut random.get_rng());
 
-        let max_removed_activities = self.limits.get_chunk_size(&insertion_ctx);
-        let tracker = self.limits.get_tracker();
+        let max_removed_activities = self.limits.get_chunk_size(refinement_ctx, &insertion_ctx);
+        let tracker = self.limits.get_tracker(refinement_ctx);
 
         routes_savings.iter().take_while(|_| tracker.is_not_limit(max_removed_activities)).for_each(|(rc, savings)| {
             let skip = savings.len().min(random.uniform_int(0, self.worst_skip as i32) as usize);
             let worst = savings.iter().filter(|(job, _)| can_remove_job(job)).nth(skip);
 
             if let Some((job, _)) = worst {
-                let remove = self.limits.get_chunk_size(&insertion_ctx);
+                let remove = self.limits.get_chunk_size(refinement_ctx, &insertion_ctx);
                 once(job.clone())
                     .chain(
                         problem
@@ -95,7 +95,8 @@ impl Ruin for WorstJobRemoval {
     }
 }
 
-fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(RouteContext, Vec<(Job, Cost)>)> {
+/// Returns cost savings of removing each job from its route, sorted in descending order per route.
+pub(crate) fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(RouteContext, Vec<(Job, Cost)>)> {
     parallel_collect(&insertion_ctx.solution.routes, |route_ctx| {
         let route = route_ctx.route.as_ref();
         let mut savings: Vec<(Job, Cost)> = route
@@ -2,19 +2,33 @@
 use super::*;
 use crate::construction::heuristics::finalize_insertion_ctx;
 use crate::models::problem::ProblemObjective;
+use rosomaxa::prelude::{HeuristicContext, Objective};
 use rosomaxa::HeuristicSolution;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/ruin_recreate_test.rs"]
+mod ruin_recreate_test;
+
 /// A mutation operator based on ruin and recreate principle.
 pub struct RuinAndRecreate {
     ruin: Arc<dyn Ruin + Send + Sync>,
     recreate: Arc<dyn Recreate + Send + Sync>,
+    adaptive_size: Option<AdaptiveRuinSize>,
 }
 
 impl RuinAndRecreate {
     /// Creates a new instance of `RuinAndRecreate` using given ruin and recreate methods.
     pub fn new(ruin: Arc<dyn Ruin + Send + Sync>, recreate: Arc<dyn Recreate + Send + Sync>) -> Self {
-        Self { ruin, recreate }
+        Self { ruin, recreate, adaptive_size: None }
+    }
+
+    /// Sets adaptive ruin size policy which controls acceptance of worse solutions: it should be
+    /// the same policy as used by ruin limits.
+    pub fn with_adaptive_acceptance(mut self, adaptive_size: AdaptiveRuinSize) -> Self {
+        self.adaptive_size = Some(adaptive_size);
+        self
     }
 }
 
@@ -27,11 +41,23 @@ impl HeuristicSearchOperator for RuinAndRecreate {
         let refinement_ctx = heuristic_ctx;
         let insertion_ctx = solution;
 
-        let mut insertion_ctx =
+        let mut new_insertion_ctx =
             self.recreate.run(refinement_ctx, self.ruin.run(refinement_ctx, insertion_ctx.deep_copy()));
 
-        finalize_insertion_ctx(&mut insertion_ctx);
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        if let Some(adaptive_size) = self.adaptive_size.as_ref() {
+            let probability = adaptive_size.get_acceptance_probability(refinement_ctx.statistics());
+
+            if probability < 1.
+                && refinement_ctx.objective().total_order(&new_insertion_ctx, insertion_ctx) == Ordering::Greater
+                && !insertion_ctx.environment.random.is_hit(probability)
+            {
+                // NOTE original solution is returned unchanged, so a cheap copy is enough
+                return insertion_ctx.cow_copy();
+            }
+        }
 
-        insertion_ctx
+        new_insertion_ctx
     }
 }
@@ -1,4 +1,4 @@
-use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
 use crate::models::problem::Job;
 use crate::models::Problem;
 use hashbrown::HashMap;
@@ -92,3 +92,32 @@ pub(crate) fn select_random_job(rc: &RouteContext, random: &Arc<dyn Random + Sen
 
     None
 }
+
+/// Returns assigned and not locked jobs from the neighbourhood of given job with their route indices.
+pub(crate) fn get_assigned_neighbours(
+    insertion_ctx: &InsertionContext,
+    seed_job: &Job,
+    max_removed: usize,
+) -> Vec<(Job, usize)> {
+    let solution = &insertion_ctx.solution;
+    let profile = match solution.routes.first() {
+        Some(route_ctx) => &route_ctx.route.actor.vehicle.profile,
+        None => return vec![],
+    };
+
+    let route_indices = solution
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, route_ctx)| route_ctx.route.tour.jobs().map(move |job| (job, idx)))
+        .collect::<HashMap<_, _>>();
+
+    insertion_ctx
+        .problem
+        .jobs
+        .neighbors(profile, seed_job, Default::default())
+        .filter(|(job, _)| !solution.locked.contains(job))
+        .filter_map(|(job, _)| route_indices.get(job).map(|&route_idx| (job.clone(), route_idx)))
+        .take(max_removed)
+        .collect()
+}
@@ -53,6 +53,14 @@ pub fn create_constraint_pipeline_with_transport() -> ConstraintPipeline {
     )))
 }
 
+/// Creates a pipeline with transport and tour size constraints, tour size is limited only if specified.
+pub fn create_constraint_pipeline_with_tour_size(tour_size: Option<usize>) -> ConstraintPipeline {
+    create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(TourSizeModule::new(Arc::new(move |_| tour_size), 2)),
+    ])
+}
+
 pub fn create_constraint_pipeline_with_simple_capacity() -> ConstraintPipeline {
     create_constraint_pipeline_with_module(Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(2)))
 }
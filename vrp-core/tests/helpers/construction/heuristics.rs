@@ -15,7 +15,7 @@ pub fn create_insertion_context(
     let registry = RegistryContext::new(problem.constraint.clone(), registry);
     InsertionContext {
         problem,
-        solution: SolutionContext { routes, registry, ..create_empty_solution_context() },
+        solution: SolutionContext { routes: routes.into(), registry, ..create_empty_solution_context() },
         environment: Arc::new(Environment::default()),
     }
 }
@@ -2,10 +2,10 @@ use crate::construction::constraints::{ConstraintPipeline, TOTAL_DISTANCE_KEY, T
 use crate::construction::heuristics::{InsertionContext, RegistryContext, SolutionContext, UnassignmentInfo};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
 use crate::helpers::models::problem::*;
-use crate::helpers::models::solution::create_route_context_with_activities;
-use crate::models::common::IdDimension;
+use crate::helpers::models::solution::{create_route_context_with_activities, test_activity_with_job};
+use crate::models::common::{IdDimension, Location};
 use crate::models::examples::create_example_problem;
-use crate::models::problem::{Fleet, Job, Jobs, ProblemObjective};
+use crate::models::problem::{Fleet, Job, Jobs, ProblemObjective, Single, Vehicle};
 use crate::models::solution::Registry;
 use crate::models::{Problem, Solution};
 use rosomaxa::utils::{DefaultRandom, Environment, Random};
@@ -43,6 +43,25 @@ pub fn create_problem_with_constraint_jobs_and_fleet(
     })
 }
 
+/// Creates a shallow copy of the problem which can be adjusted using struct update syntax.
+pub fn copy_problem(problem: &Problem) -> Problem {
+    Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        constraint: problem.constraint.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        objective: problem.objective.clone(),
+        extras: problem.extras.clone(),
+    }
+}
+
+/// Creates a copy of the problem which uses given objective.
+pub fn create_problem_with_objective(problem: &Problem, objective: Arc<ProblemObjective>) -> Arc<Problem> {
+    Arc::new(Problem { objective, ..copy_problem(problem) })
+}
+
 pub fn create_empty_solution() -> Solution {
     Solution {
         registry: Registry::new(&test_fleet(), test_random()),
@@ -59,13 +78,14 @@ pub fn create_registry_context(fleet: &Fleet) -> RegistryContext {
 
 pub fn create_empty_solution_context() -> SolutionContext {
     SolutionContext {
-        required: vec![],
-        ignored: vec![],
+        required: Default::default(),
+        ignored: Default::default(),
         unassigned: Default::default(),
         locked: Default::default(),
-        routes: vec![],
+        routes: Default::default(),
         registry: create_registry_context(&test_fleet()),
         state: Default::default(),
+        fitness_cache: Default::default(),
     }
 }
 
@@ -100,6 +120,115 @@ pub fn create_simple_insertion_ctx(distance: f64, unassigned: usize) -> Insertio
     insertion_ctx
 }
 
+/// Creates insertion context with given tours: each job is assigned to a vehicle as specified.
+pub fn create_insertion_ctx_with_tours(
+    constraint: ConstraintPipeline,
+    tours: Vec<(&str, Vec<&str>)>,
+) -> InsertionContext {
+    let singles = tours
+        .iter()
+        .flat_map(|(_, ids)| ids.iter())
+        .enumerate()
+        .map(|(idx, id)| test_single_with_id_and_location(id, Some(idx + 1)))
+        .collect::<Vec<Arc<Single>>>();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        constraint,
+        singles.iter().map(|single| Job::Single(single.clone())).collect(),
+        fleet,
+    );
+
+    let mut singles = singles.into_iter();
+    let routes = tours
+        .iter()
+        .map(|(vehicle_id, ids)| {
+            let activities = singles
+                .by_ref()
+                .take(ids.len())
+                .map(|single| {
+                    let location = single.places.first().and_then(|place| place.location).unwrap_or_default();
+                    let mut activity = test_activity_with_job(single);
+                    activity.place.location = location;
+                    activity
+                })
+                .collect();
+            create_route_context_with_activities(problem.fleet.as_ref(), vehicle_id, activities)
+        })
+        .collect::<Vec<_>>();
+
+    let mut registry = create_registry_context(problem.fleet.as_ref());
+    routes.iter().for_each(|route_ctx| {
+        registry.use_route(route_ctx);
+    });
+
+    let mut insertion_ctx = InsertionContext {
+        problem,
+        solution: SolutionContext { routes: routes.into(), registry, ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    };
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+/// Creates insertion context with given routes: each route is served by its vehicle and visits jobs
+/// at given locations in given order. Jobs are created by `job_factory` with `c0`, `c1`, .. ids.
+pub fn create_insertion_ctx_with_locations(
+    constraint: ConstraintPipeline,
+    routes: Vec<(Vehicle, Vec<Location>)>,
+    job_factory: impl Fn(&str, Option<Location>) -> Arc<Single>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> InsertionContext {
+    let (vehicles, routes): (Vec<_>, Vec<_>) = routes.into_iter().unzip();
+    let vehicle_ids = vehicles.iter().map(|vehicle| get_vehicle_id(vehicle).clone()).collect::<Vec<_>>();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles(vehicles).build();
+    let singles = routes
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(idx, location)| job_factory(&format!("c{}", idx), Some(*location)))
+        .collect::<Vec<_>>();
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        constraint,
+        singles.iter().map(|single| Job::Single(single.clone())).collect(),
+        fleet,
+    );
+
+    let mut singles = singles.into_iter();
+    let mut registry = create_registry_context(problem.fleet.as_ref());
+    let routes = vehicle_ids
+        .iter()
+        .zip(routes)
+        .map(|(vehicle_id, locations)| {
+            let activities = locations
+                .into_iter()
+                .map(|location| {
+                    let mut activity = test_activity_with_job(singles.next().unwrap());
+                    activity.place.location = location;
+                    activity
+                })
+                .collect();
+            let route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), vehicle_id, activities);
+            registry.use_route(&route_ctx);
+
+            route_ctx
+        })
+        .collect::<Vec<_>>();
+
+    let mut insertion_ctx = InsertionContext {
+        problem,
+        solution: SolutionContext { routes: routes.into(), registry, ..create_empty_solution_context() },
+        environment: Arc::new(Environment { random, ..Default::default() }),
+    };
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
 pub fn get_customer_ids_from_routes_sorted(insertion_ctx: &InsertionContext) -> Vec<Vec<String>> {
     let mut result = get_customer_ids_from_routes(insertion_ctx);
     result.sort();
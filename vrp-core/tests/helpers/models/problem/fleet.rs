@@ -61,6 +61,17 @@ pub fn test_vehicle_with_id(id: &str) -> Vehicle {
     Vehicle { profile: Profile::default(), costs: test_costs(), dimens, details: vec![test_vehicle_detail()] }
 }
 
+/// Creates a test vehicle which starts and ends at given location.
+pub fn test_vehicle_with_id_and_location(id: &str, location: Location) -> Vehicle {
+    let detail = test_vehicle_detail();
+    let detail = VehicleDetail {
+        start: Some(VehiclePlace { location, ..detail.start.unwrap() }),
+        end: Some(VehiclePlace { location, ..detail.end.unwrap() }),
+    };
+
+    Vehicle { details: vec![detail], ..test_vehicle_with_id(id) }
+}
+
 pub fn get_vehicle_id(vehicle: &Vehicle) -> &String {
     vehicle.dimens.get_id().unwrap()
 }
@@ -1,9 +1,12 @@
 use crate::algorithms::geometry::Point;
-use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::construction::constraints::{CapacityConstraintModule, TransportConstraintModule};
+use crate::construction::heuristics::{AllJobSelector, AllRouteSelector, BestResultSelector, VariableLegSelector};
+use crate::construction::heuristics::{InsertionContext, InsertionHeuristic, UnassignmentInfo};
+use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
-use crate::models::common::Location;
+use crate::models::common::{CapacityDimension, IdDimension, Location, SingleDimLoad};
 use crate::models::problem::*;
 use crate::models::solution::{Registry, Route};
 use crate::models::{Problem, Solution};
@@ -15,6 +18,19 @@ use std::sync::Arc;
 mod mutation;
 pub use self::mutation::*;
 
+/// Solves problem using insertion heuristic with default selectors.
+pub fn solve_with_insertion_heuristic(problem: Arc<Problem>) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+
+    InsertionHeuristic::default().process(
+        InsertionContext::new(problem, environment.clone()),
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &VariableLegSelector::new(environment.random.clone()),
+        &BestResultSelector::default(),
+    )
+}
+
 pub fn create_default_refinement_ctx(problem: Arc<Problem>) -> RefinementContext {
     let environment = Arc::new(Environment::default());
     RefinementContext::new(
@@ -25,6 +41,79 @@ pub fn create_default_refinement_ctx(problem: Arc<Problem>) -> RefinementContext
     )
 }
 
+/// Creates a solution with `job_count` vehicles and jobs where `c0` with demand 1 is served by vehicle `0`
+/// with capacity 2 and other jobs with given demand are unassigned. Other vehicles have capacity 1.
+pub fn create_insertion_ctx_with_unassigned_demand(unassigned_demand: i32, job_count: usize) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes(
+        1,
+        job_count,
+        false,
+        |id, location| {
+            let demand = if id == "c0" { 1 } else { unassigned_demand };
+            SingleBuilder::default().id(id).location(location).demand(create_simple_demand(demand)).build_shared()
+        },
+        |mut vehicle| {
+            let capacity = if get_vehicle_id(&vehicle) == "0" { 2 } else { 1 };
+            vehicle.dimens.set_capacity(SingleDimLoad::new(capacity));
+            vehicle
+        },
+        |data| (data.clone(), data),
+    );
+    solution.routes.iter().for_each(|route| {
+        solution.registry.use_actor(&route.actor);
+    });
+    let problem = Problem {
+        constraint: Arc::new(create_constraint_pipeline_with_modules(vec![
+            Arc::new(TransportConstraintModule::new(
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )),
+            Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(2)),
+        ])),
+        ..problem
+    };
+
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    insertion_ctx
+        .solution
+        .routes
+        .iter_mut()
+        .filter(|route_ctx| get_vehicle_id(&route_ctx.route.actor.vehicle) != "0")
+        .for_each(|route_ctx| {
+            let jobs = route_ctx.route.tour.jobs().collect::<Vec<_>>();
+            jobs.into_iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(&job);
+                insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+            });
+        });
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+/// Returns sorted pairs of vehicle and job ids assigned in the solution.
+pub fn get_vehicle_job_ids(insertion_ctx: &InsertionContext) -> Vec<(String, String)> {
+    let mut vehicle_jobs = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_id().cloned().unwrap();
+            route_ctx
+                .route
+                .tour
+                .jobs()
+                .map(move |job| (vehicle_id.clone(), job.dimens().get_id().cloned().unwrap()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    vehicle_jobs.sort();
+
+    vehicle_jobs
+}
+
 /// Generates matrix routes. See `generate_matrix_routes`.
 pub fn generate_matrix_routes_with_defaults(rows: usize, cols: usize, is_open_vrp: bool) -> (Problem, Solution) {
     generate_matrix_routes(
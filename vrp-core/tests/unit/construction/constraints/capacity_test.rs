@@ -1,10 +1,11 @@
 use crate::construction::constraints::*;
+use crate::construction::extensions::NoMultiTrip;
 use crate::construction::heuristics::{ActivityContext, RouteState};
 use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
+use crate::models::common::{CapacityDimension, Demand, DemandDimension, MultiDimLoad, SingleDimLoad};
 use crate::models::problem::{Job, Vehicle};
 use crate::models::solution::Activity;
 use std::sync::Arc;
@@ -133,6 +134,48 @@ fn can_evaluate_demand_on_activity_impl(
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_evaluate_mixed_deliveries_and_returns_on_route, (sizes, size, expected), {
+    can_evaluate_mixed_deliveries_and_returns_on_route_impl(sizes, size, expected);
+}}
+
+can_evaluate_mixed_deliveries_and_returns_on_route! {
+    case01: (vec![-4, -4, 3], 4, vec![false, true, true, true]),
+    case02: (vec![-4, -4, 3], 7, vec![false, false, true, true]),
+    case03: (vec![-4, -4, 3], 8, vec![false, false, false, false]),
+    case04: (vec![-4, 3, -4], 3, vec![false, true, true, true]),
+    case05: (vec![-2, 5, -2], -4, vec![true, true, false, false]),
+}
+
+fn can_evaluate_mixed_deliveries_and_returns_on_route_impl(sizes: Vec<i32>, size: i32, expected: Vec<bool>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        sizes
+            .into_iter()
+            .map(|size| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size))))
+            .collect(),
+    );
+    let pipeline = create_constraint_pipeline_with_simple_capacity();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size)));
+
+    let results = (0..expected.len())
+        .map(|index| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route.tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route.tour.get(index + 1),
+            };
+
+            pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx).is_none()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(results, expected);
+}
+
 parameterized_test! {can_merge_jobs_with_demand, (cluster, candidate, expected), {
     can_merge_jobs_with_demand_impl(cluster, candidate, expected);
 }}
@@ -182,3 +225,63 @@ fn can_merge_jobs_with_demand_impl(
         (Err(result), Err(expected)) => assert_eq!(result, expected),
     }
 }
+
+parameterized_test! {can_evaluate_demand_with_hard_and_soft_dimensions, (size, expected_route, expected_activity, expected_cost), {
+    can_evaluate_demand_with_hard_and_soft_dimensions_impl(size, expected_route, expected_activity, expected_cost);
+}}
+
+can_evaluate_demand_with_hard_and_soft_dimensions! {
+    case01_fit_both: (vec![1, 1], None, None, 0.),
+    case02_exceed_soft: (vec![1, 2], None, None, 100.),
+    case03_exceed_soft_more: (vec![1, 4], None, None, 300.),
+    case04_exceed_hard: (vec![9, 0], Some(RouteConstraintViolation { code: 2 }), create_activity_violation(true), 0.),
+    case05_exceed_both: (vec![9, 4], Some(RouteConstraintViolation { code: 2 }), create_activity_violation(true), 300.),
+}
+
+fn can_evaluate_demand_with_hard_and_soft_dimensions_impl(
+    size: Vec<i32>,
+    expected_route: Option<RouteConstraintViolation>,
+    expected_activity: Option<ActivityConstraintViolation>,
+    expected_cost: f64,
+) {
+    let create_single = |size: Vec<i32>| {
+        let mut single = test_single();
+        single.dimens.set_demand(Demand::<MultiDimLoad> {
+            pickup: (MultiDimLoad::default(), MultiDimLoad::default()),
+            delivery: (MultiDimLoad::new(size), MultiDimLoad::default()),
+        });
+        Arc::new(single)
+    };
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_capacity(MultiDimLoad::new(vec![10, 2]));
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx =
+        create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_job(create_single(vec![2, 1]))]);
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(
+        CapacityConstraintModule::<MultiDimLoad>::new_with_capacity_modes(
+            2,
+            Arc::new(NoMultiTrip::default()),
+            vec![CapacityMode::Hard, CapacityMode::Soft { penalty: 100. }],
+        ),
+    ));
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(create_single(size));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let route_result = pipeline.evaluate_hard_route(
+        &create_empty_solution_context(),
+        &route_ctx,
+        &Job::Single(target.job.clone().unwrap()),
+    );
+    let activity_result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+    let cost = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(route_result, expected_route);
+    assert_eq!(activity_result, expected_activity);
+    assert_eq!(cost, expected_cost);
+}
@@ -37,7 +37,8 @@ fn can_promote_jobs_between_required_and_ignored_impl(
     let required_set1: HashSet<String> = required_ids.iter().map(|s| (*s).to_string()).collect();
     let required_set2 = required_set1.clone();
 
-    let mut ctx = SolutionContext { required, ignored, ..create_empty_solution_context() };
+    let mut ctx =
+        SolutionContext { required: required.into(), ignored: ignored.into(), ..create_empty_solution_context() };
     let conditional = ConditionalJobModule::new(Box::new(ConcreteJobContextTransition {
         remove_required: move |_, _, job| !required_set1.contains(get_job_id(job)),
         promote_required: move |_, _, job| required_set2.contains(get_job_id(job)),
@@ -60,7 +61,7 @@ fn can_promote_locked_jobs() {
     let expected_locked_jobs2 = expected_locked_jobs1.clone();
 
     let mut ctx = SolutionContext {
-        required: jobs.clone(),
+        required: jobs.clone().into(),
         locked: jobs.iter().filter(move |job| already_locked_jobs.contains(get_job_id(job))).cloned().collect(),
         registry: create_registry_context(&test_fleet()),
         ..create_empty_solution_context()
@@ -0,0 +1,92 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use rosomaxa::prelude::Environment;
+
+const STATE_KEY: i32 = 1000;
+
+/// Returns emissions per distance unit: vehicle `v1` is twice as dirty as others.
+fn create_emissions_fn() -> LegMetricFn {
+    Arc::new(|actor: &Actor, distance: Distance, _: Duration| {
+        let factor = if get_vehicle_id(actor.vehicle.as_ref()) == "v1" { 2. } else { 1. };
+        distance * factor
+    })
+}
+
+fn create_pipeline(limit: Option<f64>) -> ConstraintPipeline {
+    create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(CustomMetricModule::new(
+            TestTransportCost::new_shared(),
+            create_emissions_fn(),
+            Arc::new(move |_| limit),
+            STATE_KEY,
+            2,
+        )),
+    ])
+}
+
+#[test]
+fn can_accumulate_metric_along_route() {
+    let pipeline = create_pipeline(None);
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![test_activity_with_location(10), test_activity_with_location(15)],
+    );
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    assert_eq!(get_route_metric(&route_ctx, STATE_KEY), 60.);
+}
+
+/// Creates a vehicle with fixed costs which is not interchangeable with the default one.
+fn create_clean_expensive_vehicle(id: &str) -> Vehicle {
+    let detail = test_vehicle_detail();
+    let start = detail.start.unwrap();
+    let detail = VehicleDetail {
+        start: Some(VehiclePlace { time: TimeInterval { earliest: Some(1.), ..start.time }, ..start }),
+        ..detail
+    };
+
+    Vehicle { costs: fixed_costs(), details: vec![detail], ..test_vehicle_with_id(id) }
+}
+
+parameterized_test! {can_limit_metric_with_cleaner_vehicle, (limit, expected), {
+    can_limit_metric_with_cleaner_vehicle_impl(limit, expected);
+}}
+
+can_limit_metric_with_cleaner_vehicle! {
+    case01_no_limit: (None, vec!["v1"]),
+    case02_limit_for_clean_vehicle: (Some(30.), vec!["v2"]),
+    case03_limit_for_no_vehicle: (Some(10.), vec![]),
+}
+
+fn can_limit_metric_with_cleaner_vehicle_impl(limit: Option<f64>, expected: Vec<&str>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), create_clean_expensive_vehicle("v2")])
+        .build();
+    let jobs = vec![SingleBuilder::default().id("job").location(Some(10)).build_as_job_ref()];
+    let problem = create_problem_with_constraint_jobs_and_fleet(create_pipeline(limit), jobs, fleet);
+    let environment = Arc::new(Environment::default());
+
+    let insertion_ctx = InsertionHeuristic::default().process(
+        InsertionContext::new(problem, environment.clone()),
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &VariableLegSelector::new(environment.random.clone()),
+        &BestResultSelector::default(),
+    );
+
+    let vehicles = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| get_vehicle_id(route_ctx.route.actor.vehicle.as_ref()).clone())
+        .collect::<Vec<_>>();
+    assert_eq!(vehicles, expected);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 1 - expected.len());
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_test_data(total_limit: Option<Duration>, stop_limit: Option<Duration>) -> (ConstraintPipeline, RouteContext) {
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(MaxWaitingModule::new(
+            TestTransportCost::new_shared(),
+            Arc::new(move |_| total_limit),
+            Arc::new(move |_| stop_limit),
+            2,
+        )),
+    ]);
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![
+            test_activity_with_location_tw_and_duration(10, TimeWindow::new(50., 100.), 0.),
+            test_activity_with_location_tw_and_duration(20, TimeWindow::new(0., 1000.), 0.),
+        ],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+
+    (pipeline, route_ctx)
+}
+
+parameterized_test! {can_limit_waiting_time, (limits, prev_idx, target, expected), {
+    can_limit_waiting_time_impl(limits, prev_idx, target, expected);
+}}
+
+can_limit_waiting_time! {
+    case01_no_limits: ((None, None), 2, (30, 100.), None),
+    case02_total_within_limit: ((Some(50.), None), 1, (15, 0.), None),
+    case03_total_exceeded: ((Some(50.), None), 2, (30, 100.), Some(2)),
+    case04_total_within_high_limit: ((Some(100.), None), 2, (30, 100.), None),
+    case05_stop_exceeded: ((None, Some(20.)), 2, (30, 100.), Some(2)),
+    case06_stop_within_limit: ((None, Some(40.)), 2, (30, 100.), None),
+    case07_waiting_is_absorbed: ((Some(30.), None), 0, (20, 0.), None),
+    case08_waiting_is_not_absorbed: ((Some(30.), None), 0, (5, 0.), Some(2)),
+}
+
+fn can_limit_waiting_time_impl(
+    limits: (Option<Duration>, Option<Duration>),
+    prev_idx: usize,
+    target: (Location, Timestamp),
+    expected: Option<i32>,
+) {
+    let (total_limit, stop_limit) = limits;
+    let (location, start) = target;
+    let (pipeline, route_ctx) = create_test_data(total_limit, stop_limit);
+    let target = test_activity_with_location_tw_and_duration(location, TimeWindow::new(start, 1000.), 0.);
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: prev_idx,
+            prev: route_ctx.route.tour.get(prev_idx).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(prev_idx + 1),
+        },
+    );
+
+    assert_eq!(result, expected.map(|code| ActivityConstraintViolation { code, stopped: false }));
+}
@@ -0,0 +1,122 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{Location, TimeWindow};
+use crate::models::problem::Single;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+const DELTA: Duration = 20.;
+
+fn create_pipeline(a: &Arc<Single>, b: &Arc<Single>) -> ConstraintPipeline {
+    let separation = MinSeparation { a: Job::Single(a.clone()), b: Job::Single(b.clone()), delta: DELTA };
+
+    create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(MinSeparationModule::new(vec![separation], TestTransportCost::new_shared(), 2)),
+    ])
+}
+
+fn create_activity(job: &Arc<Single>, location: Location, start: Timestamp, duration: Duration) -> Activity {
+    Activity {
+        job: Some(job.clone()),
+        ..test_activity_with_location_tw_and_duration(location, TimeWindow::new(start, 1000.), duration)
+    }
+}
+
+fn evaluate(
+    pipeline: &ConstraintPipeline,
+    route_ctx: &RouteContext,
+    prev_idx: usize,
+    target: &Activity,
+) -> Option<ActivityConstraintViolation> {
+    pipeline.evaluate_hard_activity(
+        route_ctx,
+        &ActivityContext {
+            index: prev_idx,
+            prev: route_ctx.route.tour.get(prev_idx).unwrap(),
+            target,
+            next: route_ctx.route.tour.get(prev_idx + 1),
+        },
+    )
+}
+
+parameterized_test! {can_separate_job_from_assigned_one, (target, prev_idx, expected), {
+    can_separate_job_from_assigned_one_impl(target, prev_idx, expected);
+}}
+
+can_separate_job_from_assigned_one! {
+    case01_too_close_after: (("b", 20, 0.), 1, Some(2)),
+    case02_spaced_by_time_window: (("b", 20, 40.), 1, None),
+    case03_spaced_by_travel: (("b", 40, 0.), 1, None),
+    case04_served_before: (("b", 40, 0.), 0, Some(2)),
+    case05_unrelated_job: (("c", 20, 0.), 1, None),
+}
+
+fn can_separate_job_from_assigned_one_impl(
+    target: (&str, Location, Timestamp),
+    prev_idx: usize,
+    expected: Option<i32>,
+) {
+    let (target_id, location, start) = target;
+    let (a, b, c) = (test_single_with_id("a"), test_single_with_id("b"), test_single_with_id("c"));
+    let pipeline = create_pipeline(&a, &b);
+    let mut route_ctx =
+        create_route_context_with_activities(&test_fleet(), "v1", vec![create_activity(&a, 10, 0., 5.)]);
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = create_activity(if target_id == "b" { &b } else { &c }, location, start, 0.);
+
+    let result = evaluate(&pipeline, &route_ctx, prev_idx, &target);
+
+    assert_eq!(result, expected.map(|code| ActivityConstraintViolation { code, stopped: false }));
+}
+
+parameterized_test! {can_keep_separation_when_other_job_is_inserted, (location, expected), {
+    can_keep_separation_when_other_job_is_inserted_impl(location, expected);
+}}
+
+can_keep_separation_when_other_job_is_inserted! {
+    case01_small_delay_is_absorbed: (12, None),
+    case02_large_delay_breaks_separation: (15, Some(2)),
+}
+
+fn can_keep_separation_when_other_job_is_inserted_impl(location: Location, expected: Option<i32>) {
+    let (a, b, c) = (test_single_with_id("a"), test_single_with_id("b"), test_single_with_id("c"));
+    let pipeline = create_pipeline(&a, &b);
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![create_activity(&a, 10, 0., 5.), create_activity(&b, 20, 40., 0.)],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = create_activity(&c, location, 0., 0.);
+
+    let result = evaluate(&pipeline, &route_ctx, 0, &target);
+
+    assert_eq!(result, expected.map(|code| ActivityConstraintViolation { code, stopped: false }));
+}
+
+#[test]
+fn can_split_separated_jobs_across_vehicles() {
+    let (a, b) = (test_single_with_id_and_location("a", Some(5)), test_single_with_id_and_location("b", Some(6)));
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        create_pipeline(&a, &b),
+        vec![Job::Single(a), Job::Single(b)],
+        fleet,
+    );
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+
+    let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["a"], vec!["b"]]);
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::{create_problem_with_constraint_jobs_and_fleet, get_customer_ids_from_routes};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{IdDimension, Location};
+use rosomaxa::utils::Environment;
+
+const VIOLATION_CODE: i32 = 2;
+
+fn create_transition_module() -> TransitionModule {
+    // NOTE forbid `a` to `b` transition in both directions
+    TransitionModule::new(
+        Arc::new(|from, to| {
+            matches!(
+                (from.dimens.get_id().map(|id| id.as_str()), to.dimens.get_id().map(|id| id.as_str())),
+                (Some("a"), Some("b")) | (Some("b"), Some("a"))
+            )
+        }),
+        VIOLATION_CODE,
+    )
+}
+
+fn create_activity(id: &str, location: Location) -> Activity {
+    let mut activity = test_activity_with_job(test_single_with_id_and_location(id, Some(location)));
+    activity.place.location = location;
+    activity
+}
+
+parameterized_test! {can_forbid_transition, (tour, prev_idx, target, expected), {
+    can_forbid_transition_impl(tour, prev_idx, target, expected);
+}}
+
+can_forbid_transition! {
+    case01_forbidden_prev: (vec!["a", "c"], 1, "b", Some(VIOLATION_CODE)),
+    case02_forbidden_next: (vec!["c", "a"], 1, "b", Some(VIOLATION_CODE)),
+    case03_separated_by_other: (vec!["a", "c"], 2, "b", None),
+    case04_after_depot: (vec!["c", "a"], 0, "b", None),
+    case05_allowed_pair: (vec!["a", "c"], 1, "d", None),
+    case06_forbidden_reversed: (vec!["b"], 1, "a", Some(VIOLATION_CODE)),
+}
+
+fn can_forbid_transition_impl(tour: Vec<&str>, prev_idx: usize, target: &str, expected: Option<i32>) {
+    let route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        tour.iter().enumerate().map(|(idx, id)| create_activity(id, idx as Location + 1)).collect(),
+    );
+    let target = create_activity(target, 10);
+    let pipeline = create_constraint_pipeline_with_modules(vec![Arc::new(create_transition_module())]);
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: prev_idx,
+            prev: route_ctx.route.tour.get(prev_idx).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(prev_idx + 1),
+        },
+    );
+
+    assert_eq!(result, expected.map(|code| ActivityConstraintViolation { code, stopped: false }));
+}
+
+#[test]
+fn can_avoid_forbidden_adjacency_in_insertion() {
+    let jobs = [("a", 1), ("b", 2), ("c", 10)]
+        .iter()
+        .map(|(id, location)| Job::Single(test_single_with_id_and_location(id, Some(*location))))
+        .collect();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(create_transition_module()),
+    ]);
+    let problem = create_problem_with_constraint_jobs_and_fleet(pipeline, jobs, fleet);
+    let environment = Arc::new(Environment::default());
+
+    let insertion_ctx = InsertionHeuristic::default().process(
+        InsertionContext::new(problem, environment.clone()),
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &VariableLegSelector::new(environment.random.clone()),
+        &BestResultSelector::default(),
+    );
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    let ids = get_customer_ids_from_routes(&insertion_ctx);
+    assert_eq!(ids.len(), 1);
+    assert!(ids[0].windows(2).all(|pair| !matches!((pair[0].as_str(), pair[1].as_str()), ("a", "b") | ("b", "a"))));
+}
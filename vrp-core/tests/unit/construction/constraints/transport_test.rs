@@ -139,7 +139,8 @@ mod timing {
                         .schedule(Schedule::new(35., 60.))
                         .build(),
                 ],
-            )],
+            )]
+            .into(),
             registry: create_registry_context(&fleet),
             ..create_empty_solution_context()
         };
@@ -524,3 +525,58 @@ mod time_dependent {
         }
     }
 }
+
+mod traffic {
+    use super::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::models::problem::{TrafficProfile, TrafficTransportCost};
+
+    parameterized_test! {can_consider_departure_time_with_traffic_profile, (departure, expected), {
+        can_consider_departure_time_with_traffic_profile_impl(departure, expected);
+    }}
+
+    can_consider_departure_time_with_traffic_profile! {
+        case01_before_rush_hour: (90., None),
+        case02_in_rush_hour: (100., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    }
+
+    fn can_consider_departure_time_with_traffic_profile_impl(
+        departure: Timestamp,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((departure, 1000.)))])
+                .build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+        let transport = Arc::new(
+            TrafficTransportCost::new(
+                vec![TrafficProfile { index: 0, buckets: vec![(100., 2.), (200., 1.)] }],
+                TestTransportCost::new_shared(),
+            )
+            .unwrap(),
+        );
+        let pipeline = create_constraint_pipeline_with_module(Arc::new(TransportConstraintModule::new(
+            transport,
+            TestActivityCost::new_shared(),
+            1,
+        )));
+        pipeline.accept_route_state(&mut route_ctx);
+        let target = test_activity_with_location_tw_and_duration(10, TimeWindow::new(0., 110.), 0.);
+
+        let result = pipeline.evaluate_hard_activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: route_ctx.route.tour.get(0).unwrap(),
+                target: &target,
+                next: route_ctx.route.tour.get(1),
+            },
+        );
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+fn create_route_ctx(modules: Vec<Arc<dyn ConstraintModule + Send + Sync>>) -> RouteContext {
+    let pipeline = create_constraint_pipeline_with_modules(modules);
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![test_activity_with_location(10), test_activity_with_location(20)],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_transport_module() -> Arc<dyn ConstraintModule + Send + Sync> {
+    Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1))
+}
+
+fn create_variance_module() -> Arc<dyn ConstraintModule + Send + Sync> {
+    // NOTE leg variances are chosen to have accumulated standard deviations of 2, 3 and 4
+    Arc::new(TravelVarianceModule::new(Arc::new(|_, _, to, _| match to {
+        10 => 4.,
+        20 => 5.,
+        _ => 7.,
+    })))
+}
+
+#[test]
+fn can_propagate_arrival_variance() {
+    let route_ctx = create_route_ctx(vec![create_transport_module(), create_variance_module()]);
+
+    let variances = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| route_ctx.state.get_activity_state::<f64>(ARRIVAL_VARIANCE_KEY, activity).cloned())
+        .collect::<Vec<_>>();
+
+    assert_eq!(variances, vec![None, Some(4.), Some(9.), Some(16.)]);
+}
+
+parameterized_test! {can_get_arrival_ranges, (z_score, expected), {
+    can_get_arrival_ranges_impl(z_score, expected);
+}}
+
+can_get_arrival_ranges! {
+    case01_point_estimate: (0., vec![None, Some((10., 10.)), Some((20., 20.)), Some((40., 40.))]),
+    case02_one_deviation: (1., vec![None, Some((8., 12.)), Some((17., 23.)), Some((36., 44.))]),
+    case03_two_deviations: (2., vec![None, Some((6., 14.)), Some((14., 26.)), Some((32., 48.))]),
+}
+
+fn can_get_arrival_ranges_impl(z_score: f64, expected: Vec<Option<(Timestamp, Timestamp)>>) {
+    let route_ctx = create_route_ctx(vec![create_transport_module(), create_variance_module()]);
+
+    let ranges = get_arrival_ranges(&route_ctx, z_score);
+
+    assert_eq!(ranges, expected);
+}
+
+#[test]
+fn can_return_no_ranges_without_variance_module() {
+    let route_ctx = create_route_ctx(vec![create_transport_module()]);
+
+    let ranges = get_arrival_ranges(&route_ctx, 1.);
+
+    assert_eq!(ranges, vec![None, None, None, None]);
+}
+
+parameterized_test! {can_get_z_score, (confidence, expected), {
+    can_get_z_score_impl(confidence, expected);
+}}
+
+can_get_z_score! {
+    case01_median: (0.5, 0.),
+    case02_one_deviation: (0.8413, 1.),
+    case03_two_sided_95: (0.975, 1.96),
+    case04_one_sided_99: (0.99, 2.326),
+}
+
+fn can_get_z_score_impl(confidence: f64, expected: f64) {
+    let z_score = get_z_score(confidence);
+
+    assert!((z_score - expected).abs() < 1E-3, "unexpected z-score: {}", z_score);
+}
+
+parameterized_test! {can_check_chance_constrained_time_windows, (confidence, activities, prev_idx, target, expected), {
+    can_check_chance_constrained_time_windows_impl(confidence, activities, prev_idx, target, expected);
+}}
+
+can_check_chance_constrained_time_windows! {
+    case01_deterministic: (0.5, vec![], 0, (10, 10.), None),
+    case02_one_deviation_met: (0.8413, vec![], 0, (10, 12.1), None),
+    case03_one_deviation_violated: (0.8413, vec![], 0, (10, 11.9), Some(2)),
+    case04_two_deviations_violated: (0.975, vec![], 0, (10, 13.), Some(2)),
+    case05_next_violated: (0.8413, vec![(20, 22.5)], 0, (10, 1000.), Some(2)),
+    case06_next_deterministic: (0.5, vec![(20, 22.5)], 0, (10, 1000.), None),
+    case07_slack_violated: (0.8413, vec![(20, 1000.), (30, 33.)], 0, (10, 1000.), Some(2)),
+    case08_slack_met: (0.8413, vec![(20, 1000.), (30, 34.)], 0, (10, 1000.), None),
+    case09_delayed_violated: (0.8413, vec![(20, 1000.), (30, 42.)], 0, (25, 1000.), Some(2)),
+    case10_delayed_met: (0.8413, vec![(20, 1000.), (30, 44.)], 0, (25, 1000.), None),
+}
+
+fn can_check_chance_constrained_time_windows_impl(
+    confidence: f64,
+    activities: Vec<(Location, Timestamp)>,
+    prev_idx: usize,
+    target: (Location, Timestamp),
+    expected: Option<i32>,
+) {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport.clone(), activity.clone(), 1)),
+        Arc::new(TravelVarianceModule::new_chance_constrained(
+            Arc::new(|_, _, _, _| 4.),
+            confidence,
+            transport,
+            activity,
+            2,
+        )),
+    ]);
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        activities
+            .into_iter()
+            .map(|(location, end)| test_activity_with_location_and_tw(location, TimeWindow::new(0., end)))
+            .collect(),
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location_and_tw(target.0, TimeWindow::new(0., target.1));
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: prev_idx,
+            prev: route_ctx.route.tour.get(prev_idx).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(prev_idx + 1),
+        },
+    );
+
+    assert_eq!(result, expected.map(|code| ActivityConstraintViolation { code, stopped: false }));
+}
@@ -0,0 +1,164 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::{create_empty_solution_context, create_problem_with_constraint_jobs_and_fleet};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{Schedule, TimeSpan};
+use crate::models::problem::Place;
+use crate::models::solution::Place as ActivityPlace;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_visits(times: Vec<(f64, f64)>, min_spacing: f64) -> Vec<Arc<Single>> {
+    let place = Place {
+        location: Some(10),
+        duration: 0.,
+        times: times.into_iter().map(|(start, end)| TimeSpan::Window(TimeWindow::new(start, end))).collect(),
+    };
+
+    Single::new_visits(vec![place], Default::default(), 2, min_spacing)
+}
+
+fn create_visit_activity(job: Arc<Single>, location: Location, time: (f64, f64), arrival: f64) -> Activity {
+    Activity {
+        place: ActivityPlace { location, duration: 0., time: TimeWindow::new(time.0, time.1) },
+        schedule: Schedule::new(arrival, arrival),
+        job: Some(job),
+        commute: None,
+    }
+}
+
+fn create_pipeline() -> ConstraintPipeline {
+    create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 2)),
+        Arc::new(VisitFrequencyModule::new(TestTransportCost::new_shared(), VIOLATION_CODE)),
+    ])
+}
+
+#[test]
+fn can_create_visits_sharing_frequency() {
+    let visits = create_visits(vec![(0., 1000.)], 100.);
+
+    assert_eq!(visits.len(), 2);
+    let frequencies = visits.iter().map(|visit| visit.visit_frequency().expect("no frequency")).collect::<Vec<_>>();
+    assert!(Arc::ptr_eq(frequencies[0], frequencies[1]));
+    assert_eq!(frequencies[0].visits, 2);
+    assert_eq!(frequencies[0].min_spacing, 100.);
+}
+
+parameterized_test! {can_check_visit_spacing, (time_start, expected), {
+    can_check_visit_spacing_impl(time_start, expected);
+}}
+
+can_check_visit_spacing! {
+    case01: (0., Some(ActivityConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+    case02: (90., Some(ActivityConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+    case03: (110., None),
+    case04: (200., None),
+}
+
+fn can_check_visit_spacing_impl(time_start: f64, expected: Option<ActivityConstraintViolation>) {
+    let visits = create_visits(vec![(0., 1000.)], 100.);
+    let fleet = test_fleet();
+    let scheduled = create_visit_activity(visits[0].clone(), 10, (0., 1000.), 10.);
+    let mut solution_ctx = SolutionContext {
+        routes: vec![create_route_context_with_activities(&fleet, "v1", vec![scheduled])].into(),
+        ..create_empty_solution_context()
+    };
+    let pipeline = create_pipeline();
+    pipeline.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.first().unwrap();
+    let target = create_visit_activity(visits[1].clone(), 10, (time_start, 1000.), 0.);
+
+    let result = pipeline.evaluate_hard_activity(
+        route_ctx,
+        &ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        },
+    );
+
+    assert_eq!(result.map(|v| (v.code, v.stopped)), expected.map(|v| (v.code, v.stopped)));
+}
+
+parameterized_test! {can_check_spacing_of_shifted_visits, (duration, expected), {
+    can_check_spacing_of_shifted_visits_impl(duration, expected);
+}}
+
+can_check_spacing_of_shifted_visits! {
+    case01_not_shifted: (10., None),
+    case02_shifted_too_close: (100., Some(ActivityConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+}
+
+fn can_check_spacing_of_shifted_visits_impl(duration: f64, expected: Option<ActivityConstraintViolation>) {
+    let visits = create_visits(vec![(0., 1000.)], 100.);
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let mut solution_ctx = SolutionContext {
+        routes: vec![
+            create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![create_visit_activity(visits[0].clone(), 10, (200., 1000.), 200.)],
+            ),
+            create_route_context_with_activities(
+                &fleet,
+                "v2",
+                vec![create_visit_activity(visits[1].clone(), 10, (90., 1000.), 10.)],
+            ),
+        ]
+        .into(),
+        ..create_empty_solution_context()
+    };
+    let pipeline = create_pipeline();
+    pipeline.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(1).unwrap();
+    let target = test_activity_with_location_and_duration(5, duration);
+
+    let result = pipeline.evaluate_hard_activity(
+        route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route.tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(1),
+        },
+    );
+
+    assert_eq!(result.map(|v| (v.code, v.stopped)), expected.map(|v| (v.code, v.stopped)));
+}
+
+#[test]
+fn can_schedule_twice_daily_job() {
+    let visits = create_visits(vec![(0., 100.), (500., 600.)], 300.);
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        create_pipeline(),
+        visits.iter().cloned().map(Job::Single).collect(),
+        test_fleet(),
+    );
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+
+    let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    let mut starts = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+        .filter(|activity| activity.job.is_some())
+        .map(|activity| activity.schedule.arrival.max(activity.place.time.start))
+        .collect::<Vec<_>>();
+    starts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(starts.len(), 2);
+    assert!(starts[1] - starts[0] >= 300.);
+}
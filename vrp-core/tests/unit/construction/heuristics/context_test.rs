@@ -1,5 +1,14 @@
-use crate::construction::heuristics::RouteState;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::{test_fleet, test_single_with_id};
 use crate::helpers::models::solution::{create_empty_route_ctx, test_activity};
+use crate::models::common::{IdDimension, SingleDimLoad};
+use crate::models::problem::Job;
+use crate::models::Problem;
+use rosomaxa::prelude::*;
+use std::sync::Arc;
 
 #[test]
 fn can_put_and_get_activity_state() {
@@ -94,3 +103,175 @@ fn can_use_stale_flag() {
     assert!(route_ctx_clone.is_stale());
     assert!(!route_ctx_fork.is_stale());
 }
+
+fn remove_first_job(route_ctx: &mut RouteContext) {
+    let job = route_ctx.route.tour.jobs().next().unwrap();
+    route_ctx.route_mut().tour.remove(&job);
+}
+
+parameterized_test! {can_share_routes_until_modified_in_cow_copy, is_copy_modified, {
+    can_share_routes_until_modified_in_cow_copy_impl(is_copy_modified);
+}}
+
+can_share_routes_until_modified_in_cow_copy! {
+    case01_modify_copy: true,
+    case02_modify_original: false,
+}
+
+fn can_share_routes_until_modified_in_cow_copy_impl(is_copy_modified: bool) {
+    let mut original = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_transport(),
+        vec![("v1", vec!["c0", "c1"]), ("v2", vec!["c2"])],
+    );
+    let mut copy = original.cow_copy();
+    assert!(original.solution.routes.iter().zip(copy.solution.routes.iter()).all(|(a, b)| a == b));
+
+    let (modified, unchanged) = if is_copy_modified { (&mut copy, &original) } else { (&mut original, &copy) };
+    remove_first_job(modified.solution.routes.first_mut().unwrap());
+
+    assert_eq!(get_customer_ids_from_routes(modified), vec![vec!["c1"], vec!["c2"]]);
+    assert_eq!(get_customer_ids_from_routes(unchanged), vec![vec!["c0", "c1"], vec!["c2"]]);
+    assert!(modified.solution.routes[0] != unchanged.solution.routes[0]);
+    assert!(modified.solution.routes[1] == unchanged.solution.routes[1]);
+}
+
+#[test]
+fn can_keep_activity_state_when_cow_copy_is_detached() {
+    let mut original =
+        create_insertion_ctx_with_tours(create_constraint_pipeline_with_transport(), vec![("v1", vec!["c0", "c1"])]);
+    let (route, state) = original.solution.routes[0].as_mut();
+    state.put_activity_state(CURRENT_CAPACITY_KEY, route.tour.get(2).unwrap(), SingleDimLoad::new(3));
+    let mut copy = original.cow_copy();
+
+    copy.solution.routes[0].state_mut();
+
+    let route_ctx = &copy.solution.routes[0];
+    let activity = route_ctx.route.tour.get(2).unwrap();
+    assert_eq!(route_ctx.state.get_activity_state(CURRENT_CAPACITY_KEY, activity), Some(&SingleDimLoad::new(3)));
+    assert!(!std::ptr::eq(activity, original.solution.routes[0].route.tour.get(2).unwrap()));
+}
+
+#[test]
+fn can_cache_fitness_values_until_invalidated() {
+    let mut cache = FitnessCache::default();
+    let calls = std::cell::Cell::new(0);
+    let calculate = || {
+        calls.set(calls.get() + 1);
+        vec![calls.get() as f64]
+    };
+
+    assert_eq!(cache.get_or_insert_with(1, calculate), vec![1.]);
+    assert_eq!(cache.get_or_insert_with(1, calculate), vec![1.]);
+    assert_eq!(cache.get_or_insert_with(2, calculate), vec![2.]);
+    cache.invalidate();
+    assert_eq!(cache.get_or_insert_with(2, calculate), vec![3.]);
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn can_invalidate_cached_fitness_when_solution_is_changed() {
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        create_constraint_pipeline_with_transport(),
+        vec![Job::Single(test_single_with_id("job1"))],
+        test_fleet(),
+    );
+    let mut insertion_ctx = InsertionContext::new_empty(problem, Arc::new(Environment::default()));
+    let original = insertion_ctx.get_fitness().collect::<Vec<_>>();
+
+    let job = insertion_ctx.problem.jobs.all().next().unwrap();
+    insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+
+    let fitness = insertion_ctx.get_fitness().collect::<Vec<_>>();
+    assert_ne!(fitness, original);
+    assert_eq!(fitness, insertion_ctx.deep_copy().get_fitness().collect::<Vec<_>>());
+}
+
+#[test]
+fn can_invalidate_cached_fitness_when_route_is_changed() {
+    let mut insertion_ctx =
+        create_insertion_ctx_with_tours(create_constraint_pipeline_with_transport(), vec![("v1", vec!["c0", "c1"])]);
+    let original = insertion_ctx.get_fitness().collect::<Vec<_>>();
+
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let route_ctx = insertion_ctx.solution.routes.first_mut().unwrap();
+    let job = route_ctx.route.tour.jobs().last().unwrap();
+    route_ctx.route_mut().tour.remove(&job);
+    constraint.accept_route_state(route_ctx);
+
+    let fitness = insertion_ctx.get_fitness().collect::<Vec<_>>();
+    assert_ne!(fitness, original);
+    assert_eq!(fitness, insertion_ctx.deep_copy().get_fitness().collect::<Vec<_>>());
+}
+
+#[test]
+fn can_change_solution_version_on_mutable_access() {
+    let mut insertion_ctx =
+        create_insertion_ctx_with_tours(create_constraint_pipeline_with_transport(), vec![("v1", vec!["c0", "c1"])]);
+    let solution = &mut insertion_ctx.solution;
+    let original = solution.get_version();
+
+    assert!(solution.unassigned.is_empty() && solution.state.get(&1).is_none());
+    assert_eq!(solution.get_version(), original);
+
+    solution.state.insert(1, Arc::new(1_usize));
+    let inserted = solution.get_version();
+    assert!(inserted > original);
+
+    solution.state.remove(&1);
+    solution.state.insert(1, Arc::new(2_usize));
+    assert!(solution.get_version() > inserted);
+}
+
+#[test]
+fn can_get_route_plan() {
+    let mut insertion_ctx =
+        create_insertion_ctx_with_tours(create_constraint_pipeline_with_transport(), vec![("v1", vec!["c0", "c1"])]);
+    let route_ctx = insertion_ctx.solution.routes.first_mut().unwrap();
+    let (route, state) = route_ctx.as_mut();
+    let activity = route.tour.get(1).unwrap();
+    state.put_activity_state(CURRENT_CAPACITY_KEY, activity, SingleDimLoad::new(3));
+
+    let plans = insertion_ctx.route_plan();
+
+    assert_eq!(plans.len(), 1);
+    assert_eq!(plans[0].vehicle_id.as_deref(), Some("v1"));
+    let job_ids = plans[0].activities.iter().map(|activity| activity.job_id.as_deref()).collect::<Vec<_>>();
+    assert_eq!(job_ids, vec![None, Some("c0"), Some("c1"), None]);
+    let loads = plans[0].activities.iter().map(|activity| activity.load.clone()).collect::<Vec<_>>();
+    assert_eq!(loads, vec![vec![], vec![3], vec![], vec![]]);
+    assert!(plans[0].activities.iter().all(|activity| activity.arrival <= activity.departure));
+}
+
+struct RejectJobConstraint {
+    job_id: String,
+}
+
+impl HardRouteConstraint for RejectJobConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if job.dimens().get_id() == Some(&self.job_id) {
+            Some(RouteConstraintViolation { code: 7 })
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn can_revalidate_solution_with_changed_constraint() {
+    let mut insertion_ctx = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_transport(),
+        vec![("v1", vec!["c0", "c1", "c2"])],
+    );
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_constraint(ConstraintVariant::HardRoute(Arc::new(RejectJobConstraint { job_id: "c1".to_string() })));
+    let problem = insertion_ctx.problem.clone();
+    let problem = Arc::new(Problem { constraint: Arc::new(constraint), ..copy_problem(problem.as_ref()) });
+
+    insertion_ctx.revalidate(problem);
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["c0", "c2"]]);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 1);
+    let (job, info) = insertion_ctx.solution.unassigned.iter().next().unwrap();
+    assert_eq!(job.dimens().get_id().unwrap(), "c1");
+    assert!(matches!(info, UnassignmentInfo::Simple(7)));
+}
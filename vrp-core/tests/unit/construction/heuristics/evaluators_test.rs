@@ -359,3 +359,210 @@ mod multi {
         }
     }
 }
+
+mod explanation {
+    use super::*;
+
+    parameterized_test! {can_explain_insertion, (time, expected), {
+        can_explain_insertion_impl(time, expected);
+    }}
+
+    can_explain_insertion! {
+        case01: ((0., 1000.), vec![(0, None), (1, None), (2, None)]),
+        case02: ((0., 2.), vec![(0, Some(1)), (1, Some(1)), (2, Some(1))]),
+        case03: ((0., 7.), vec![(0, None), (1, None), (2, Some(1))]),
+    }
+
+    fn can_explain_insertion_impl(time: (f64, f64), expected: Vec<(usize, Option<i32>)>) {
+        let registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1).insert_at(create_activity_at(10), 2);
+        let ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+        let job = Job::Single(Arc::new(Single {
+            places: vec![JobPlace {
+                location: Some(7),
+                duration: 0.0,
+                times: vec![TimeSpan::Window(TimeWindow::new(time.0, time.1))],
+            }],
+            dimens: Default::default(),
+        }));
+
+        let explanation = explain_insertion(&ctx, ctx.solution.routes.first().unwrap(), &job);
+
+        assert!(explanation.route_violation.is_none());
+        let attempts = explanation
+            .attempts
+            .iter()
+            .map(|attempt| {
+                assert_eq!(attempt.violation.is_none(), attempt.cost.is_some());
+                (attempt.index, attempt.violation.as_ref().map(|violation| violation.code))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(attempts, expected);
+    }
+}
+
+mod reassignment {
+    use super::*;
+    use crate::helpers::models::domain::test_random;
+    use crate::models::common::TimeInterval;
+    use crate::models::problem::{Costs, VehiclePlace};
+
+    parameterized_test! {can_evaluate_route_on_vehicle, (costs, end_time, expected), {
+        can_evaluate_route_on_vehicle_impl(costs, end_time, expected);
+    }}
+
+    can_evaluate_route_on_vehicle! {
+        case01_same_costs: (test_costs(), 1000., Ok(40.)),
+        case02_cheaper_costs: (Costs { per_driving_time: 0., per_waiting_time: 0., per_service_time: 0., ..test_costs() }, 1000., Ok(20.)),
+        case03_fixed_costs: (fixed_costs(), 1000., Ok(140.)),
+        case04_infeasible_time: (test_costs(), 15., Err(1)),
+    }
+
+    fn can_evaluate_route_on_vehicle_impl(costs: Costs, end_time: f64, expected: Result<Cost, i32>) {
+        let detail = VehicleDetail {
+            end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(end_time) } }),
+            ..test_vehicle_detail()
+        };
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicle(VehicleBuilder::default().id("v1").build())
+            .add_vehicle(VehicleBuilder::default().id("v2").costs(costs).details(vec![detail]).build())
+            .build();
+        let registry = Registry::new(&fleet, test_random());
+        let mut route_ctx = RouteContext::new(get_test_actor_from_fleet(&fleet, "v1"));
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1).insert_at(create_activity_at(10), 2);
+        let ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+
+        let result = evaluate_route_on_vehicle(
+            &ctx,
+            ctx.solution.routes.first().unwrap(),
+            get_test_actor_from_fleet(&fleet, "v2"),
+        );
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod feasibility {
+    use super::*;
+    use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+    use crate::models::common::TimeInterval;
+    use crate::models::problem::VehiclePlace;
+
+    parameterized_test! {can_get_feasible_vehicles, (v2_end_time, job_location, expected), {
+        can_get_feasible_vehicles_impl(v2_end_time, job_location, expected);
+    }}
+
+    can_get_feasible_vehicles! {
+        case01_all_feasible: (1000., 5, vec!["v1", "v2"]),
+        case02_one_feasible: (15., 10, vec!["v1"]),
+        case03_none_feasible: (15., 600, vec![]),
+    }
+
+    fn can_get_feasible_vehicles_impl(v2_end_time: f64, job_location: Location, expected: Vec<&str>) {
+        let create_detail = |end_time: f64| VehicleDetail {
+            end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(end_time) } }),
+            ..test_vehicle_detail()
+        };
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicle(VehicleBuilder::default().id("v1").details(vec![create_detail(1000.)]).build())
+            .add_vehicle(VehicleBuilder::default().id("v2").details(vec![create_detail(v2_end_time)]).build())
+            .build();
+        let job = SingleBuilder::default().id("job1").location(Some(job_location)).build_as_job_ref();
+        let problem = create_problem_with_constraint_jobs_and_fleet(
+            create_constraint_pipeline_with_transport(),
+            vec![job.clone()],
+            fleet,
+        );
+
+        let vehicle_ids = feasible_vehicles(problem, &job);
+
+        assert_eq!(vehicle_ids, expected);
+    }
+}
+
+mod fixed_cost {
+    use super::*;
+    use crate::helpers::models::domain::test_random;
+    use crate::models::problem::{Costs, VehiclePlace};
+
+    parameterized_test! {can_prefer_used_vehicle_when_fixed_cost_is_applied, (costs, expected_vehicle, expected_cost), {
+        can_prefer_used_vehicle_when_fixed_cost_is_applied_impl(costs, expected_vehicle, expected_cost);
+    }}
+
+    can_prefer_used_vehicle_when_fixed_cost_is_applied! {
+        case01_no_fixed_cost: (test_costs(), "v2", 4.),
+        case02_fixed_cost: (fixed_costs(), "v1", 44.),
+    }
+
+    fn can_prefer_used_vehicle_when_fixed_cost_is_applied_impl(
+        costs: Costs,
+        expected_vehicle: &str,
+        expected_cost: Cost,
+    ) {
+        let v2_detail = VehicleDetail {
+            start: Some(VehiclePlace { location: 20, ..test_vehicle_detail().start.unwrap() }),
+            end: Some(VehiclePlace { location: 20, ..test_vehicle_detail().end.unwrap() }),
+        };
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicle(VehicleBuilder::default().id("v1").build())
+            .add_vehicle(VehicleBuilder::default().id("v2").costs(costs).details(vec![v2_detail]).build())
+            .build();
+        let mut registry = Registry::new(&fleet, test_random());
+        let v1 = get_test_actor_from_fleet(&fleet, "v1");
+        registry.use_actor(&v1);
+        let mut route_ctx = RouteContext::new(v1);
+        route_ctx.route_mut().tour.insert_at(create_activity_at(10), 1);
+        let mut ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+        ctx.problem.constraint.accept_route_state(ctx.solution.routes.first_mut().unwrap());
+        let job = Job::Single(test_single_with_location(Some(21)));
+
+        let result = evaluate_job_insertion(&mut ctx, &job, InsertionPosition::Any);
+
+        if let InsertionResult::Success(success) = result {
+            assert_eq!(get_vehicle_id(success.context.route.actor.vehicle.deref()), &expected_vehicle.to_owned());
+            assert_eq!(compare_floats(success.cost, expected_cost), Ordering::Equal);
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+mod window_relaxation {
+    use super::*;
+    use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+    use rosomaxa::prelude::Environment;
+
+    parameterized_test! {can_suggest_window_relaxation, (job_location, job_end_time, expected), {
+        can_suggest_window_relaxation_impl(job_location, job_end_time, expected);
+    }}
+
+    can_suggest_window_relaxation! {
+        case01_no_relaxation_needed: (100, 100., None),
+        case02_one_minute: (5, 2., Some(1.)),
+        case03_several_minutes: (300, 100., Some(4.)),
+        case04_relaxation_does_not_help: (600, 100., None),
+    }
+
+    fn can_suggest_window_relaxation_impl(job_location: Location, job_end_time: f64, expected: Option<f64>) {
+        let job = SingleBuilder::default()
+            .id("job1")
+            .location(Some(job_location))
+            .times(vec![TimeWindow::new(0., job_end_time)])
+            .build_as_job_ref();
+        let problem = create_problem_with_constraint_jobs_and_fleet(
+            create_constraint_pipeline_with_transport(),
+            vec![],
+            test_fleet(),
+        );
+        let mut insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+        insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+
+        let suggestions = suggest_window_relaxation(problem.as_ref(), &insertion_ctx);
+
+        assert_eq!(suggestions.into_iter().map(|(_, minutes)| minutes).next(), expected);
+    }
+}
@@ -0,0 +1,14 @@
+use super::*;
+use crate::helpers::models::problem::test_single;
+use crate::helpers::models::solution::create_empty_route_ctx;
+use std::sync::Arc;
+
+#[test]
+fn can_get_insertion_cost() {
+    let job = Job::Single(Arc::new(test_single()));
+    let success = InsertionResult::make_success(12.5, job, vec![], create_empty_route_ctx());
+    let failure = InsertionResult::make_failure();
+
+    assert_eq!(success.cost(), Some(12.5));
+    assert_eq!(failure.cost(), None);
+}
@@ -1,9 +1,14 @@
 use crate::construction::constraints::*;
 use crate::construction::heuristics::*;
-use crate::helpers::models::domain::create_empty_insertion_context;
-use crate::helpers::models::solution::create_empty_route_ctx;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{CapacityDimension, DemandDimension, Location, SingleDimLoad, TimeWindow};
+use crate::models::problem::Costs;
 use rosomaxa::prelude::compare_floats;
 use std::cmp::Ordering::Equal;
+use std::sync::Arc;
 
 fn create_insertion_ctx(route_amount: usize, route_factory: &(dyn Fn(usize) -> RouteContext)) -> InsertionContext {
     let mut ctx = create_empty_insertion_context();
@@ -65,3 +70,147 @@ fn can_get_distance_mean() {
 
     assert_eq!(compare_floats(mean, 7.), Equal);
 }
+
+fn create_route_ctx_with_demands(demands: &[(Location, i32)]) -> RouteContext {
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_capacity(SingleDimLoad::new(10));
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+    let activities = demands
+        .iter()
+        .map(|&(location, demand)| {
+            let mut single = test_single_with_location(Some(location));
+            Arc::get_mut(&mut single).unwrap().dimens.set_demand(create_simple_demand(-demand));
+            let mut activity = test_activity_with_job(single);
+            activity.place.location = location;
+            activity
+        })
+        .collect();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+parameterized_test! {can_get_weighted_medoid, (heavy_weight, expected), {
+    can_get_weighted_medoid_impl(heavy_weight, expected);
+}}
+
+can_get_weighted_medoid! {
+    case01_same_weights: (1., Some(10)),
+    case02_heavy_stop: (10., Some(30)),
+}
+
+fn can_get_weighted_medoid_impl(heavy_weight: f64, expected: Option<Location>) {
+    let route_ctx = create_route_ctx_with_demands(&[(10, 1), (11, 1), (30, 1)]);
+    let transport = TestTransportCost::default();
+
+    let result = get_weighted_medoid(&route_ctx, &transport, &|_, activity| {
+        if activity.place.location == 30 {
+            heavy_weight
+        } else {
+            1.
+        }
+    });
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_use_demand_as_activity_weight() {
+    let route_ctx = create_route_ctx_with_demands(&[(10, 5), (30, 10)]);
+    let actor = route_ctx.route.actor.as_ref();
+    let activity_weight = create_demand_activity_weight::<SingleDimLoad>();
+
+    let weights =
+        route_ctx.route.tour.all_activities().map(|activity| activity_weight(actor, activity)).collect::<Vec<_>>();
+
+    assert_eq!(weights, vec![1., 1.5, 2., 1.]);
+}
+
+parameterized_test! {can_get_route_marginal_costs, (tour_size, expected), {
+    can_get_route_marginal_costs_impl(tour_size, expected);
+}}
+
+can_get_route_marginal_costs! {
+    case01_all_reassigned: (None, vec![("v1", 24., 0), ("v2", 24., 0)]),
+    case02_some_unassigned: (Some(3), vec![("v1", 24., 1), ("v2", 32., 1)]),
+}
+
+fn can_get_route_marginal_costs_impl(tour_size: Option<usize>, expected: Vec<(&str, f64, usize)>) {
+    let constraint = create_constraint_pipeline_with_tour_size(tour_size);
+    let insertion_ctx =
+        create_insertion_ctx_with_tours(constraint, vec![("v1", vec!["a", "b", "c"]), ("v2", vec!["d"])]);
+
+    let mut result = get_route_marginal_costs(&insertion_ctx)
+        .into_iter()
+        .map(|cost| (get_vehicle_id(&cost.actor.vehicle).clone(), cost.saving, cost.unassigned.len()))
+        .collect::<Vec<_>>();
+    result.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+    assert_eq!(
+        result,
+        expected.into_iter().map(|(id, saving, count)| (id.to_string(), saving, count)).collect::<Vec<_>>()
+    );
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["a", "b", "c"], vec!["d"]]);
+}
+
+parameterized_test! {can_get_route_geometry, (locations, expected_centroid, expected_spread), {
+    can_get_route_geometry_impl(locations, expected_centroid, expected_spread);
+}}
+
+can_get_route_geometry! {
+    case01_no_jobs: (vec![], Some(0), 0.),
+    case02_single_job: (vec![5], Some(0), (25. / 3_f64).sqrt()),
+    case03_line: (vec![10, 11, 12], Some(10), (205. / 5_f64).sqrt()),
+}
+
+fn can_get_route_geometry_impl(locations: Vec<Location>, expected_centroid: Option<Location>, expected_spread: f64) {
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        locations.into_iter().map(test_activity_with_location).collect(),
+    ));
+
+    let result = get_route_geometry(&insertion_ctx);
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(get_vehicle_id(&result[0].actor.vehicle), "v1");
+    assert_eq!(result[0].centroid, expected_centroid);
+    assert!((result[0].spread - expected_spread).abs() < 1E-9);
+}
+
+parameterized_test! {can_get_route_cost_breakdown, (vehicle_costs, expected), {
+    can_get_route_cost_breakdown_impl(vehicle_costs, expected);
+}}
+
+can_get_route_cost_breakdown! {
+    case01_default_costs: (test_costs(), (0., 20., 20., 8., 3.)),
+    case02_different_costs: (
+        Costs { fixed: 10., per_distance: 1., per_driving_time: 2., per_waiting_time: 3., per_service_time: 4. },
+        (10., 20., 40., 24., 12.)
+    ),
+}
+
+fn can_get_route_cost_breakdown_impl(vehicle_costs: Costs, expected: (f64, f64, f64, f64, f64)) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_costs(empty_costs()))
+        .add_vehicle(VehicleBuilder::default().id("v1").costs(vehicle_costs).build())
+        .build();
+    let problem =
+        create_problem_with_constraint_jobs_and_fleet(create_constraint_pipeline_with_transport(), vec![], fleet);
+    let mut route_ctx = create_route_context_with_activities(
+        problem.fleet.as_ref(),
+        "v1",
+        vec![
+            test_activity_with_location_tw_and_duration(5, TimeWindow::new(0., 1000.), 2.),
+            test_activity_with_location_tw_and_duration(10, TimeWindow::new(20., 1000.), 1.),
+        ],
+    );
+    problem.constraint.accept_route_state(&mut route_ctx);
+
+    let breakdown = route_cost_breakdown(&route_ctx, problem.as_ref());
+
+    let (fixed, distance, driving, waiting, service) = expected;
+    assert_eq!(breakdown, RouteCostBreakdown { fixed, distance, driving, waiting, service });
+    assert_eq!(route_cost(&route_ctx, problem.as_ref()), fixed + distance + driving + waiting + service);
+}
@@ -323,3 +323,42 @@ fn can_restore_solution_impl(
 //   - invalid order
 //   - multi assignment?
 //   - not all or no jobs
+
+parameterized_test! {can_repair_solution, (singles, routes, expected_routes, expected_unrepaired), {
+    can_repair_solution_impl(singles, routes, expected_routes, expected_unrepaired);
+}}
+
+can_repair_solution! {
+    case01_reinsert_at_valid_position: (vec![("job1", (Some(1), (0., 100.), 1., 1)), ("job2", (Some(3), (2., 4.), 1., 1)), ("job3", (Some(5), (0., 100.), 1., 1))],
+        vec![("v1", vec![ ("job1", 1, 1., (0., 100.), 0), ("job3", 5, 1., (0., 100.), 0), ("job2", 3, 1., (2., 4.), 0)])],
+        vec![("v1", vec!["job1", "job2", "job3"])], vec![]),
+
+    case02_keep_unassigned: (vec![("job1", (Some(1), (1., 3.), 1., 1)), ("job2", (Some(2), (0., 1.), 1., 1)), ("job3", (Some(3), (3., 5.), 1., 1))],
+        vec![("v1", vec![ ("job1", 1, 1., (1., 3.), 0), ("job2", 2, 1., (0., 1.), 0), ("job3", 3, 1., (3., 5.), 0)])],
+        vec![("v1", vec!["job1", "job3"])], vec!["job2"]),
+
+    case03_all_correct: (vec![("job1", (Some(1), (1., 3.), 1., 1)), ("job2", (Some(2), (2., 4.), 1., 1))],
+        vec![("v1", vec![ ("job1", 1, 1., (1., 3.), 0), ("job2", 2, 1., (2., 4.), 0)])],
+        vec![("v1", vec!["job1", "job2"])], vec![]),
+}
+
+fn can_repair_solution_impl(
+    singles: Vec<(&str, JobData)>,
+    routes: Vec<(&str, RouteData)>,
+    expected_routes: Vec<(&str, Vec<&str>)>,
+    expected_unrepaired: Vec<&str>,
+) {
+    let vehicles = vec![("v1", (10, (0, Some(0.), None), Some((0, None, Some(100.)))))];
+    let problem = Arc::new(create_test_problem(singles, vec![], vehicles, vec![]));
+    let mut insertion_ctx = create_test_insertion_ctx(problem.clone());
+    add_routes(&mut insertion_ctx, routes);
+    problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    let unrepaired = repair(&mut insertion_ctx);
+
+    let unrepaired = unrepaired.iter().map(|job| job.dimens().get_id().unwrap().as_str()).collect::<Vec<_>>();
+    assert_eq!(unrepaired, expected_unrepaired);
+    assert_eq!(get_routes(&insertion_ctx), expected_routes);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), expected_unrepaired.len());
+    assert!(insertion_ctx.solution.required.is_empty());
+}
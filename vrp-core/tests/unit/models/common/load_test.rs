@@ -45,6 +45,11 @@ mod single {
 
         assert!(from_value(10).can_fit(&from_value(5)));
         assert!(!from_value(5).can_fit(&from_value(10)));
+
+        assert_eq!(from_value(5).dimension(0), 5);
+        assert_eq!(from_value(5).dimension(1), 0);
+        assert!(from_value(5).unbounded(&[0]).can_fit(&from_value(10)));
+        assert!(!from_value(5).unbounded(&[1]).can_fit(&from_value(10)));
     }
 }
 
@@ -101,5 +106,10 @@ mod multi {
 
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
+
+        assert_eq!(from_vec(vec![3, 0, 2]).dimension(2), 2);
+        assert_eq!(from_vec(vec![3, 0, 2]).dimension(3), 0);
+        assert!(from_vec(vec![3, 0, 2]).unbounded(&[1, 2]).can_fit(&from_vec(vec![1, 1, 4])));
+        assert!(!from_vec(vec![3, 0, 2]).unbounded(&[2]).can_fit(&from_vec(vec![1, 1, 4])));
     }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::helpers::models::problem::TestTransportCost;
 use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile};
 
 fn create_matrix_data(
@@ -139,6 +140,53 @@ fn can_search_for_reserved_time_impl(times: Vec<(f64, f64)>, tests: Vec<((f64, f
     }
 }
 
+parameterized_test! {can_apply_traffic_profile, (profile_idx, timestamp, expected), {
+    can_apply_traffic_profile_impl(profile_idx, timestamp, expected);
+}}
+
+can_apply_traffic_profile! {
+    case01: (0, 0., 10.),
+    case02: (0, 99., 10.),
+    case03: (0, 100., 20.),
+    case04: (0, 150., 20.),
+    case05: (0, 200., 5.),
+    case06: (0, 1000., 5.),
+    case07: (1, 150., 10.),
+}
+
+fn can_apply_traffic_profile_impl(profile_idx: usize, timestamp: Timestamp, expected: Duration) {
+    let route = Route { actor: test_actor_with_profile(profile_idx), tour: Default::default() };
+    let costs = TrafficTransportCost::new(
+        vec![TrafficProfile { index: 0, buckets: vec![(200., 0.5), (100., 2.)] }],
+        TestTransportCost::new_shared(),
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration(&route, 0, 10, TravelTime::Departure(timestamp)), expected);
+    assert_eq!(costs.distance(&route, 0, 10, TravelTime::Departure(timestamp)), 10.);
+    assert_eq!(costs.duration_approx(&route.actor.vehicle.profile, 0, 10), 10.);
+}
+
+parameterized_test! {can_detect_invalid_traffic_profiles, (profiles, expected), {
+    can_detect_invalid_traffic_profiles_impl(profiles, expected);
+}}
+
+can_detect_invalid_traffic_profiles! {
+    case01_zero: (vec![(0, vec![(0., 0.)])], "traffic profile 0 has non-positive or non-finite duration factor"),
+    case02_nan: (vec![(0, vec![(0., f64::NAN)])], "traffic profile 0 has non-positive or non-finite duration factor"),
+    case03_inf: (vec![(0, vec![(0., f64::INFINITY)])], "traffic profile 0 has non-positive or non-finite duration factor"),
+    case04: (vec![(0, vec![(10., 1.), (10., 2.)])], "traffic profile 0 has buckets with the same start time"),
+    case05: (vec![(1, vec![(10., 1.)]), (1, vec![(20., 2.)])], "duplicate traffic profile: 1"),
+}
+
+fn can_detect_invalid_traffic_profiles_impl(profiles: Vec<(usize, Vec<(Timestamp, f64)>)>, expected: &str) {
+    let profiles = profiles.into_iter().map(|(index, buckets)| TrafficProfile { index, buckets }).collect();
+
+    let result = TrafficTransportCost::new(profiles, TestTransportCost::new_shared());
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
+
 mod objective {
     use super::*;
     use crate::helpers::models::domain::create_empty_insertion_context;
@@ -248,4 +296,51 @@ mod objective {
 
         assert_eq!(result, expected);
     }
+
+    parameterized_test! {can_get_emphasized_objective, (index, expected), {
+        can_get_emphasized_objective_impl(index, expected);
+    }}
+
+    can_get_emphasized_objective! {
+        case01_first: (0, vec![0, 1, 2]),
+        case02_second: (1, vec![1, 0, 2]),
+        case03_last: (2, vec![2, 0, 1]),
+        case04_overflow: (4, vec![1, 0, 2]),
+    }
+
+    fn can_get_emphasized_objective_impl(index: usize, expected: Vec<usize>) {
+        let objective = ProblemObjective::default();
+
+        let emphasized = objective.get_emphasized(index).expect("cannot get emphasized objective");
+
+        let actual = emphasized
+            .objectives
+            .iter()
+            .map(|group| objective.objectives.iter().position(|other| Arc::ptr_eq(&group[0], &other[0])).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_use_total_order_with_emphasized_objective() {
+        let objective = ProblemObjective::new(vec![
+            vec![Arc::new(TestObjective { index: 0 })],
+            vec![Arc::new(TestObjective { index: 1 })],
+            vec![Arc::new(TestObjective { index: 2 })],
+        ]);
+        let a = create_individual(vec![0., 0., 1.]);
+        let b = create_individual(vec![1., 1., 0.]);
+
+        let emphasized = objective.get_emphasized(2).expect("cannot get emphasized objective");
+
+        assert_eq!(objective.total_order(&a, &b), Ordering::Less);
+        assert_eq!(emphasized.total_order(&a, &b), Ordering::Greater);
+    }
+
+    #[test]
+    fn can_skip_emphasis_for_single_objective() {
+        let objective = ProblemObjective::new(vec![vec![TotalCost::minimize()]]);
+
+        assert!(objective.get_emphasized(1).is_none());
+    }
 }
@@ -0,0 +1,124 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::models::common::{Distance, Duration, Location, Profile};
+use crate::models::problem::*;
+use crate::models::solution::Route;
+use std::sync::Arc;
+
+struct LimitedTransportCost {
+    size: usize,
+}
+
+impl TransportCost for LimitedTransportCost {
+    fn duration_approx(&self, _: &Profile, from: Location, to: Location) -> Duration {
+        fake_routing(from, to)
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn duration(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        fake_routing(from, to)
+    }
+
+    fn distance(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.size)
+    }
+
+    fn has_profile(&self, profile_index: usize) -> bool {
+        profile_index == 0
+    }
+}
+
+fn create_problem(fleet: Fleet, jobs: Vec<Job>) -> Problem {
+    let transport: Arc<dyn TransportCost + Send + Sync> = Arc::new(LimitedTransportCost { size: 2 });
+    let fleet = Arc::new(fleet);
+    let jobs = Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport));
+
+    Problem {
+        fleet,
+        jobs,
+        locks: vec![],
+        constraint: Arc::new(Default::default()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ProblemObjective::default()),
+        extras: Arc::new(Default::default()),
+    }
+}
+
+#[test]
+fn can_validate_consistent_problem() {
+    let problem = create_problem(test_fleet(), vec![Job::Single(test_single_with_id_and_location("job1", Some(1)))]);
+
+    assert_eq!(problem.validate(), Ok(()));
+}
+
+#[test]
+fn can_detect_inconsistent_problem() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").profile(Profile::new(1, None)).build())
+        .build();
+    let no_times = Job::Single(Arc::new(Single {
+        places: vec![Place { location: Some(0), duration: 0., times: vec![] }],
+        dimens: Default::default(),
+    }));
+    let problem = create_problem(fleet, vec![Job::Single(test_single_with_id_and_location("job1", Some(5))), no_times]);
+
+    let errors = problem.validate().expect_err("should be invalid");
+
+    assert_eq!(
+        errors,
+        vec![
+            ProblemError::UnknownProfile { vehicle_id: Some("v1".to_string()), profile_index: 1 },
+            ProblemError::JobLocationOutOfRange { job_id: Some("job1".to_string()), location: 5 },
+            ProblemError::JobWithoutVisits { job_id: None },
+        ]
+    );
+}
+
+#[test]
+fn can_detect_empty_fleet() {
+    let mut problem = create_problem(test_fleet(), vec![]);
+    Arc::get_mut(&mut problem.fleet).unwrap().vehicles.clear();
+
+    assert_eq!(problem.validate(), Err(vec![ProblemError::EmptyFleet]));
+}
+
+parameterized_test! {can_detect_vehicle_location_out_of_range, (start, end, expected), {
+    can_detect_vehicle_location_out_of_range_impl(start, end, expected);
+}}
+
+can_detect_vehicle_location_out_of_range! {
+    case01_start: (3, 0, vec![3]),
+    case02_end: (0, 4, vec![4]),
+    case03_both: (3, 4, vec![3, 4]),
+    case04_none: (1, 0, vec![]),
+}
+
+fn can_detect_vehicle_location_out_of_range_impl(start: Location, end: Location, expected: Vec<Location>) {
+    let mut detail = test_vehicle_detail();
+    detail.start.as_mut().unwrap().location = start;
+    detail.end.as_mut().unwrap().location = end;
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").details(vec![detail]).build())
+        .build();
+    let problem = create_problem(fleet, vec![Job::Single(test_single_with_id_and_location("job1", Some(1)))]);
+
+    let errors = problem.validate().err().unwrap_or_default();
+
+    assert_eq!(
+        errors,
+        expected
+            .into_iter()
+            .map(|location| ProblemError::VehicleLocationOutOfRange { vehicle_id: Some("v1".to_string()), location })
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::construction::extensions::NoMultiTrip;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Demand, DemandDimension, MultiDimLoad};
+
+parameterized_test! {can_calculate_capacity_overflow, (demands, expected), {
+    can_calculate_capacity_overflow_impl(demands, expected);
+}}
+
+can_calculate_capacity_overflow! {
+    case01_no_overflow: (vec![vec![2, 1], vec![1, 1]], 0.),
+    case02_soft_overflow: (vec![vec![2, 1], vec![1, 2]], 100.),
+    case03_soft_overflow_more: (vec![vec![2, 2], vec![1, 3]], 300.),
+    case04_empty_route: (vec![], 0.),
+}
+
+fn can_calculate_capacity_overflow_impl(demands: Vec<Vec<i32>>, expected: f64) {
+    let create_activity = |size: Vec<i32>| {
+        let mut single = test_single();
+        single.dimens.set_demand(Demand::<MultiDimLoad> {
+            pickup: (MultiDimLoad::default(), MultiDimLoad::default()),
+            delivery: (MultiDimLoad::new(size), MultiDimLoad::default()),
+        });
+        test_activity_with_job(Arc::new(single))
+    };
+    let modes = vec![CapacityMode::Hard, CapacityMode::Soft { penalty: 100. }];
+    let multi_trip = Arc::new(NoMultiTrip::<MultiDimLoad>::default());
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_capacity(MultiDimLoad::new(vec![10, 2]));
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx =
+        create_route_context_with_activities(&fleet, "v1", demands.into_iter().map(create_activity).collect());
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(
+        CapacityConstraintModule::<MultiDimLoad>::new_with_capacity_modes(2, multi_trip.clone(), modes.clone()),
+    ));
+    pipeline.accept_route_state(&mut route_ctx);
+    let insertion_ctx = InsertionContext {
+        solution: SolutionContext { routes: vec![route_ctx].into(), ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    };
+
+    let fitness = CapacityOverflow::new(multi_trip, modes.as_slice()).fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
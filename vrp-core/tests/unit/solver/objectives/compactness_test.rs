@@ -0,0 +1,69 @@
+use super::*;
+use crate::algorithms::geometry::Point;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::domain::{copy_problem, test_random};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::generate_matrix_distances_from_points;
+use crate::models::problem::{create_matrix_transport_cost, MatrixData};
+use crate::models::solution::Registry;
+use crate::models::Problem;
+
+fn create_insertion_ctx_with_route(locations: Vec<usize>) -> InsertionContext {
+    // NOTE depot is at the first point
+    let points =
+        vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.), Point::new(0., 1.), Point::new(2., 0.)];
+    let distances = generate_matrix_distances_from_points(points.as_slice());
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)]).unwrap();
+
+    let fleet = test_fleet();
+    let activities = locations.into_iter().map(test_activity_with_location).collect();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let registry = Registry::new(&fleet, test_random());
+    let insertion_ctx =
+        create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+
+    let problem = insertion_ctx.problem.clone();
+    let problem = Arc::new(Problem { transport, ..copy_problem(problem.as_ref()) });
+
+    InsertionContext { problem, ..insertion_ctx }
+}
+
+#[test]
+fn can_prefer_tidy_loop_over_zigzag_with_same_distance() {
+    // NOTE both routes have total distance equal to 4
+    let tidy = create_insertion_ctx_with_route(vec![1, 2, 3]);
+    let zigzag = create_insertion_ctx_with_route(vec![4, 1]);
+    let objective = Compactness::minimize(1.);
+
+    let (tidy_fitness, zigzag_fitness) = (objective.fitness(&tidy), objective.fitness(&zigzag));
+
+    assert!(tidy_fitness > 0.);
+    assert_eq!(compare_floats(tidy_fitness, zigzag_fitness), Ordering::Less);
+    assert_eq!(objective.total_order(&tidy, &zigzag), Ordering::Less);
+}
+
+parameterized_test! {can_apply_weight, (weight, expected), {
+    can_apply_weight_impl(weight, expected);
+}}
+
+can_apply_weight! {
+    case01: (1., 0.75),
+    case02: (2., 1.5),
+}
+
+fn can_apply_weight_impl(weight: f64, expected: f64) {
+    let insertion_ctx = create_insertion_ctx_with_route(vec![4, 1]);
+
+    let fitness = Compactness::minimize(weight).fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+#[test]
+fn can_ignore_empty_routes() {
+    let insertion_ctx = create_insertion_ctx_with_route(vec![]);
+
+    assert_eq!(Compactness::minimize(1.).fitness(&insertion_ctx), 0.);
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::construction::heuristics::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::models::common::{Distance, Duration, TimeInterval};
+use crate::models::problem::{Actor, ProblemObjective, Vehicle, VehicleDetail, VehiclePlace};
+
+/// Creates a vehicle which is not interchangeable with the default one, so both are offered for insertion.
+fn create_vehicle_with_later_start(id: &str) -> Vehicle {
+    let detail = test_vehicle_detail();
+    let start = detail.start.unwrap();
+    let detail = VehicleDetail {
+        start: Some(VehiclePlace { time: TimeInterval { earliest: Some(1.), ..start.time }, ..start }),
+        ..detail
+    };
+
+    Vehicle { details: vec![detail], ..test_vehicle_with_id(id) }
+}
+
+#[test]
+fn can_prefer_vehicle_with_lower_metric() {
+    let transport = TestTransportCost::new_shared();
+    let leg_fn: LegMetricFn = Arc::new(|actor: &Actor, distance: Distance, _: Duration| {
+        let factor = if get_vehicle_id(actor.vehicle.as_ref()) == "v1" { 3. } else { 1. };
+        distance * factor
+    });
+    let (constraint, objective) = TotalCustomMetric::minimize(transport.clone(), leg_fn, Arc::new(|_| None), 1000, 2);
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport, TestActivityCost::new_shared(), 1)),
+        constraint,
+    ]);
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), create_vehicle_with_later_start("v2")])
+        .build();
+    let jobs = vec![SingleBuilder::default().id("job").location(Some(10)).build_as_job_ref()];
+    let problem = create_problem_with_constraint_jobs_and_fleet(pipeline, jobs, fleet);
+    let problem =
+        create_problem_with_objective(problem.as_ref(), Arc::new(ProblemObjective::new(vec![vec![objective.clone()]])));
+    let environment = Arc::new(Environment::default());
+
+    let insertion_ctx = InsertionHeuristic::default().process(
+        InsertionContext::new(problem, environment.clone()),
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &VariableLegSelector::new(environment.random.clone()),
+        &BestResultSelector::default(),
+    );
+
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(get_vehicle_id(insertion_ctx.solution.routes[0].route.actor.vehicle.as_ref()), "v2");
+    assert_eq!(objective.fitness(&insertion_ctx), 20.);
+}
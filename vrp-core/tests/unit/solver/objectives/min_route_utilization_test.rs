@@ -0,0 +1,69 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(activities: usize, max_load: Option<f64>) -> RouteContext {
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        (0..activities).map(|idx| test_activity_with_location(idx + 1)).collect(),
+    );
+
+    if let Some(max_load) = max_load {
+        route_ctx.state_mut().put_route_state(MAX_LOAD_KEY, max_load);
+    }
+
+    route_ctx
+}
+
+parameterized_test! {can_calculate_job_count_shortage, (min_jobs, activities, expected), {
+    can_calculate_job_count_shortage_impl(min_jobs, activities, expected);
+}}
+
+can_calculate_job_count_shortage! {
+    case01_no_jobs: (4, 0, 1.),
+    case02_less_jobs: (4, 1, 0.75),
+    case03_enough_jobs: (4, 4, 0.),
+    case04_more_jobs: (4, 5, 0.),
+    case05_no_limit: (0, 0, 0.),
+}
+
+fn can_calculate_job_count_shortage_impl(min_jobs: usize, activities: usize, expected: f64) {
+    let shortage_fn = MinRouteUtilization::create_job_count_shortage(min_jobs);
+
+    let result = shortage_fn.deref()(&create_route_ctx(activities, None));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_calculate_load_shortage, (min_ratio, max_load, expected), {
+    can_calculate_load_shortage_impl(min_ratio, max_load, expected);
+}}
+
+can_calculate_load_shortage! {
+    case01_no_state: (0.5, None, 1.),
+    case02_less_load: (0.5, Some(0.25), 0.5),
+    case03_enough_load: (0.5, Some(0.5), 0.),
+    case04_more_load: (0.5, Some(0.75), 0.),
+}
+
+fn can_calculate_load_shortage_impl(min_ratio: f64, max_load: Option<f64>, expected: f64) {
+    let shortage_fn = MinRouteUtilization::create_load_shortage(min_ratio);
+
+    let result = shortage_fn.deref()(&create_route_ctx(1, max_load));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_penalize_underutilized_routes() {
+    let (_, objective) = MinRouteUtilization::new_penalized(MinRouteUtilization::create_job_count_shortage(4));
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes =
+        vec![create_route_ctx(1, None), create_route_ctx(4, None), create_route_ctx(2, None)].into();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, 1.25);
+}
@@ -0,0 +1,112 @@
+use super::*;
+use crate::construction::heuristics::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::solve_with_insertion_heuristic;
+use crate::models::problem::{ProblemObjective, Vehicle};
+use crate::models::Problem;
+
+fn create_overtime_fn(rules: Vec<(&str, Timestamp, Cost)>) -> OvertimeFn {
+    let rules = rules.into_iter().map(|(id, shift_end, rate)| (id.to_string(), (shift_end, rate))).collect::<Vec<_>>();
+
+    Arc::new(move |actor: &Actor| {
+        let vehicle_id = get_vehicle_id(actor.vehicle.as_ref());
+        rules.iter().find(|(id, _)| id == vehicle_id).map(|(_, rule)| *rule)
+    })
+}
+
+fn create_problem(vehicles: Vec<Vehicle>, overtime_fn: OvertimeFn) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let (constraint, objective) = Overtime::minimize(transport.clone(), activity.clone(), overtime_fn);
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport, activity, 1)),
+        constraint,
+    ]);
+    let jobs = vec![SingleBuilder::default().id("job").location(Some(15)).build_as_job_ref()];
+    let mut fleet = FleetBuilder::default();
+    fleet.add_driver(test_driver());
+    vehicles.into_iter().for_each(|vehicle| {
+        fleet.add_vehicle(vehicle);
+    });
+    let problem = create_problem_with_constraint_jobs_and_fleet(pipeline, jobs, fleet.build());
+
+    create_problem_with_objective(problem.as_ref(), Arc::new(ProblemObjective::new(vec![vec![objective]])))
+}
+
+fn get_used_vehicle_ids(insertion_ctx: &InsertionContext) -> Vec<String> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| get_vehicle_id(route_ctx.route.actor.vehicle.as_ref()).clone())
+        .collect()
+}
+
+parameterized_test! {can_calculate_overtime_cost, (rules, expected), {
+    can_calculate_overtime_cost_impl(rules, expected);
+}}
+
+can_calculate_overtime_cost! {
+    case01_no_rules: (vec![], 0.),
+    case02_finish_before_shift_end: (vec![("v1", 50., 2.)], 0.),
+    case03_finish_after_shift_end: (vec![("v1", 30., 2.)], 20.),
+    case04_another_vehicle: (vec![("v2", 0., 2.)], 0.),
+}
+
+fn can_calculate_overtime_cost_impl(rules: Vec<(&str, Timestamp, Cost)>, expected: Cost) {
+    let (constraint, objective) =
+        Overtime::minimize(TestTransportCost::new_shared(), TestActivityCost::new_shared(), create_overtime_fn(rules));
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        constraint,
+    ]);
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![test_activity_with_location(10), test_activity_with_location(20)],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![route_ctx].into();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_discourage_late_finish, (rate, expected_vehicle), {
+    can_discourage_late_finish_impl(rate, expected_vehicle);
+}}
+
+can_discourage_late_finish! {
+    case01_no_overtime_rate: (0., "v2"),
+    case02_low_overtime_rate: (1., "v2"),
+    case03_high_overtime_rate: (10., "v1"),
+}
+
+fn can_discourage_late_finish_impl(rate: Cost, expected_vehicle: &str) {
+    // NOTE serving the job costs 120 with v1 and 40 plus 10 time units of overtime with v2
+    let problem = create_problem(
+        vec![test_vehicle_with_id_and_location("v1", 0), test_vehicle_with_id_and_location("v2", 20)],
+        create_overtime_fn(vec![("v2", 0., rate)]),
+    );
+
+    let insertion_ctx = solve_with_insertion_heuristic(problem);
+
+    assert_eq!(get_used_vehicle_ids(&insertion_ctx), vec![expected_vehicle.to_string()]);
+}
+
+#[test]
+fn can_allow_late_finish() {
+    let problem =
+        create_problem(vec![test_vehicle_with_id_and_location("v1", 20)], create_overtime_fn(vec![("v1", 0., 100.)]));
+
+    let insertion_ctx = solve_with_insertion_heuristic(problem);
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["job"]]);
+    assert!(get_customer_ids_from_unassigned(&insertion_ctx).is_empty());
+    assert_eq!(insertion_ctx.get_fitness().next(), Some(1000.));
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::solve_with_insertion_heuristic;
+use crate::models::common::Dimensions;
+use crate::models::problem::ProblemObjective;
+use crate::models::Problem;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_revenue_fn() -> SimpleValueFn {
+    Arc::new(|job| match get_job_id(job).as_str() {
+        "near" => 100.,
+        "cheap" => 10.,
+        _ => 1000.,
+    })
+}
+
+fn create_problem(jobs: Vec<(&str, usize)>) -> Arc<Problem> {
+    create_problem_with_fixed_costs(jobs.into_iter().map(|(id, location)| (id, location, None)).collect())
+}
+
+fn create_problem_with_fixed_costs(jobs: Vec<(&str, usize, Option<Cost>)>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let (constraint, objective) = ProfitObjective::maximize(transport.clone(), create_revenue_fn(), VIOLATION_CODE);
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport, TestActivityCost::new_shared(), 2)),
+        constraint,
+    ]);
+    let jobs = jobs
+        .into_iter()
+        .map(|(id, location, fixed_cost)| {
+            let mut dimens = Dimensions::default();
+            if let Some(fixed_cost) = fixed_cost {
+                dimens.set_job_fixed_cost(fixed_cost);
+            }
+
+            SingleBuilder::default().dimens(dimens).id(id).location(Some(location)).build_as_job_ref()
+        })
+        .collect();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let problem = create_problem_with_constraint_jobs_and_fleet(pipeline, jobs, fleet);
+
+    create_problem_with_objective(problem.as_ref(), Arc::new(ProblemObjective::new(vec![vec![objective]])))
+}
+
+parameterized_test! {can_drop_unprofitable_jobs, (jobs, expected_routes, expected_unassigned), {
+    can_drop_unprofitable_jobs_impl(jobs, expected_routes, expected_unassigned);
+}}
+
+can_drop_unprofitable_jobs! {
+    case01_drop_distant_low_revenue: (vec![("near", 5), ("cheap", 100)], vec![vec!["near"]], vec!["cheap"]),
+    case02_keep_distant_high_revenue: (vec![("near", 5), ("rich", 100)], vec![vec!["near", "rich"]], vec![]),
+    case03_keep_close_low_revenue: (vec![("cheap", 1)], vec![vec!["cheap"]], vec![]),
+}
+
+fn can_drop_unprofitable_jobs_impl(
+    jobs: Vec<(&str, usize)>,
+    expected_routes: Vec<Vec<&str>>,
+    expected_unassigned: Vec<&str>,
+) {
+    let insertion_ctx = solve_with_insertion_heuristic(create_problem(jobs));
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected_routes);
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), expected_unassigned);
+}
+
+#[test]
+fn can_calculate_fitness_as_negative_profit() {
+    let insertion_ctx = solve_with_insertion_heuristic(create_problem(vec![("near", 5)]));
+
+    let fitness = insertion_ctx.get_fitness().next().unwrap();
+
+    assert_eq!(fitness, insertion_ctx.solution.get_total_cost() - 100.);
+    assert!(fitness < 0.);
+}
+
+parameterized_test! {can_drop_jobs_with_high_fixed_cost, (fixed_cost, expected_routes, expected_unassigned), {
+    can_drop_jobs_with_high_fixed_cost_impl(fixed_cost, expected_routes, expected_unassigned);
+}}
+
+can_drop_jobs_with_high_fixed_cost! {
+    case01_no_fixed_cost: (None, vec![vec!["near"]], vec![]),
+    case02_low_fixed_cost: (Some(50.), vec![vec!["near"]], vec![]),
+    case03_high_fixed_cost: (Some(70.), vec![], vec!["near"]),
+}
+
+fn can_drop_jobs_with_high_fixed_cost_impl(
+    fixed_cost: Option<Cost>,
+    expected_routes: Vec<Vec<&str>>,
+    expected_unassigned: Vec<&str>,
+) {
+    // NOTE round trip to the job costs 40 and its revenue is 100
+    let insertion_ctx = solve_with_insertion_heuristic(create_problem_with_fixed_costs(vec![("near", 5, fixed_cost)]));
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected_routes);
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), expected_unassigned);
+}
+
+#[test]
+fn can_include_job_fixed_cost_into_fitness() {
+    let insertion_ctx = solve_with_insertion_heuristic(create_problem_with_fixed_costs(vec![("near", 5, Some(50.))]));
+
+    let fitness = insertion_ctx.get_fitness().next().unwrap();
+
+    assert_eq!(fitness, insertion_ctx.solution.get_total_cost() + 50. - 100.);
+}
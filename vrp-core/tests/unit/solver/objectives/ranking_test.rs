@@ -0,0 +1,47 @@
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use std::sync::Arc;
+
+fn create_insertion_ctxs() -> (Arc<Problem>, Vec<InsertionContext>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        problem.clone(),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![]))),
+    );
+
+    let mut with_unassigned = insertion_ctx.deep_copy();
+    let route_ctx = with_unassigned.solution.routes.pop().unwrap();
+    route_ctx.route.tour.jobs().for_each(|job| {
+        with_unassigned.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+    });
+
+    (problem, vec![with_unassigned, insertion_ctx.deep_copy(), insertion_ctx])
+}
+
+#[test]
+fn can_rank_solutions() {
+    let (problem, insertion_ctxs) = create_insertion_ctxs();
+
+    let result = rank_solutions(problem.as_ref(), insertion_ctxs.as_slice());
+
+    assert_eq!(result.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(), vec![1, 2, 0]);
+    assert!(result.iter().all(|(_, fitness)| fitness.len() == 3));
+    assert_eq!(result[0].1, result[1].1);
+    assert_eq!(result[0].1[0], 0.);
+    assert_eq!(result[2].1[0], 3.);
+    assert_eq!(result[2].1[1], result[0].1[1] - 1.);
+}
+
+#[test]
+fn can_rank_empty_solutions() {
+    let (problem, _) = create_insertion_ctxs();
+
+    let result = rank_solutions(problem.as_ref(), &[]);
+
+    assert!(result.is_empty());
+}
@@ -58,8 +58,8 @@ fn can_calculate_transport_cost() {
     let mut insertion_ctx = InsertionContext {
         problem,
         solution: SolutionContext {
-            unassigned,
-            routes: vec![route1, route2],
+            unassigned: unassigned.into(),
+            routes: vec![route1, route2].into(),
             registry: create_registry_context(&fleet),
             ..create_empty_solution_context()
         },
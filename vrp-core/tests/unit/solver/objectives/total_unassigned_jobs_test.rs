@@ -1,5 +1,7 @@
 use super::*;
 use crate::helpers::models::domain::{create_empty_insertion_context, create_simple_insertion_ctx};
+use crate::helpers::models::problem::test_single_with_id;
+use crate::models::common::IdDimension;
 
 #[test]
 fn can_properly_estimate_empty_solution() {
@@ -11,3 +13,34 @@ fn can_properly_estimate_empty_solution() {
 
     assert_eq!(result, Ordering::Greater);
 }
+
+parameterized_test! {can_prefer_solution_with_assigned_mandatory_job, (has_mandatory, expected), {
+    can_prefer_solution_with_assigned_mandatory_job_impl(has_mandatory, expected);
+}}
+
+can_prefer_solution_with_assigned_mandatory_job! {
+    case01_with_mandatory: (true, Ordering::Greater),
+    case02_without_mandatory: (false, Ordering::Less),
+}
+
+fn can_prefer_solution_with_assigned_mandatory_job_impl(has_mandatory: bool, expected: Ordering) {
+    let mandatory = Job::Single(test_single_with_id("mandatory"));
+    let optional = Job::Single(test_single_with_id("optional"));
+    let create_insertion_ctx = |job: &Job| {
+        let mut insertion_ctx = create_simple_insertion_ctx(10., 0);
+        insertion_ctx.solution.unassigned.insert(job.clone(), UnassignmentInfo::Unknown);
+        insertion_ctx
+    };
+    let is_mandatory = |job: &Job| job.dimens().get_id().map(String::as_str) == Some("mandatory");
+    // NOTE optional job is estimated higher, so it is preferred to be assigned when mandatory is not set
+    let estimator: UnassignedJobEstimator = Arc::new(move |_, job, _| if is_mandatory(job) { 1. } else { 10. });
+    let objective = if has_mandatory {
+        TotalUnassignedJobs::new_with_mandatory(estimator, Arc::new(is_mandatory))
+    } else {
+        TotalUnassignedJobs::new(estimator)
+    };
+
+    let result = objective.total_order(&create_insertion_ctx(&mandatory), &create_insertion_ctx(&optional));
+
+    assert_eq!(result, expected);
+}
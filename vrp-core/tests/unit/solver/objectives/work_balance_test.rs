@@ -0,0 +1,85 @@
+use super::*;
+use crate::construction::heuristics::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::solve_with_insertion_heuristic;
+use crate::models::common::Location;
+use crate::models::problem::ProblemObjective;
+use crate::models::Problem;
+
+fn create_problem(weight: f64, job_locations: Vec<Location>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let (constraint, objective) = JobCountBalance::new_balanced(weight);
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport, activity, 1)),
+        constraint,
+    ]);
+    let jobs = job_locations
+        .into_iter()
+        .enumerate()
+        .map(|(idx, location)| {
+            SingleBuilder::default().id(&format!("job{}", idx)).location(Some(location)).build_as_job_ref()
+        })
+        .collect();
+    let mut fleet = FleetBuilder::default();
+    fleet.add_driver(test_driver());
+    fleet.add_vehicle(test_vehicle_with_id_and_location("v1", 0));
+    fleet.add_vehicle(test_vehicle_with_id_and_location("v2", 10));
+    let problem = create_problem_with_constraint_jobs_and_fleet(pipeline, jobs, fleet.build());
+
+    create_problem_with_objective(problem.as_ref(), Arc::new(ProblemObjective::new(vec![vec![objective]])))
+}
+
+fn get_sorted_job_counts(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    let mut counts = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route.tour.job_count())
+        .filter(|count| *count > 0)
+        .collect::<Vec<_>>();
+    counts.sort();
+
+    counts
+}
+
+parameterized_test! {can_calculate_job_count_balance_fitness, (weight, tours, expected), {
+    can_calculate_job_count_balance_fitness_impl(weight, tours, expected);
+}}
+
+can_calculate_job_count_balance_fitness! {
+    case01_even: (1., vec![("v1", vec!["c0", "c1"]), ("v2", vec!["c2", "c3"])], 0.),
+    case02_uneven: (1., vec![("v1", vec!["c0", "c1", "c2"]), ("v2", vec!["c3"])], 1.),
+    case03_uneven_weighted: (3., vec![("v1", vec!["c0", "c1", "c2"]), ("v2", vec!["c3"])], 3.),
+    case04_empty_route: (1., vec![("v1", vec!["c0", "c1", "c2"]), ("v2", vec![])], 0.),
+}
+
+fn can_calculate_job_count_balance_fitness_impl(weight: f64, tours: Vec<(&str, Vec<&str>)>, expected: f64) {
+    let (constraint, objective) = JobCountBalance::new_balanced(weight);
+    let insertion_ctx =
+        create_insertion_ctx_with_tours(create_constraint_pipeline_with_modules(vec![constraint]), tours);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_trade_cost_for_balanced_job_counts, (weight, expected_counts, expected_cost), {
+    can_trade_cost_for_balanced_job_counts_impl(weight, expected_counts, expected_cost);
+}}
+
+can_trade_cost_for_balanced_job_counts! {
+    case01_no_weight: (0., vec![1, 3], 40.),
+    case02_small_weight: (5., vec![1, 3], 40.),
+    case03_large_weight: (50., vec![2, 2], 72.),
+}
+
+fn can_trade_cost_for_balanced_job_counts_impl(weight: f64, expected_counts: Vec<usize>, expected_cost: f64) {
+    let insertion_ctx = solve_with_insertion_heuristic(create_problem(weight, vec![2, 3, 4, 9]));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_sorted_job_counts(&insertion_ctx), expected_counts);
+    assert_eq!(insertion_ctx.solution.get_total_cost(), expected_cost);
+}
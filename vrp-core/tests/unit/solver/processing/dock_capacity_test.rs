@@ -0,0 +1,103 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_tour_size;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+
+const REJECTION_CODE: i32 = 10;
+
+fn get_departures(insertion_ctx: &InsertionContext) -> Vec<Timestamp> {
+    let mut departures = insertion_ctx.solution.routes.iter().map(get_departure).collect::<Vec<_>>();
+    departures.sort_by(|a, b| compare_floats(*a, *b));
+
+    departures
+}
+
+fn assert_dock_capacity(insertion_ctx: &InsertionContext, capacity: usize, duration: Duration) {
+    let departures = get_departures(insertion_ctx);
+
+    departures.iter().for_each(|departure| {
+        let loading =
+            departures.iter().filter(|other| **other <= *departure && *departure < **other + duration).count();
+        assert!(loading <= capacity, "dock capacity is exceeded at {}: {:?}", departure, departures);
+    });
+}
+
+parameterized_test! {can_stagger_departures, (capacity, duration, expected_departures), {
+    can_stagger_departures_impl(capacity, duration, expected_departures);
+}}
+
+can_stagger_departures! {
+    case01_single_dock: (1, 10., vec![0., 10.]),
+    case02_enough_docks: (2, 10., vec![0., 0.]),
+    case03_no_loading_duration: (1, 0., vec![0., 0.]),
+}
+
+fn can_stagger_departures_impl(capacity: usize, duration: Duration, expected_departures: Vec<Timestamp>) {
+    let insertion_ctx = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_tour_size(None),
+        vec![("v1", vec!["a"]), ("v2", vec!["b"])],
+    );
+    let processing =
+        DockCapacity::new(vec![LoadingDock { location: DEFAULT_JOB_LOCATION, capacity, duration }], REJECTION_CODE);
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    assert_eq!(get_departures(&insertion_ctx), expected_departures);
+    assert_dock_capacity(&insertion_ctx, capacity, duration);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
+
+parameterized_test! {can_reject_route_which_cannot_be_postponed, (tour_size, expected_routes, expected_unassigned), {
+    can_reject_route_which_cannot_be_postponed_impl(tour_size, expected_routes, expected_unassigned);
+}}
+
+can_reject_route_which_cannot_be_postponed! {
+    case01_absorbed_by_other_route: (None, vec![vec!["a", "b"]], vec![]),
+    case02_kept_unassigned: (Some(1), vec![vec!["a"]], vec!["b"]),
+}
+
+fn can_reject_route_which_cannot_be_postponed_impl(
+    tour_size: Option<usize>,
+    expected_routes: Vec<Vec<&str>>,
+    expected_unassigned: Vec<&str>,
+) {
+    let insertion_ctx = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_tour_size(tour_size),
+        vec![("v1", vec!["a"]), ("v2", vec!["b"])],
+    );
+    let processing = DockCapacity::new(
+        vec![LoadingDock { location: DEFAULT_JOB_LOCATION, capacity: 1, duration: 2000. }],
+        REJECTION_CODE,
+    );
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    let actual_routes = get_customer_ids_from_routes_sorted(&insertion_ctx)
+        .into_iter()
+        .map(|mut ids| {
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(actual_routes, expected_routes);
+    assert_dock_capacity(&insertion_ctx, 1, 2000.);
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), expected_unassigned);
+    assert!(insertion_ctx
+        .solution
+        .unassigned
+        .values()
+        .all(|code| matches!(code, UnassignmentInfo::Simple(REJECTION_CODE))));
+}
+
+#[test]
+fn can_ignore_routes_from_other_locations() {
+    let insertion_ctx = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_tour_size(None),
+        vec![("v1", vec!["a"]), ("v2", vec!["b"])],
+    );
+    let processing = DockCapacity::new(vec![LoadingDock { location: 42, capacity: 1, duration: 10. }], REJECTION_CODE);
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    assert_eq!(get_departures(&insertion_ctx), vec![0., 0.]);
+}
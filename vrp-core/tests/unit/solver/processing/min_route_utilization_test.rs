@@ -0,0 +1,61 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_tour_size;
+use crate::helpers::models::domain::*;
+use crate::solver::objectives::MinRouteUtilization;
+
+const REJECTION_CODE: i32 = 10;
+
+parameterized_test! {can_reject_underutilized_routes, (tour_size, expected_routes, expected_unassigned), {
+    can_reject_underutilized_routes_impl(tour_size, expected_routes, expected_unassigned);
+}}
+
+can_reject_underutilized_routes! {
+    case01_absorbed_by_other_route: (None, vec![vec!["a", "b", "c", "d"]], vec![]),
+    case02_kept_unassigned: (Some(3), vec![vec!["a", "b", "c"]], vec!["d"]),
+}
+
+fn can_reject_underutilized_routes_impl(
+    tour_size: Option<usize>,
+    expected_routes: Vec<Vec<&str>>,
+    expected_unassigned: Vec<&str>,
+) {
+    let insertion_ctx = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_tour_size(tour_size),
+        vec![("v1", vec!["a", "b", "c"]), ("v2", vec!["d"])],
+    );
+    let processing =
+        MinRouteUtilizationRejection::new(MinRouteUtilization::create_job_count_shortage(2), REJECTION_CODE);
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    let actual_routes = get_customer_ids_from_routes_sorted(&insertion_ctx)
+        .into_iter()
+        .map(|mut ids| {
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(actual_routes, expected_routes);
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), expected_unassigned);
+    assert!(insertion_ctx
+        .solution
+        .unassigned
+        .values()
+        .all(|code| matches!(code, UnassignmentInfo::Simple(REJECTION_CODE))));
+    assert_eq!(insertion_ctx.solution.registry.next().count(), 1);
+}
+
+#[test]
+fn can_keep_utilized_routes() {
+    let insertion_ctx = create_insertion_ctx_with_tours(
+        create_constraint_pipeline_with_tour_size(None),
+        vec![("v1", vec!["a", "b"]), ("v2", vec!["c", "d"])],
+    );
+    let processing =
+        MinRouteUtilizationRejection::new(MinRouteUtilization::create_job_count_shortage(2), REJECTION_CODE);
+
+    let insertion_ctx = processing.post_process(insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["a", "b"], vec!["c", "d"]]);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
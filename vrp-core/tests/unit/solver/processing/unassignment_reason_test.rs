@@ -28,7 +28,7 @@ fn create_test_insertion_ctx(unassigned: Vec<(Job, UnassignmentInfo)>) -> Insert
         ),
         solution: SolutionContext {
             unassigned: unassigned.into_iter().collect(),
-            routes,
+            routes: routes.into(),
             ..create_empty_solution_context()
         },
         ..create_empty_insertion_context()
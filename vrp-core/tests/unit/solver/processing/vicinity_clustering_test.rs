@@ -92,7 +92,8 @@ fn can_unwrap_clusters_in_route_on_post_process_impl(
                     }],
                 )),
                 Arc::new(RouteState::default()),
-            )],
+            )]
+            .into(),
             ..create_empty_solution_context()
         },
         ..create_empty_insertion_context()
@@ -0,0 +1,99 @@
+use super::*;
+use crate::construction::constraints::{CapacityConstraintModule, TransportConstraintModule};
+use crate::helpers::construction::constraints::{create_constraint_pipeline_with_modules, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{SingleDimLoad, TimeWindow};
+use crate::models::Problem;
+use crate::solver::search::RecreateWithCheapest;
+use rosomaxa::prelude::Environment;
+
+fn create_problem(capacity: i32, jobs: Vec<Job>) -> Arc<Problem> {
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(2)),
+    ]);
+    let vehicles = (0..4).map(|idx| VehicleBuilder::default().id(&format!("v{}", idx)).capacity(capacity).build());
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles(vehicles.collect()).build();
+
+    create_problem_with_constraint_jobs_and_fleet(pipeline, jobs, fleet)
+}
+
+fn create_job(id: &str, location: Location, demand: i32) -> Job {
+    SingleBuilder::default().id(id).location(Some(location)).demand(create_simple_demand(demand)).build_as_job_ref()
+}
+
+fn create_line_jobs() -> Vec<Job> {
+    (0..4).map(|idx| create_job(&format!("c{}", idx), idx + 1, 1)).collect()
+}
+
+fn construct(problem: Arc<Problem>) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let construction = SavingsConstruction::new(8, Arc::new(RecreateWithCheapest::new(environment.random.clone())));
+
+    construction.construct(&refinement_ctx, InsertionContext::new(problem, environment))
+}
+
+parameterized_test! {can_merge_routes_respecting_capacity, (capacity, expected), {
+    can_merge_routes_respecting_capacity_impl(capacity, expected);
+}}
+
+can_merge_routes_respecting_capacity! {
+    case01_single_route: (4, vec![vec!["c0", "c1", "c2", "c3"]]),
+    case02_two_routes: (2, vec![vec!["c0", "c1"], vec!["c2", "c3"]]),
+    case03_no_merges: (1, vec![vec!["c0"], vec!["c1"], vec!["c2"], vec!["c3"]]),
+}
+
+fn can_merge_routes_respecting_capacity_impl(capacity: i32, expected: Vec<Vec<&str>>) {
+    let insertion_ctx = construct(create_problem(capacity, create_line_jobs()));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_reject_merge_which_violates_time_windows() {
+    let create_job_with_time = |id: &str, location: Location, end: f64| {
+        SingleBuilder::default()
+            .id(id)
+            .location(Some(location))
+            .duration(1.)
+            .demand(create_simple_demand(1))
+            .times(vec![TimeWindow::new(0., end)])
+            .build_as_job_ref()
+    };
+    let jobs = vec![
+        create_job_with_time("c0", 1, 1.),
+        create_job_with_time("c1", 2, 1000.),
+        create_job_with_time("c2", 3, 1000.),
+        create_job_with_time("c3", 4, 4.),
+    ];
+
+    let insertion_ctx = construct(create_problem(4, jobs));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["c0"], vec!["c3", "c2", "c1"]]);
+}
+
+#[test]
+fn can_use_fallback_for_jobs_which_cannot_be_merged() {
+    let mut jobs = create_line_jobs();
+    jobs.push(create_job("c4", 5, 5));
+    let multi = test_multi_with_id(
+        "m0",
+        vec![
+            SingleBuilder::default().location(Some(2)).build_shared(),
+            SingleBuilder::default().location(Some(3)).build_shared(),
+        ],
+    );
+    jobs.push(Job::Multi(multi));
+
+    let insertion_ctx = construct(create_problem(4, jobs));
+
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 1);
+    assert!(insertion_ctx.solution.required.is_empty());
+    let routes = get_customer_ids_from_routes(&insertion_ctx);
+    assert_eq!(routes.iter().map(|route| route.len()).sum::<usize>(), 6);
+}
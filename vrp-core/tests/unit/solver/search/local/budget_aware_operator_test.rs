@@ -0,0 +1,88 @@
+use super::*;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use rosomaxa::utils::{DefaultRandom, Timer};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+struct FakeLocalOperator {
+    cost: OperatorCost,
+    calls: AtomicUsize,
+}
+
+impl LocalOperator for FakeLocalOperator {
+    fn explore(&self, _: &RefinementContext, _: &InsertionContext) -> Option<InsertionContext> {
+        self.calls.fetch_add(1, AtomicOrdering::Relaxed);
+        None
+    }
+
+    fn cost_hint(&self) -> OperatorCost {
+        self.cost
+    }
+}
+
+fn create_fake_operators() -> Vec<Arc<FakeLocalOperator>> {
+    [OperatorCost::Cheap, OperatorCost::Moderate, OperatorCost::Expensive]
+        .into_iter()
+        .map(|cost| Arc::new(FakeLocalOperator { cost, calls: AtomicUsize::new(0) }))
+        .collect()
+}
+
+fn create_budget_aware_operator(operators: &[Arc<FakeLocalOperator>]) -> BudgetAwareLocalOperator {
+    let operators = operators
+        .iter()
+        .map(|operator| (operator.clone() as Arc<dyn LocalOperator + Send + Sync>, 1))
+        .collect::<Vec<_>>();
+
+    BudgetAwareLocalOperator::new(operators, 10, 10, 0.5, 0.8)
+}
+
+parameterized_test! {can_get_allowed_cost, (termination_estimate, expected), {
+    can_get_allowed_cost_impl(termination_estimate, expected);
+}}
+
+can_get_allowed_cost! {
+    case_01: (0., OperatorCost::Expensive),
+    case_02: (0.49, OperatorCost::Expensive),
+    case_03: (0.5, OperatorCost::Moderate),
+    case_04: (0.79, OperatorCost::Moderate),
+    case_05: (0.8, OperatorCost::Cheap),
+    case_06: (1., OperatorCost::Cheap),
+}
+
+fn can_get_allowed_cost_impl(termination_estimate: f64, expected: OperatorCost) {
+    let operator = create_budget_aware_operator(create_fake_operators().as_slice());
+
+    assert_eq!(operator.get_allowed_cost(termination_estimate), expected);
+}
+
+parameterized_test! {can_skip_expensive_operators_near_termination, (termination_estimate, expected_called), {
+    can_skip_expensive_operators_near_termination_impl(termination_estimate, expected_called);
+}}
+
+can_skip_expensive_operators_near_termination! {
+    case_01: (0., &[true, true, true]),
+    case_02: (0.6, &[true, true, false]),
+    case_03: (0.9, &[true, false, false]),
+}
+
+fn can_skip_expensive_operators_near_termination_impl(termination_estimate: f64, expected_called: &[bool]) {
+    let fake_operators = create_fake_operators();
+    let operator = create_budget_aware_operator(fake_operators.as_slice());
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(DefaultRandom::default())),
+    );
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.on_generation(vec![], termination_estimate, Timer::start());
+
+    (0..10).for_each(|_| {
+        operator.explore(&refinement_ctx, &insertion_ctx);
+    });
+
+    let called =
+        fake_operators.iter().map(|operator| operator.calls.load(AtomicOrdering::Relaxed) > 0).collect::<Vec<_>>();
+    assert_eq!(called, expected_called);
+    assert_eq!(operator.cost_hint(), OperatorCost::Expensive);
+}
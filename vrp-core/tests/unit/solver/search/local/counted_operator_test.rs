@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use rosomaxa::utils::DefaultRandom;
+
+struct FakeLocalOperator {
+    result: Option<bool>,
+}
+
+impl LocalOperator for FakeLocalOperator {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        self.result.map(|is_improved| {
+            let mut insertion_ctx = insertion_ctx.deep_copy();
+            if is_improved {
+                insertion_ctx.solution.unassigned.clear();
+            }
+            insertion_ctx
+        })
+    }
+}
+
+fn create_insertion_ctx_with_unassigned() -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(DefaultRandom::default())),
+    );
+    let job = insertion_ctx.problem.jobs.all().next().unwrap();
+    insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+
+    insertion_ctx
+}
+
+#[test]
+fn can_count_operator_calls() {
+    let operators = [
+        CountedLocalOperator::new("none", Arc::new(FakeLocalOperator { result: None })),
+        CountedLocalOperator::new("same", Arc::new(FakeLocalOperator { result: Some(false) })),
+        CountedLocalOperator::new("better", Arc::new(FakeLocalOperator { result: Some(true) })),
+    ];
+    let insertion_ctx = create_insertion_ctx_with_unassigned();
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    operators.iter().for_each(|operator| {
+        operator.explore(&refinement_ctx, &insertion_ctx);
+        operator.explore(&refinement_ctx, &insertion_ctx);
+    });
+
+    let stats = refinement_ctx.operator_counters().get_statistics();
+    let stats = stats.iter().map(|s| (s.name.as_str(), s.calls, s.explored, s.improved)).collect::<Vec<_>>();
+    assert_eq!(stats, vec![("none", 2, 0, 0), ("same", 2, 2, 0), ("better", 2, 2, 2)]);
+}
+
+#[test]
+fn can_share_counters_between_operators_with_same_name() {
+    let first = CountedLocalOperator::new("op", Arc::new(FakeLocalOperator { result: None }));
+    let second = CountedLocalOperator::new("op", Arc::new(FakeLocalOperator { result: Some(false) }));
+    let insertion_ctx = create_insertion_ctx_with_unassigned();
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    first.explore(&refinement_ctx, &insertion_ctx);
+    second.explore(&refinement_ctx, &insertion_ctx);
+
+    let stats = refinement_ctx.operator_counters().get_statistics();
+    assert_eq!(stats.len(), 1);
+    assert_eq!((stats[0].calls, stats[0].explored, stats[0].improved), (2, 1, 0));
+}
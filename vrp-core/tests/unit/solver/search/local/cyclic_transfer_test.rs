@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::Location;
+
+/// Creates a solution where each route is served by its own vehicle and visits given locations in given order.
+fn create_insertion_ctx(routes: Vec<Vec<Location>>) -> InsertionContext {
+    create_insertion_ctx_with_locations(
+        create_constraint_pipeline_with_transport(),
+        routes
+            .into_iter()
+            .enumerate()
+            .map(|(idx, locations)| (test_vehicle_with_id(&format!("v{}", idx + 1)), locations))
+            .collect(),
+        test_single_with_id_and_location,
+        test_random(),
+    )
+}
+
+fn get_route_ids(insertion_ctx: &InsertionContext) -> Vec<Vec<String>> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route.tour.jobs().map(|job| get_customer_id(&job)).collect())
+        .collect()
+}
+
+parameterized_test! {can_transfer_jobs_cyclically, (routes, locked, expected), {
+    can_transfer_jobs_cyclically_impl(routes, locked, expected);
+}}
+
+can_transfer_jobs_cyclically! {
+    case01_misplaced_jobs: (
+        vec![vec![10, 11, 52], vec![50, 51, 92], vec![90, 91, 12]], vec![],
+        Some(vec![vec!["c0", "c1", "c8"], vec!["c2", "c3", "c4"], vec!["c5", "c6", "c7"]])
+    ),
+    case02_optimal_routes: (vec![vec![10, 11, 12], vec![50, 51, 52], vec![90, 91, 92]], vec![], None),
+    case03_locked_misplaced_job: (
+        vec![vec![10, 11, 52], vec![50, 51, 92], vec![90, 91, 12]], vec!["c2", "c5", "c8"],
+        Some(vec![vec!["c1", "c2", "c3"], vec!["c4", "c5", "c7"], vec!["c0", "c6", "c8"]])
+    ),
+    case04_two_routes_only: (vec![vec![10, 11, 52], vec![50, 51, 12]], vec![], None),
+}
+
+fn can_transfer_jobs_cyclically_impl(routes: Vec<Vec<Location>>, locked: Vec<&str>, expected: Option<Vec<Vec<&str>>>) {
+    let mut insertion_ctx = create_insertion_ctx(routes);
+    let locked_jobs = insertion_ctx
+        .problem
+        .jobs
+        .all()
+        .filter(|job| locked.contains(&get_customer_id(job).as_str()))
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked_jobs);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = CyclicTransfer::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.is_some(), expected.is_some());
+    if let (Some(result), Some(expected)) = (result, expected) {
+        let mut routes = get_route_ids(&result);
+        routes.iter_mut().for_each(|route| route.sort());
+        assert_eq!(routes, expected);
+        assert!(result.solution.get_total_cost() < insertion_ctx.solution.get_total_cost());
+    }
+}
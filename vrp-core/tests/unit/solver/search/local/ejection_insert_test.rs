@@ -0,0 +1,63 @@
+use super::*;
+use crate::helpers::solver::*;
+
+fn get_unassigned_ids(insertion_ctx: &InsertionContext) -> Vec<String> {
+    let mut ids =
+        insertion_ctx.solution.unassigned.keys().map(|job| job.dimens().get_id().cloned().unwrap()).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_place_job_which_cannot_be_inserted_directly() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned_demand(2, 2);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    assert_eq!(get_unassigned_ids(&insert_required(insertion_ctx.deep_copy())), vec!["c1".to_string()]);
+
+    let result = EjectionInsert::default().explore(&refinement_ctx, &insertion_ctx).expect("cannot find new solution");
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(
+        get_vehicle_job_ids(&result),
+        vec![("0".to_string(), "c1".to_string()), ("1".to_string(), "c0".to_string())]
+    );
+}
+
+#[test]
+fn can_keep_other_unassigned_jobs() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned_demand(2, 3);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = EjectionInsert::default().explore(&refinement_ctx, &insertion_ctx).expect("cannot find new solution");
+
+    // NOTE seed job is selected randomly, but only one of them can be assigned
+    let unassigned = get_unassigned_ids(&result);
+    assert_eq!(unassigned.len(), 1);
+    let vehicle_jobs = get_vehicle_job_ids(&result);
+    assert_eq!(vehicle_jobs.len(), 2);
+    assert!(vehicle_jobs.iter().any(|(vehicle_id, job_id)| vehicle_id != "0" && job_id == "c0"));
+    assert!(!vehicle_jobs.iter().any(|(_, job_id)| *job_id == unassigned[0]));
+}
+
+#[test]
+fn can_skip_job_which_cannot_be_placed_after_ejection() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned_demand(3, 2);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = EjectionInsert::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn can_skip_solution_without_unassigned_jobs() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned_demand(1, 2);
+    let insertion_ctx = insert_required(insertion_ctx);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+
+    let result = EjectionInsert::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx);
+
+    assert!(result.is_none());
+}
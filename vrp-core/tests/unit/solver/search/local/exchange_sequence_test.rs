@@ -1,5 +1,8 @@
 use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
 use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
 use crate::helpers::solver::*;
 use crate::helpers::utils::create_test_environment_with_random;
 use crate::helpers::utils::random::FakeRandom;
@@ -14,6 +17,8 @@ can_extract_jobs! {
     case_01: (1, 1, 3, &[], &[vec!["c0", "c1", "c2", "c3", "c4"], vec!["c5", "c9"]], &[vec!["c6", "c7", "c8"]]),
     case_02: (1, 0, 2, &[], &[vec!["c0", "c1", "c2", "c3", "c4"], vec!["c7", "c8", "c9"]], &[vec!["c5", "c6"]]),
     case_03: (1, 0, 2, &["c6", "c7"], &[vec!["c0", "c1", "c2", "c3", "c4"], vec!["c6", "c7", "c9"]], &[vec!["c5", "c8"]]),
+    case_04: (1, 0, 5, &[], &[vec!["c0", "c1", "c2", "c3", "c4"], vec![]], &[vec!["c5", "c6", "c7", "c8", "c9"]]),
+    case_05: (1, 0, 4, &["c6", "c7"], &[vec!["c0", "c1", "c2", "c3", "c4"], vec!["c6", "c7"]], &[vec!["c5", "c8", "c9"]]),
 }
 
 fn can_extract_jobs_impl(
@@ -34,7 +39,100 @@ fn can_extract_jobs_impl(
         locked_ids,
     );
 
-    let jobs = extract_jobs(&mut insertion_ctx, route_idx, sequence_size);
+    let jobs = extract_jobs(&mut insertion_ctx, route_idx, sequence_size, false).expect("cannot extract jobs");
+
+    compare_with_ignore(get_customer_ids_from_routes(&insertion_ctx).as_slice(), expected_route_ids, "");
+    compare_with_ignore(&[get_customer_ids_from_jobs(jobs.as_slice())], expected_extracted_ids, "")
+}
+
+#[test]
+fn can_extract_unique_jobs_when_job_has_multiple_activities() {
+    let multi = test_multi_with_id("m0", vec![test_single_with_id("m0_0"), test_single_with_id("m0_1")]);
+    let singles = [test_single_with_id("c0"), test_single_with_id("c1")];
+    let jobs = vec![Job::Multi(multi.clone()), Job::Single(singles[0].clone()), Job::Single(singles[1].clone())];
+    let problem =
+        create_problem_with_constraint_jobs_and_fleet(create_constraint_pipeline_with_transport(), jobs, test_fleet());
+    let activities = vec![
+        test_activity_with_job(multi.jobs[0].clone()),
+        test_activity_with_job(singles[0].clone()),
+        test_activity_with_job(multi.jobs[1].clone()),
+        test_activity_with_job(singles[1].clone()),
+    ];
+    let route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), "v1", activities);
+    let job_count = route_ctx.route.tour.job_count();
+    let mut insertion_ctx = InsertionContext {
+        problem,
+        solution: SolutionContext { routes: vec![route_ctx].into(), ..create_empty_solution_context() },
+        environment: create_test_environment_with_random(Arc::new(FakeRandom::new(vec![0], vec![]))),
+    };
+
+    let jobs = extract_jobs(&mut insertion_ctx, 0, job_count, false).expect("cannot extract jobs");
+
+    assert_eq!(job_count, 3);
+    assert_eq!(jobs.len(), job_count);
+    assert_eq!(jobs.iter().collect::<HashSet<_>>().len(), job_count);
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 0);
+}
+
+parameterized_test! { can_skip_extraction_from_route_without_enough_jobs, (is_guided, locked_ids), {
+    can_skip_extraction_from_route_without_enough_jobs_impl(is_guided, locked_ids);
+}}
+
+can_skip_extraction_from_route_without_enough_jobs! {
+    case_01_random_one_unlocked: (false, &["c5", "c6", "c7", "c8"]),
+    case_02_random_all_locked: (false, &["c5", "c6", "c7", "c8", "c9"]),
+    case_03_guided_one_unlocked: (true, &["c5", "c6", "c7", "c8"]),
+    case_04_guided_all_locked: (true, &["c5", "c6", "c7", "c8", "c9"]),
+}
+
+fn can_skip_extraction_from_route_without_enough_jobs_impl(is_guided: bool, locked_ids: &[&str]) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(
+            Arc::new(problem),
+            (solution, None),
+            create_test_environment_with_random(Arc::new(FakeRandom::new(vec![0], vec![]))),
+        ),
+        locked_ids,
+    );
+
+    let jobs = extract_jobs(&mut insertion_ctx, 1, 2, is_guided);
+
+    assert!(jobs.is_none());
+    compare_with_ignore(
+        get_customer_ids_from_routes(&insertion_ctx).as_slice(),
+        &[vec!["c0", "c1", "c2", "c3", "c4"], vec!["c5", "c6", "c7", "c8", "c9"]],
+        "",
+    );
+}
+
+parameterized_test! { can_extract_most_expensive_sequence, (sequence_size, locked_ids, expected_route_ids, expected_extracted_ids), {
+    can_extract_most_expensive_sequence_impl(sequence_size, locked_ids, expected_route_ids, expected_extracted_ids);
+}}
+
+can_extract_most_expensive_sequence! {
+    case_01: (2, &[], &[vec!["c0", "c2", "c3"]], &[vec!["c4", "c1"]]),
+    case_02: (3, &[], &[vec!["c2", "c3"]], &[vec!["c0", "c4", "c1"]]),
+    case_03: (2, &["c1"], &[vec!["c1", "c2", "c3"]], &[vec!["c0", "c4"]]),
+}
+
+fn can_extract_most_expensive_sequence_impl(
+    sequence_size: usize,
+    locked_ids: &[&str],
+    expected_route_ids: &[Vec<&str>],
+    expected_extracted_ids: &[Vec<&str>],
+) {
+    let mut insertion_ctx = promote_to_locked(
+        create_insertion_ctx_with_tours(
+            create_constraint_pipeline_with_transport(),
+            vec![("v1", vec!["c0", "c1", "c2", "c3", "c4"])],
+        ),
+        locked_ids,
+    );
+    // NOTE c4 is located far away from its neighbours c0 and c1, so it has the highest detour
+    rearrange_jobs_in_routes(&mut insertion_ctx, &[vec!["c0", "c4", "c1", "c2", "c3"]]);
+
+    let jobs = extract_jobs(&mut insertion_ctx, 0, sequence_size, true).expect("cannot extract jobs");
 
     compare_with_ignore(get_customer_ids_from_routes(&insertion_ctx).as_slice(), expected_route_ids, "");
     compare_with_ignore(&[get_customer_ids_from_jobs(jobs.as_slice())], expected_extracted_ids, "")
@@ -78,7 +176,9 @@ fn can_insert_jobs_impl(
     );
     let jobs = get_jobs_by_ids(&insertion_ctx, insert_job_ids);
 
-    insert_jobs(&mut insertion_ctx, route_idx, jobs, reverse_probability_threshold, 0.);
+    let settings = ExchangeSequence::new(2, reverse_probability_threshold, 0.);
+
+    insert_jobs(&mut insertion_ctx, route_idx, jobs, &settings);
 
     compare_with_ignore(
         vec![get_customer_ids_from_routes(&insertion_ctx).get(0).cloned().unwrap()].as_slice(),
@@ -88,6 +188,37 @@ fn can_insert_jobs_impl(
     compare_with_ignore(vec![get_customer_ids_from_unassigned(&insertion_ctx)].as_slice(), expected_unassigned_ids, "");
 }
 
+parameterized_test! { can_insert_jobs_contiguously, (insert_job_ids, disallowed_pairs), {
+    can_insert_jobs_contiguously_impl(insert_job_ids, disallowed_pairs);
+}}
+
+can_insert_jobs_contiguously! {
+    case_01_no_constraints: (&["c5", "c6"], vec![]),
+    case_02_scattered_without_block: (&["c5", "c6"], vec![("c5", "c1")]),
+    case_03_three_jobs: (&["c5", "c6", "c7"], vec![("c5", "c1"), ("c6", "c2")]),
+}
+
+fn can_insert_jobs_contiguously_impl(insert_job_ids: &[&str], disallowed_pairs: Vec<(&str, &str)>) {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    add_leg_constraint(&mut problem, disallowed_pairs);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![1], vec![1., 1.]))),
+    );
+    let jobs = get_jobs_by_ids(&insertion_ctx, insert_job_ids);
+    let settings = ExchangeSequence::new(2, 0.01, 0.).with_keep_contiguous();
+
+    insert_jobs(&mut insertion_ctx, 0, jobs, &settings);
+
+    let route = get_customer_ids_from_routes(&insertion_ctx).first().cloned().unwrap();
+    let positions = insert_job_ids
+        .iter()
+        .map(|id| route.iter().position(|route_id| route_id == id).expect("job is not inserted"))
+        .collect::<Vec<_>>();
+    assert!(positions.windows(2).all(|pair| pair[1] == pair[0] + 1), "jobs are not adjacent: {:?}", route);
+}
+
 parameterized_test! { can_exchange_jobs, (first_route, second_route, expected_route_ids), {
     can_exchange_jobs_impl(first_route, second_route, expected_route_ids);
 }}
@@ -122,7 +253,33 @@ fn can_exchange_jobs_impl(
         create_test_environment_with_random(Arc::new(FakeRandom::new(ints, vec![1., 1., 1., 1.]))),
     );
 
-    exchange_jobs(&mut insertion_ctx, &[0, 1], 4, 0.01, 0.01);
+    exchange_jobs(&mut insertion_ctx, &[0, 1], &ExchangeSequence::new(4, 0.01, 0.01)).expect("cannot exchange jobs");
+
+    compare_with_ignore(get_customer_ids_from_routes(&insertion_ctx).as_slice(), expected_route_ids, "");
+}
+
+parameterized_test! {can_exchange_jobs_with_size_bias, (route_reals, ints, expected_route_ids), {
+    can_exchange_jobs_with_size_bias_impl(route_reals, ints, expected_route_ids);
+}}
+
+can_exchange_jobs_with_size_bias! {
+    case_01_different_routes: ((2., 5.), vec![3, 1, 2, 2, 2, 1],
+        &[vec!["c0", "c4", "c7", "c8"], vec!["c5", "c1", "c2", "c3", "c6", "c9"]]),
+    case_02_same_route: ((2., 1.5), vec![3, 1, 2],
+        &[vec!["c0", "c4", "c1", "c2", "c3"], vec!["c5", "c6", "c7", "c8", "c9"]]),
+}
+
+fn can_exchange_jobs_with_size_bias_impl(route_reals: (f64, f64), ints: Vec<i32>, expected_route_ids: &[Vec<&str>]) {
+    let reals = vec![route_reals.0, route_reals.1, 1., 1., 1., 1.];
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, reals))),
+    );
+
+    exchange_jobs(&mut insertion_ctx, &[0, 1], &ExchangeSequence::new(4, 0.01, 0.01).with_size_bias())
+        .expect("cannot exchange jobs");
 
     compare_with_ignore(get_customer_ids_from_routes(&insertion_ctx).as_slice(), expected_route_ids, "");
 }
@@ -149,6 +306,25 @@ fn can_get_route_indices_impl(rows: usize, locked_ids: &[&str], expected: &[usiz
     assert_eq!(indices, expected);
 }
 
+#[test]
+fn can_get_only_routes_with_enough_unlocked_jobs() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        &["c0", "c1", "c2", "c3", "c4"],
+    );
+    let route_ctx = insertion_ctx.solution.routes.get_mut(1).unwrap();
+    let removed = route_ctx.route.tour.jobs().filter(|job| get_customer_id(job) != "c5").collect::<Vec<_>>();
+    removed.iter().for_each(|job| assert!(route_ctx.route_mut().tour.remove(job)));
+    insertion_ctx.solution.required.extend(removed);
+    let job_counts = insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count());
+    assert_eq!(job_counts.collect::<Vec<_>>(), vec![5, 1, 5]);
+
+    let indices = get_route_indices(&insertion_ctx);
+
+    assert_eq!(indices, vec![2]);
+}
+
 parameterized_test! { can_exchange_sequence, (locked_ids, expected), {
     can_exchange_sequence_impl(locked_ids, expected);
 }}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::IdDimension;
+use std::sync::Arc;
+
+fn create_insertion_ctx_with_unassigned(unassigned_id: Option<&str>, locked_ids: &[&str]) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 3, true);
+    let ints = [0].into_iter().chain([16; 128]).collect();
+    let random = Arc::new(FakeRandom::new(ints, vec![1.; 128]));
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(
+            Arc::new(problem),
+            (solution, None),
+            create_test_environment_with_random(random),
+        ),
+        locked_ids,
+    );
+
+    if let Some(unassigned_id) = unassigned_id {
+        let job = insertion_ctx
+            .problem
+            .jobs
+            .all()
+            .find(|job| job.dimens().get_id().map(|id| id.as_str()) == Some(unassigned_id))
+            .expect("cannot find job");
+        insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.route_mut().tour.remove(&job);
+        });
+        insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+        insertion_ctx.restore();
+    }
+
+    insertion_ctx
+}
+
+#[test]
+fn can_assign_unassigned_job() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned(Some("c4"), &[]);
+
+    let new_insertion_ctx = RedistributeUnassigned::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .expect("cannot find new solution");
+
+    assert!(new_insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes(&new_insertion_ctx).iter().flatten().count(), 9);
+}
+
+#[test]
+fn can_keep_locked_jobs_in_place() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned(Some("c4"), &["c3", "c5"]);
+
+    let new_insertion_ctx = RedistributeUnassigned::new(2)
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .expect("cannot find new solution");
+
+    let new_ids = get_customer_ids_from_routes(&new_insertion_ctx);
+    assert!(new_insertion_ctx.solution.unassigned.is_empty());
+    assert!(new_ids[1].contains(&"c3".to_string()) && new_ids[1].contains(&"c5".to_string()));
+}
+
+#[test]
+fn can_skip_solution_without_unassigned_jobs() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned(None, &[]);
+
+    let result = RedistributeUnassigned::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx);
+
+    assert!(result.is_none());
+}
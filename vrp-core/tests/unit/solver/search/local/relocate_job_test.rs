@@ -0,0 +1,73 @@
+use super::*;
+use crate::construction::constraints::{CapacityConstraintModule, TransportConstraintModule};
+use crate::helpers::construction::constraints::{create_constraint_pipeline_with_modules, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::{Location, SingleDimLoad};
+
+/// Creates a solution with routes served by vehicles with given capacity. Each route visits jobs
+/// with unit demand at given locations in given order.
+fn create_insertion_ctx(routes: Vec<(usize, Vec<Location>)>, ints: Vec<i32>) -> InsertionContext {
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(2)),
+    ]);
+
+    create_insertion_ctx_with_locations(
+        pipeline,
+        routes
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (capacity, locations))| {
+                (VehicleBuilder::default().id(&format!("v{}", idx)).capacity(capacity as i32).build(), locations)
+            })
+            .collect(),
+        |id, location| {
+            SingleBuilder::default().id(id).location(location).demand(create_simple_demand(1)).build_shared()
+        },
+        Arc::new(FakeRandom::new(ints, vec![])),
+    )
+}
+
+parameterized_test! {can_relocate_job, (routes, ints, locked, expected), {
+    can_relocate_job_impl(routes, ints, locked, expected);
+}}
+
+can_relocate_job! {
+    case01_move_to_spare_capacity: (
+        vec![(3, vec![1, 2, 10]), (3, vec![11])], vec![0, 2], vec![],
+        Some(vec![vec!["c0", "c1"], vec!["c2", "c3"]])
+    ),
+    case02_no_spare_capacity: (vec![(3, vec![1, 2, 10]), (1, vec![11])], vec![0, 2], vec![], None),
+    case03_skip_locked_route: (
+        vec![(3, vec![1, 2, 10]), (3, vec![11])], vec![0, 1], vec!["c3"],
+        Some(vec![vec!["c0", "c2"], vec!["c1", "c3"]])
+    ),
+    case04_all_locked: (vec![(3, vec![1, 2]), (3, vec![11])], vec![], vec!["c0", "c1", "c2"], None),
+}
+
+fn can_relocate_job_impl(
+    routes: Vec<(usize, Vec<Location>)>,
+    ints: Vec<i32>,
+    locked: Vec<&str>,
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let mut insertion_ctx = create_insertion_ctx(routes, ints);
+    let locked_jobs = insertion_ctx
+        .problem
+        .jobs
+        .all()
+        .filter(|job| locked.contains(&get_customer_id(job).as_str()))
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked_jobs);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = RelocateJob::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.is_some(), expected.is_some());
+    if let (Some(result), Some(expected)) = (result, expected) {
+        assert_eq!(get_customer_ids_from_routes_sorted(&result), expected);
+    }
+}
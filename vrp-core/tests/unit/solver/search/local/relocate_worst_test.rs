@@ -0,0 +1,46 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::Location;
+
+/// Creates a solution with one route served by `v1` which visits jobs at given locations in given order.
+fn create_insertion_ctx(locations: Vec<Location>) -> InsertionContext {
+    create_insertion_ctx_with_locations(
+        create_constraint_pipeline_with_transport(),
+        vec![(test_vehicle_with_id("v1"), locations)],
+        test_single_with_id_and_location,
+        test_random(),
+    )
+}
+
+parameterized_test! {can_relocate_worst_job, (locations, locked, expected), {
+    can_relocate_worst_job_impl(locations, locked, expected);
+}}
+
+can_relocate_worst_job! {
+    case01_misplaced_stop: (vec![5, 6, 1, 7, 8], vec![], Some(vec!["c2", "c0", "c1", "c3", "c4"])),
+    case02_optimal_route: (vec![1, 5, 6, 7, 8], vec![], None),
+    case03_locked_misplaced_stop: (vec![5, 6, 1, 7, 8], vec!["c2"], Some(vec!["c0", "c2", "c1", "c3", "c4"])),
+}
+
+fn can_relocate_worst_job_impl(locations: Vec<Location>, locked: Vec<&str>, expected: Option<Vec<&str>>) {
+    let mut insertion_ctx = create_insertion_ctx(locations);
+    let locked_jobs = insertion_ctx
+        .problem
+        .jobs
+        .all()
+        .filter(|job| locked.contains(&get_customer_id(job).as_str()))
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked_jobs);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = RelocateWorst::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.is_some(), expected.is_some());
+    if let (Some(result), Some(expected)) = (result, expected) {
+        assert_eq!(get_customer_ids_from_routes(&result), vec![expected]);
+        assert!(result.solution.get_total_cost() < insertion_ctx.solution.get_total_cost());
+    }
+}
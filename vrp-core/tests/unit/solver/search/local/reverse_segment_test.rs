@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::Location;
+
+/// Creates a solution with one route served by `v1` which visits jobs at given locations in given order.
+fn create_insertion_ctx(locations: Vec<Location>, ints: Vec<i32>) -> InsertionContext {
+    create_insertion_ctx_with_locations(
+        create_constraint_pipeline_with_transport(),
+        vec![(test_vehicle_with_id("v1"), locations)],
+        test_single_with_id_and_location,
+        Arc::new(FakeRandom::new(ints, vec![])),
+    )
+}
+
+parameterized_test! {can_reverse_segment, (locations, ints, locked, expected), {
+    can_reverse_segment_impl(locations, ints, locked, expected);
+}}
+
+can_reverse_segment! {
+    case01_crossing: (vec![1, 3, 2, 4], vec![0, 2, 2], vec![], Some(vec!["c0", "c2", "c1", "c3"])),
+    case02_longer_crossing: (vec![1, 4, 3, 2, 5], vec![0, 3, 2], vec![], Some(vec!["c0", "c3", "c2", "c1", "c4"])),
+    case03_worse_route: (vec![1, 2, 3, 4], vec![0, 2, 2], vec![], None),
+    case04_locked_job: (vec![1, 3, 2, 4], vec![0, 2, 2], vec!["c1"], None),
+    case05_not_enough_jobs: (vec![2, 1], vec![], vec![], None),
+}
+
+fn can_reverse_segment_impl(locations: Vec<Location>, ints: Vec<i32>, locked: Vec<&str>, expected: Option<Vec<&str>>) {
+    let mut insertion_ctx = create_insertion_ctx(locations, ints);
+    let locked_jobs = insertion_ctx
+        .problem
+        .jobs
+        .all()
+        .filter(|job| locked.contains(&get_customer_id(job).as_str()))
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked_jobs);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = ReverseSegment::new(4).explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.is_some(), expected.is_some());
+    if let (Some(result), Some(expected)) = (result, expected) {
+        assert_eq!(get_customer_ids_from_routes(&result), vec![expected]);
+        assert!(result.solution.get_total_cost() < insertion_ctx.solution.get_total_cost());
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::{create_default_refinement_ctx, promote_to_locked};
+use crate::models::problem::ProblemObjective;
+use crate::solver::objectives::WorkBalance;
+
+/// Minimizes max amount of jobs in a single route.
+struct MaxRouteJobs {}
+
+impl Objective for MaxRouteJobs {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).max().unwrap_or(0) as f64
+    }
+}
+
+fn create_insertion_ctx(tours: Vec<(&str, Vec<&str>)>, is_balanced: bool, locked_ids: &[&str]) -> InsertionContext {
+    let mut constraint = create_constraint_pipeline_with_transport();
+
+    let insertion_ctx = if is_balanced {
+        // NOTE work balance module enables the operator, a simpler objective is used to check improvement
+        let (module, _) = WorkBalance::new_activity_balanced(None);
+        constraint.add_module(module);
+
+        let insertion_ctx = create_insertion_ctx_with_tours(constraint, tours);
+        let problem = insertion_ctx.problem.clone();
+        let problem = create_problem_with_objective(
+            problem.as_ref(),
+            Arc::new(ProblemObjective::new(vec![vec![Arc::new(MaxRouteJobs {})]])),
+        );
+
+        InsertionContext { problem, ..insertion_ctx }
+    } else {
+        create_insertion_ctx_with_tours(constraint, tours)
+    };
+
+    promote_to_locked(insertion_ctx, locked_ids)
+}
+
+fn explore(insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+    UtilizeSpareVehicle::default().explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx)
+}
+
+#[test]
+fn can_move_jobs_to_spare_vehicle_when_objective_improves() {
+    let insertion_ctx = create_insertion_ctx(vec![("v1", vec!["c0", "c1", "c2", "c3"])], true, &[]);
+
+    let new_insertion_ctx = explore(&insertion_ctx).expect("cannot find new solution");
+
+    let ids = get_customer_ids_from_routes(&new_insertion_ctx);
+    assert_eq!(ids.len(), 2);
+    assert!(matches!(ids[0].len(), 2 | 3));
+    assert_eq!(ids.iter().flatten().count(), 4);
+    assert_eq!(new_insertion_ctx.solution.routes[1].route.actor.vehicle.dimens.get_id().unwrap(), "v2");
+    assert!(new_insertion_ctx.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_keep_locked_jobs_in_place() {
+    let insertion_ctx = create_insertion_ctx(vec![("v1", vec!["c0", "c1", "c2", "c3"])], true, &["c0", "c1", "c2"]);
+
+    let new_insertion_ctx = explore(&insertion_ctx).expect("cannot find new solution");
+
+    let ids = get_customer_ids_from_routes(&new_insertion_ctx);
+    assert_eq!(ids, vec![vec!["c0", "c1", "c2"], vec!["c3"]]);
+}
+
+#[test]
+fn can_skip_problem_without_work_balance() {
+    let insertion_ctx = create_insertion_ctx(vec![("v1", vec!["c0", "c1", "c2", "c3"])], false, &[]);
+
+    assert!(explore(&insertion_ctx).is_none());
+}
+
+#[test]
+fn can_skip_solution_without_spare_vehicles() {
+    let insertion_ctx = create_insertion_ctx(vec![("v1", vec!["c0", "c1", "c2"]), ("v2", vec!["c3"])], true, &[]);
+
+    assert!(explore(&insertion_ctx).is_none());
+}
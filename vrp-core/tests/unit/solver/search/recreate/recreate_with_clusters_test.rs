@@ -0,0 +1,55 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::{create_problem_with_constraint_jobs_and_fleet, get_customer_ids_from_routes};
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use rosomaxa::utils::Environment;
+
+fn create_test_problem(locations: &[(&str, Location)], vehicles: usize) -> Arc<crate::models::Problem> {
+    let jobs = locations
+        .iter()
+        .map(|(id, location)| Job::Single(test_single_with_id_and_location(id, Some(*location))))
+        .collect();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles((0..vehicles).map(|idx| test_vehicle_with_id(format!("v{}", idx).as_str())).collect())
+        .build();
+
+    create_problem_with_constraint_jobs_and_fleet(create_constraint_pipeline_with_transport(), jobs, fleet)
+}
+
+#[test]
+fn can_serve_each_cluster_by_separate_vehicle() {
+    let problem =
+        create_test_problem(&[("c1", 1), ("c2", 2), ("c3", 3), ("c100", 100), ("c101", 101), ("c102", 102)], 2);
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+
+    let insertion_ctx = RecreateWithClusters::new(2, environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    let mut routes = get_customer_ids_from_routes(&insertion_ctx)
+        .into_iter()
+        .map(|mut ids| {
+            ids.sort();
+            ids
+        })
+        .collect::<Vec<_>>();
+    routes.sort();
+    assert_eq!(routes, vec![vec!["c1", "c2", "c3"], vec!["c100", "c101", "c102"]]);
+}
+
+#[test]
+fn can_insert_jobs_when_there_are_less_vehicles_than_clusters() {
+    let problem = create_test_problem(&[("c1", 1), ("c50", 50), ("c100", 100)], 1);
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+
+    let insertion_ctx = RecreateWithClusters::new(3, environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 3);
+}
@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::solver::*;
+use crate::solver::search::RecreateWithCheapest;
+use rosomaxa::prelude::Environment;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingRecreate {
+    inner: RecreateWithCheapest,
+    calls: AtomicUsize,
+}
+
+impl Recreate for CountingRecreate {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.inner.run(refinement_ctx, insertion_ctx)
+    }
+}
+
+fn create_recreate() -> Arc<CountingRecreate> {
+    Arc::new(CountingRecreate {
+        inner: RecreateWithCheapest::new(Environment::default().random),
+        calls: AtomicUsize::new(0),
+    })
+}
+
+#[test]
+fn can_assign_job_blocked_by_earlier_insertion() {
+    let insertion_ctx = create_insertion_ctx_with_unassigned_demand(2, 2);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let inner = create_recreate();
+    assert_eq!(inner.run(&refinement_ctx, insertion_ctx.deep_copy()).solution.unassigned.len(), 1);
+
+    let result = RecreateWithUnassignedRetry::new(inner, 2, 1).run(&refinement_ctx, insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(
+        get_vehicle_job_ids(&result),
+        vec![("0".to_string(), "c1".to_string()), ("1".to_string(), "c0".to_string())]
+    );
+}
+
+parameterized_test! {can_limit_attempts_per_job, (max_attempts, expected_calls), {
+    can_limit_attempts_per_job_impl(max_attempts, expected_calls);
+}}
+
+can_limit_attempts_per_job! {
+    case01_no_attempts: (0, 1),
+    case02_single_attempt: (1, 3),
+    case03_many_attempts: (3, 7),
+}
+
+fn can_limit_attempts_per_job_impl(max_attempts: usize, expected_calls: usize) {
+    let insertion_ctx = create_insertion_ctx_with_unassigned_demand(3, 2);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let inner = create_recreate();
+
+    let result = RecreateWithUnassignedRetry::new(inner.clone(), 2, max_attempts).run(&refinement_ctx, insertion_ctx);
+
+    assert_eq!(result.solution.unassigned.len(), 1);
+    assert_eq!(get_vehicle_job_ids(&result), vec![("0".to_string(), "c0".to_string())]);
+    assert_eq!(inner.calls.load(Ordering::Relaxed), expected_calls);
+}
@@ -0,0 +1,51 @@
+use super::*;
+use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::SingleDimLoad;
+use std::sync::Arc;
+
+parameterized_test! {can_get_time_difference, (a, b, expected), {
+    can_get_time_difference_impl(TimeWindow::new(a.0, a.1), TimeWindow::new(b.0, b.1), expected);
+}}
+
+can_get_time_difference! {
+    case01_same: ((0., 10.), (0., 10.), 0.),
+    case02_inside: ((0., 10.), (2., 4.), 0.),
+    case03_partial: ((0., 10.), (5., 15.), 0.5),
+    case04_no_overlap: ((0., 10.), (20., 30.), 1.),
+    case05_unlimited: ((0., f64::MAX), (5., 15.), 0.),
+    case06_point: ((5., 5.), (0., 10.), 0.),
+}
+
+fn can_get_time_difference_impl(a: TimeWindow, b: TimeWindow, expected: f64) {
+    assert_eq!(get_time_difference(&a, &b), expected);
+    assert_eq!(get_time_difference(&b, &a), expected);
+}
+
+parameterized_test! {can_ruin_related_jobs, (ints, expected_ids), {
+    can_ruin_related_jobs_impl(ints, expected_ids);
+}}
+
+can_ruin_related_jobs! {
+    case01_seed_in_middle: (vec![1, 3, 3], vec!["c2", "c6", "c7"]),
+    case02_seed_at_edge: (vec![0, 1, 2], vec!["c0", "c1"]),
+}
+
+fn can_ruin_related_jobs_impl(ints: Vec<i32>, expected_ids: Vec<&str>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, vec![]))),
+    );
+    let ruin = RelatedJobRemoval::<SingleDimLoad>::new(
+        RelatednessWeights { distance: 1., time: 0., demand: 0. },
+        RuinLimits::new(1, 16, 1., 8),
+    );
+
+    let insertion_ctx = ruin.run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
+}
@@ -0,0 +1,51 @@
+use super::*;
+use rosomaxa::prelude::compare_floats;
+use std::cmp::Ordering;
+
+parameterized_test! {can_get_adaptive_ruin_scale, (stagnation, expected), {
+    can_get_adaptive_ruin_scale_impl(stagnation, expected);
+}}
+
+can_get_adaptive_ruin_scale! {
+    case01_improving: (0, 1.),
+    case02_stuck: (50, 2.),
+    case03_stuck_max: (100, 3.),
+    case04_stuck_long: (1000, 3.),
+}
+
+fn can_get_adaptive_ruin_scale_impl(stagnation: usize, expected: f64) {
+    let statistics = HeuristicStatistics { stagnation, ..HeuristicStatistics::default() };
+
+    let scale = AdaptiveRuinSize::new(100, 3., (0.5, 1.)).get_scale(&statistics);
+
+    assert_eq!(compare_floats(scale, expected), Ordering::Equal);
+}
+
+parameterized_test! {can_get_adaptive_acceptance_probability, (stagnation, expected), {
+    can_get_adaptive_acceptance_probability_impl(stagnation, expected);
+}}
+
+can_get_adaptive_acceptance_probability! {
+    case01_improving: (0, 0.2),
+    case02_stuck: (50, 0.5),
+    case03_stuck_long: (1000, 0.8),
+}
+
+fn can_get_adaptive_acceptance_probability_impl(stagnation: usize, expected: f64) {
+    let statistics = HeuristicStatistics { stagnation, ..HeuristicStatistics::default() };
+
+    let probability = AdaptiveRuinSize::new(100, 3., (0.2, 0.8)).get_acceptance_probability(&statistics);
+
+    assert_eq!(compare_floats(probability, expected), Ordering::Equal);
+}
+
+#[test]
+fn can_use_neutral_acceptance_by_default() {
+    let adaptive_size = AdaptiveRuinSize::default();
+
+    [0, 100, 1000].iter().for_each(|&stagnation| {
+        let statistics = HeuristicStatistics { stagnation, ..HeuristicStatistics::default() };
+
+        assert_eq!(compare_floats(adaptive_size.get_acceptance_probability(&statistics), 1.), Ordering::Equal);
+    });
+}
@@ -0,0 +1,55 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use rosomaxa::prelude::Environment;
+
+struct RemoveAllJobs {}
+
+impl Ruin for RemoveAllJobs {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let jobs =
+            insertion_ctx.solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<Vec<_>>();
+
+        insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            })
+        });
+        insertion_ctx.solution.required.extend(jobs);
+
+        insertion_ctx
+    }
+}
+
+struct SkipRecreate {}
+
+impl Recreate for SkipRecreate {
+    fn run(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        insertion_ctx
+    }
+}
+
+parameterized_test! {can_use_adaptive_acceptance, (acceptance, expected_unassigned), {
+    can_use_adaptive_acceptance_impl(acceptance, expected_unassigned);
+}}
+
+can_use_adaptive_acceptance! {
+    case01_strict: (Some((0., 0.)), 0),
+    case02_loose: (Some((1., 1.)), 6),
+    case03_disabled: (None, 6),
+}
+
+fn can_use_adaptive_acceptance_impl(acceptance: Option<(f64, f64)>, expected_unassigned: usize) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 3, false);
+    let insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let ruin_recreate = RuinAndRecreate::new(Arc::new(RemoveAllJobs {}), Arc::new(SkipRecreate {}));
+    let ruin_recreate = match acceptance {
+        Some(acceptance) => ruin_recreate.with_adaptive_acceptance(AdaptiveRuinSize::new(10, 2., acceptance)),
+        None => ruin_recreate,
+    };
+
+    let result_ctx = ruin_recreate.search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result_ctx.solution.unassigned.len(), expected_unassigned);
+}
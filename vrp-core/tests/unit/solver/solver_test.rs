@@ -0,0 +1,228 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::models::Extras;
+use rosomaxa::algorithms::gsom::Input;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingConstruction {
+    calls: AtomicUsize,
+}
+
+impl ConstructionHeuristic for CountingConstruction {
+    fn construct(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        insertion_ctx
+    }
+}
+
+#[test]
+fn can_use_custom_construction_heuristic() {
+    let (problem, _) = generate_matrix_routes_with_defaults(2, 2, false);
+    let refinement_ctx = create_default_refinement_ctx(Arc::new(problem));
+    let construction = Arc::new(CountingConstruction { calls: AtomicUsize::new(0) });
+    let operator = ConstructionInitialOperator::new(construction.clone());
+
+    let insertion_ctx = operator.create(&refinement_ctx);
+
+    assert_eq!(construction.calls.load(Ordering::Relaxed), 1);
+    assert!(insertion_ctx.solution.routes.is_empty());
+}
+
+#[test]
+fn can_use_default_construction_heuristic() {
+    let (problem, _) = generate_matrix_routes_with_defaults(2, 2, false);
+    let refinement_ctx = create_default_refinement_ctx(Arc::new(problem));
+    let operator = ConstructionInitialOperator::new(get_default_construction_heuristic(&refinement_ctx.environment));
+
+    let insertion_ctx = operator.create(&refinement_ctx);
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert!(!insertion_ctx.solution.routes.is_empty());
+}
+
+#[test]
+fn can_collect_solutions_at_checkpoints() {
+    let problem = crate::models::examples::create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let (_, cost, _, checkpoints) =
+        Solver::new(problem.clone(), config).solve_with_checkpoints(vec![2, 5, 20]).unwrap();
+
+    assert_eq!(checkpoints.iter().map(|(generation, _)| *generation).collect::<Vec<_>>(), vec![2, 5]);
+    checkpoints.iter().for_each(|(_, insertion_ctx)| assert!(problem.objective.fitness(insertion_ctx) >= cost));
+}
+
+fn create_degenerate_problem(job_count: usize, vehicle_count: usize) -> Arc<Problem> {
+    let jobs = (0..job_count)
+        .map(|idx| SingleBuilder::default().id(&format!("job{}", idx)).location(Some(idx + 1)).build_as_job_ref())
+        .collect();
+    let vehicles = (0..vehicle_count).map(|idx| test_vehicle_with_id(&format!("v{}", idx))).collect();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles(vehicles).build();
+
+    create_problem_with_constraint_jobs_and_fleet(create_constraint_pipeline_with_transport(), jobs, fleet)
+}
+
+parameterized_test! {can_solve_degenerate_problem, (job_count, vehicle_count), {
+    can_solve_degenerate_problem_impl(job_count, vehicle_count);
+}}
+
+can_solve_degenerate_problem! {
+    case01_no_vehicles: (2, 0),
+    case02_no_jobs: (0, 2),
+    case03_no_jobs_and_vehicles: (0, 0),
+}
+
+fn can_solve_degenerate_problem_impl(job_count: usize, vehicle_count: usize) {
+    let problem = create_degenerate_problem(job_count, vehicle_count);
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let (solution, cost, metrics) = Solver::new(problem, config).solve().unwrap();
+
+    assert!(solution.routes.is_empty());
+    assert_eq!(solution.unassigned.len(), job_count);
+    assert_eq!(cost, 0.);
+    assert!(metrics.is_none());
+}
+
+#[test]
+fn can_create_degenerate_solution_in_initial_operator() {
+    let refinement_ctx = create_default_refinement_ctx(create_degenerate_problem(2, 0));
+    let operator = ConstructionInitialOperator::new(get_default_construction_heuristic(&refinement_ctx.environment));
+
+    let insertion_ctx = operator.create(&refinement_ctx);
+
+    assert!(insertion_ctx.solution.routes.is_empty());
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert_eq!(
+        get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.unassigned.keys().cloned().collect::<Vec<_>>()),
+        vec!["job0", "job1"]
+    );
+}
+
+#[test]
+fn can_return_solver_parameters() {
+    let problem = crate::models::examples::create_example_problem();
+    let environment = Arc::new(Environment { random: Arc::new(DefaultRandom::with_seed(7)), ..Environment::default() });
+    let rosomaxa_config = RosomaxaConfig { node_selection_size: Some(1), ..RosomaxaConfig::new_with_defaults(4) };
+    let population = Rosomaxa::new(problem.objective.clone(), environment.clone(), rosomaxa_config).unwrap();
+    let config = create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+        .with_context(RefinementContext::new(
+            problem.clone(),
+            Box::new(population),
+            TelemetryMode::None,
+            environment.clone(),
+        ))
+        .with_max_generations(Some(10))
+        .with_max_time(Some(300))
+        .build()
+        .unwrap();
+
+    let (_, _, _, parameters) = Solver::new(problem, config).solve_with_parameters().unwrap();
+
+    match parameters.population {
+        Some(PopulationSettings::Rosomaxa(config)) => {
+            assert_eq!(config.selection_size, 4);
+            assert_eq!(config.node_selection_size, Some(1));
+            assert_eq!(config.node_size, RosomaxaConfig::new_with_defaults(4).node_size);
+        }
+        _ => unreachable!("unexpected population settings: {:?}", parameters.population),
+    }
+    assert_eq!(parameters.available_cpus, environment.parallelism.available_cpus());
+    assert_eq!(parameters.thread_pool_size, environment.parallelism.thread_pool_size());
+    assert!(!parameters.is_experimental);
+    assert!(!parameters.has_quota);
+    assert_eq!(parameters.seed, Some(7));
+    match parameters.heuristic {
+        Some(HeuristicSettings::Dynamic { ref search_operators }) => assert!(!search_operators.is_empty()),
+        _ => unreachable!("unexpected heuristic settings: {:?}", parameters.heuristic),
+    }
+    match parameters.termination.as_slice() {
+        [TerminationSettings::MaxGeneration { limit: 10 }, TerminationSettings::MaxTime { limit_in_secs }] => {
+            assert_eq!(*limit_in_secs, 300.)
+        }
+        _ => unreachable!("unexpected termination settings: {:?}", parameters.termination),
+    }
+}
+
+#[test]
+fn can_return_static_heuristic_settings() {
+    let problem = crate::models::examples::create_example_problem();
+    let environment = Arc::new(Environment::default());
+
+    let settings = get_static_heuristic(problem, environment.clone()).settings();
+
+    match settings {
+        Some(HeuristicSettings::Static { search_weights }) => {
+            assert_eq!(search_weights.len(), 4);
+            assert!(search_weights.iter().any(|(name, weight)| name == "ruin-recreate" && *weight == 1.));
+        }
+        _ => unreachable!("unexpected heuristic settings: {:?}", settings),
+    }
+    assert!(environment.random.seed().is_none());
+}
+
+struct RouteFeatures;
+
+impl SolutionFeatures for RouteFeatures {
+    fn extract(&self, insertion_ctx: &InsertionContext) -> Vec<f64> {
+        vec![insertion_ctx.solution.routes.len() as f64, insertion_ctx.solution.get_total_cost()]
+    }
+}
+
+#[test]
+fn can_use_custom_solution_features() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let mut extras = Extras::default();
+    extras.set_solution_features(Arc::new(RouteFeatures));
+    let problem = Arc::new(Problem { extras: Arc::new(extras), ..problem });
+    let environment = Arc::new(Environment::default());
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let config = RosomaxaConfig::new_with_defaults(4);
+    let mut population = Rosomaxa::new(problem.objective.clone(), environment, config).unwrap();
+
+    (0..5).for_each(|generation| {
+        population.add(insertion_ctx.deep_copy());
+        population.on_generation(&HeuristicStatistics { generation, ..HeuristicStatistics::default() });
+    });
+
+    assert_eq!(population.selection_phase(), SelectionPhase::Exploration);
+    assert!(population.all().all(|individual| individual.weights().len() == 2));
+    assert_eq!(population.select().next().unwrap().weights(), &[2., insertion_ctx.solution.get_total_cost()]);
+}
+
+#[test]
+fn can_report_local_operator_statistics_in_metrics() {
+    let problem = crate::models::examples::create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let telemetry_mode = TelemetryMode::OnlyMetrics { track_population: 1000 };
+    let heuristic_group: TargetHeuristicGroup = vec![(
+        create_default_heuristic_operator(problem.clone(), environment.clone()),
+        create_scalar_operator_probability(1., environment.random.clone()),
+    )];
+    let config = create_default_config_builder(problem.clone(), environment.clone(), telemetry_mode)
+        .with_heuristic(get_static_heuristic_from_heuristic_group(problem.clone(), environment, heuristic_group))
+        .with_max_generations(Some(50))
+        .build()
+        .unwrap();
+
+    let (_, _, metrics) = Solver::new(problem, config).solve().unwrap();
+
+    let operators = metrics.expect("no metrics").operators;
+    assert!(!operators.is_empty());
+    assert!(operators.iter().map(|operator| operator.calls).sum::<usize>() > 0);
+    assert!(operators
+        .iter()
+        .all(|operator| operator.improved <= operator.explored && operator.explored <= operator.calls));
+}
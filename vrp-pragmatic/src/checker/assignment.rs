@@ -9,6 +9,7 @@ use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::{Load, MultiDimLoad};
 use vrp_core::prelude::compare_floats;
 
 /// Checks assignment of jobs and vehicles.
@@ -50,6 +51,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         pub deliveries: Vec<usize>,
         pub replacements: Vec<usize>,
         pub services: Vec<usize>,
+        pub demand: Vec<i32>,
     }
     let new_assignment = |tour_info: (String, usize)| JobAssignment {
         tour_info,
@@ -57,6 +59,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         deliveries: vec![],
         replacements: vec![],
         services: vec![],
+        demand: vec![],
     };
     let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
 
@@ -74,10 +77,16 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
-                if asgn.tour_info != tour_info {
+                let is_split = all_jobs.get(&activity.job_id).and_then(|job| job.split.as_ref()).is_some();
+                if asgn.tour_info != tour_info && !is_split {
                     return Err(format!("job served in multiple tours: '{}'", activity.job_id));
                 }
 
+                if let Some(demand) = activity.demand.as_ref() {
+                    asgn.demand.resize(demand.len().max(asgn.demand.len()), 0);
+                    asgn.demand.iter_mut().zip(demand.iter()).for_each(|(total, value)| *total += value);
+                }
+
                 match activity.activity_type.as_str() {
                     "pickup" => asgn.pickups.push(idx),
                     "delivery" => asgn.deliveries.push(idx),
@@ -99,6 +108,10 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             + job.replacements.as_ref().map_or(0, |r| r.len());
         let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
 
+        if let Some(split) = job.split.as_ref() {
+            return check_split_job_presence(ctx, job, split, asgn.demand.as_slice(), assigned_tasks);
+        }
+
         if expected_tasks != assigned_tasks {
             return Err(format!(
                 "not all tasks served for '{}', expected: {}, assigned: {}",
@@ -132,7 +145,8 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             return Err(format!("unknown job id in the list of unassigned jobs: '{}'", job_id));
         }
 
-        if used_jobs.contains_key(job_id) {
+        let is_split = all_jobs.get(job_id).and_then(|job| job.split.as_ref()).is_some();
+        if used_jobs.contains_key(job_id) && !is_split {
             return Err(format!("job present as assigned and unassigned: '{}'", job_id));
         }
 
@@ -140,7 +154,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     })?;
 
     let all_used_job =
-        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
+        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<HashSet<_>>();
 
     if all_used_job.len() != all_jobs.len() {
         return Err(format!(
@@ -153,10 +167,43 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Checks that partial visits of split job serve no more than its demand and the whole demand
+/// is served when the job is not reported as unassigned.
+fn check_split_job_presence(
+    ctx: &CheckerContext,
+    job: &Job,
+    split: &JobSplit,
+    served: &[i32],
+    assigned_tasks: usize,
+) -> Result<(), String> {
+    let demand = get_job_tasks(job).next().and_then(|task| task.demand.clone()).unwrap_or_default();
+    let capacity = get_max_vehicle_capacity(&ctx.problem.fleet);
+    let expected_tasks = get_split_demands(demand.as_slice(), split.min_size.as_slice(), capacity.as_slice()).len();
+
+    if assigned_tasks > expected_tasks {
+        return Err(format!(
+            "too many partial visits for split job '{}', expected: {}, assigned: {}",
+            job.id, expected_tasks, assigned_tasks
+        ));
+    }
+
+    let served = MultiDimLoad::new(served.to_vec());
+    let demand = MultiDimLoad::new(demand);
+    let is_unassigned =
+        ctx.solution.unassigned.iter().flat_map(|jobs| jobs.iter()).any(|unassigned| unassigned.job_id == job.id);
+
+    match (demand.can_fit(&served), served == demand || is_unassigned) {
+        (true, true) => Ok(()),
+        (false, _) => Err(format!("split job '{}' served more than its demand", job.id)),
+        (true, false) => Err(format!("split job '{}' is not served completely", job.id)),
+    }
+}
+
 /// Checks job constraint violations.
 fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
     let job_index = get_job_index(&ctx.core_problem);
     let coord_index = get_coord_index(&ctx.core_problem);
+    let used_jobs = &HashSet::default();
     let job_ids = ctx
         .solution
         .tours
@@ -170,7 +217,7 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                         move |(idx, activity)| {
                             match stop {
                                 Stop::Point(stop) => {
-                                    let result = try_match_point_job(tour, stop, activity, job_index, coord_index);
+                                    let result = try_match_point_job(tour, stop, activity, job_index, coord_index, used_jobs);
                                     match result {
                                         Err(_) => {
                                             // NOTE required break is not a job
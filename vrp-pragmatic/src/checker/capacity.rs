@@ -172,7 +172,9 @@ fn get_demand(
         |job, task| {
             let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
                 && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
-            let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            // NOTE partial visit of split job serves only part of the task demand
+            let demand = if job.split.is_some() { activity.demand.clone() } else { task.demand.clone() };
+            let demand = demand.map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
             (is_dynamic, demand)
         },
@@ -0,0 +1,106 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/mandatory_test.rs"]
+mod mandatory_test;
+
+use crate::extensions::JobTie;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::{Job, Single, TransportCost, TravelTime};
+
+/// A mandatory module makes insertion of mandatory jobs more preferable than optional ones.
+pub struct MandatoryModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl MandatoryModule {
+    /// Creates a new instance of `MandatoryModule`.
+    pub fn new(code: i32, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(MandatorySoftRouteConstraint { transport }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for MandatoryModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        // NOTE merged job is mandatory if any of its parts is mandatory
+        if !candidate.dimens().get_job_mandatory() || source.dimens().get_job_mandatory() {
+            return Ok(source);
+        }
+
+        match &source {
+            Job::Single(single) => {
+                let mut dimens = single.dimens.clone();
+                dimens.set_job_mandatory(Some(true));
+
+                Ok(Job::Single(Arc::new(Single { places: single.places.clone(), dimens })))
+            }
+            // NOTE multi job cannot be rebuilt without losing its permutation generator
+            Job::Multi(_) => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Controls whether mandatory job is more preferable for insertion or not.
+struct MandatorySoftRouteConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl MandatorySoftRouteConstraint {
+    /// Estimates the most expensive round trip from route start to job places. It keeps mandatory
+    /// job preferable even when solution has no routes yet and max route cost is zero.
+    fn estimate_round_trip(&self, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let start = match route.tour.start() {
+            Some(start) => start,
+            None => return 0.,
+        };
+
+        let locations: Vec<_> = match job {
+            Job::Single(single) => single.places.iter().filter_map(|place| place.location).collect(),
+            Job::Multi(multi) => {
+                multi.jobs.iter().flat_map(|single| single.places.iter().filter_map(|place| place.location)).collect()
+            }
+        };
+
+        let departure = TravelTime::Departure(start.schedule.departure);
+
+        locations
+            .into_iter()
+            .map(|location| {
+                self.transport.cost(route, start.place.location, location, departure)
+                    + self.transport.cost(route, location, start.place.location, departure)
+            })
+            .fold(0., f64::max)
+    }
+}
+
+impl SoftRouteConstraint for MandatorySoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        if job.dimens().get_job_mandatory() {
+            -(solution_ctx.get_max_cost() + self.estimate_round_trip(route_ctx, job))
+        } else {
+            0.
+        }
+    }
+}
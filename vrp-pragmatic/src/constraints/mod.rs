@@ -67,6 +67,9 @@ pub use self::dispatch::DispatchModule;
 mod groups;
 pub use self::groups::GroupModule;
 
+mod mandatory;
+pub use self::mandatory::MandatoryModule;
+
 mod reloads;
 pub use self::reloads::*;
 
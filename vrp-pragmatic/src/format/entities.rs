@@ -130,6 +130,16 @@ pub trait JobTie {
     /// Sets job compatibility.
     fn set_job_compatibility(&mut self, compatibility: Option<String>) -> &mut Self;
 
+    /// Gets job mandatory flag.
+    fn get_job_mandatory(&self) -> bool;
+    /// Sets job mandatory flag.
+    fn set_job_mandatory(&mut self, is_mandatory: Option<bool>) -> &mut Self;
+
+    /// Gets job split flag: true if job is a partial visit of split job.
+    fn get_job_split(&self) -> bool;
+    /// Sets job split flag.
+    fn set_job_split(&mut self, is_split: bool) -> &mut Self;
+
     /// Gets job (activity) type.
     fn get_job_type(&self) -> Option<&String>;
     /// Sets job (activity) type
@@ -230,6 +240,34 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_mandatory(&self) -> bool {
+        self.get_value("job_mandatory").cloned().unwrap_or(false)
+    }
+
+    fn set_job_mandatory(&mut self, is_mandatory: Option<bool>) -> &mut Self {
+        if let Some(true) = is_mandatory {
+            self.set_value("job_mandatory", true);
+        } else {
+            self.remove("job_mandatory");
+        }
+
+        self
+    }
+
+    fn get_job_split(&self) -> bool {
+        self.get_value("job_split").cloned().unwrap_or(false)
+    }
+
+    fn set_job_split(&mut self, is_split: bool) -> &mut Self {
+        if is_split {
+            self.set_value("job_split", true);
+        } else {
+            self.remove("job_split");
+        }
+
+        self
+    }
+
     fn get_job_type(&self) -> Option<&String> {
         self.get_value("job_type")
     }
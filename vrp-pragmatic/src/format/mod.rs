@@ -135,6 +135,7 @@ const TOUR_ORDER_CONSTRAINT_CODE: i32 = 12;
 const GROUP_CONSTRAINT_CODE: i32 = 13;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 14;
 const RELOAD_RESOURCE_CONSTRAINT_CODE: i32 = 15;
+const MANDATORY_CONSTRAINT_CODE: i32 = 16;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -148,6 +149,26 @@ pub fn get_job_index(problem: &CoreProblem) -> &JobIndex {
         .unwrap_or_else(|| panic!("cannot get job index!"))
 }
 
+/// Returns a job index key of the partial job of split job. Indexing starts from one.
+pub(crate) fn get_split_job_key(job_id: &str, idx: usize) -> String {
+    format!("{}_split_{}", job_id, idx)
+}
+
+/// Returns all jobs indexed by given job id: partial jobs in case of split job, otherwise the job itself.
+pub(crate) fn get_indexed_jobs<'a>(job_index: &'a JobIndex, job_id: &str) -> Vec<&'a CoreJob> {
+    let partial_jobs = (1..)
+        .map(|idx| job_index.get(&get_split_job_key(job_id, idx)))
+        .take_while(|job| job.is_some())
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if partial_jobs.is_empty() {
+        job_index.get(job_id).into_iter().collect()
+    } else {
+        partial_jobs
+    }
+}
+
 /// Gets coord index from core problem definition.
 pub fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
     problem.extras.get("coord_index").and_then(|s| s.downcast_ref::<CoordIndex>()).expect("cannot get coord index!")
@@ -100,8 +100,8 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             fixed: vehicle.costs.fixed.unwrap_or(0.),
             per_distance: vehicle.costs.distance,
             per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
-            per_service_time: vehicle.costs.time,
+            per_waiting_time: vehicle.costs.waiting.unwrap_or(vehicle.costs.time),
+            per_service_time: vehicle.costs.service.unwrap_or(vehicle.costs.time),
         };
 
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
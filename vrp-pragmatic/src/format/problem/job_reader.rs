@@ -4,7 +4,7 @@ use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
 use crate::format::problem::JobSkills as FormatJobSkills;
 use crate::format::problem::*;
-use crate::format::{JobIndex, Location};
+use crate::format::{get_indexed_jobs, get_split_job_key, JobIndex, Location};
 use crate::parse_time;
 use crate::utils::VariableJobPermutation;
 use hashbrown::HashMap;
@@ -81,12 +81,13 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                         }
                         _ => job.clone(),
                     };
-                    let job = job_index
-                        .get(&job_id)
-                        .cloned()
-                        .unwrap_or_else(|| panic!("cannot find job with id: '{};", job_id));
+                    // NOTE all partial jobs of split job are locked
+                    let indexed_jobs = get_indexed_jobs(job_index, &job_id);
+                    if indexed_jobs.is_empty() {
+                        panic!("cannot find job with id: '{};", job_id);
+                    }
 
-                    jobs.push(job);
+                    jobs.extend(indexed_jobs.into_iter().cloned());
 
                     (indexer, jobs)
                 });
@@ -111,6 +112,7 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let max_capacity = get_max_vehicle_capacity(&api_problem.fleet);
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
@@ -160,6 +162,34 @@ fn read_required_jobs(
 
         assert!(!singles.is_empty());
 
+        if let Some(split) = job.split.as_ref() {
+            let (task, activity_type) = job
+                .pickups
+                .iter()
+                .flatten()
+                .map(|task| (task, "pickup"))
+                .chain(job.deliveries.iter().flatten().map(|task| (task, "delivery")))
+                .next()
+                .expect("split job should have a pickup or delivery task");
+
+            let partial_jobs = get_split_tasks(task, split, max_capacity.as_slice()).into_iter().map(|task| {
+                let mut single = get_single_from_task(&task, activity_type, true);
+                single.dimens.set_job_split(true);
+                get_single_job(job, single)
+            });
+
+            // NOTE the first partial job is also registered by job id to keep id based lookups working
+            partial_jobs.enumerate().for_each(|(idx, problem_job)| {
+                if idx == 0 {
+                    job_index.insert(job.id.clone(), problem_job.clone());
+                }
+                job_index.insert(get_split_job_key(&job.id, idx + 1), problem_job.clone());
+                jobs.push(problem_job);
+            });
+
+            return;
+        }
+
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
             get_multi_job(job, singles, deliveries_start_index, random)
@@ -352,6 +382,28 @@ fn get_conditional_job(
     single
 }
 
+/// Creates tasks for partial visits of split job. Each partial visit keeps original task places, but
+/// service duration is pro-rated by the share of the served demand and rounded up to whole seconds.
+fn get_split_tasks(task: &JobTask, split: &JobSplit, capacity: &[i32]) -> Vec<JobTask> {
+    let demand = task.demand.clone().unwrap_or_default();
+    let demands = get_split_demands(demand.as_slice(), split.min_size.as_slice(), capacity);
+
+    let total = demand.iter().sum::<i32>().max(1) as f64;
+
+    demands
+        .into_iter()
+        .map(|demand| {
+            let part = demand.iter().sum::<i32>() as f64;
+            let places = task
+                .places
+                .iter()
+                .map(|place| JobPlace { duration: (place.duration * part / total).ceil(), ..place.clone() });
+
+            JobTask { places: places.collect(), demand: Some(demand), ..task.clone() }
+        })
+        .collect()
+}
+
 fn add_conditional_job(job_index: &mut JobIndex, jobs: &mut Vec<Job>, job_id: String, single: Single) {
     let job = Job::Single(Arc::new(single));
     job_index.insert(job_id, job.clone());
@@ -414,6 +466,7 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
+        .set_job_mandatory(job.mandatory)
         .set_job_skills(get_skills(&job.skills));
 
     Job::Single(Arc::new(single))
@@ -431,6 +484,7 @@ fn get_multi_job(
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
+        .set_job_mandatory(job.mandatory)
         .set_job_skills(get_skills(&job.skills));
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
@@ -11,3 +11,48 @@ pub use self::reader::PragmaticProblem;
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
     job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
 }
+
+/// Returns the largest vehicle capacity in each dimension.
+pub(crate) fn get_max_vehicle_capacity(fleet: &Fleet) -> Vec<i32> {
+    fleet.vehicles.iter().fold(vec![], |mut acc, vehicle| {
+        acc.resize(acc.len().max(vehicle.capacity.len()), 0);
+        acc.iter_mut().zip(vehicle.capacity.iter()).for_each(|(acc, &capacity)| *acc = (*acc).max(capacity));
+
+        acc
+    })
+}
+
+/// Partitions demand of split job into the fewest parts as equal as possible, so that each part fits
+/// the largest vehicle capacity. The amount of parts is limited so that each part is not less than
+/// min split size in each dimension where min size is positive.
+pub(crate) fn get_split_demands(demand: &[i32], min_size: &[i32], capacity: &[i32]) -> Vec<Vec<i32>> {
+    let max_parts = demand
+        .iter()
+        .zip(min_size.iter())
+        .filter(|(_, &min_size)| min_size > 0)
+        .map(|(&demand, &min_size)| demand / min_size)
+        .min()
+        .unwrap_or(1)
+        .max(1);
+
+    let parts = demand
+        .iter()
+        .zip(capacity.iter())
+        .filter(|(_, &capacity)| capacity > 0)
+        .map(|(&demand, &capacity)| (demand + capacity - 1) / capacity)
+        .max()
+        .unwrap_or(1)
+        .clamp(1, max_parts) as usize;
+
+    (0..parts)
+        .map(|idx| {
+            demand
+                .iter()
+                .map(|&value| {
+                    let value = value as usize;
+                    (value / parts + if idx < value % parts { 1 } else { 0 }) as i32
+                })
+                .collect()
+        })
+        .collect()
+}
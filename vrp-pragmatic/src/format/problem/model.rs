@@ -62,6 +62,15 @@ pub struct JobSkills {
     pub none_of: Option<Vec<String>>,
 }
 
+/// Specifies split delivery settings: job demand is partitioned into multiple partial visits
+/// which can be served by different vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSplit {
+    /// A minimum demand of a single partial visit.
+    pub min_size: Vec<i32>,
+}
+
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct JobPlace {
@@ -131,6 +140,15 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// If set to true, job has to be assigned: a solution with unassigned mandatory job is
+    /// considered worse than any solution with less unassigned mandatory jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandatory: Option<bool>,
+
+    /// Split delivery settings: if set, job demand can be served by multiple vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<JobSplit>,
 }
 
 // region Clustering
@@ -256,6 +274,14 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Cost per waiting time unit. If not set, time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<f64>,
+
+    /// Cost per service time unit. If not set, time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<f64>,
 }
 
 /// Specifies vehicle shift start.
@@ -240,7 +240,10 @@ fn get_load_balance(
 }
 
 fn get_unassigned_objective(break_value: f64) -> TotalUnassignedJobs {
-    TotalUnassignedJobs::new(Arc::new(move |_, job, _| get_unassigned_job_estimate(job, break_value, 1.)))
+    TotalUnassignedJobs::new_with_mandatory(
+        Arc::new(move |_, job, _| get_unassigned_job_estimate(job, break_value, 1.)),
+        Arc::new(|job| job.dimens().get_job_mandatory()),
+    )
 }
 
 fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64) -> f64 {
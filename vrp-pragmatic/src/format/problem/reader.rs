@@ -121,6 +121,7 @@ pub struct ProblemProperties {
     has_order: bool,
     has_group: bool,
     has_compatibility: bool,
+    has_mandatory: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
     max_job_value: Option<f64>,
@@ -351,6 +352,10 @@ fn create_constraint_pipeline(
         constraint.add_module(Arc::new(CompatibilityModule::new(COMPATIBILITY_CONSTRAINT_CODE, COMPATIBILITY_KEY)));
     }
 
+    if props.has_mandatory {
+        constraint.add_module(Arc::new(MandatoryModule::new(MANDATORY_CONSTRAINT_CODE, transport.clone())));
+    }
+
     if props.has_group {
         constraint.add_module(Arc::new(GroupModule::new(jobs.size(), GROUP_CONSTRAINT_CODE, GROUP_KEY)));
     }
@@ -575,6 +580,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+    let has_mandatory = api_problem.plan.jobs.iter().any(|job| job.mandatory.unwrap_or(false));
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
 
@@ -594,6 +600,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_order,
         has_group,
         has_compatibility,
+        has_mandatory,
         has_tour_size_limits,
         has_tour_travel_limits,
         max_job_value,
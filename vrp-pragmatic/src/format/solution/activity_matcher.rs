@@ -3,7 +3,7 @@ use crate::format::problem::VehicleBreak;
 use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
 use crate::format::solution::{PointStop, TransitStop};
-use crate::format::{CoordIndex, JobIndex};
+use crate::format::{get_indexed_jobs, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
@@ -18,13 +18,15 @@ use vrp_core::utils::compare_floats;
 pub(crate) struct JobInfo(pub Job, pub Arc<Single>, pub Place, pub TimeWindow);
 
 /// Tries to match given activity to core job models. None is returned in case of
-/// non-job activity (departure, arrival).
+/// non-job activity (departure, arrival). Partial jobs of split job are matched by served demand
+/// skipping already used ones.
 pub(crate) fn try_match_point_job(
     tour: &FormatTour,
     stop: &PointStop,
     activity: &FormatActivity,
     job_index: &JobIndex,
     coord_index: &CoordIndex,
+    used_jobs: &HashSet<Job>,
 ) -> Result<Option<JobInfo>, String> {
     let ctx = ActivityContext {
         route_start_time: get_route_start_time(tour)?,
@@ -40,8 +42,13 @@ pub(crate) fn try_match_point_job(
     match activity.activity_type.as_str() {
         "departure" | "arrival" => Ok(None),
         "pickup" | "delivery" | "replacement" | "service" => {
-            let job =
-                job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
+            let job = get_indexed_jobs(job_index, &activity.job_id)
+                .into_iter()
+                .find(|&job| match get_split_demand(job) {
+                    Some(demand) => !used_jobs.contains(job) && activity.demand.iter().all(|d| *d == demand),
+                    None => true,
+                })
+                .ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
             let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
                 Job::Single(single) => Box::new(once(single)),
                 Job::Multi(multi) => {
@@ -251,3 +258,14 @@ fn get_activity_time(activity: &FormatActivity, stop_schedule: &FormatSchedule)
 fn get_route_start_time(tour: &FormatTour) -> Result<Timestamp, String> {
     tour.stops.first().map(|stop| parse_time(&stop.schedule().departure)).ok_or_else(|| "empty route".to_owned())
 }
+
+fn get_split_demand(job: &Job) -> Option<Vec<i32>> {
+    let dimens = &job.as_single().filter(|single| single.dimens.get_job_split())?.dimens;
+
+    let multi_dimen: Option<&Demand<MultiDimLoad>> = dimens.get_demand();
+    let single_dimen: Option<&Demand<SingleDimLoad>> = dimens.get_demand();
+
+    multi_dimen
+        .map(|demand| (demand.pickup.0 + demand.delivery.0).as_vec())
+        .or_else(|| single_dimen.map(|demand| vec![demand.pickup.0.value + demand.delivery.0.value]))
+}
@@ -8,7 +8,7 @@ use crate::format::solution::Activity as FormatActivity;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::{deserialize_solution, map_reason_code};
-use crate::format::{get_coord_index, get_job_index, CoordIndex, JobIndex};
+use crate::format::{get_coord_index, get_indexed_jobs, get_job_index, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::io::{BufReader, Read};
@@ -61,10 +61,10 @@ pub fn read_init_solution<R: Read>(
     let mut unassigned = solution.unassigned.unwrap_or_default().iter().try_fold::<Vec<_>, _, Result<_, String>>(
         Default::default(),
         |mut acc, unassigned_job| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
-                .cloned()
-                .ok_or_else(|| format!("cannot get job id for: {:?}", unassigned_job))?;
+            let jobs = get_indexed_jobs(job_index, &unassigned_job.job_id);
+            if jobs.is_empty() {
+                return Err(format!("cannot get job id for: {:?}", unassigned_job));
+            }
             // NOTE we take the first reason only and map it to simple variant
             let code = unassigned_job
                 .reasons
@@ -72,8 +72,10 @@ pub fn read_init_solution<R: Read>(
                 .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
                 .ok_or_else(|| format!("cannot get reason for: {:?}", unassigned_job))?;
 
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
+            // NOTE split job is reported once, so all its partial jobs which are not assigned are unassigned
+            jobs.into_iter().filter(|&job| added_jobs.insert(job.clone())).for_each(|job| {
+                acc.push((job.clone(), code.clone()));
+            });
 
             Ok(acc)
         },
@@ -104,7 +106,8 @@ fn try_insert_activity(
         FormatStop::Point(stop) => stop,
     };
 
-    if let Some(JobInfo(job, single, place, time)) = try_match_point_job(tour, stop, activity, job_index, coord_index)?
+    if let Some(JobInfo(job, single, place, time)) =
+        try_match_point_job(tour, stop, activity, job_index, coord_index, added_jobs)?
     {
         added_jobs.insert(job);
         insert_new_activity(route, single, place, time);
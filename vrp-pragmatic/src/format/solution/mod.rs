@@ -13,6 +13,9 @@ pub use self::initial_reader::read_init_solution;
 
 mod extensions;
 
+mod unassigned;
+pub use self::unassigned::get_unassigned_reasons_histogram;
+
 mod writer;
 pub use self::writer::create_solution;
 pub use self::writer::PragmaticSolution;
@@ -46,6 +49,7 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         RELOAD_RESOURCE_CONSTRAINT_CODE => {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
+        MANDATORY_CONSTRAINT_CODE => ("MANDATORY_CONSTRAINT", "cannot be assigned due to mandatory constraint"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -67,6 +71,7 @@ fn map_reason_code(reason: &str) -> i32 {
         "GROUP_CONSTRAINT" => GROUP_CONSTRAINT_CODE,
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
+        "MANDATORY_CONSTRAINT" => MANDATORY_CONSTRAINT_CODE,
         _ => -1,
     }
 }
@@ -100,6 +100,9 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// A demand served by activity, specified only for a partial visit of split job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub demand: Option<Vec<i32>>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
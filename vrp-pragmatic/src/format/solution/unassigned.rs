@@ -0,0 +1,31 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/unassigned_test.rs"]
+mod unassigned_test;
+
+use crate::format::solution::Solution;
+use hashbrown::{HashMap, HashSet};
+
+/// Aggregates unassigned job reasons across given solutions into a histogram: a list of reason codes
+/// within amount of unassigned jobs which have that reason, sorted by amount in descending order.
+/// A job with multiple reasons is counted once for each distinct reason code.
+pub fn get_unassigned_reasons_histogram<'a, I>(solutions: I) -> Vec<(String, usize)>
+where
+    I: IntoIterator<Item = &'a Solution>,
+{
+    let mut histogram = solutions
+        .into_iter()
+        .filter_map(|solution| solution.unassigned.as_ref())
+        .flat_map(|unassigned| unassigned.iter())
+        .flat_map(|job| job.reasons.iter().map(|reason| reason.code.as_str()).collect::<HashSet<_>>().into_iter())
+        .fold(HashMap::<&str, usize>::new(), |mut acc, code| {
+            *acc.entry(code).or_insert(0) += 1;
+            acc
+        })
+        .into_iter()
+        .map(|(code, count)| (code.to_string(), count))
+        .collect::<Vec<_>>();
+
+    histogram.sort_by(|(a_code, a_count), (b_code, b_count)| b_count.cmp(a_count).then_with(|| a_code.cmp(b_code)));
+
+    histogram
+}
@@ -9,6 +9,7 @@ use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
 use crate::{format_time, parse_time};
+use hashbrown::HashSet;
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
 use vrp_core::construction::extensions::route_intervals;
@@ -186,6 +187,7 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    demand: None,
                 }],
                 parking: None,
             }));
@@ -308,6 +310,7 @@ fn create_tour(
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    demand: get_split_demand(act, is_multi_dimen),
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -473,6 +476,7 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                             }),
                             job_tag: None,
                             commute: None,
+                            demand: None,
                         },
                     );
 
@@ -507,16 +511,26 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
+fn get_split_demand(act: &Activity, is_multi_dimen: bool) -> Option<Vec<i32>> {
+    let job = act.job.as_ref().filter(|job| job.dimens.get_job_split())?;
+    let demand = get_capacity(&job.dimens, is_multi_dimen).unwrap_or_default();
+
+    Some((demand.delivery.0 + demand.pickup.0).as_vec())
+}
+
 fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     let create_simple_reasons = |code: i32| {
         let (code, reason) = map_code_reason(code);
         vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
     };
 
+    let mut split_jobs = HashSet::new();
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
+        // NOTE report split job once even if multiple partial visits are not assigned
+        .filter(|(job, _)| !job.dimens().get_job_split() || split_jobs.insert(job.dimens().get_job_id().cloned()))
         .map(|(job, code)| {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
@@ -4,6 +4,7 @@ mod jobs_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -179,6 +180,55 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that split job has a single pickup or delivery task with proper min split size.
+fn check_e1108_invalid_split_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let used_in_relations = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.split.as_ref().map(|split| (job, split)))
+        .filter(|(job, split)| {
+            let tasks = job.pickups.iter().chain(job.deliveries.iter()).flatten().collect::<Vec<_>>();
+            let task = match tasks.as_slice() {
+                [task] if ctx.tasks(job).len() == 1 => Some(*task),
+                _ => None,
+            };
+
+            let has_valid_size = task
+                .and_then(|task| task.demand.as_ref())
+                .filter(|demand| {
+                    demand.len() == split.min_size.len()
+                        && split.min_size.iter().all(|&size| size >= 0)
+                        && split.min_size.iter().any(|&size| size > 0)
+                })
+                .is_some();
+
+            !has_valid_size || used_in_relations.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid split job".to_string(),
+            format!(
+                "use a single pickup or delivery task with min split size matching its demand dimensions \
+                 and do not use split jobs in relations: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -190,5 +240,6 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_split_jobs(ctx),
     ])
 }
@@ -76,7 +76,8 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                demand: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -87,7 +88,8 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                demand: None
                             }
                         ],
                     }),
@@ -207,7 +209,8 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                demand: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -218,7 +221,8 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                demand: None
                             }
                         ],
                     }),
@@ -103,7 +103,8 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                demand: None
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -114,7 +115,8 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                demand: None
                             }
                         ],
                     }),
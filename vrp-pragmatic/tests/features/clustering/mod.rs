@@ -45,6 +45,7 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            demand: None,
         }
     }
 }
@@ -74,6 +74,7 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                demand: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -85,6 +86,7 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                demand: None,
                             },
                         ],
                     }),
@@ -137,6 +139,7 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                demand: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -148,6 +151,7 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                demand: None,
                             },
                         ],
                     }),
@@ -30,3 +30,30 @@ fn can_use_scale() {
     assert_eq!(tour.statistic.distance, 20);
     assert_eq!(tour.statistic.duration, 11)
 }
+
+parameterized_test! {can_use_scale_with_time_window, (scale, is_assigned), {
+    can_use_scale_with_time_window_impl(scale, is_assigned);
+}}
+
+can_use_scale_with_time_window! {
+    case01_fast: (Some(0.5), true),
+    case02_normal: (None, true),
+    case03_slow: (Some(2.), false),
+}
+
+fn can_use_scale_with_time_window_impl(scale: Option<f64>, is_assigned: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (10., 0.), vec![(0, 15)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_type("vehicle", scale)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.is_none(), is_assigned);
+    assert_eq!(solution.tours.len(), if is_assigned { 1 } else { 0 });
+}
@@ -15,6 +15,7 @@ mod priorities;
 mod relations;
 mod reload;
 mod skills;
+mod split;
 mod timing;
 mod unassigned;
 mod work_balance;
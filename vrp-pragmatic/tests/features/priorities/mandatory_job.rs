@@ -0,0 +1,38 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_serve_mandatory_job_and_drop_optional, objectives, {
+    can_serve_mandatory_job_and_drop_optional_impl(objectives);
+}}
+
+can_serve_mandatory_job_and_drop_optional! {
+    case01: Some(vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]),
+    case02: None,
+}
+
+fn can_serve_mandatory_job_and_drop_optional_impl(objectives: Option<Vec<Vec<Objective>>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { mandatory: Some(true), ..create_delivery_job("job2", (5., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![1], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job2"], vec!["arrival"]]);
+    let unassigned = solution.unassigned.expect("unassigned jobs are expected");
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+}
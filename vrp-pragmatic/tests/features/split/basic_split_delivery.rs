@@ -0,0 +1,88 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::UnassignmentInfo;
+
+fn create_split_job(id: &str, location: (f64, f64), demand: i32, min_size: i32) -> Job {
+    Job {
+        split: Some(JobSplit { min_size: vec![min_size] }),
+        ..create_delivery_job_with_demand(id, location, vec![demand])
+    }
+}
+
+fn get_served_demand(solution: &Solution, job_id: &str) -> Vec<i32> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .filter(|activity| activity.job_id == job_id)
+        .map(|activity| activity.demand.as_ref().map_or(0, |demand| demand[0]))
+        .collect()
+}
+
+fn create_split_problem(vehicles: Vec<&str>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_split_job("job1", (1., 0.), 25, 5)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicles.iter().map(|id| id.to_string()).collect(),
+                capacity: vec![10],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_split_delivery_exceeding_vehicle_capacity, (vehicles, expected_tours, expected_parts, is_unassigned), {
+    can_split_delivery_exceeding_vehicle_capacity_impl(vehicles, expected_tours, expected_parts, is_unassigned);
+}}
+
+can_split_delivery_exceeding_vehicle_capacity! {
+    case01_enough_vehicles: (vec!["v1", "v2", "v3"], 3, 3, false),
+    case02_not_enough_vehicles: (vec!["v1"], 1, 1, true),
+}
+
+fn can_split_delivery_exceeding_vehicle_capacity_impl(
+    vehicles: Vec<&str>,
+    expected_tours: usize,
+    expected_parts: usize,
+    is_unassigned: bool,
+) {
+    let problem = create_split_problem(vehicles);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let served = get_served_demand(&solution, "job1");
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert_eq!(served.len(), expected_parts);
+    assert!(served.iter().all(|&demand| (8..=9).contains(&demand)));
+    assert_eq!(served.iter().sum::<i32>() == 25, !is_unassigned);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), if is_unassigned { Some(1) } else { None });
+}
+
+parameterized_test! {can_read_init_solution_with_split_job, (vehicles, expected_unassigned), {
+    can_read_init_solution_with_split_job_impl(vehicles, expected_unassigned);
+}}
+
+can_read_init_solution_with_split_job! {
+    case01_all_assigned: (vec!["v1", "v2", "v3"], 0),
+    case02_partially_assigned: (vec!["v1"], 2),
+}
+
+fn can_read_init_solution_with_split_job_impl(vehicles: Vec<&str>, expected_unassigned: usize) {
+    let problem = create_split_problem(vehicles);
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let core_solution = to_core_solution(&solution, core_problem, create_random()).expect("cannot read init solution");
+
+    let assigned = core_solution.routes.iter().map(|route| route.tour.job_count()).sum::<usize>();
+    assert_eq!(assigned, 3 - expected_unassigned);
+    assert_eq!(core_solution.unassigned.len(), expected_unassigned);
+    assert!(core_solution.unassigned.iter().all(|(_, code)| matches!(code, UnassignmentInfo::Simple(_))));
+}
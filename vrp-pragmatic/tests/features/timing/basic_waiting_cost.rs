@@ -0,0 +1,53 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_type(type_id: &str, location: (f64, f64), waiting: Option<f64>) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{}_1", type_id)],
+        costs: VehicleCosts { waiting, ..create_default_vehicle_costs() },
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: location.to_loc() },
+            ..create_default_vehicle_shift_with_locations(location, location)
+        }],
+        ..create_default_vehicle_type()
+    }
+}
+
+parameterized_test! {can_use_waiting_cost_to_select_vehicle, (waiting, expected_vehicle, expected_cost), {
+    can_use_waiting_cost_to_select_vehicle_impl(waiting, expected_vehicle, expected_cost);
+}}
+
+can_use_waiting_cost_to_select_vehicle! {
+    case01_default_waiting_cost: (None, "near_1", 60.),
+    case02_zero_waiting_cost: (Some(0.), "near_1", 50.),
+    case03_high_waiting_cost: (Some(10.), "far_1", 120.),
+}
+
+fn can_use_waiting_cost_to_select_vehicle_impl(waiting: Option<f64>, expected_vehicle: &str, expected_cost: f64) {
+    // NOTE near vehicle drives 10 and waits 10 time units, far vehicle drives 15 and waits 5
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (10., 0.), vec![(20, 30)], 0.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_type("near", (0., 0.), waiting),
+                create_vehicle_type("far", (25., 0.), waiting),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.vehicle_id, expected_vehicle);
+    assert_eq!(tour.statistic.cost, expected_cost);
+}
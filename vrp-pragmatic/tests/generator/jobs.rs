@@ -90,7 +90,9 @@ prop_compose! {
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            mandatory: None,
+            split: None,
         }
     }
 }
@@ -137,6 +139,8 @@ prop_compose! {
             value,
             group,
             compatibility,
+            mandatory: None,
+            split: None,
         }
     }
 }
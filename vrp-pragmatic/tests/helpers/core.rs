@@ -116,13 +116,14 @@ pub fn single_demand_as_multi(pickup: (i32, i32), delivery: (i32, i32)) -> Deman
 pub fn create_solution_context_for_fleet(fleet: &Fleet) -> SolutionContext {
     let constraint = Arc::new(ConstraintPipeline::default());
     SolutionContext {
-        required: vec![],
-        ignored: vec![],
+        required: Default::default(),
+        ignored: Default::default(),
         unassigned: Default::default(),
         locked: Default::default(),
         state: Default::default(),
         routes: Default::default(),
         registry: RegistryContext::new(constraint, Registry::new(fleet, Arc::new(DefaultRandom::default()))),
+        fitness_cache: Default::default(),
     }
 }
 
@@ -23,6 +23,8 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        mandatory: None,
+        split: None,
     }
 }
 
@@ -222,7 +224,7 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: None, service: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
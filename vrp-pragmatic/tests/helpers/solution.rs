@@ -63,6 +63,7 @@ fn create_stop_with_activity_impl(
             time: None,
             job_tag,
             commute: None,
+            demand: None,
         }],
         parking: None,
     })
@@ -103,6 +103,7 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        demand: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -112,6 +113,7 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            demand: None,
         });
     }
 
@@ -90,6 +90,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             time: None,
                             job_tag: None,
                             commute: None,
+                            demand: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -98,6 +99,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             time: None,
                             job_tag: Some("p1".to_string()),
                             commute: None,
+                            demand: None,
                         },
                     ],
                 }),
@@ -117,6 +119,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         time: None,
                         job_tag: None,
                         commute: None,
+                        demand: None,
                     }],
                 }),
                 Stop::Point(PointStop {
@@ -139,6 +142,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             }),
                             job_tag: None,
                             commute: None,
+                            demand: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -150,6 +154,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             }),
                             job_tag: None,
                             commute: None,
+                            demand: None,
                         },
                     ],
                 }),
@@ -232,6 +237,7 @@ fn can_check_load_when_departure_has_other_activity() {
                             time: None,
                             job_tag: None,
                             commute: None,
+                            demand: None,
                         },
                         Activity {
                             job_id: "job1".to_string(),
@@ -240,6 +246,7 @@ fn can_check_load_when_departure_has_other_activity() {
                             time: None,
                             job_tag: Some("p1".to_string()),
                             commute: None,
+                            demand: None,
                         },
                     ],
                 }),
@@ -155,6 +155,7 @@ mod single {
                                     time: None,
                                     job_tag: None,
                                     commute: None,
+                                    demand: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -163,6 +164,7 @@ mod single {
                                     time: None,
                                     job_tag: None,
                                     commute: None,
+                                    demand: None,
                                 },
                             ],
                         }),
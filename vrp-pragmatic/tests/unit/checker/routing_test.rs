@@ -48,6 +48,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         time: None,
                         job_tag: None,
                         commute: None,
+                        demand: None,
                     }],
                 }),
                 Stop::Point(PointStop {
@@ -63,6 +64,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         time: None,
                         job_tag: None,
                         commute: None,
+                        demand: None,
                     }],
                 }),
                 create_stop_with_activity(
@@ -52,7 +52,8 @@ fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_
                 ],
             )),
             Arc::new(RouteState::default()),
-        )],
+        )]
+        .into(),
         ..create_solution_context_for_fleet(&fleet)
     };
 
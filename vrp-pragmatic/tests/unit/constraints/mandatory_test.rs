@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::common::Dimensions;
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, Multi};
+
+const MANDATORY_CODE: i32 = 1;
+
+fn create_test_job(mandatory: Option<bool>) -> Job {
+    let mut single = create_single_with_location(Some(1));
+    single.dimens.set_job_mandatory(mandatory);
+    Job::Single(Arc::new(single))
+}
+
+fn create_test_multi_job(mandatory: Option<bool>) -> Job {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_mandatory(mandatory);
+    let singles = vec![Arc::new(create_single_with_location(Some(1))), Arc::new(create_single_with_location(Some(2)))];
+
+    Job::Multi(Multi::new_shared(singles, dimens))
+}
+
+fn create_test_route_ctx() -> RouteContext {
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&test_fleet(), "v1", vec![])),
+        Arc::new(Default::default()),
+    )
+}
+
+fn create_test_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, vec![0., 5., 5., 0.], vec![0., 5., 5., 0.])])
+        .expect("cannot create transport")
+}
+
+parameterized_test! {can_estimate_job, (mandatory, expected), {
+    can_estimate_job_impl(mandatory, expected);
+}}
+
+can_estimate_job! {
+    case_01: (Some(true), -40.),
+    case_02: (Some(false), 0.),
+    case_03: (None, 0.),
+}
+
+fn can_estimate_job_impl(mandatory: Option<bool>, expected: f64) {
+    let solution_ctx = create_solution_context_for_fleet(&test_fleet());
+    let route_ctx = create_test_route_ctx();
+    let constraint = MandatorySoftRouteConstraint { transport: create_test_transport() };
+
+    let result = constraint.estimate_job(&solution_ctx, &route_ctx, &create_test_job(mandatory));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case_01: (Some(true), None, true),
+    case_02: (None, Some(true), true),
+    case_03: (None, None, false),
+    case_04: (Some(false), Some(false), false),
+}
+
+fn can_merge_jobs_impl(source: Option<bool>, candidate: Option<bool>, expected: bool) {
+    let result = MandatoryModule::new(MANDATORY_CODE, create_test_transport())
+        .merge(create_test_job(source), create_test_job(candidate));
+
+    assert_eq!(result.map(|job| job.dimens().get_job_mandatory()), Ok(expected));
+}
+
+parameterized_test! {can_merge_multi_job, (source, candidate, expected), {
+    can_merge_multi_job_impl(source, candidate, expected);
+}}
+
+can_merge_multi_job! {
+    case_01_mandatory_source: (Some(true), Some(true), Ok(true)),
+    case_02_optional_candidate: (None, None, Ok(false)),
+    case_03_mandatory_candidate: (None, Some(true), Err(MANDATORY_CODE)),
+}
+
+fn can_merge_multi_job_impl(source: Option<bool>, candidate: Option<bool>, expected: Result<bool, i32>) {
+    let result = MandatoryModule::new(MANDATORY_CODE, create_test_transport())
+        .merge(create_test_multi_job(source), create_test_job(candidate));
+
+    assert_eq!(result.map(|job| job.dimens().get_job_mandatory()), expected);
+}
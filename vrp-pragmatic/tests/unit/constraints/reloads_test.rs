@@ -181,7 +181,8 @@ fn can_remove_trivial_reloads_when_used_from_capacity_constraint_impl(
     let threshold = 0.9;
 
     let (route_ctx, fleet) = create_route_context_with_fleet(vec![capacity], activities);
-    let mut solution_ctx = SolutionContext { routes: vec![route_ctx], ..create_solution_context_for_fleet(&fleet) };
+    let mut solution_ctx =
+        SolutionContext { routes: vec![route_ctx].into(), ..create_solution_context_for_fleet(&fleet) };
     let mut pipeline = ConstraintPipeline::default();
     pipeline.add_module(Arc::new(CapacityConstraintModule::new_with_multi_trip(
         1,
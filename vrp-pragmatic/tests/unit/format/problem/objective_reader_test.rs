@@ -21,6 +21,7 @@ fn create_problem_props() -> ProblemProperties {
         has_order: false,
         has_group: false,
         has_compatibility: false,
+        has_mandatory: false,
         has_tour_size_limits: false,
         has_tour_travel_limits: false,
         max_job_value: None,
@@ -1,5 +1,6 @@
 use super::create_approx_matrices;
 use crate::extensions::{JobTie, VehicleTie};
+use crate::format::get_job_index;
 use crate::format::problem::*;
 use crate::helpers::*;
 use hashbrown::HashSet;
@@ -139,7 +140,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., waiting: None, service: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -267,6 +268,45 @@ fn can_read_complex_problem() {
     });
 }
 
+parameterized_test! {can_read_split_job, (demand, min_size, expected), {
+    can_read_split_job_impl(demand, min_size, expected);
+}}
+
+can_read_split_job! {
+    case01_fewest_parts: (vec![25, 0], vec![5, 0], vec![vec![9, 0], vec![8, 0], vec![8, 0]]),
+    case02_fits_vehicle: (vec![7, 0], vec![3, 0], vec![vec![7, 0]]),
+    case03_multi_dimens: (vec![10, 24], vec![0, 2], vec![vec![4, 8], vec![3, 8], vec![3, 8]]),
+    case04_demand_less_than_min: (vec![3, 0], vec![5, 0], vec![vec![3, 0]]),
+    case05_limited_by_min_size: (vec![30, 0], vec![20, 0], vec![vec![30, 0]]),
+}
+
+fn can_read_split_job_impl(demand: Vec<i32>, min_size: Vec<i32>, expected: Vec<Vec<i32>>) {
+    let total = demand.iter().sum::<i32>() as f64;
+    let mut job =
+        Job { split: Some(JobSplit { min_size }), ..create_delivery_job_with_demand("job1", (1., 0.), demand) };
+    job.deliveries.as_mut().unwrap()[0].places[0].duration = 100.;
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("v1", vec![10, 10])], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let job_index = get_job_index(&problem);
+    let jobs = problem.jobs.all().map(|job| job.to_single().clone()).collect::<Vec<_>>();
+    assert_eq!(jobs.len(), expected.len());
+    jobs.iter().zip(expected).enumerate().for_each(|(idx, (job, expected))| {
+        assert_eq!(job.dimens.get_job_id().unwrap(), "job1");
+        assert!(job.dimens.get_job_split());
+        assert_eq!(get_single_place(job).duration, (100. * expected.iter().sum::<i32>() as f64 / total).ceil());
+        let demand: &Demand<MultiDimLoad> = job.dimens.get_demand().unwrap();
+        assert_eq!(demand.delivery.0.as_vec(), expected);
+        assert!(Arc::ptr_eq(job_index.get(&format!("job1_split_{}", idx + 1)).unwrap().to_single(), job));
+    });
+}
+
 #[test]
 fn can_deserialize_minimal_problem_and_matrix() {
     let problem = (SIMPLE_PROBLEM.to_string(), vec![SIMPLE_MATRIX.to_string()]).read_pragmatic().ok().unwrap();
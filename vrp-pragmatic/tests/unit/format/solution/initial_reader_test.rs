@@ -137,6 +137,7 @@ fn can_read_basic_init_solution() {
                             }),
                             job_tag: Some("p2".to_owned()),
                             commute: None,
+                            demand: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
@@ -148,6 +149,7 @@ fn can_read_basic_init_solution() {
                             }),
                             job_tag: None,
                             commute: None,
+                            demand: None,
                         },
                     ],
                 }),
@@ -238,6 +240,7 @@ fn can_handle_commute_error_in_init_solution() {
                         }),
                         job_tag: None,
                         commute: Some(Commute { forward: None, backward: None }),
+                        demand: None,
                     }],
                 }),
             ],
@@ -0,0 +1,55 @@
+use super::*;
+use crate::format::solution::{UnassignedJob, UnassignedJobReason};
+use crate::helpers::create_empty_solution;
+
+fn create_solution(unassigned: Vec<(&str, Vec<&str>)>) -> Solution {
+    Solution {
+        unassigned: Some(
+            unassigned
+                .into_iter()
+                .map(|(job_id, codes)| UnassignedJob {
+                    job_id: job_id.to_string(),
+                    reasons: codes
+                        .into_iter()
+                        .map(|code| UnassignedJobReason {
+                            code: code.to_string(),
+                            description: "".to_string(),
+                            details: None,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        ),
+        ..create_empty_solution()
+    }
+}
+
+#[test]
+fn can_get_unassigned_reasons_histogram_for_multiple_solutions() {
+    let solutions = [
+        create_solution(vec![
+            ("job1", vec!["CAPACITY_CONSTRAINT"]),
+            ("job2", vec!["TIME_WINDOW_CONSTRAINT", "CAPACITY_CONSTRAINT", "CAPACITY_CONSTRAINT"]),
+        ]),
+        create_empty_solution(),
+        create_solution(vec![("job1", vec!["SKILL_CONSTRAINT"]), ("job3", vec!["CAPACITY_CONSTRAINT"])]),
+    ];
+
+    let histogram = get_unassigned_reasons_histogram(solutions.iter());
+
+    assert_eq!(
+        histogram,
+        vec![
+            ("CAPACITY_CONSTRAINT".to_string(), 3),
+            ("SKILL_CONSTRAINT".to_string(), 1),
+            ("TIME_WINDOW_CONSTRAINT".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn can_get_empty_unassigned_reasons_histogram() {
+    let solution = create_empty_solution();
+
+    assert!(get_unassigned_reasons_histogram(std::iter::once(&solution)).is_empty());
+}
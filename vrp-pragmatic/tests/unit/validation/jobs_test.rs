@@ -74,3 +74,45 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_split_job, (job, has_relation, is_valid), {
+    can_detect_invalid_split_job_impl(job, has_relation, is_valid);
+}}
+
+can_detect_invalid_split_job! {
+    case01_valid: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), false, true),
+    case02_used_in_relation: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), true, false),
+    case03_multi_job: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), false, false),
+    case04_service: (create_service_job("job1", (1., 0.)), false, false),
+    case05_wrong_dimensions: (create_delivery_job_with_demand("job1", (1., 0.), vec![10, 1]), false, false),
+}
+
+fn can_detect_invalid_split_job_impl(job: Job, has_relation: bool, is_valid: bool) {
+    let relations = if has_relation {
+        Some(vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec!["job1".to_string()],
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+        }])
+    } else {
+        None
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { split: Some(JobSplit { min_size: vec![2] }), ..job }],
+            relations,
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_invalid_split_jobs(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if is_valid {
+        assert!(result.is_none());
+    } else {
+        assert_result("E1108", "job1", result);
+    }
+}
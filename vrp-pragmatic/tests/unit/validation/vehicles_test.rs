@@ -147,7 +147,7 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, waiting: None, service: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()